@@ -2,22 +2,23 @@
 //!
 //! Uses SSoT validation queries from eure crate.
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Instant;
 
+use eure::check::{self, CheckOutcome, run_with_file_loading};
 use eure::query::{
     DecorStyle, DecorStyleKey, TextFile, TextFileContent, ValidateDocument, ValidateTargetResult,
-    ValidateTargets, ValidateTargetsResult, WithFormattedError, Workspace, WorkspaceId,
-    build_runtime, load_config,
+    WithFormattedError, Workspace, WorkspaceId, build_runtime, load_config,
 };
 use eure::query_flow::DurabilityLevel;
-use eure::report::{ErrorReports, format_error_reports};
+use eure::report::{ErrorReports, format_error_reports, format_error_reports_json};
 use eure_env::{CONFIG_FILENAME, EureConfig};
 use nu_ansi_term::Color;
 
 use crate::args::CacheArgs;
-use crate::util::{handle_formatted_error, read_input, run_query_with_file_loading_cached};
+use crate::util::{handle_formatted_error, read_input};
 
 #[derive(clap::Args)]
 pub struct Args {
@@ -39,11 +40,23 @@ pub struct Args {
     #[arg(long)]
     pub all: bool,
 
+    /// Output format for diagnostics
+    #[arg(long, value_enum, default_value = "human")]
+    pub format: OutputFormat,
+
     /// Cache-related options for remote schemas
     #[command(flatten)]
     pub cache: CacheArgs,
 }
 
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Annotated source snippets and a summary line
+    Human,
+    /// A JSON array of diagnostics on stdout
+    Json,
+}
+
 pub fn run(args: Args) {
     let current_dir = std::env::current_dir().expect("Failed to get current directory");
 
@@ -110,7 +123,7 @@ fn run_project_mode(args: Args, config_path: &Path) {
     }
 
     // Collect targets
-    let mut targets = Vec::new();
+    let mut targets = HashMap::new();
     for target_name in &target_names {
         let target = match config.get_target(target_name) {
             Some(t) => t.clone(),
@@ -126,8 +139,12 @@ fn run_project_mode(args: Args, config_path: &Path) {
                 std::process::exit(1);
             }
         };
-        targets.push((target_name.to_string(), target));
+        targets.insert(target_name.to_string(), target);
     }
+    let selected = EureConfig {
+        targets,
+        ..config.clone()
+    };
 
     // Create runtime and run validation
     let runtime = build_runtime();
@@ -140,17 +157,9 @@ fn run_project_mode(args: Args, config_path: &Path) {
     );
 
     let cache_opts = args.cache.to_cache_options();
+    let outcome = check::run_on(&runtime, &selected, config_dir, Some(&cache_opts));
 
-    let result = handle_formatted_error(run_query_with_file_loading_cached(
-        &runtime,
-        WithFormattedError::new(
-            ValidateTargets::new(Arc::new(targets), config_dir.to_path_buf()),
-            true,
-        ),
-        Some(&cache_opts),
-    ));
-
-    report_targets_result(&runtime, &result, &args, target_names.len(), start);
+    report_outcome(&runtime, &outcome, &args, start);
 }
 
 fn run_file_mode(args: Args) {
@@ -234,15 +243,27 @@ fn run_file_mode(args: Args) {
     });
 
     let cache_opts = args.cache.to_cache_options();
-    let result = handle_formatted_error(run_query_with_file_loading_cached(
+    let result = handle_formatted_error(run_with_file_loading(
         &runtime,
         WithFormattedError::new(ValidateDocument::new(doc_file.clone(), schema_file), true),
         Some(&cache_opts),
     ));
 
+    if args.format == OutputFormat::Json {
+        report_json(&runtime, &result);
+    }
     report_document_result(&runtime, file, &result, args.quiet, start);
 }
 
+/// Print diagnostics as JSON and exit with status 1 if there are any.
+fn report_json(runtime: &eure::query_flow::QueryRuntime, reports: &ErrorReports) -> ! {
+    println!(
+        "{}",
+        format_error_reports_json(runtime, reports).expect("file content should be loaded")
+    );
+    std::process::exit(if reports.is_empty() { 0 } else { 1 });
+}
+
 fn report_document_result(
     runtime: &eure::query_flow::QueryRuntime,
     file: &str,
@@ -285,24 +306,55 @@ fn report_document_result(
     }
 }
 
-fn report_targets_result(
+fn report_outcome(
     runtime: &eure::query_flow::QueryRuntime,
-    result: &Arc<ValidateTargetsResult>,
+    outcome: &CheckOutcome,
     args: &Args,
-    target_count: usize,
     start: Instant,
-) {
+) -> ! {
+    // Quiet mode treats warnings as errors
+    let exit_code = if args.quiet && outcome.warnings > 0 {
+        outcome.exit_code().max(1)
+    } else {
+        outcome.exit_code()
+    };
+
+    if !outcome.failures.is_empty() {
+        for failure in &outcome.failures {
+            eprintln!("{}", Color::Red.paint(format!("Error: {failure}")));
+        }
+        std::process::exit(exit_code);
+    }
+
+    if args.format == OutputFormat::Json {
+        let mut reports = ErrorReports::default();
+        for report in &outcome.reports {
+            reports.push(report.clone());
+        }
+        println!(
+            "{}",
+            format_error_reports_json(runtime, &reports).expect("file content should be loaded")
+        );
+        std::process::exit(exit_code);
+    }
+
     let duration_s = start.elapsed().as_secs_f64();
-    let total_files: usize = result.iter().map(|(_, r)| r.len()).sum();
-    let total_errors: usize = result
-        .iter()
-        .flat_map(|(_, r)| r.iter())
-        .filter(|(_, e)| !e.is_empty())
-        .count();
-
-    // Print per-target results if not quiet
-    if !args.quiet {
-        for (name, target_result) in result.iter() {
+    let target_count = outcome.targets.len();
+    let total_files: usize = outcome.targets.iter().map(|(_, r)| r.len()).sum();
+
+    if outcome.targets.is_empty() {
+        // Validation stopped before reaching the files
+        let mut reports = ErrorReports::default();
+        for report in &outcome.reports {
+            reports.push(report.clone());
+        }
+        eprintln!(
+            "{}",
+            format_error_reports(runtime, &reports, true).expect("file content should be loaded")
+        );
+    } else if !args.quiet {
+        // Print per-target results if not quiet
+        for (name, target_result) in outcome.targets.iter() {
             println!(
                 "\n{} Checking target: {}",
                 Color::Blue.bold().paint("→"),
@@ -320,47 +372,45 @@ fn report_targets_result(
         }
     } else {
         // In quiet mode, still print errors
-        for (_, target_result) in result.iter() {
+        for (_, target_result) in outcome.targets.iter() {
             report_target_errors(runtime, target_result);
         }
     }
 
     // Print summary
     if args.quiet {
-        if total_errors == 0 {
+        if exit_code == 0 {
             println!(
                 "eure check: {} file(s), {} target(s), ok in {:.2}s",
                 total_files, target_count, duration_s
             );
-            std::process::exit(0);
         } else {
             println!(
-                "eure check: {} file(s), {} target(s), {} error(s) in {:.2}s",
-                total_files, target_count, total_errors, duration_s
+                "eure check: {} file(s), {} target(s), {} error(s), {} warning(s) in {:.2}s",
+                total_files, target_count, outcome.errors, outcome.warnings, duration_s
             );
-            std::process::exit(1);
         }
     } else {
         println!();
-        if total_errors == 0 {
+        if exit_code == 0 {
             println!(
                 "{} Checked {} file(s) in {} target(s) - all valid",
                 Color::Green.bold().paint("✓"),
                 total_files,
                 target_count
             );
-            std::process::exit(0);
         } else {
             println!(
-                "{} Checked {} file(s) in {} target(s) - {} error(s)",
+                "{} Checked {} file(s) in {} target(s) - {} error(s), {} warning(s)",
                 Color::Red.bold().paint("✗"),
                 total_files,
                 target_count,
-                total_errors
+                outcome.errors,
+                outcome.warnings
             );
-            std::process::exit(1);
         }
     }
+    std::process::exit(exit_code);
 }
 
 fn report_target_errors(
@@ -0,0 +1,15 @@
+#[derive(clap::Args)]
+pub struct Args {
+    /// Error code to explain (e.g. E0001)
+    pub code: String,
+}
+
+pub fn run(args: Args) {
+    match eure::codes::explain(&args.code) {
+        Some(explanation) => println!("{explanation}"),
+        None => {
+            eprintln!("Unknown error code: {}", args.code);
+            std::process::exit(1);
+        }
+    }
+}
@@ -1,15 +1,15 @@
+use eure::check::run_with_file_loading;
 use eure::query::{
     DocumentToSchemaQuery, TextFile, TextFileContent, WithFormattedError, build_runtime,
 };
 use eure::query_flow::DurabilityLevel;
+use eure_document::source::SourceDocument;
 use eure_json::{Config as JsonConfig, JsonToEure};
-use eure_document::plan::LayoutPlan;
 use eure_schema::interop::VariantRepr;
 
 use crate::args::CacheArgs;
 use crate::util::{
     VariantFormat, display_path, handle_formatted_error, handle_query_error, read_input,
-    run_query_with_file_loading_cached,
 };
 
 #[derive(clap::Args)]
@@ -64,7 +64,7 @@ pub fn run(args: Args) {
             }
         };
 
-        let validated = handle_formatted_error(run_query_with_file_loading_cached(
+        let validated = handle_formatted_error(run_with_file_loading(
             &runtime,
             WithFormattedError::new(DocumentToSchemaQuery::new(schema_file), false),
             Some(&cache_opts),
@@ -98,7 +98,10 @@ pub fn run(args: Args) {
             },
             VariantFormat::Untagged => VariantRepr::Untagged,
         };
-        let config = JsonConfig { variant_repr };
+        let config = JsonConfig {
+            variant_repr,
+            ..JsonConfig::default()
+        };
 
         match runtime.query(JsonToEure::new(file.clone(), config)) {
             Ok(doc) => doc,
@@ -106,16 +109,15 @@ pub fn run(args: Args) {
         }
     };
 
-    // 6. Plan layout and build SourceDocument for formatting
+    // 6. Infer layout and build SourceDocument for formatting
     let doc = std::sync::Arc::unwrap_or_clone(document);
-    let plan = match LayoutPlan::auto(doc) {
-        Ok(plan) => plan,
+    let source_doc = match SourceDocument::from_document(doc) {
+        Ok(source_doc) => source_doc,
         Err(e) => {
-            eprintln!("Error: {e}");
+            eprintln!("Failed to lay out the converted document: {e}");
             std::process::exit(1);
         }
     };
-    let source_doc = plan.emit();
 
     // 7. Format and output
     let output = eure_fmt::format_source_document(&source_doc);
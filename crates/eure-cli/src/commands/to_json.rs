@@ -1,3 +1,4 @@
+use eure::check::run_with_file_loading;
 use eure::query::{
     DocumentToSchemaQuery, ParseDocument, TextFile, TextFileContent, WithFormattedError,
     build_runtime,
@@ -7,10 +8,7 @@ use eure_json::{Config as JsonConfig, EureToJsonFormatted};
 use eure_schema::interop::VariantRepr;
 
 use crate::args::CacheArgs;
-use crate::util::{
-    VariantFormat, display_path, handle_formatted_error, read_input,
-    run_query_with_file_loading_cached,
-};
+use crate::util::{VariantFormat, display_path, handle_formatted_error, read_input};
 
 #[derive(clap::Args)]
 pub struct Args {
@@ -72,13 +70,13 @@ pub fn run(args: Args) {
             }
         };
 
-        let validated = handle_formatted_error(run_query_with_file_loading_cached(
+        let validated = handle_formatted_error(run_with_file_loading(
             &runtime,
             WithFormattedError::new(DocumentToSchemaQuery::new(schema_file), false),
             Some(&cache_opts),
         ));
 
-        let parsed = handle_formatted_error(run_query_with_file_loading_cached(
+        let parsed = handle_formatted_error(run_with_file_loading(
             &runtime,
             WithFormattedError::new(ParseDocument::new(file.clone()), true),
             Some(&cache_opts),
@@ -107,7 +105,10 @@ pub fn run(args: Args) {
             VariantFormat::Untagged => VariantRepr::Untagged,
         };
 
-        let config = JsonConfig { variant_repr };
+        let config = JsonConfig {
+            variant_repr,
+            ..JsonConfig::default()
+        };
 
         let json = handle_formatted_error(runtime.query(WithFormattedError::new(
             EureToJsonFormatted::new(file.clone(), config),
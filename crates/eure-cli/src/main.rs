@@ -37,6 +37,8 @@ enum Commands {
     Mark(commands::mark::Args),
     /// Manage remote schema cache
     Cache(commands::cache::Args),
+    /// Explain an error code
+    Explain(commands::explain::Args),
 }
 
 fn main() {
@@ -54,5 +56,6 @@ fn main() {
         Commands::Check(args) => commands::check::run(args),
         Commands::Mark(args) => commands::mark::run(args),
         Commands::Cache(args) => commands::cache::run(args),
+        Commands::Explain(args) => commands::explain::run(args),
     }
 }
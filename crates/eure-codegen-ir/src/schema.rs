@@ -72,6 +72,7 @@ pub struct TextSchemaIr {
     pub min_length: Option<u32>,
     pub max_length: Option<u32>,
     pub pattern: Option<String>,
+    pub format: Option<String>,
     pub unknown_fields: IndexMap<String, ValueIr>,
 }
 
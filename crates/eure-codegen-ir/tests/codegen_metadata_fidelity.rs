@@ -39,6 +39,7 @@ fn preserves_root_and_default_codegen_metadata() {
                 min_length: None,
                 max_length: None,
                 pattern: None,
+                format: None,
                 unknown_fields: IndexMap::new(),
             }),
             SchemaMetadataIr::default(),
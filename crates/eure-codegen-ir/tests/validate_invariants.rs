@@ -167,6 +167,7 @@ fn rejects_empty_codegen_overrides() {
                     min_length: None,
                     max_length: None,
                     pattern: None,
+                    format: None,
                     unknown_fields: IndexMap::new(),
                 }),
                 SchemaMetadataIr::default(),
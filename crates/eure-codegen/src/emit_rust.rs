@@ -1220,6 +1220,7 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 pattern: None,
+                format: None,
                 unknown_fields: IndexMap::new(),
             })),
         );
@@ -1265,6 +1266,7 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 pattern: None,
+                format: None,
                 unknown_fields: IndexMap::new(),
             })),
         );
@@ -1348,6 +1350,7 @@ mod tests {
                     min_length: None,
                     max_length: None,
                     pattern: None,
+                    format: None,
                     unknown_fields: IndexMap::new(),
                 })),
             ),
@@ -1385,6 +1388,7 @@ mod tests {
                     min_length: None,
                     max_length: None,
                     pattern: None,
+                    format: None,
                     unknown_fields: IndexMap::new(),
                 })),
             ),
@@ -1450,6 +1454,7 @@ mod tests {
                     min_length: None,
                     max_length: None,
                     pattern: None,
+                    format: None,
                     unknown_fields: IndexMap::new(),
                 })),
             ),
@@ -1526,6 +1531,7 @@ mod tests {
                     min_length: None,
                     max_length: None,
                     pattern: None,
+                    format: None,
                     unknown_fields: IndexMap::new(),
                 })),
             ),
@@ -1634,6 +1640,7 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 pattern: None,
+                format: None,
                 unknown_fields: IndexMap::new(),
             })),
         );
@@ -1701,6 +1708,7 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 pattern: None,
+                format: None,
                 unknown_fields: IndexMap::new(),
             })),
         );
@@ -1765,6 +1773,7 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 pattern: None,
+                format: None,
                 unknown_fields: IndexMap::new(),
             })),
         );
@@ -1827,6 +1836,7 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 pattern: None,
+                format: None,
                 unknown_fields: IndexMap::new(),
             })),
         );
@@ -1888,6 +1898,7 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 pattern: None,
+                format: None,
                 unknown_fields: IndexMap::new(),
             })),
         )]);
@@ -1921,6 +1932,7 @@ mod tests {
                     min_length: None,
                     max_length: None,
                     pattern: None,
+                    format: None,
                     unknown_fields: IndexMap::new(),
                 })),
             ),
@@ -2053,6 +2065,7 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 pattern: None,
+                format: None,
                 unknown_fields: IndexMap::new(),
             })),
         );
@@ -2079,6 +2092,7 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 pattern: None,
+                format: None,
                 unknown_fields: IndexMap::new(),
             })),
         );
@@ -2161,6 +2175,7 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 pattern: None,
+                format: None,
                 unknown_fields: IndexMap::new(),
             })),
         );
@@ -2370,6 +2385,7 @@ mod tests {
                 min_length: None,
                 max_length: None,
                 pattern: None,
+                format: None,
                 unknown_fields: IndexMap::new(),
             })),
         );
@@ -315,6 +315,7 @@ fn convert_schema_content(
                 min_length: text.min_length,
                 max_length: text.max_length,
                 pattern: text.pattern.as_ref().map(|re| re.as_str().to_string()),
+                format: text.format.map(|format| format.as_str().to_string()),
                 unknown_fields,
             })
         }
@@ -724,6 +725,7 @@ mod tests {
                 min_length: Some(1),
                 max_length: Some(16),
                 pattern: None,
+                format: None,
                 unknown_fields: {
                     let mut fields = indexmap::IndexMap::new();
                     fields.insert(
@@ -815,42 +817,38 @@ mod tests {
             }),
         };
 
-        let schema = SchemaDocument {
-            nodes: vec![
-                record_node,
-                text_node,
-                integer_node,
-                union_node,
-                SchemaNode {
-                    content: SchemaNodeContent::Boolean,
-                    metadata: SchemaMetadata::default(),
-                    ext_types: Default::default(),
-                    type_codegen: TypeCodegen::None,
-                },
-                SchemaNode {
-                    content: SchemaNodeContent::Null,
-                    metadata: SchemaMetadata::default(),
-                    ext_types: Default::default(),
-                    type_codegen: TypeCodegen::None,
-                },
-            ],
-            root: SchemaNodeId(0),
-            types: {
-                let mut types = indexmap::IndexMap::new();
-                types.insert("root-record".parse().unwrap(), SchemaNodeId(0));
-                types
-            },
-            root_codegen: RootCodegen {
-                type_name: Some("RootRecord".to_string()),
+        let mut schema = SchemaDocument::new();
+        schema.nodes = vec![
+            record_node,
+            text_node,
+            integer_node,
+            union_node,
+            SchemaNode {
+                content: SchemaNodeContent::Boolean,
+                metadata: SchemaMetadata::default(),
+                ext_types: Default::default(),
+                type_codegen: TypeCodegen::None,
             },
-            codegen_defaults: CodegenDefaults {
-                derive: Some(vec!["Debug".to_string(), "Clone".to_string()]),
-                inline_derive: Some(vec!["Clone".to_string()]),
-                variant_type_derive: Some(vec!["Eq".to_string()]),
-                ext_types_field_prefix: Some("ext_".to_string()),
-                ext_types_type_prefix: Some("Ext".to_string()),
-                document_node_id_field: Some("node_id".to_string()),
+            SchemaNode {
+                content: SchemaNodeContent::Null,
+                metadata: SchemaMetadata::default(),
+                ext_types: Default::default(),
+                type_codegen: TypeCodegen::None,
             },
+        ];
+        schema
+            .types
+            .insert("root-record".parse().unwrap(), SchemaNodeId(0));
+        schema.root_codegen = RootCodegen {
+            type_name: Some("RootRecord".to_string()),
+        };
+        schema.codegen_defaults = CodegenDefaults {
+            derive: Some(vec!["Debug".to_string(), "Clone".to_string()]),
+            inline_derive: Some(vec!["Clone".to_string()]),
+            variant_type_derive: Some(vec!["Eq".to_string()]),
+            ext_types_field_prefix: Some("ext_".to_string()),
+            ext_types_type_prefix: Some("Ext".to_string()),
+            document_node_id_field: Some("node_id".to_string()),
         };
 
         let module = schema_to_ir_module(&schema).expect("schema conversion should succeed");
@@ -957,22 +955,16 @@ mod tests {
             .node_id;
         doc.set_content(tuple_child, PrimitiveValue::Bool(false).into());
 
-        let schema = SchemaDocument {
-            nodes: vec![SchemaNode {
-                content: SchemaNodeContent::Literal(doc),
-                metadata: SchemaMetadata::default(),
-                ext_types: Default::default(),
-                type_codegen: TypeCodegen::None,
-            }],
-            root: SchemaNodeId(0),
-            types: {
-                let mut types = indexmap::IndexMap::new();
-                types.insert("literal-map".parse().unwrap(), SchemaNodeId(0));
-                types
-            },
-            root_codegen: RootCodegen::default(),
-            codegen_defaults: CodegenDefaults::default(),
-        };
+        let mut schema = SchemaDocument::new();
+        schema.nodes = vec![SchemaNode {
+            content: SchemaNodeContent::Literal(doc),
+            metadata: SchemaMetadata::default(),
+            ext_types: Default::default(),
+            type_codegen: TypeCodegen::None,
+        }];
+        schema
+            .types
+            .insert("literal-map".parse().unwrap(), SchemaNodeId(0));
 
         let module = schema_to_ir_module(&schema).expect("schema conversion should succeed");
         let ty = module
@@ -1005,30 +997,24 @@ mod tests {
             .node_id;
         value.set_content(ext_node, PrimitiveValue::Bool(false).into());
 
-        let schema = SchemaDocument {
-            nodes: vec![SchemaNode {
-                content: SchemaNodeContent::Reference(TypeReference {
-                    namespace: None,
-                    name: "self".parse().unwrap(),
-                }),
-                metadata: SchemaMetadata {
-                    description: None,
-                    deprecated: false,
-                    default: Some(value),
-                    examples: None,
-                },
-                ext_types: Default::default(),
-                type_codegen: TypeCodegen::None,
-            }],
-            root: SchemaNodeId(0),
-            types: {
-                let mut types = indexmap::IndexMap::new();
-                types.insert("self".parse().unwrap(), SchemaNodeId(0));
-                types
+        let mut schema = SchemaDocument::new();
+        schema.nodes = vec![SchemaNode {
+            content: SchemaNodeContent::Reference(TypeReference {
+                namespace: None,
+                name: "self".parse().unwrap(),
+            }),
+            metadata: SchemaMetadata {
+                description: None,
+                deprecated: false,
+                default: Some(value),
+                examples: None,
             },
-            root_codegen: RootCodegen::default(),
-            codegen_defaults: CodegenDefaults::default(),
-        };
+            ext_types: Default::default(),
+            type_codegen: TypeCodegen::None,
+        }];
+        schema
+            .types
+            .insert("self".parse().unwrap(), SchemaNodeId(0));
 
         let err = schema_to_ir_module(&schema).expect_err("conversion should reject extensions");
         assert!(matches!(
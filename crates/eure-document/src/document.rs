@@ -1,7 +1,11 @@
 pub mod constructor;
 pub mod interpreter_sink;
 pub mod node;
+pub mod schema_ref;
 pub mod source_constructor;
+pub mod visit;
+
+use alloc::collections::BTreeMap;
 
 use crate::document::node::{NodeArray, NodeTuple};
 use crate::map::PartialNodeMap;
@@ -77,9 +81,39 @@ impl Default for EureDocument {
     }
 }
 
+/// How two documents are compared by [`EureDocument::nodes_equal`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EqMode {
+    /// Whether extensions are compared in addition to content.
+    pub(crate) with_extensions: bool,
+    /// Compare numbers by value within this tolerance instead of by variant.
+    pub(crate) numeric_tolerance: Option<f64>,
+}
+
+impl EqMode {
+    /// The mode of `PartialEq`.
+    pub(crate) const STRICT: Self = Self {
+        with_extensions: true,
+        numeric_tolerance: None,
+    };
+    /// The mode of [`EureDocument::data_eq`].
+    pub(crate) const DATA: Self = Self {
+        with_extensions: false,
+        numeric_tolerance: None,
+    };
+}
+
 impl PartialEq for EureDocument {
     fn eq(&self, other: &Self) -> bool {
-        self.nodes_equal(self.root, other, other.root)
+        self.nodes_equal(self.root, other, other.root, EqMode::STRICT)
+    }
+}
+
+impl Eq for EureDocument {}
+
+impl core::hash::Hash for EureDocument {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.hash_node(self.root, state);
     }
 }
 
@@ -89,62 +123,151 @@ impl EureDocument {
         self.nodes.len()
     }
 
+    /// Compare two documents as data, ignoring extensions on every node.
+    ///
+    /// Unlike `PartialEq`, documents that differ only in extensions such as
+    /// `$variant` are considered equal.
+    pub fn data_eq(&self, other: &EureDocument) -> bool {
+        self.nodes_equal(self.root, other, other.root, EqMode::DATA)
+    }
+
+    /// Compare two documents, treating numbers as equal by value.
+    ///
+    /// Unlike `PartialEq`, `Integer(1)`, `F32(1.0)` and `F64(1.0)` are all
+    /// equal here, which helps when a round trip through another format
+    /// changes number representations. Numbers involving a float are equal
+    /// when they differ by at most `tolerance`; two integers must be equal
+    /// exactly. Everything else, including extensions, is compared as by
+    /// `PartialEq`.
+    pub fn numeric_eq(&self, other: &EureDocument, tolerance: f64) -> bool {
+        let mode = EqMode {
+            numeric_tolerance: Some(tolerance),
+            ..EqMode::STRICT
+        };
+        self.nodes_equal(self.root, other, other.root, mode)
+    }
+
     /// Compare two nodes structurally, ignoring NodeId values
-    fn nodes_equal(&self, id1: NodeId, other: &EureDocument, id2: NodeId) -> bool {
+    fn nodes_equal(&self, id1: NodeId, other: &EureDocument, id2: NodeId, mode: EqMode) -> bool {
         let node1 = &self.nodes[id1.0];
         let node2 = &other.nodes[id2.0];
 
         // Compare extensions
-        if node1.extensions.len() != node2.extensions.len() {
-            return false;
-        }
+        if mode.with_extensions {
+            if node1.extensions.len() != node2.extensions.len() {
+                return false;
+            }
 
-        for (key1, &child_id1) in &node1.extensions {
-            match node2.extensions.get(key1) {
-                Some(&child_id2) => {
-                    if !self.nodes_equal(child_id1, other, child_id2) {
-                        return false;
+            for (key1, &child_id1) in &node1.extensions {
+                match node2.extensions.get(key1) {
+                    Some(&child_id2) => {
+                        if !self.nodes_equal(child_id1, other, child_id2, mode) {
+                            return false;
+                        }
                     }
+                    None => return false,
                 }
-                None => return false,
             }
         }
 
         // Compare content
-        self.node_values_equal(&node1.content, other, &node2.content)
+        self.node_values_equal(&node1.content, other, &node2.content, mode)
     }
 
     /// Compare two NodeValues structurally
-    fn node_values_equal(
+    pub(crate) fn node_values_equal(
         &self,
         value1: &NodeValue,
         other: &EureDocument,
         value2: &NodeValue,
+        mode: EqMode,
     ) -> bool {
         match (value1, value2) {
             (NodeValue::Hole(l1), NodeValue::Hole(l2)) => l1 == l2,
-            (NodeValue::Primitive(p1), NodeValue::Primitive(p2)) => p1 == p2,
+            (NodeValue::Primitive(p1), NodeValue::Primitive(p2)) => match mode.numeric_tolerance {
+                Some(tolerance) => p1.numeric_eq(p2, tolerance),
+                None => p1 == p2,
+            },
             (NodeValue::Array(arr1), NodeValue::Array(arr2)) => {
-                self.node_arrays_equal(arr1, other, arr2)
+                self.node_arrays_equal(arr1, other, arr2, mode)
             }
             (NodeValue::Tuple(tup1), NodeValue::Tuple(tup2)) => {
-                self.node_tuples_equal(tup1, other, tup2)
+                self.node_tuples_equal(tup1, other, tup2, mode)
+            }
+            (NodeValue::Map(map1), NodeValue::Map(map2)) => {
+                self.node_maps_equal(map1, other, map2, mode)
             }
-            (NodeValue::Map(map1), NodeValue::Map(map2)) => self.node_maps_equal(map1, other, map2),
             (NodeValue::PartialMap(pm1), NodeValue::PartialMap(pm2)) => {
-                self.node_partial_maps_equal(pm1, other, pm2)
+                self.node_partial_maps_equal(pm1, other, pm2, mode)
             }
             _ => false,
         }
     }
 
-    fn node_arrays_equal(&self, arr1: &NodeArray, other: &EureDocument, arr2: &NodeArray) -> bool {
+    /// Hash a node consistently with `nodes_equal` (with extensions).
+    ///
+    /// Extensions and map entries are compared regardless of order, so they
+    /// are hashed in key order.
+    fn hash_node<H: core::hash::Hasher>(&self, id: NodeId, state: &mut H) {
+        use core::hash::Hash;
+
+        let node = &self.nodes[id.0];
+        let mut extensions: Vec<_> = node.extensions.iter().collect();
+        extensions.sort_by_key(|&(key, _)| key);
+        extensions.len().hash(state);
+        for (key, &child) in extensions {
+            key.hash(state);
+            self.hash_node(child, state);
+        }
+
+        core::mem::discriminant(&node.content).hash(state);
+        match &node.content {
+            NodeValue::Hole(label) => label.hash(state),
+            NodeValue::Primitive(primitive) => primitive.hash(state),
+            NodeValue::Array(array) => {
+                array.len().hash(state);
+                for &child in array.iter() {
+                    self.hash_node(child, state);
+                }
+            }
+            NodeValue::Tuple(tuple) => {
+                tuple.len().hash(state);
+                for &child in tuple.iter() {
+                    self.hash_node(child, state);
+                }
+            }
+            NodeValue::Map(map) => {
+                let mut entries: Vec<_> = map.iter().collect();
+                entries.sort_by_key(|&(key, _)| key);
+                entries.len().hash(state);
+                for (key, &child) in entries {
+                    key.hash(state);
+                    self.hash_node(child, state);
+                }
+            }
+            NodeValue::PartialMap(pm) => {
+                pm.len().hash(state);
+                for (key, &child) in pm.iter() {
+                    key.hash(state);
+                    self.hash_node(child, state);
+                }
+            }
+        }
+    }
+
+    fn node_arrays_equal(
+        &self,
+        arr1: &NodeArray,
+        other: &EureDocument,
+        arr2: &NodeArray,
+        mode: EqMode,
+    ) -> bool {
         if arr1.len() != arr2.len() {
             return false;
         }
 
         for (child_id1, child_id2) in arr1.iter().zip(arr2.iter()) {
-            if !self.nodes_equal(*child_id1, other, *child_id2) {
+            if !self.nodes_equal(*child_id1, other, *child_id2, mode) {
                 return false;
             }
         }
@@ -152,13 +275,19 @@ impl EureDocument {
         true
     }
 
-    fn node_tuples_equal(&self, tup1: &NodeTuple, other: &EureDocument, tup2: &NodeTuple) -> bool {
+    fn node_tuples_equal(
+        &self,
+        tup1: &NodeTuple,
+        other: &EureDocument,
+        tup2: &NodeTuple,
+        mode: EqMode,
+    ) -> bool {
         if tup1.len() != tup2.len() {
             return false;
         }
 
         for (child_id1, child_id2) in tup1.iter().zip(tup2.iter()) {
-            if !self.nodes_equal(*child_id1, other, *child_id2) {
+            if !self.nodes_equal(*child_id1, other, *child_id2, mode) {
                 return false;
             }
         }
@@ -171,6 +300,7 @@ impl EureDocument {
         pm1: &PartialNodeMap,
         other: &EureDocument,
         pm2: &PartialNodeMap,
+        mode: EqMode,
     ) -> bool {
         if pm1.len() != pm2.len() {
             return false;
@@ -179,14 +309,20 @@ impl EureDocument {
             if key1 != key2 {
                 return false;
             }
-            if !self.nodes_equal(child_id1, other, child_id2) {
+            if !self.nodes_equal(child_id1, other, child_id2, mode) {
                 return false;
             }
         }
         true
     }
 
-    fn node_maps_equal(&self, map1: &NodeMap, other: &EureDocument, map2: &NodeMap) -> bool {
+    fn node_maps_equal(
+        &self,
+        map1: &NodeMap,
+        other: &EureDocument,
+        map2: &NodeMap,
+        mode: EqMode,
+    ) -> bool {
         if map1.len() != map2.len() {
             return false;
         }
@@ -194,7 +330,7 @@ impl EureDocument {
         for (key1, &child_id1) in map1.iter() {
             match map2.get(key1) {
                 Some(&child_id2) => {
-                    if !self.nodes_equal(child_id1, other, child_id2) {
+                    if !self.nodes_equal(child_id1, other, child_id2, mode) {
                         return false;
                     }
                 }
@@ -505,6 +641,33 @@ impl EureDocument {
         self.add_child_by_segment(segment, parent_node_id)
     }
 
+    /// Bind `value` at `path`, creating the nodes along it as needed.
+    ///
+    /// Existing nodes are reused as in [`Self::resolve_child_by_segment`], so
+    /// `ArrayIndex(Push)` appends a new element. A hole or primitive already at
+    /// the target is overwritten; a map, array or tuple there is an error.
+    pub fn set(&mut self, path: &EurePath, value: PrimitiveValue) -> Result<NodeId, InsertError> {
+        let mut node_id = self.get_root_id();
+        for (index, segment) in path.0.iter().enumerate() {
+            node_id = self
+                .resolve_child_by_segment(segment.clone(), node_id)
+                .map_err(|kind| InsertError {
+                    kind,
+                    path: EurePath(path.0[..=index].to_vec()),
+                })?
+                .node_id;
+        }
+        let node = self.node_mut(node_id);
+        if !matches!(node.content, NodeValue::Hole(_) | NodeValue::Primitive(_)) {
+            return Err(InsertError {
+                kind: InsertErrorKind::BindingTargetHasValue,
+                path: path.clone(),
+            });
+        }
+        node.content = NodeValue::Primitive(value);
+        Ok(node_id)
+    }
+
     /// Convert a subtree of a document to a standalone document.
     pub fn node_subtree_to_document(&self, node_id: NodeId) -> EureDocument {
         let mut result = EureDocument::new();
@@ -591,6 +754,176 @@ impl EureDocument {
         self.node_mut(dst_id).extensions = Map::new();
         src_doc.copy_subtree(src_id, self, dst_id);
     }
+
+    /// Drop nodes that are unreachable from the root and renumber the rest.
+    ///
+    /// Removals such as [`remove_map_child`](Self::remove_map_child) only detach
+    /// a subtree, leaving its nodes in the arena. Compaction keeps the nodes
+    /// reachable from the root through container children and extensions, in
+    /// their original order, and returns the old-to-new id translation table.
+    ///
+    /// Every `NodeId` obtained before compaction is invalidated; ids held
+    /// elsewhere must be translated through the returned table.
+    pub fn compact(&mut self) -> BTreeMap<NodeId, NodeId> {
+        let mut reachable = vec![false; self.nodes.len()];
+        let mut stack = vec![self.root];
+        while let Some(id) = stack.pop() {
+            if core::mem::replace(&mut reachable[id.0], true) {
+                continue;
+            }
+            let node = &self.nodes[id.0];
+            stack.extend(node.extensions.iter().map(|(_, &child)| child));
+            match &node.content {
+                NodeValue::Hole(_) | NodeValue::Primitive(_) => {}
+                NodeValue::Array(array) => stack.extend(array.iter().copied()),
+                NodeValue::Tuple(tuple) => stack.extend(tuple.iter().copied()),
+                NodeValue::Map(map) => stack.extend(map.iter().map(|(_, &child)| child)),
+                NodeValue::PartialMap(pm) => stack.extend(pm.iter().map(|(_, &child)| child)),
+            }
+        }
+
+        let ids: BTreeMap<NodeId, NodeId> = (0..self.nodes.len())
+            .filter(|&old| reachable[old])
+            .enumerate()
+            .map(|(new, old)| (NodeId(old), NodeId(new)))
+            .collect();
+
+        let nodes = core::mem::take(&mut self.nodes);
+        self.nodes = nodes
+            .into_iter()
+            .enumerate()
+            .filter(|&(old, _)| reachable[old])
+            .map(|(_, node)| Node {
+                content: remap_node_value(node.content, &ids),
+                extensions: node
+                    .extensions
+                    .into_iter()
+                    .map(|(key, child)| (key, ids[&child]))
+                    .collect(),
+            })
+            .collect();
+        self.root = ids[&self.root];
+        ids
+    }
+
+    /// Build a document from flat `(path, value)` pairs.
+    ///
+    /// Each path is navigated from the root, creating intermediate maps,
+    /// arrays and tuples as its segments require, and the value is bound at
+    /// its end. A path that binds an already bound node, or that passes
+    /// through a scalar, is an error.
+    pub fn from_flat<I>(pairs: I) -> Result<EureDocument, InsertError>
+    where
+        I: IntoIterator<Item = (EurePath, PrimitiveValue)>,
+    {
+        let mut constructor = constructor::DocumentConstructor::new();
+        for (path, value) in pairs {
+            let scope = constructor.begin_scope();
+            for segment in path.0 {
+                constructor.navigate(segment)?;
+            }
+            constructor.bind_primitive(value)?;
+            if let Err(e) = constructor.end_scope(scope) {
+                return Err(InsertError {
+                    kind: e.into(),
+                    path: EurePath(constructor.current_path().to_vec()),
+                });
+            }
+        }
+        Ok(constructor.finish())
+    }
+
+    /// Find the path from the root to `target`.
+    ///
+    /// Map keys that are valid identifiers become [`PathSegment::Ident`].
+    /// Returns `None` if `target` is not reachable from the root.
+    pub fn path_to(&self, target: NodeId) -> Option<EurePath> {
+        self.paths()
+            .find_map(|(node_id, path)| (node_id == target).then_some(path))
+    }
+
+    /// Iterate every node reachable from the root together with its path, as
+    /// [`path_to`](Self::path_to) would report it.
+    pub fn paths(&self) -> impl Iterator<Item = (NodeId, EurePath)> + '_ {
+        let mut stack = vec![(self.root, EurePath::root())];
+        core::iter::from_fn(move || {
+            let (node_id, path) = stack.pop()?;
+            let node = &self.nodes[node_id.0];
+            let child = |segment: PathSegment| {
+                let mut child_path = path.clone();
+                child_path.0.push(segment);
+                child_path
+            };
+            for (ident, &ext_id) in node.extensions.iter() {
+                stack.push((ext_id, child(PathSegment::Extension(ident.clone()))));
+            }
+            match &node.content {
+                NodeValue::Map(map) => {
+                    for (key, &child_id) in map.iter() {
+                        stack.push((child_id, child(map_key_segment(key))));
+                    }
+                }
+                NodeValue::PartialMap(map) => {
+                    for (key, &child_id) in map.iter() {
+                        stack.push((child_id, child(PathSegment::PartialValue(key.clone()))));
+                    }
+                }
+                NodeValue::Array(items) => {
+                    for (index, &child_id) in items.iter().enumerate() {
+                        let segment = PathSegment::ArrayIndex(ArrayIndexKind::Specific(index));
+                        stack.push((child_id, child(segment)));
+                    }
+                }
+                NodeValue::Tuple(items) => {
+                    for (index, &child_id) in items.iter().enumerate() {
+                        stack.push((child_id, child(PathSegment::TupleIndex(index as u8))));
+                    }
+                }
+                NodeValue::Hole(_) | NodeValue::Primitive(_) => {}
+            }
+            Some((node_id, path))
+        })
+    }
+}
+
+/// The path segment naming the map entry under `key`: [`PathSegment::Ident`]
+/// when the key is a valid identifier, [`PathSegment::Value`] otherwise.
+pub fn map_key_segment(key: &ObjectKey) -> PathSegment {
+    if let ObjectKey::String(name) = key
+        && let Ok(ident) = name.parse::<Identifier>()
+    {
+        return PathSegment::Ident(ident);
+    }
+    PathSegment::Value(key.clone())
+}
+
+/// Rewrite the child ids of a container value through `ids`.
+fn remap_node_value(value: NodeValue, ids: &BTreeMap<NodeId, NodeId>) -> NodeValue {
+    match value {
+        NodeValue::Hole(_) | NodeValue::Primitive(_) => value,
+        NodeValue::Array(array) => {
+            let mut remapped = NodeArray::new();
+            for child in array.iter() {
+                let _ = remapped.push(ids[child]);
+            }
+            NodeValue::Array(remapped)
+        }
+        NodeValue::Tuple(tuple) => NodeValue::Tuple(NodeTuple::from_vec(
+            tuple.iter().map(|child| ids[child]).collect(),
+        )),
+        NodeValue::Map(map) => NodeValue::Map(
+            map.into_iter()
+                .map(|(key, child)| (key, ids[&child]))
+                .collect(),
+        ),
+        NodeValue::PartialMap(pm) => {
+            let mut remapped = PartialNodeMap::new();
+            for (key, child) in pm.iter() {
+                remapped.push(key.clone(), ids[child]);
+            }
+            NodeValue::PartialMap(remapped)
+        }
+    }
 }
 
 /// Commands
@@ -616,6 +949,100 @@ mod tests {
         s.parse().unwrap()
     }
 
+    #[test]
+    fn test_compact_drops_removed_subtree() {
+        let mut doc = eure!({
+            keep = 1,
+            drop { a = 1, b = [1, 2] },
+            tail = (1, "x"),
+            tail.%note = "kept",
+        });
+        let root_id = doc.get_root_id();
+        let before = doc.node_count();
+        doc.remove_map_child(&ObjectKey::String("drop".to_string()), root_id)
+            .unwrap()
+            .unwrap();
+
+        let ids = doc.compact();
+
+        assert!(doc.node_count() < before);
+        assert_eq!(doc.node_count(), ids.len());
+        assert_eq!(
+            doc,
+            eure!({
+                keep = 1,
+                tail = (1, "x"),
+                tail.%note = "kept",
+            })
+        );
+    }
+
+    #[test]
+    fn test_documents_with_floats_in_hash_set() {
+        let mut set = std::collections::HashSet::new();
+        assert!(set.insert(eure!({ ratio = 0.5, name = "a" })));
+        assert!(set.insert(eure!({ ratio = 0.25, name = "a" })));
+        // Same data in a different key order is the same document
+        assert!(!set.insert(eure!({ name = "a", ratio = 0.5 })));
+        assert_eq!(set.len(), 2);
+
+        let nan = f64::NAN;
+        assert!(set.insert(eure!({ ratio = nan })));
+        assert!(!set.insert(eure!({ ratio = nan })));
+        assert_eq!(set.len(), 3);
+    }
+
+    fn flat_path(keys: &[&str]) -> EurePath {
+        EurePath(
+            keys.iter()
+                .map(|key| PathSegment::Ident(identifier(key)))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_from_flat_builds_nested_config() {
+        let doc = EureDocument::from_flat([
+            (flat_path(&["server", "host"]), "localhost".into()),
+            (flat_path(&["server", "port"]), 8080.into()),
+            (flat_path(&["server", "tls", "enabled"]), true.into()),
+            (flat_path(&["name"]), "app".into()),
+        ])
+        .unwrap();
+
+        let expected = eure!({
+            server {
+                host = "localhost",
+                port = 8080,
+                tls.enabled = true,
+            },
+            name = "app",
+        });
+        assert_eq!(doc, expected);
+    }
+
+    #[test]
+    fn test_from_flat_scalar_then_parent_conflict() {
+        let err = EureDocument::from_flat([
+            (flat_path(&["server"]), "localhost".into()),
+            (flat_path(&["server", "port"]), 8080.into()),
+        ])
+        .unwrap_err();
+        assert_eq!(err.kind, InsertErrorKind::ExpectedMap);
+        assert_eq!(err.path, flat_path(&["server"]));
+    }
+
+    #[test]
+    fn test_from_flat_parent_then_scalar_conflict() {
+        let err = EureDocument::from_flat([
+            (flat_path(&["server", "port"]), 8080.into()),
+            (flat_path(&["server"]), "localhost".into()),
+        ])
+        .unwrap_err();
+        assert_eq!(err.kind, InsertErrorKind::BindingTargetHasValue);
+        assert_eq!(err.path, flat_path(&["server"]));
+    }
+
     #[test]
     fn test_add_map_child_success() {
         let mut doc = EureDocument::new();
@@ -1225,6 +1652,114 @@ mod tests {
         assert_ne!(doc1, doc2);
     }
 
+    #[test]
+    fn test_data_eq_ignores_variant_extension() {
+        let doc1 = eure!({ value.%variant = "text", value = "hello" });
+        let doc2 = eure!({ value = "hello" });
+
+        assert_ne!(doc1, doc2);
+        assert!(doc1.data_eq(&doc2));
+        assert!(doc2.data_eq(&doc1));
+    }
+
+    #[test]
+    fn test_data_eq_detects_content_difference() {
+        let doc1 = eure!({ value.%variant = "text", value = "hello" });
+        let doc2 = eure!({ value.%variant = "text", value = "world" });
+
+        assert!(!doc1.data_eq(&doc2));
+    }
+
+    #[test]
+    fn test_numeric_eq_ignores_number_representation() {
+        let integer = eure!({ port = 1, ratio = 0.5 });
+        let float = eure!({ port = 1.0, ratio = 0.5 });
+        let single = 1.0f32;
+        let f32_doc = eure!({ port = single, ratio = 0.5 });
+
+        assert_ne!(integer, float);
+        assert!(integer.numeric_eq(&float, 0.0));
+        assert!(float.numeric_eq(&integer, 0.0));
+        assert!(integer.numeric_eq(&f32_doc, 0.0));
+    }
+
+    #[test]
+    fn test_numeric_eq_tolerance() {
+        let doc1 = eure!({ ratio = 0.1 });
+        let doc2 = eure!({ ratio = 0.1000001 });
+
+        assert!(!doc1.numeric_eq(&doc2, 0.0));
+        assert!(doc1.numeric_eq(&doc2, 1e-6));
+        assert!(!eure!({ port = 1 }).numeric_eq(&eure!({ port = 2 }), 1e-6));
+        assert!(!eure!({ port = 1 }).numeric_eq(&eure!({ port = "1" }), 1e-6));
+    }
+
+    #[test]
+    fn test_set_creates_deep_path() {
+        let mut doc = EureDocument::new();
+        let server = PathSegment::Ident(identifier("server"));
+        let path = |segments: &[PathSegment]| EurePath(segments.to_vec());
+
+        doc.set(
+            &path(&[server.clone(), PathSegment::Ident(identifier("host"))]),
+            PrimitiveValue::Text(Text::plaintext("localhost")),
+        )
+        .unwrap();
+        let ports = PathSegment::Ident(identifier("ports"));
+        let push = PathSegment::ArrayIndex(ArrayIndexKind::Push);
+        for port in [80, 443] {
+            doc.set(
+                &path(&[server.clone(), ports.clone(), push.clone()]),
+                PrimitiveValue::Integer(port.into()),
+            )
+            .unwrap();
+        }
+        assert_eq!(
+            doc,
+            eure!({
+                server.host = "localhost",
+                server.ports[] = 80,
+                server.ports[] = 443,
+            })
+        );
+
+        doc.set(
+            &path(&[server, PathSegment::Extension(identifier("note"))]),
+            PrimitiveValue::Bool(true),
+        )
+        .unwrap();
+        assert_eq!(
+            doc,
+            eure!({
+                server.host = "localhost",
+                server.ports[] = 80,
+                server.ports[] = 443,
+                server.%note = true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_set_overwrites_existing_value() {
+        let mut doc = eure!({ server.port = 8080 });
+        let port = EurePath(vec![
+            PathSegment::Ident(identifier("server")),
+            PathSegment::Ident(identifier("port")),
+        ]);
+        doc.set(&port, PrimitiveValue::Integer(9090.into()))
+            .unwrap();
+        assert_eq!(doc, eure!({ server.port = 9090 }));
+
+        let server = EurePath(vec![PathSegment::Ident(identifier("server"))]);
+        assert_eq!(
+            doc.set(&server, PrimitiveValue::Null),
+            Err(InsertError {
+                kind: InsertErrorKind::BindingTargetHasValue,
+                path: server.clone(),
+            })
+        );
+    }
+
     #[test]
     fn test_partialeq_with_arrays() {
         let mut doc1 = EureDocument::new();
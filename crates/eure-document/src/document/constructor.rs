@@ -357,6 +357,84 @@ impl DocumentConstructor {
         Ok(())
     }
 
+    /// Copy the subtree of `src` rooted at `src_id` into the current node.
+    ///
+    /// Containers merge into a current node that already holds the same kind
+    /// of container: map entries and extensions are added alongside existing
+    /// ones and array elements are appended. Binding a value where one is
+    /// already bound fails as with the `bind_*` methods.
+    pub fn import_subtree(
+        &mut self,
+        src: &EureDocument,
+        src_id: NodeId,
+    ) -> Result<(), InsertError> {
+        let node = src.node(src_id);
+        let current = &self.current_node().content;
+        match &node.content {
+            NodeValue::Hole(label) => self.bind_hole(label.clone())?,
+            NodeValue::Primitive(value) => self.bind_primitive(value.clone())?,
+            NodeValue::Map(map) => {
+                if !matches!(current, NodeValue::Map(_)) {
+                    self.bind_empty_map()?;
+                }
+                for (key, &child) in map.iter() {
+                    self.import_child(PathSegment::Value(key.clone()), src, child)?;
+                }
+            }
+            NodeValue::PartialMap(map) => {
+                if !matches!(current, NodeValue::PartialMap(_)) {
+                    self.bind_empty_partial_map()?;
+                }
+                for (key, &child) in map.iter() {
+                    let scope = self.begin_scope();
+                    self.navigate_partial_map_entry(key.clone())?;
+                    self.import_subtree(src, child)?;
+                    self.end_import_scope(scope)?;
+                }
+            }
+            NodeValue::Array(items) => {
+                if !matches!(current, NodeValue::Array(_)) {
+                    self.bind_empty_array()?;
+                }
+                for &child in items.iter() {
+                    let segment = PathSegment::ArrayIndex(ArrayIndexKind::Push);
+                    self.import_child(segment, src, child)?;
+                }
+            }
+            NodeValue::Tuple(items) => {
+                if !matches!(current, NodeValue::Tuple(_)) {
+                    self.bind_empty_tuple()?;
+                }
+                for (index, &child) in items.iter().enumerate() {
+                    self.import_child(PathSegment::TupleIndex(index as u8), src, child)?;
+                }
+            }
+        }
+        for (ident, &child) in node.extensions.iter() {
+            self.import_child(PathSegment::Extension(ident.clone()), src, child)?;
+        }
+        Ok(())
+    }
+
+    fn import_child(
+        &mut self,
+        segment: PathSegment,
+        src: &EureDocument,
+        child: NodeId,
+    ) -> Result<(), InsertError> {
+        let scope = self.begin_scope();
+        self.navigate(segment)?;
+        self.import_subtree(src, child)?;
+        self.end_import_scope(scope)
+    }
+
+    fn end_import_scope(&mut self, scope: Scope) -> Result<(), InsertError> {
+        self.end_scope(scope).map_err(|e| InsertError {
+            kind: InsertErrorKind::ScopeError(e),
+            path: EurePath::from_iter(self.path.iter().cloned()),
+        })
+    }
+
     /// Push a fresh block scope used to track `[^]` resolution.
     fn begin_block_scope(&mut self) {
         self.block_scope_stack.push(BlockScope::default());
@@ -728,6 +806,26 @@ mod tests {
         assert_eq!(constructor.current_path(), &[]);
     }
 
+    #[test]
+    fn test_current_path_tracks_navigation() {
+        let mut constructor = DocumentConstructor::new();
+        let segments = [
+            PathSegment::Ident(create_identifier("servers")),
+            PathSegment::ArrayIndex(ArrayIndexKind::Push),
+            PathSegment::Ident(create_identifier("address")),
+            PathSegment::Extension(create_identifier("variant")),
+        ];
+
+        for segment in &segments {
+            constructor
+                .navigate(segment.clone())
+                .expect("Failed to navigate");
+        }
+
+        let path = EurePath::from_iter(constructor.current_path().iter().cloned());
+        assert_eq!(path, EurePath::from_iter(segments.iter().cloned()));
+    }
+
     #[test]
     fn test_require_hole_success() {
         let mut constructor = DocumentConstructor::new();
@@ -1,7 +1,8 @@
 use crate::{
+    document::{EqMode, map_key_segment},
     map::PartialNodeMap,
     prelude_internal::*,
-    value::{PartialObjectKey, ValueKind},
+    value::{PartialObjectKey, Tuple, Value, ValueError, ValueKind},
 };
 
 #[derive(Debug, Clone)]
@@ -143,6 +144,72 @@ impl Node {
         self.extensions.get(ident).copied()
     }
 
+    /// Borrow this node's content as a [`ValueRef`].
+    pub fn value_ref(&self) -> ValueRef<'_> {
+        match &self.content {
+            NodeValue::Hole(label) => ValueRef::Hole(label.as_ref()),
+            NodeValue::Primitive(primitive) => ValueRef::Primitive(primitive),
+            NodeValue::Array(array) => ValueRef::Array(array),
+            NodeValue::Map(map) => ValueRef::Map(map),
+            NodeValue::Tuple(tuple) => ValueRef::Tuple(tuple),
+            NodeValue::PartialMap(pm) => ValueRef::PartialMap(pm),
+        }
+    }
+
+    /// Compare this node's content with `other` as data, ignoring extensions.
+    ///
+    /// `doc` and `other_doc` are the documents owning `self` and `other`, used to
+    /// resolve child nodes.
+    pub fn data_eq(&self, doc: &EureDocument, other: &Node, other_doc: &EureDocument) -> bool {
+        doc.node_values_equal(&self.content, other_doc, &other.content, EqMode::DATA)
+    }
+
+    /// Build an owned [`Value`] from this node and its descendants in `doc`.
+    ///
+    /// Extensions are dropped. Fails on the first hole, or on a map with a hole
+    /// key, found in document order.
+    pub fn try_into_value(&self, doc: &EureDocument) -> Result<Value, ValueError> {
+        self.to_value_at(doc, &mut EurePath::root())
+    }
+
+    fn to_value_at(&self, doc: &EureDocument, path: &mut EurePath) -> Result<Value, ValueError> {
+        let mut child_value = |segment: PathSegment, child: NodeId| {
+            path.0.push(segment);
+            let value = doc.node(child).to_value_at(doc, path);
+            path.0.pop();
+            value
+        };
+
+        match &self.content {
+            NodeValue::Hole(label) => Err(ValueError::Hole {
+                path: path.clone(),
+                label: label.clone(),
+            }),
+            NodeValue::PartialMap(_) => Err(ValueError::HoleKey { path: path.clone() }),
+            NodeValue::Primitive(primitive) => Ok(Value::Primitive(primitive.clone())),
+            NodeValue::Array(array) => array
+                .iter()
+                .enumerate()
+                .map(|(index, &child)| {
+                    let segment = PathSegment::ArrayIndex(ArrayIndexKind::Specific(index));
+                    child_value(segment, child)
+                })
+                .collect::<Result<_, _>>()
+                .map(Value::Array),
+            NodeValue::Tuple(tuple) => tuple
+                .iter()
+                .enumerate()
+                .map(|(index, &child)| child_value(PathSegment::TupleIndex(index as u8), child))
+                .collect::<Result<_, _>>()
+                .map(|items| Value::Tuple(Tuple(items))),
+            NodeValue::Map(map) => map
+                .iter()
+                .map(|(key, &child)| Ok((key.clone(), child_value(map_key_segment(key), child)?)))
+                .collect::<Result<_, ValueError>>()
+                .map(Value::Map),
+        }
+    }
+
     pub(crate) fn require_map(&mut self) -> Result<&mut NodeMap, InsertErrorKind> {
         if self.content.is_hole() {
             self.content = NodeValue::Map(Default::default());
@@ -279,6 +346,92 @@ impl NodeValue {
     }
 }
 
+/// A borrowed view of a node's content, for inspecting a document without cloning.
+///
+/// Obtained from [`Node::value_ref`]. Children are yielded as [`NodeId`]s to be
+/// resolved against the owning document.
+///
+/// ```
+/// use eure_document::document::node::ValueRef;
+/// use eure_document::eure;
+/// use eure_document::value::{ObjectKey, PrimitiveValue};
+///
+/// let doc = eure!({ name = "eure", tags = ["a", "b"] });
+/// let root = doc.node(doc.get_root_id());
+///
+/// let mut keys = Vec::new();
+/// for (key, child) in root.value_ref().map_entries().unwrap() {
+///     keys.push(key.clone());
+///     if let ValueRef::Array(_) = doc.node(child).value_ref() {
+///         assert_eq!(doc.node(child).value_ref().children().count(), 2);
+///     } else {
+///         assert!(matches!(doc.node(child).value_ref(), ValueRef::Primitive(PrimitiveValue::Text(_))));
+///     }
+/// }
+/// assert_eq!(
+///     keys,
+///     vec![ObjectKey::String("name".into()), ObjectKey::String("tags".into())]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValueRef<'a> {
+    Hole(Option<&'a Identifier>),
+    Primitive(&'a PrimitiveValue),
+    Array(&'a NodeArray),
+    Map(&'a NodeMap),
+    Tuple(&'a NodeTuple),
+    PartialMap(&'a PartialNodeMap),
+}
+
+impl<'a> ValueRef<'a> {
+    pub fn value_kind(&self) -> ValueKind {
+        match self {
+            Self::Hole(_) => ValueKind::Hole,
+            Self::Primitive(primitive) => primitive.kind(),
+            Self::Array(_) => ValueKind::Array,
+            Self::Map(_) => ValueKind::Map,
+            Self::Tuple(_) => ValueKind::Tuple,
+            Self::PartialMap(_) => ValueKind::PartialMap,
+        }
+    }
+
+    /// Iterate `(key, child)` pairs in document order, if this is a map.
+    pub fn map_entries(self) -> Option<impl Iterator<Item = (&'a ObjectKey, NodeId)> + 'a> {
+        match self {
+            Self::Map(map) => Some(map.iter().map(|(key, id)| (key, *id))),
+            _ => None,
+        }
+    }
+
+    /// Iterate element ids in order, if this is an array or a tuple.
+    pub fn elements(self) -> Option<impl Iterator<Item = NodeId> + 'a> {
+        let elements = match self {
+            Self::Array(array) => array.0.as_slice(),
+            Self::Tuple(tuple) => tuple.0.as_slice(),
+            _ => return None,
+        };
+        Some(elements.iter().copied())
+    }
+
+    /// Iterate all direct children: map and partial map values, or array and
+    /// tuple elements. Yields nothing for primitives and holes.
+    pub fn children(self) -> impl Iterator<Item = NodeId> + 'a {
+        let (map, partial_map) = match self {
+            Self::Map(map) => (Some(map), None),
+            Self::PartialMap(pm) => (None, Some(pm)),
+            _ => (None, None),
+        };
+        map.into_iter()
+            .flat_map(|map| map.iter().map(|(_, id)| *id))
+            .chain(
+                partial_map
+                    .into_iter()
+                    .flat_map(|pm| pm.iter().map(|(_, id)| *id)),
+            )
+            .chain(self.elements().into_iter().flatten())
+    }
+}
+
 // ============================================================================
 // From implementations for NodeValue
 // ============================================================================
@@ -391,6 +544,60 @@ mod tests {
         s.parse().unwrap()
     }
 
+    #[test]
+    fn test_try_into_value_nested() {
+        let doc = eure!({
+            name = "app",
+            ports = [80, 443],
+            pair = (true, null),
+            db {
+                host = "localhost"
+                replicas.primary = 1
+            }
+        });
+        let root = doc.node(doc.get_root_id());
+
+        let text = |s: &str| Value::Primitive(PrimitiveValue::Text(Text::plaintext(s)));
+        let int = |n: i64| Value::Primitive(PrimitiveValue::from(n));
+        let map = |entries: Vec<(&str, Value)>| {
+            Value::Map(entries.into_iter().map(|(k, v)| (k.into(), v)).collect())
+        };
+        let expected = map(vec![
+            ("name", text("app")),
+            ("ports", Value::Array(vec![int(80), int(443)])),
+            (
+                "pair",
+                Value::Tuple(Tuple(vec![
+                    Value::Primitive(PrimitiveValue::Bool(true)),
+                    Value::Primitive(PrimitiveValue::Null),
+                ])),
+            ),
+            (
+                "db",
+                map(vec![
+                    ("host", text("localhost")),
+                    ("replicas", map(vec![("primary", int(1))])),
+                ]),
+            ),
+        ]);
+        assert_eq!(root.try_into_value(&doc), Ok(expected));
+    }
+
+    #[test]
+    fn test_try_into_value_errors_on_hole() {
+        let doc = eure!({
+            items[] = 1,
+            items[] = !,
+        });
+        let root = doc.node(doc.get_root_id());
+
+        let Err(ValueError::Hole { path, label }) = root.try_into_value(&doc) else {
+            panic!("expected a hole error");
+        };
+        assert_eq!(path.to_string(), "items[1]");
+        assert_eq!(label, None);
+    }
+
     #[test]
     fn test_require_map_on_uninitialized() {
         let mut node = Node {
@@ -499,6 +706,68 @@ mod tests {
         assert_eq!(result, Err(InsertErrorKind::ExpectedArray));
     }
 
+    #[test]
+    fn test_node_data_eq_ignores_extensions() {
+        let doc1 = eure!({ value.%variant = "text", value = "hello" });
+        let doc2 = eure!({ value = "hello" });
+
+        assert!(doc1.root().data_eq(&doc1, doc2.root(), &doc2));
+    }
+
+    #[test]
+    fn test_value_ref_map_entries() {
+        let doc = eure!({ a = 1, b = 2 });
+        let root = doc.root();
+
+        let entries: Vec<_> = root
+            .value_ref()
+            .map_entries()
+            .unwrap()
+            .map(|(key, id)| (key.clone(), doc.parse::<i64>(id).unwrap()))
+            .collect();
+        assert_eq!(entries, vec![("a".into(), 1), ("b".into(), 2)]);
+        assert!(root.value_ref().elements().is_none());
+        assert_eq!(root.value_ref().children().count(), 2);
+    }
+
+    #[test]
+    fn test_value_ref_elements() {
+        let doc = eure!({ = [1, 2, 3] });
+        let array = doc.root();
+        let elements: Vec<_> = array
+            .value_ref()
+            .elements()
+            .unwrap()
+            .map(|id| doc.parse::<i64>(id).unwrap())
+            .collect();
+        assert_eq!(elements, vec![1, 2, 3]);
+        assert!(array.value_ref().map_entries().is_none());
+
+        let doc = eure!({ = (1, 2) });
+        let tuple: Vec<_> = doc
+            .root()
+            .value_ref()
+            .children()
+            .map(|id| doc.parse::<i64>(id).unwrap())
+            .collect();
+        assert_eq!(tuple, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_value_ref_leaves() {
+        let doc = eure!({ = 1 });
+        let root = doc.root();
+        assert_eq!(
+            root.value_ref(),
+            ValueRef::Primitive(&PrimitiveValue::Integer(1.into()))
+        );
+        assert_eq!(root.value_ref().children().count(), 0);
+
+        let doc = EureDocument::new();
+        assert!(matches!(doc.root().value_ref(), ValueRef::Hole(None)));
+        assert_eq!(doc.root().value_ref().value_kind(), ValueKind::Hole);
+    }
+
     #[test]
     fn test_node_get_extension_exists() {
         let mut doc = EureDocument::new();
@@ -0,0 +1,84 @@
+//! The `$schema` reference of a document.
+
+use crate::prelude_internal::*;
+
+/// The schema a document declares with a root-level `$schema` extension.
+///
+/// ```eure
+/// $schema = "./person.schema.eure"
+/// name = "John"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaReference {
+    /// The schema location as written: a path relative to the document or a URL.
+    pub path: String,
+    /// The node holding the `$schema` value, for error reporting.
+    pub node_id: NodeId,
+}
+
+impl SchemaReference {
+    /// Read the `$schema` extension of the root node.
+    ///
+    /// Returns `None` if the root has no `$schema` extension or its value is not text.
+    pub fn from_document(doc: &EureDocument) -> Option<Self> {
+        let node_id = doc.root().get_extension(&Identifier::SCHEMA)?;
+        let path: String = doc.parse(node_id).ok()?;
+        Some(Self { path, node_id })
+    }
+
+    /// Whether the reference is a remote URL rather than a file path.
+    ///
+    /// See [is_remote_location].
+    pub fn is_remote(&self) -> bool {
+        is_remote_location(&self.path)
+    }
+}
+
+/// Whether a schema or file location names a remote file.
+///
+/// Only `https://` URLs are remote. Anything else, `http://` included, is a
+/// local path.
+pub fn is_remote_location(location: &str) -> bool {
+    location.starts_with("https://")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_reference_from_document() {
+        let doc = eure!({
+            %schema = "./x.schema.eure",
+            name = "John",
+        });
+        let reference = SchemaReference::from_document(&doc).expect("$schema should be read");
+        assert_eq!(reference.path, "./x.schema.eure");
+        assert_eq!(
+            reference.node_id,
+            doc.root().get_extension(&Identifier::SCHEMA).unwrap()
+        );
+        assert!(!reference.is_remote());
+    }
+
+    #[test]
+    fn test_schema_reference_missing() {
+        let doc = eure!({ name = "John" });
+        assert_eq!(SchemaReference::from_document(&doc), None);
+
+        // A `$schema` on a nested node does not apply to the document.
+        let doc = eure!({ person.%schema = "./x.schema.eure" });
+        assert_eq!(SchemaReference::from_document(&doc), None);
+    }
+
+    #[test]
+    fn test_schema_reference_url() {
+        let doc = eure!({ %schema = "https://eure.dev/v0.1.0/schemas/eure-schema.schema.eure" });
+        let reference = SchemaReference::from_document(&doc).unwrap();
+        assert!(reference.is_remote());
+
+        let doc = eure!({ %schema = "http://example.com/x.schema.eure" });
+        let reference = SchemaReference::from_document(&doc).unwrap();
+        assert!(!reference.is_remote());
+    }
+}
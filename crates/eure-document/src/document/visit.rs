@@ -0,0 +1,184 @@
+//! In-place transformation of document leaves.
+
+use crate::document::map_key_segment;
+use crate::prelude_internal::*;
+
+/// Visitor for [`EureDocument::visit_mut`].
+///
+/// Each method receives the path of the visited node and a [`LeafMut`] handle
+/// through which the node's value can be replaced. Both methods do nothing by
+/// default.
+pub trait DocumentVisitorMut {
+    /// Called for every node holding a primitive value.
+    fn visit_primitive(&mut self, path: &EurePath, leaf: LeafMut<'_>) {
+        let _ = (path, leaf);
+    }
+
+    /// Called for every hole (`!` or `!label`).
+    fn visit_hole(&mut self, path: &EurePath, leaf: LeafMut<'_>) {
+        let _ = (path, leaf);
+    }
+}
+
+/// Mutable handle to a leaf node: a primitive value or a hole.
+///
+/// A leaf may only be replaced by another leaf. Turning it into a container
+/// would require allocating children, which a visitor cannot do while the
+/// document is being traversed.
+pub struct LeafMut<'d> {
+    content: &'d mut NodeValue,
+}
+
+impl LeafMut<'_> {
+    /// The current value of the node.
+    pub fn content(&self) -> &NodeValue {
+        self.content
+    }
+
+    /// The primitive value of the node, or `None` for a hole.
+    pub fn as_primitive(&self) -> Option<&PrimitiveValue> {
+        match &*self.content {
+            NodeValue::Primitive(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Replace the node's value with `value`.
+    pub fn set_primitive(&mut self, value: PrimitiveValue) {
+        *self.content = NodeValue::Primitive(value);
+    }
+
+    /// Replace the node's value with a hole carrying `label`.
+    pub fn set_hole(&mut self, label: Option<Identifier>) {
+        *self.content = NodeValue::Hole(label);
+    }
+}
+
+impl EureDocument {
+    /// Walk every node reachable from the root in document order, letting
+    /// `visitor` replace primitive values and holes in place.
+    ///
+    /// Container nodes are not passed to the visitor, and the structure of the
+    /// document (its maps, arrays, tuples and extensions) is left unchanged.
+    /// Paths are built as by [`Self::path_to`].
+    pub fn visit_mut(&mut self, visitor: &mut impl DocumentVisitorMut) {
+        let mut path = EurePath::root();
+        self.visit_node_mut(self.root, &mut path, visitor);
+    }
+
+    fn visit_node_mut(
+        &mut self,
+        node_id: NodeId,
+        path: &mut EurePath,
+        visitor: &mut impl DocumentVisitorMut,
+    ) {
+        let children: Vec<(PathSegment, NodeId)> = match &mut self.nodes[node_id.0].content {
+            content @ NodeValue::Primitive(_) => {
+                visitor.visit_primitive(path, LeafMut { content });
+                Vec::new()
+            }
+            content @ NodeValue::Hole(_) => {
+                visitor.visit_hole(path, LeafMut { content });
+                Vec::new()
+            }
+            NodeValue::Map(map) => map
+                .iter()
+                .map(|(key, &child)| (map_key_segment(key), child))
+                .collect(),
+            NodeValue::PartialMap(map) => map
+                .iter()
+                .map(|(key, &child)| (PathSegment::PartialValue(key.clone()), child))
+                .collect(),
+            NodeValue::Array(items) => items
+                .iter()
+                .enumerate()
+                .map(|(index, &child)| {
+                    let segment = PathSegment::ArrayIndex(ArrayIndexKind::Specific(index));
+                    (segment, child)
+                })
+                .collect(),
+            NodeValue::Tuple(items) => items
+                .iter()
+                .enumerate()
+                .map(|(index, &child)| (PathSegment::TupleIndex(index as u8), child))
+                .collect(),
+        };
+        let extensions: Vec<(PathSegment, NodeId)> = self.nodes[node_id.0]
+            .extensions
+            .iter()
+            .map(|(ident, &child)| (PathSegment::Extension(ident.clone()), child))
+            .collect();
+
+        for (segment, child) in children.into_iter().chain(extensions) {
+            path.0.push(segment);
+            self.visit_node_mut(child, path, visitor);
+            path.0.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Redact {
+        visited: Vec<EurePath>,
+    }
+
+    impl DocumentVisitorMut for Redact {
+        fn visit_primitive(&mut self, path: &EurePath, mut leaf: LeafMut<'_>) {
+            if let Some(PrimitiveValue::Text(text)) = leaf.as_primitive()
+                && text.as_str() == "secret"
+            {
+                self.visited.push(path.clone());
+                leaf.set_primitive(PrimitiveValue::Text(Text::plaintext("***")));
+            }
+        }
+    }
+
+    #[test]
+    fn test_visit_mut_redacts_nested_text() {
+        let mut doc = eure!({
+            name = "app",
+            db.password = "secret",
+            tokens = ["secret", "public"],
+            users[].key = "secret",
+            users[].key = "visible",
+        });
+        let node_count = doc.node_count();
+
+        let mut visitor = Redact {
+            visited: Vec::new(),
+        };
+        doc.visit_mut(&mut visitor);
+
+        let expected = eure!({
+            name = "app",
+            db.password = "***",
+            tokens = ["***", "public"],
+            users[].key = "***",
+            users[].key = "visible",
+        });
+        assert_eq!(doc, expected);
+        assert_eq!(doc.node_count(), node_count);
+        assert_eq!(visitor.visited.len(), 3);
+    }
+
+    #[test]
+    fn test_visit_mut_fills_holes() {
+        struct FillHoles;
+
+        impl DocumentVisitorMut for FillHoles {
+            fn visit_hole(&mut self, _path: &EurePath, mut leaf: LeafMut<'_>) {
+                leaf.set_primitive(PrimitiveValue::Integer(0.into()));
+            }
+        }
+
+        let mut doc = eure!({
+            a = !,
+            b.c = !,
+        });
+        doc.visit_mut(&mut FillHoles);
+        assert_eq!(doc, eure!({ a = 0, b.c = 0 }));
+    }
+}
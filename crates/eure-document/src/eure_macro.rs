@@ -18,6 +18,8 @@
 /// - `@code("lang", "content")`: Creates inline code with explicit language
 /// - `@block("content")`: Creates block code with implicit language
 /// - `@block("lang", "content")`: Creates block code with explicit language
+/// - `..doc`: Copies the nodes of an existing [`EureDocument`](crate::document::EureDocument)
+///   into the current block (`doc` must be a single token tree, e.g. an identifier)
 ///
 /// # Examples
 ///
@@ -91,6 +93,15 @@
 ///     point = (1.0f64, 2.0f64)
 /// });
 ///
+/// // Spreading an existing document into a block
+/// let defaults = eure!({ host = "localhost", port = 8080 });
+/// let doc = eure!({
+///     server {
+///         ..defaults
+///         name = "api"
+///     }
+/// });
+///
 /// // Sections (like TOML)
 /// let doc = eure!({
 ///     @user
@@ -472,6 +483,13 @@ macro_rules! eure {
         $crate::eure!(@section_after_seg $c scope; $($rest)*);
     }};
 
+    // Spread: `..doc` copies the nodes of an existing document into the current node
+    (@stmt $c:ident; .. $src:tt $($rest:tt)*) => {{
+        let src: &$crate::document::EureDocument = &$src;
+        $c.import_subtree(src, src.get_root_id()).unwrap();
+        $crate::eure!(@stmt $c; $($rest)*);
+    }};
+
     // Start parsing a path-based statement - delegate to path parser
     // Creates a scope that will be closed when the statement ends
     (@stmt $c:ident; $($tokens:tt)+) => {{
@@ -2658,4 +2676,54 @@ mod tests {
         let root = doc.parse_context(doc.get_root_id()).parse_record().unwrap();
         assert_eq!(root.parse_field::<&str>("message").unwrap(), "hello");
     }
+
+    #[test]
+    fn test_eure_spread_document_into_block() {
+        let defaults = eure!({
+            host = "localhost"
+            port = 8080
+            tls.%enabled = false
+        });
+        let doc = eure!({
+            name = "app"
+            server {
+                ..defaults
+                tls.cert = "cert.pem"
+                workers = 4
+            }
+        });
+        let expected = eure!({
+            name = "app"
+            server {
+                host = "localhost"
+                port = 8080
+                tls.%enabled = false
+                tls.cert = "cert.pem"
+                workers = 4
+            }
+        });
+        assert_eq!(doc, expected);
+    }
+
+    #[test]
+    fn test_eure_spread_fragments_at_root() {
+        let first = eure!({ items[] = 1, a = "x" });
+        let second = eure!({ items[] = 2, b = "y" });
+        let doc = eure!({ ..first, ..second });
+        assert_eq!(doc, eure!({ items = [1, 2], a = "x", b = "y" }));
+    }
+
+    #[test]
+    fn test_import_subtree_rejects_rebinding() {
+        use crate::document::InsertErrorKind;
+        use crate::document::constructor::DocumentConstructor;
+
+        let fragment = eure!({ a = 1 });
+        let mut c = DocumentConstructor::new();
+        eure!(c; { a = 2 });
+        let err = c
+            .import_subtree(&fragment, fragment.get_root_id())
+            .unwrap_err();
+        assert_eq!(err.kind, InsertErrorKind::BindingTargetHasValue);
+    }
 }
@@ -106,6 +106,19 @@ impl Identifier {
         Identifier(Cow::Borrowed(s))
     }
 
+    /// Creates a new Identifier, validating it against the Eure identifier rules.
+    ///
+    /// Equivalent to `s.parse::<Identifier>()`. Use this for identifiers that
+    /// come from user input; use [`Identifier::new_unchecked`] for constants.
+    pub fn new(s: &str) -> Result<Self, IdentifierError> {
+        s.parse()
+    }
+
+    /// Returns true if `s` is a valid identifier.
+    pub fn is_valid(s: &str) -> bool {
+        Self::new(s).is_ok()
+    }
+
     pub fn into_string(self) -> String {
         self.0.into()
     }
@@ -217,6 +230,34 @@ mod tests {
         assert_eq!(id.as_ref(), "borrowed");
     }
 
+    #[test]
+    fn test_identifier_new() {
+        assert_eq!(Identifier::new("hello").unwrap().as_ref(), "hello");
+        assert_eq!(Identifier::new(""), Err(IdentifierError::Empty));
+        assert_eq!(
+            Identifier::new("9lives"),
+            Err(IdentifierError::InvalidChar {
+                at: 0,
+                invalid_char: '9',
+            })
+        );
+        assert_eq!(
+            Identifier::new("two words"),
+            Err(IdentifierError::InvalidChar {
+                at: 3,
+                invalid_char: ' ',
+            })
+        );
+    }
+
+    #[test]
+    fn test_identifier_is_valid() {
+        assert!(Identifier::is_valid("kebab-case_ok"));
+        assert!(!Identifier::is_valid(""));
+        assert!(!Identifier::is_valid("1st"));
+        assert!(!Identifier::is_valid("has space"));
+    }
+
     #[test]
     fn test_empty_string_returns_empty_error() {
         let result = Identifier::from_str("");
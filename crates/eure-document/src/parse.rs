@@ -2,6 +2,7 @@
 
 extern crate alloc;
 
+pub mod coercion;
 pub mod object_key;
 pub mod record;
 pub mod tuple;
@@ -10,6 +11,7 @@ pub mod variant_path;
 
 use alloc::borrow::{Cow, ToOwned};
 use alloc::string::String;
+pub use coercion::Coercions;
 use indexmap::{IndexMap, IndexSet};
 pub use object_key::ParseObjectKey;
 pub use record::RecordParser;
@@ -287,6 +289,10 @@ pub struct ParseContext<'doc> {
     flatten_ctx: Option<FlattenContext>,
     /// Tracks accessed fields and extensions.
     accessed: AccessedSet,
+    /// Replacement primitive values, inherited by child contexts.
+    coercions: Option<&'doc Coercions>,
+    /// Whether `Option<T>` reads a hole as `None`, inherited by child contexts.
+    holes_as_missing: bool,
 }
 
 impl<'doc> ParseContext<'doc> {
@@ -298,6 +304,8 @@ impl<'doc> ParseContext<'doc> {
             variant_path: None,
             flatten_ctx: None,
             accessed: AccessedSet::new(),
+            coercions: None,
+            holes_as_missing: false,
         }
     }
 
@@ -319,6 +327,8 @@ impl<'doc> ParseContext<'doc> {
             variant_path: None,
             flatten_ctx: Some(flatten_ctx),
             accessed,
+            coercions: None,
+            holes_as_missing: false,
         }
     }
 
@@ -358,6 +368,36 @@ impl<'doc> ParseContext<'doc> {
         self.doc.node(self.node_id)
     }
 
+    /// Attach replacement primitive values to this context.
+    ///
+    /// While attached, [`parse_primitive`](Self::parse_primitive) returns the
+    /// replacement for any node that has one, and records it in
+    /// [`Coercions::applied`]. Child contexts inherit the coercions.
+    pub fn with_coercions(mut self, coercions: &'doc Coercions) -> Self {
+        self.coercions = Some(coercions);
+        self
+    }
+
+    /// Get the attached coercions, if any.
+    pub fn coercions(&self) -> Option<&'doc Coercions> {
+        self.coercions
+    }
+
+    /// Set how `Option<T>` treats a hole (`!`). Child contexts inherit the mode.
+    ///
+    /// `Option<T>` always reads `null` as `None` and any other value as
+    /// `Some`. A hole is an error by default; with `holes_as_missing` it is
+    /// read as `None`, as if the value had not been written yet.
+    pub fn with_holes_as_missing(mut self, holes_as_missing: bool) -> Self {
+        self.holes_as_missing = holes_as_missing;
+        self
+    }
+
+    /// Whether `Option<T>` reads a hole as `None`.
+    pub fn holes_as_missing(&self) -> bool {
+        self.holes_as_missing
+    }
+
     /// Create a new context at a different node (clears variant path and flatten context).
     pub(crate) fn at(&self, node_id: NodeId) -> Self {
         Self {
@@ -366,6 +406,8 @@ impl<'doc> ParseContext<'doc> {
             variant_path: None,
             flatten_ctx: None,
             accessed: AccessedSet::new(),
+            coercions: self.coercions,
+            holes_as_missing: self.holes_as_missing,
         }
     }
 
@@ -418,6 +460,8 @@ impl<'doc> ParseContext<'doc> {
             variant_path: self.variant_path.clone(),
             flatten_ctx: Some(flatten_ctx),
             accessed: self.accessed.clone(),
+            coercions: self.coercions,
+            holes_as_missing: self.holes_as_missing,
         }
     }
 
@@ -426,6 +470,13 @@ impl<'doc> ParseContext<'doc> {
         T::parse(self)
     }
 
+    /// Parse the current node as a value borrowing from the document.
+    ///
+    /// See [`FromEureRef`].
+    pub fn parse_ref<T: FromEureRef<'doc>>(&self) -> Result<T, ParseError> {
+        T::parse_ref(self)
+    }
+
     /// Parse the current node as type T using a marker/strategy type M.
     ///
     /// This is used for parsing remote types where `M` implements
@@ -474,8 +525,13 @@ impl<'doc> ParseContext<'doc> {
     ///
     /// Returns `NotPrimitive` error if the node is not a primitive.
     /// Returns `UnexpectedVariantPath` error if variant path is not empty.
+    /// Returns the coerced value instead if coercions are attached and have a
+    /// replacement for this node.
     pub fn parse_primitive(&self) -> Result<&'doc PrimitiveValue, ParseError> {
         self.ensure_no_variant_path()?;
+        if let Some(value) = self.coercions.and_then(|c| c.apply(self.node_id)) {
+            return Ok(value);
+        }
         match &self.node().content {
             NodeValue::Primitive(p) => Ok(p),
             _ => Err(ParseError {
@@ -487,6 +543,41 @@ impl<'doc> ParseContext<'doc> {
         }
     }
 
+    /// Parse known fields as `K` and collect the remaining fields as `T` values.
+    ///
+    /// `K` is parsed in a flattened context, so its `deny_unknown_fields()` is a no-op
+    /// and every field it accesses is excluded from the extras. Unlike
+    /// `allow_unknown_fields()`, the remaining string-keyed entries are parsed as `T`
+    /// and returned instead of being discarded.
+    ///
+    /// Returns `InvalidKeyType` if the map contains non-string keys.
+    pub fn parse_map_with_values<K, T>(&self) -> Result<(K, HashMap<String, T>), K::Error>
+    where
+        K: FromEure<'doc>,
+        K::Error: From<ParseError>,
+        T: FromEure<'doc>,
+        T::Error: Into<K::Error>,
+    {
+        let flatten_ctx = self.flatten();
+        let known = K::parse(&flatten_ctx)?;
+
+        let rec = flatten_ctx.parse_record()?;
+        let mut rest = HashMap::new();
+        for entry in rec.unknown_fields() {
+            let (name, field_ctx) = entry.map_err(|(key, field_ctx)| ParseError {
+                node_id: field_ctx.node_id(),
+                kind: ParseErrorKind::InvalidKeyType(key.clone()),
+            })?;
+            rest.insert(name.to_string(), T::parse(&field_ctx).map_err(Into::into)?);
+        }
+        for name in rest.keys() {
+            self.accessed.add_field(name.as_str());
+        }
+
+        self.deny_unknown_extensions()?;
+        Ok((known, rest))
+    }
+
     // =========================================================================
     // Extension parsing methods
     // =========================================================================
@@ -547,7 +638,7 @@ impl<'doc> ParseContext<'doc> {
                 node_id: self.node_id,
                 kind: ParseErrorKind::MissingExtension(name.to_string()),
             })?;
-        let ctx = ParseContext::new(self.doc, *ext_node_id);
+        let ctx = self.at(*ext_node_id);
         parser.parse(&ctx)
     }
 
@@ -581,7 +672,7 @@ impl<'doc> ParseContext<'doc> {
         self.mark_ext_accessed(ident.clone());
         match self.node().extensions.get(&ident) {
             Some(ext_node_id) => {
-                let ctx = ParseContext::new(self.doc, *ext_node_id);
+                let ctx = self.at(*ext_node_id);
                 Ok(Some(parser.parse(&ctx)?))
             }
             None => Ok(None),
@@ -607,7 +698,7 @@ impl<'doc> ParseContext<'doc> {
                     node_id: self.node_id,
                     kind: ParseErrorKind::MissingExtension(name.to_string()),
                 })?;
-        Ok(ParseContext::new(self.doc, ext_node_id))
+        Ok(self.at(ext_node_id))
     }
 
     /// Get the parse context for an optional extension field without parsing it.
@@ -620,7 +711,7 @@ impl<'doc> ParseContext<'doc> {
         self.node()
             .extensions
             .get(&ident)
-            .map(|&node_id| ParseContext::new(self.doc, node_id))
+            .map(|&node_id| self.at(node_id))
     }
 
     /// Finish parsing with Deny policy (error if unknown extensions exist).
@@ -651,7 +742,6 @@ impl<'doc> ParseContext<'doc> {
     pub fn unknown_extensions(
         &self,
     ) -> impl Iterator<Item = (&'doc Identifier, ParseContext<'doc>)> + '_ {
-        let doc = self.doc;
         // Clone the accessed set for filtering - we need the current state
         let accessed = self.accessed.clone();
         self.node()
@@ -659,7 +749,7 @@ impl<'doc> ParseContext<'doc> {
             .iter()
             .filter_map(move |(ident, &node_id)| {
                 if !accessed.has_ext(ident) {
-                    Some((ident, ParseContext::new(doc, node_id)))
+                    Some((ident, self.at(node_id)))
                 } else {
                     None
                 }
@@ -701,7 +791,11 @@ impl<'doc> ParseContext<'doc> {
             None => FlattenContext::new(self.accessed.clone(), ParserScope::Extension),
         };
 
-        ParseContext::with_flatten_ctx(self.doc, self.node_id, flatten_ctx)
+        ParseContext {
+            coercions: self.coercions,
+            holes_as_missing: self.holes_as_missing,
+            ..ParseContext::with_flatten_ctx(self.doc, self.node_id, flatten_ctx)
+        }
     }
 
     /// Check if the current node is null.
@@ -712,6 +806,11 @@ impl<'doc> ParseContext<'doc> {
         )
     }
 
+    /// Check if the current node is a hole.
+    pub fn is_hole(&self) -> bool {
+        matches!(&self.node().content, NodeValue::Hole(_))
+    }
+
     /// Create a child context with the remaining variant path.
     pub(crate) fn with_variant_rest(&self, rest: Option<VariantPath>) -> Self {
         Self {
@@ -720,6 +819,8 @@ impl<'doc> ParseContext<'doc> {
             variant_path: rest,
             flatten_ctx: self.flatten_ctx.clone(),
             accessed: self.accessed.clone(),
+            coercions: self.coercions,
+            holes_as_missing: self.holes_as_missing,
         }
     }
 
@@ -903,6 +1004,13 @@ pub enum ParseErrorKind {
     #[error("invalid {kind}: {reason}")]
     InvalidPattern { kind: String, reason: String },
 
+    /// Regular expression failed to compile.
+    #[error("invalid regex '{pattern}': {error}")]
+    InvalidRegex {
+        pattern: String,
+        error: regex::Error,
+    },
+
     /// Nested parse error with path context.
     #[error("at {path}: {source}")]
     Nested {
@@ -979,6 +1087,10 @@ pub enum ParseErrorKind {
     /// Expected a primitive value but got a non-primitive node.
     #[error("expected primitive value, got {actual}")]
     NotPrimitive { actual: ValueKind },
+
+    /// Text value could not be parsed as the expected type, e.g. an IP address.
+    #[error("invalid value: expected {expected}, got {got:?}")]
+    InvalidValue { expected: String, got: String },
 }
 
 impl ParseErrorKind {
@@ -989,6 +1101,40 @@ impl ParseErrorKind {
             source: Box::new(self),
         }
     }
+
+    /// Stable error code for this kind of error.
+    ///
+    /// Parse error codes live in the `E1xxx` range. [`ParseErrorKind::Nested`] reports
+    /// the code of the error it wraps.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::UnexpectedHole => "E1001",
+            Self::TypeMismatch { .. } => "E1002",
+            Self::MissingField(_) => "E1003",
+            Self::MissingExtension(_) => "E1004",
+            Self::UnknownVariant(_) => "E1005",
+            Self::OutOfRange(_) => "E1006",
+            Self::InvalidPattern { .. } => "E1007",
+            Self::InvalidRegex { .. } => "E1008",
+            Self::Nested { source, .. } => source.code(),
+            Self::InvalidIdentifier(_) => "E1009",
+            Self::UnexpectedTupleLength { .. } => "E1010",
+            Self::UnknownField(_) => "E1011",
+            Self::UnknownExtension(_) => "E1012",
+            Self::InvalidKeyType(_) => "E1013",
+            Self::NoMatchingVariant { .. } => "E1014",
+            Self::ConflictingVariantTags { .. } => "E1015",
+            Self::AmbiguousUnion(_) => "E1016",
+            Self::LiteralMismatch { .. } => "E1017",
+            Self::UnexpectedVariantPath(_) => "E1018",
+            Self::InvalidVariantType(_) => "E1019",
+            Self::InvalidVariantPath(_) => "E1020",
+            Self::RecordInExtensionScope => "E1021",
+            Self::UnexpectedArrayLength { .. } => "E1022",
+            Self::NotPrimitive { .. } => "E1023",
+            Self::InvalidValue { .. } => "E1024",
+        }
+    }
 }
 
 fn format_no_matching_variant(
@@ -1104,6 +1250,73 @@ impl FromEure<'_> for String {
     }
 }
 
+/// Parse a value that borrows from the document instead of copying.
+///
+/// [`FromEure`] already covers `&'doc str`, but the blanket
+/// `Cow<'static, T>` impl always produces an owned value. This trait provides
+/// the borrowing counterpart, so `Cow<'doc, str>` fields can be parsed as
+/// [`Cow::Borrowed`] without allocating.
+///
+/// Use it through [`ParseContext::parse_ref`], [`RecordParser::parse_field_ref`],
+/// or the [`Borrowed`] marker with `#[eure(via = "Borrowed")]`.
+///
+/// # Example
+///
+/// ```
+/// # use eure_document::eure;
+/// # use std::borrow::Cow;
+/// let doc = eure!({ name = "app" });
+/// let rec = doc.parse_record(doc.get_root_id()).unwrap();
+/// let name: Cow<'_, str> = rec.parse_field_ref("name").unwrap();
+/// assert!(matches!(name, Cow::Borrowed("app")));
+/// ```
+pub trait FromEureRef<'doc>: Sized {
+    /// Parse a borrowed value from the given parse context.
+    fn parse_ref(ctx: &ParseContext<'doc>) -> Result<Self, ParseError>;
+}
+
+impl<'doc> FromEureRef<'doc> for &'doc str {
+    fn parse_ref(ctx: &ParseContext<'doc>) -> Result<Self, ParseError> {
+        ctx.parse::<&'doc str>()
+    }
+}
+
+impl<'doc> FromEureRef<'doc> for Cow<'doc, str> {
+    fn parse_ref(ctx: &ParseContext<'doc>) -> Result<Self, ParseError> {
+        ctx.parse::<&'doc str>().map(Cow::Borrowed)
+    }
+}
+
+impl<'doc> FromEureRef<'doc> for &'doc Text {
+    fn parse_ref(ctx: &ParseContext<'doc>) -> Result<Self, ParseError> {
+        if let PrimitiveValue::Text(text) = ctx.parse_primitive()? {
+            return Ok(text);
+        }
+        Err(ctx.unexpected_kind(ValueKind::Text))
+    }
+}
+
+/// Marker type that parses any [`FromEureRef`] value through [`FromEure`].
+///
+/// This lets derived types opt into borrowing per field:
+///
+/// ```ignore
+/// #[derive(FromEure)]
+/// struct Config<'a> {
+///     #[eure(via = "Borrowed")]
+///     name: Cow<'a, str>,
+/// }
+/// ```
+pub struct Borrowed;
+
+impl<'doc, T: FromEureRef<'doc>> FromEure<'doc, T> for Borrowed {
+    type Error = ParseError;
+
+    fn parse(ctx: &ParseContext<'doc>) -> Result<T, Self::Error> {
+        T::parse_ref(ctx)
+    }
+}
+
 impl FromEure<'_> for crate::plan::Form {
     type Error = ParseError;
 
@@ -1527,16 +1740,56 @@ impl FromEure<'_> for regex::Regex {
 
     fn parse(ctx: &ParseContext<'_>) -> Result<Self, Self::Error> {
         let pattern: &str = ctx.parse()?;
-        regex::Regex::new(pattern).map_err(|e| ParseError {
+        regex::Regex::new(pattern).map_err(|error| ParseError {
             node_id: ctx.node_id(),
-            kind: ParseErrorKind::InvalidPattern {
-                kind: format!("regex '{}'", pattern),
-                reason: e.to_string(),
+            kind: ParseErrorKind::InvalidRegex {
+                pattern: pattern.to_string(),
+                error,
             },
         })
     }
 }
 
+/// Implements `FromEure` for `std` types parsed from their text form via `FromStr`.
+#[cfg(feature = "std")]
+macro_rules! impl_from_eure_from_str {
+    ($($ty:ty => $expected:literal),* $(,)?) => {
+        $(
+            impl FromEure<'_> for $ty {
+                type Error = ParseError;
+
+                fn parse(ctx: &ParseContext<'_>) -> Result<Self, Self::Error> {
+                    let value: &str = ctx.parse()?;
+                    value.parse().map_err(|_| ParseError {
+                        node_id: ctx.node_id(),
+                        kind: ParseErrorKind::InvalidValue {
+                            expected: $expected.to_string(),
+                            got: value.to_string(),
+                        },
+                    })
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "std")]
+impl_from_eure_from_str!(
+    std::net::IpAddr => "IP address",
+    std::net::Ipv4Addr => "IPv4 address",
+    std::net::Ipv6Addr => "IPv6 address",
+    std::net::SocketAddr => "socket address",
+);
+
+#[cfg(feature = "std")]
+impl FromEure<'_> for std::path::PathBuf {
+    type Error = ParseError;
+
+    fn parse(ctx: &ParseContext<'_>) -> Result<Self, Self::Error> {
+        ctx.parse::<&str>().map(std::path::PathBuf::from)
+    }
+}
+
 /// `Option<M>` parses `Option<T>` using M's FromEure implementation.
 ///
 /// When `M = T` (same type), this is standard `Option<T>` parsing.
@@ -1545,6 +1798,8 @@ impl FromEure<'_> for regex::Regex {
 /// - `$variant: some` -> parse T via M
 /// - `$variant: none` -> None
 /// - No `$variant` and value is null -> None
+/// - No `$variant` and value is a hole -> None if the context reads
+///   [holes as missing](ParseContext::with_holes_as_missing), an error otherwise
 /// - No `$variant` and value is not null -> try parsing as T (Some)
 #[diagnostic::do_not_recommend]
 impl<'doc, M, T> FromEure<'doc, Option<T>> for Option<M>
@@ -1555,6 +1810,9 @@ where
     type Error = M::Error;
 
     fn parse(ctx: &ParseContext<'doc>) -> Result<Option<T>, Self::Error> {
+        if ctx.holes_as_missing() && ctx.is_hole() {
+            return Ok(None);
+        }
         ctx.parse_union::<Option<T>, M::Error>()?
             .variant("some", (M::parse).map(Some))
             .variant("none", |ctx: &ParseContext<'_>| {
@@ -1845,6 +2103,41 @@ mod tests {
         assert_eq!(value, None);
     }
 
+    fn parse_option_u32(doc: &EureDocument, holes_as_missing: bool) -> Option<Option<u32>> {
+        doc.parse_context(doc.get_root_id())
+            .with_holes_as_missing(holes_as_missing)
+            .parse::<Option<u32>>()
+            .ok()
+    }
+
+    #[test]
+    fn test_option_hole_policy() {
+        let null = eure!({ = null });
+        let hole = eure!({ = ! });
+        let value = eure!({ = 7 });
+
+        // Strict (default): a hole is an error
+        assert_eq!(parse_option_u32(&null, false), Some(None));
+        assert_eq!(parse_option_u32(&hole, false), None);
+        assert_eq!(parse_option_u32(&value, false), Some(Some(7)));
+
+        // Holes as missing: a hole reads as None
+        assert_eq!(parse_option_u32(&null, true), Some(None));
+        assert_eq!(parse_option_u32(&hole, true), Some(None));
+        assert_eq!(parse_option_u32(&value, true), Some(Some(7)));
+    }
+
+    #[test]
+    fn test_option_hole_policy_inherited_by_fields() {
+        let doc = eure!({ value = ! });
+        let ctx = doc
+            .parse_context(doc.get_root_id())
+            .with_holes_as_missing(true);
+        let rec = ctx.parse_record().unwrap();
+        let value: Option<u32> = rec.parse_field("value").unwrap();
+        assert_eq!(value, None);
+    }
+
     #[test]
     fn test_result_ok_tagged() {
         let doc = create_record_with_variant(
@@ -1988,6 +2281,67 @@ mod tests {
         rec.deny_unknown_fields().unwrap();
     }
 
+    #[derive(Debug, PartialEq)]
+    struct ServerConfig {
+        host: String,
+        port: u16,
+    }
+
+    impl<'doc> FromEure<'doc> for ServerConfig {
+        type Error = ParseError;
+
+        fn parse(ctx: &ParseContext<'doc>) -> Result<Self, Self::Error> {
+            let rec = ctx.parse_record()?;
+            let host = rec.parse_field("host")?;
+            let port = rec.parse_field("port")?;
+            rec.deny_unknown_fields()?;
+            Ok(ServerConfig { host, port })
+        }
+    }
+
+    #[test]
+    fn test_parse_map_with_values_collects_extras() {
+        let doc = eure!({
+            host = "localhost",
+            port = 8080,
+            env = "prod",
+            team = "core",
+        });
+        let ctx = doc.parse_context(doc.get_root_id());
+
+        let (known, rest) = ctx.parse_map_with_values::<ServerConfig, String>().unwrap();
+
+        assert_eq!(
+            known,
+            ServerConfig {
+                host: "localhost".to_string(),
+                port: 8080,
+            }
+        );
+        assert_eq!(
+            rest,
+            HashMap::from([
+                ("env".to_string(), "prod".to_string()),
+                ("team".to_string(), "core".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_map_with_values_extra_type_mismatch() {
+        let doc = eure!({
+            host = "localhost",
+            port = 8080,
+            env = 1,
+        });
+        let ctx = doc.parse_context(doc.get_root_id());
+
+        let err = ctx
+            .parse_map_with_values::<ServerConfig, String>()
+            .unwrap_err();
+        assert!(matches!(err.kind, ParseErrorKind::TypeMismatch { .. }));
+    }
+
     // =========================================================================
     // Remote type support tests
     // =========================================================================
@@ -2058,6 +2412,21 @@ mod tests {
         assert_eq!(duration, None);
     }
 
+    #[test]
+    fn test_vec_error_reports_element_index() {
+        let doc = eure!({ ports = [80, 443, "http", 8080] });
+        let root_id = doc.get_root_id();
+        let rec = doc.parse_record(root_id).unwrap();
+        let err = rec.parse_field::<Vec<u32>>("ports").unwrap_err();
+
+        let path = doc.path_to(err.node_id).unwrap();
+        assert_eq!(path.to_string(), "ports[2]");
+        assert!(matches!(
+            doc.node(err.node_id).content,
+            NodeValue::Primitive(PrimitiveValue::Text(_))
+        ));
+    }
+
     #[test]
     fn test_remote_type_in_vec() {
         let doc = eure!({
@@ -2267,4 +2636,109 @@ mod tests {
         let value: Cow<'static, str> = rec.parse_field("name").unwrap();
         assert_eq!(value, Cow::<str>::Owned("hello".to_string()));
     }
+
+    // =========================================================================
+    // FromEureRef tests
+    // =========================================================================
+
+    struct BorrowedConfig<'a> {
+        name: Cow<'a, str>,
+        description: Option<&'a str>,
+    }
+
+    impl<'doc> FromEure<'doc> for BorrowedConfig<'doc> {
+        type Error = ParseError;
+
+        fn parse(ctx: &ParseContext<'doc>) -> Result<Self, Self::Error> {
+            let rec = ctx.parse_record()?;
+            let name = rec.parse_field_ref("name")?;
+            let description = rec.parse_field_optional_ref("description")?;
+            rec.deny_unknown_fields()?;
+            Ok(BorrowedConfig { name, description })
+        }
+    }
+
+    fn text_ptr(doc: &EureDocument, name: &str) -> *const u8 {
+        let rec = doc.parse_record(doc.get_root_id()).unwrap();
+        let text: &Text = rec.parse_field_ref(name).unwrap();
+        text.as_str().as_ptr()
+    }
+
+    #[test]
+    fn test_from_eure_ref_cow_borrows_from_document() {
+        let doc = eure!({ name = "hello", description = "world" });
+        let config: BorrowedConfig<'_> = doc.parse(doc.get_root_id()).unwrap();
+
+        // No allocation: the parsed strings point into the document's text.
+        let Cow::Borrowed(name) = config.name else {
+            panic!("expected borrowed Cow, got owned");
+        };
+        assert_eq!(name, "hello");
+        assert_eq!(name.as_ptr(), text_ptr(&doc, "name"));
+
+        let description = config.description.unwrap();
+        assert_eq!(description, "world");
+        assert_eq!(description.as_ptr(), text_ptr(&doc, "description"));
+    }
+
+    #[test]
+    fn test_from_eure_ref_optional_missing() {
+        let doc = eure!({ name = "hello" });
+        let config: BorrowedConfig<'_> = doc.parse(doc.get_root_id()).unwrap();
+        assert_eq!(config.name, "hello");
+        assert!(config.description.is_none());
+    }
+
+    #[test]
+    fn test_from_eure_ref_type_mismatch() {
+        let doc = eure!({ name = 1 });
+        let rec = doc.parse_record(doc.get_root_id()).unwrap();
+        let err = rec.parse_field_ref::<Cow<'_, str>>("name").unwrap_err();
+        assert!(matches!(err.kind, ParseErrorKind::TypeMismatch { .. }));
+    }
+
+    #[test]
+    fn test_borrowed_marker_via() {
+        let doc = eure!({ name = "hello" });
+        let ctx = doc.parse_context(doc.get_root_id());
+        let rec = ctx.parse_record().unwrap();
+        let field = rec.field("name").unwrap();
+        let value: Cow<'_, str> = field.parse_via::<Borrowed, _>().unwrap();
+        assert!(matches!(value, Cow::Borrowed("hello")));
+    }
+
+    #[test]
+    fn test_parse_ipv6_socket_addr() {
+        let doc = eure!({ listen = "[::1]:8080" });
+        let rec = doc.parse_record(doc.get_root_id()).unwrap();
+        let addr: std::net::SocketAddr = rec.parse_field("listen").unwrap();
+        assert_eq!(
+            addr,
+            std::net::SocketAddr::new(std::net::Ipv6Addr::LOCALHOST.into(), 8080)
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_ip_addr() {
+        let doc = eure!({ host = "999.0.0.1" });
+        let rec = doc.parse_record(doc.get_root_id()).unwrap();
+        let err = rec.parse_field::<std::net::IpAddr>("host").unwrap_err();
+        let host_id = rec.field("host").unwrap().node_id();
+        assert_eq!(err.node_id, host_id);
+        assert_eq!(
+            err.kind,
+            ParseErrorKind::InvalidValue {
+                expected: "IP address".to_string(),
+                got: "999.0.0.1".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_path_buf() {
+        let doc = eure!({ path = "config/app.eure" });
+        let rec = doc.parse_record(doc.get_root_id()).unwrap();
+        let path: std::path::PathBuf = rec.parse_field("path").unwrap();
+        assert_eq!(path, std::path::PathBuf::from("config/app.eure"));
+    }
 }
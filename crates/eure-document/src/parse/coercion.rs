@@ -0,0 +1,62 @@
+//! Replacement primitive values applied while parsing.
+
+use core::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::prelude_internal::*;
+
+/// Replacement primitive values keyed by node, applied by
+/// [`ParseContext::parse_primitive`](super::ParseContext::parse_primitive).
+///
+/// A parse context only consults coercions when they are attached with
+/// [`ParseContext::with_coercions`](super::ParseContext::with_coercions);
+/// parsing is strict otherwise. Schema-driven coercions are built by
+/// `eure_schema::coerce::schema_coercions`.
+///
+/// Only values read through `parse_primitive` are replaced. Code inspecting
+/// [`Node::content`](crate::document::node::Node::content) directly sees the
+/// original value.
+#[derive(Debug, Default)]
+pub struct Coercions {
+    values: HashMap<NodeId, PrimitiveValue>,
+    applied: RefCell<Vec<NodeId>>,
+}
+
+impl Coercions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the primitive value of `node_id` with `value`.
+    pub fn insert(&mut self, node_id: NodeId, value: PrimitiveValue) {
+        self.values.insert(node_id, value);
+    }
+
+    /// Get the replacement value for `node_id`, if any.
+    pub fn get(&self, node_id: NodeId) -> Option<&PrimitiveValue> {
+        self.values.get(&node_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Nodes whose replacement value was read during parsing, in read order.
+    pub fn applied(&self) -> Vec<NodeId> {
+        self.applied.borrow().clone()
+    }
+
+    /// Get the replacement value for `node_id` and record that it was used.
+    pub(crate) fn apply(&self, node_id: NodeId) -> Option<&PrimitiveValue> {
+        let value = self.values.get(&node_id)?;
+        let mut applied = self.applied.borrow_mut();
+        if !applied.contains(&node_id) {
+            applied.push(node_id);
+        }
+        Some(value)
+    }
+}
@@ -3,7 +3,7 @@
 use crate::parse::DocumentParser;
 use crate::prelude_internal::*;
 
-use super::{FromEure, ParseContext, ParseError, ParseErrorKind, ParserScope};
+use super::{FromEure, FromEureRef, ParseContext, ParseError, ParseErrorKind, ParserScope};
 
 /// Helper for parsing record (map with string keys) from Eure documents.
 ///
@@ -110,7 +110,7 @@ impl<'doc> RecordParser<'doc> {
                 node_id: self.ctx.node_id(),
                 kind: ParseErrorKind::MissingField(name.to_string()),
             })?;
-        let ctx = ParseContext::new(self.ctx.doc(), *field_node_id);
+        let ctx = self.ctx.at(*field_node_id);
         parser.parse(&ctx)
     }
 
@@ -122,6 +122,26 @@ impl<'doc> RecordParser<'doc> {
         self.parse_field_optional_with(name, T::parse)
     }
 
+    /// Get a required field, borrowing from the document.
+    ///
+    /// See [`FromEureRef`].
+    pub fn parse_field_ref<T>(&self, name: &str) -> Result<T, ParseError>
+    where
+        T: FromEureRef<'doc>,
+    {
+        self.parse_field_with(name, T::parse_ref)
+    }
+
+    /// Get an optional field, borrowing from the document.
+    ///
+    /// Returns `Ok(None)` if the field is not present.
+    pub fn parse_field_optional_ref<T>(&self, name: &str) -> Result<Option<T>, ParseError>
+    where
+        T: FromEureRef<'doc>,
+    {
+        self.parse_field_optional_with(name, T::parse_ref)
+    }
+
     /// Get an optional field.
     ///
     /// Returns `Ok(None)` if the field is not present.
@@ -137,7 +157,7 @@ impl<'doc> RecordParser<'doc> {
         self.mark_accessed(name);
         match self.map.get(&ObjectKey::String(name.to_string())) {
             Some(field_node_id) => {
-                let ctx = ParseContext::new(self.ctx.doc(), *field_node_id);
+                let ctx = self.ctx.at(*field_node_id);
                 Ok(Some(parser.parse(&ctx)?))
             }
             None => Ok(None),
@@ -157,7 +177,7 @@ impl<'doc> RecordParser<'doc> {
                 node_id: self.ctx.node_id(),
                 kind: ParseErrorKind::MissingField(name.to_string()),
             })?;
-        Ok(ParseContext::new(self.ctx.doc(), *field_node_id))
+        Ok(self.ctx.at(*field_node_id))
     }
 
     /// Get the parse context for an optional field without parsing it.
@@ -168,7 +188,7 @@ impl<'doc> RecordParser<'doc> {
         self.mark_accessed(name);
         self.map
             .get(&ObjectKey::String(name.to_string()))
-            .map(|node_id| ParseContext::new(self.ctx.doc(), *node_id))
+            .map(|node_id| self.ctx.at(*node_id))
     }
 
     /// Get a field as a nested record parser.
@@ -183,7 +203,7 @@ impl<'doc> RecordParser<'doc> {
                 node_id: self.ctx.node_id(),
                 kind: ParseErrorKind::MissingField(name.to_string()),
             })?;
-        let ctx = ParseContext::new(self.ctx.doc(), *field_node_id);
+        let ctx = self.ctx.at(*field_node_id);
         RecordParser::new(&ctx)
     }
 
@@ -197,7 +217,7 @@ impl<'doc> RecordParser<'doc> {
         self.mark_accessed(name);
         match self.map.get(&ObjectKey::String(name.to_string())) {
             Some(field_node_id) => {
-                let ctx = ParseContext::new(self.ctx.doc(), *field_node_id);
+                let ctx = self.ctx.at(*field_node_id);
                 Ok(Some(RecordParser::new(&ctx)?))
             }
             None => Ok(None),
@@ -273,7 +293,7 @@ impl<'doc> RecordParser<'doc> {
     ) -> impl Iterator<
         Item = Result<(&'doc str, ParseContext<'doc>), (&'doc ObjectKey, ParseContext<'doc>)>,
     > + '_ {
-        let doc = self.ctx.doc();
+        let ctx = &self.ctx;
         // Clone the accessed set for filtering - we need the current state
         let accessed = self.ctx.accessed().clone();
         self.map
@@ -281,12 +301,12 @@ impl<'doc> RecordParser<'doc> {
             .filter_map(move |(key, &node_id)| match key {
                 ObjectKey::String(name) => {
                     if !accessed.has_field(name.as_str()) {
-                        Some(Ok((name.as_str(), ParseContext::new(doc, node_id))))
+                        Some(Ok((name.as_str(), ctx.at(node_id))))
                     } else {
                         None // Accessed, skip
                     }
                 }
-                other => Some(Err((other, ParseContext::new(doc, node_id)))),
+                other => Some(Err((other, ctx.at(node_id)))),
             })
     }
 
@@ -301,7 +321,7 @@ impl<'doc> RecordParser<'doc> {
     pub fn unknown_entries(
         &self,
     ) -> impl Iterator<Item = (&'doc ObjectKey, ParseContext<'doc>)> + '_ {
-        let doc = self.ctx.doc();
+        let ctx = &self.ctx;
         // Clone the accessed set for filtering - we need the current state
         let accessed = self.ctx.accessed().clone();
         self.map.iter().filter_map(move |(key, &node_id)| {
@@ -309,13 +329,13 @@ impl<'doc> RecordParser<'doc> {
                 ObjectKey::String(name) => {
                     // For string keys, only return if not accessed
                     if !accessed.has_field(name.as_str()) {
-                        Some((key, ParseContext::new(doc, node_id)))
+                        Some((key, ctx.at(node_id)))
                     } else {
                         None
                     }
                 }
                 // Non-string keys are always returned (they can't be "accessed" via field methods)
-                _ => Some((key, ParseContext::new(doc, node_id))),
+                _ => Some((key, ctx.at(node_id))),
             }
         })
     }
@@ -7,7 +7,7 @@ use alloc::format;
 use crate::document::node::NodeTuple;
 use crate::prelude_internal::*;
 
-use super::{DocumentParser, FromEure, ParseContext, ParseError, ParseErrorKind};
+use super::{Coercions, DocumentParser, FromEure, ParseContext, ParseError, ParseErrorKind};
 
 /// Helper for parsing tuple types from Eure documents.
 ///
@@ -26,6 +26,8 @@ use super::{DocumentParser, FromEure, ParseContext, ParseError, ParseErrorKind};
 #[must_use]
 pub struct TupleParser<'doc> {
     doc: &'doc EureDocument,
+    coercions: Option<&'doc Coercions>,
+    holes_as_missing: bool,
     node_id: NodeId,
     tuple: &'doc NodeTuple,
     position: usize,
@@ -34,7 +36,10 @@ pub struct TupleParser<'doc> {
 impl<'doc> TupleParser<'doc> {
     /// Create a new TupleParser for the given context.
     pub(crate) fn new(ctx: &ParseContext<'doc>) -> Result<Self, ParseError> {
-        Self::from_doc_and_node(ctx.doc(), ctx.node_id())
+        let mut parser = Self::from_doc_and_node(ctx.doc(), ctx.node_id())?;
+        parser.coercions = ctx.coercions();
+        parser.holes_as_missing = ctx.holes_as_missing();
+        Ok(parser)
     }
 
     /// Create a new TupleParser from document and node ID directly.
@@ -46,6 +51,8 @@ impl<'doc> TupleParser<'doc> {
         match &node.content {
             NodeValue::Tuple(tuple) => Ok(Self {
                 doc,
+                coercions: None,
+                holes_as_missing: false,
                 node_id,
                 tuple,
                 position: 0,
@@ -93,8 +100,7 @@ impl<'doc> TupleParser<'doc> {
             kind: ParseErrorKind::MissingField(format!("#{}", index)),
         })?;
         self.position += 1;
-        let ctx = ParseContext::new(self.doc, element_node_id);
-        parser.parse(&ctx)
+        parser.parse(&self.element_ctx(element_node_id))
     }
 
     /// Get the next element using a marker type, advancing the position.
@@ -127,8 +133,7 @@ impl<'doc> TupleParser<'doc> {
             node_id: self.node_id,
             kind: ParseErrorKind::MissingField(format!("#{}", index)),
         })?;
-        let ctx = ParseContext::new(self.doc, element_node_id);
-        parser.parse(&ctx)
+        parser.parse(&self.element_ctx(element_node_id))
     }
 
     /// Get the element at a specific index using a marker type.
@@ -186,6 +191,16 @@ impl<'doc> TupleParser<'doc> {
     pub fn is_empty(&self) -> bool {
         self.tuple.is_empty()
     }
+
+    /// Create a context for an element, inheriting the tuple context's modes.
+    fn element_ctx(&self, element_node_id: NodeId) -> ParseContext<'doc> {
+        let mut ctx = ParseContext::new(self.doc, element_node_id)
+            .with_holes_as_missing(self.holes_as_missing);
+        if let Some(coercions) = self.coercions {
+            ctx = ctx.with_coercions(coercions);
+        }
+        ctx
+    }
 }
 
 #[cfg(test)]
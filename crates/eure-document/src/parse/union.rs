@@ -392,6 +392,8 @@ fn is_structural_parse_mismatch(kind: &ParseErrorKind) -> bool {
             | ParseErrorKind::UnknownExtension(_)
             | ParseErrorKind::LiteralMismatch { .. }
             | ParseErrorKind::InvalidPattern { .. }
+            | ParseErrorKind::InvalidValue { .. }
+            | ParseErrorKind::InvalidRegex { .. }
     )
 }
 
@@ -400,7 +402,10 @@ fn parse_error_priority(kind: &ParseErrorKind) -> u8 {
         ParseErrorKind::UnknownField(_) | ParseErrorKind::UnknownExtension(_) => 4,
         ParseErrorKind::MissingField(_) | ParseErrorKind::MissingExtension(_) => 3,
         ParseErrorKind::UnknownVariant(_) | ParseErrorKind::UnexpectedVariantPath(_) => 2,
-        ParseErrorKind::LiteralMismatch { .. } | ParseErrorKind::InvalidPattern { .. } => 2,
+        ParseErrorKind::LiteralMismatch { .. }
+        | ParseErrorKind::InvalidPattern { .. }
+        | ParseErrorKind::InvalidValue { .. }
+        | ParseErrorKind::InvalidRegex { .. } => 2,
         ParseErrorKind::TypeMismatch { .. }
         | ParseErrorKind::UnexpectedTupleLength { .. }
         | ParseErrorKind::UnexpectedArrayLength { .. }
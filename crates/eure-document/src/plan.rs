@@ -868,6 +868,31 @@ mod tests {
         c.finish()
     }
 
+    #[test]
+    fn extensions_emitted_in_stable_order() {
+        let mut c = DocumentConstructor::new();
+        c.bind_empty_map().unwrap();
+        c.set_extension("zeta", true).unwrap();
+        c.set_extension("alpha", true).unwrap();
+        c.set_extension("variant", "ok").unwrap();
+        let src = LayoutPlan::auto(c.finish()).unwrap().emit();
+
+        let keys: Vec<_> = src
+            .root_source()
+            .bindings
+            .iter()
+            .map(|b| b.path[0].key.clone())
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                crate::source::SourceKey::Extension("variant".parse().unwrap()),
+                crate::source::SourceKey::Extension("alpha".parse().unwrap()),
+                crate::source::SourceKey::Extension("zeta".parse().unwrap()),
+            ]
+        );
+    }
+
     #[test]
     fn auto_scalar_produces_inline_binding() {
         let doc = scalar_doc();
@@ -8,7 +8,7 @@
 use alloc::string::ToString;
 use alloc::vec::Vec;
 
-use crate::document::node::NodeValue;
+use crate::document::node::{Node, NodeValue};
 use crate::document::{EureDocument, NodeId};
 use crate::identifier::Identifier;
 use crate::path::{ArrayIndexKind, PathSegment};
@@ -161,8 +161,8 @@ fn emit_children(
     let node = ctx.doc.node(parent_id);
     let mut children: Vec<(PathSegment, NodeId)> = Vec::new();
 
-    for (ident, &cid) in node.extensions.iter() {
-        children.push((PathSegment::Extension(ident.clone()), cid));
+    for (ident, cid) in sorted_extensions(node) {
+        children.push((PathSegment::Extension(ident), cid));
     }
 
     if emit_map_fields {
@@ -199,6 +199,32 @@ fn emit_children(
     }
 }
 
+/// Extensions emitted first, in this order.
+const LEADING_EXTENSIONS: [&str; 3] = ["schema", "variant", "variant-repr"];
+
+/// The extensions of `node` in emission order: the well-known
+/// [`LEADING_EXTENSIONS`] first, then the rest alphabetically, so output does
+/// not depend on the order extensions were inserted.
+fn sorted_extensions(node: &Node) -> Vec<(Identifier, NodeId)> {
+    let mut extensions: Vec<(Identifier, NodeId)> = node
+        .extensions
+        .iter()
+        .map(|(ident, &id)| (ident.clone(), id))
+        .collect();
+    extensions.sort_by(|(a, _), (b, _)| {
+        let rank = |ident: &Identifier| {
+            LEADING_EXTENSIONS
+                .iter()
+                .position(|leading| *leading == ident.as_ref())
+                .unwrap_or(LEADING_EXTENSIONS.len())
+        };
+        rank(a)
+            .cmp(&rank(b))
+            .then_with(|| a.as_ref().cmp(b.as_ref()))
+    });
+    extensions
+}
+
 fn apply_order(
     ctx: &EmitCtx,
     parent_id: NodeId,
@@ -457,12 +483,7 @@ fn emit_extensions_only(
     allow_sections: bool,
     dest: &mut EureSource,
 ) {
-    let node = ctx.doc.node(node_id);
-    let ext_children: Vec<(Identifier, NodeId)> = node
-        .extensions
-        .iter()
-        .map(|(k, &v)| (k.clone(), v))
-        .collect();
+    let ext_children = sorted_extensions(ctx.doc.node(node_id));
     for (ident, ext_id) in ext_children {
         let seg = PathSegment::Extension(ident);
         let mut ext_node_path = child_node_path.to_vec();
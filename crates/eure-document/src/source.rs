@@ -173,6 +173,15 @@ impl SourceDocument {
         }
     }
 
+    /// Create a source document from a bare [`EureDocument`], inferring the layout.
+    ///
+    /// Uses [`LayoutPlan::auto`](crate::plan::LayoutPlan::auto): maps with nested
+    /// structure become sections, scalars become bindings, and arrays of maps
+    /// become repeated `@ items[]` sections.
+    pub fn from_document(document: EureDocument) -> Result<Self, crate::plan::PlanError> {
+        Ok(crate::plan::LayoutPlan::auto(document)?.emit())
+    }
+
     /// Create an empty source document.
     pub fn empty() -> Self {
         Self {
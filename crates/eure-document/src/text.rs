@@ -66,8 +66,23 @@ impl Language {
         }
     }
 
-    /// Returns the language as a string slice, or `None` for [`Implicit`](Language::Implicit).
-    pub fn as_str(&self) -> Option<&str> {
+    /// Create a Language from a tag produced by [`as_tag`](Language::as_tag).
+    ///
+    /// `None` → [`Implicit`](Language::Implicit); otherwise the same as [`Language::new`].
+    pub fn from_tag(tag: Option<&str>) -> Self {
+        match tag {
+            None => Language::Implicit,
+            Some(tag) => Language::new(String::from(tag)),
+        }
+    }
+
+    /// Returns the tag identifying this language, or `None` for [`Implicit`](Language::Implicit).
+    ///
+    /// [`Plaintext`](Language::Plaintext) is tagged `"plaintext"`, so it stays
+    /// distinguishable from `Implicit`. Converters use this tag (and
+    /// [`from_tag`](Language::from_tag) to read it back) whenever they record a
+    /// text's language.
+    pub fn as_tag(&self) -> Option<&str> {
         match self {
             Language::Plaintext => Some("plaintext"),
             Language::Implicit => None,
@@ -75,6 +90,13 @@ impl Language {
         }
     }
 
+    /// Returns the language as a string slice, or `None` for [`Implicit`](Language::Implicit).
+    ///
+    /// Same as [`as_tag`](Language::as_tag).
+    pub fn as_str(&self) -> Option<&str> {
+        self.as_tag()
+    }
+
     /// Returns true if this is the [`Plaintext`](Language::Plaintext) variant.
     pub fn is_plaintext(&self) -> bool {
         matches!(self, Language::Plaintext)
@@ -201,6 +223,16 @@ impl SyntaxHint {
                 | SyntaxHint::Block6
         )
     }
+
+    /// Returns the block hint for a fence of `len` backticks, saturating at `Block6`.
+    pub fn block_with_fence_len(len: usize) -> SyntaxHint {
+        match len {
+            0..=3 => SyntaxHint::Block3,
+            4 => SyntaxHint::Block4,
+            5 => SyntaxHint::Block5,
+            _ => SyntaxHint::Block6,
+        }
+    }
 }
 
 /// A text value in Eure, unifying strings and code.
@@ -251,6 +283,16 @@ impl PartialEq for Text {
     }
 }
 
+impl Eq for Text {}
+
+impl core::hash::Hash for Text {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        // Must agree with `PartialEq`, so syntax_hint is excluded here too
+        self.content.hash(state);
+        self.language.hash(state);
+    }
+}
+
 impl Text {
     /// Create a new text value.
     pub fn new(content: impl Into<String>, language: Language) -> Self {
@@ -345,10 +387,101 @@ impl Text {
         }
     }
 
+    /// Returns the number of backticks needed to fence this text as a code block.
+    ///
+    /// The fence is one longer than the longest backtick run in the content
+    /// (and at least 3), so the content can never close the block early.
+    /// Returns `None` if that would exceed the 6-backtick fence, the longest
+    /// Eure accepts: such content cannot be written as a code block at all.
+    pub fn fence_len(&self) -> Option<usize> {
+        let longest_run = self
+            .content
+            .split(|c| c != '`')
+            .map(str::len)
+            .max()
+            .unwrap_or(0);
+        let len = (longest_run + 1).max(3);
+        (len <= 6).then_some(len)
+    }
+
     /// Returns the content as a string slice.
     pub fn as_str(&self) -> &str {
         &self.content
     }
+
+    /// Returns the content without the trailing newline that block syntax
+    /// always carries.
+    ///
+    /// Only a single `\n` is removed, and only for block text; other text is
+    /// returned unchanged.
+    pub fn trimmed_content(&self) -> &str {
+        if self.syntax_hint.is_some_and(|hint| hint.is_block()) {
+            self.content.strip_suffix('\n').unwrap_or(&self.content)
+        } else {
+            &self.content
+        }
+    }
+
+    /// Check that the syntax hint can represent the content.
+    ///
+    /// Inline and literal-string syntax cannot span lines or contain their own
+    /// closing delimiter, and a fixed-size block fence must be longer than any
+    /// backtick run in the content. Escaped strings, generic `Block` hints and
+    /// text without a hint are always consistent.
+    pub fn validate_consistency(&self) -> Result<(), TextError> {
+        let Some(hint) = self.syntax_hint else {
+            return Ok(());
+        };
+        if (hint.is_inline() || hint.is_literal_string()) && self.content.contains('\n') {
+            return Err(TextError::NewlineInInline { hint });
+        }
+        let delimiter = match hint {
+            SyntaxHint::Inline1 => "`",
+            SyntaxHint::Delim1 => "`>",
+            SyntaxHint::Delim2 => "`>>",
+            SyntaxHint::Delim3 => "`>>>",
+            SyntaxHint::LitStr => "'",
+            SyntaxHint::LitStr1 => "'>",
+            SyntaxHint::LitStr2 => "'>>",
+            SyntaxHint::LitStr3 => "'>>>",
+            _ => "",
+        };
+        if !delimiter.is_empty() && self.content.contains(delimiter) {
+            return Err(TextError::DelimiterInContent { hint, delimiter });
+        }
+        let fence = match hint {
+            SyntaxHint::Block3 => 3,
+            SyntaxHint::Block4 => 4,
+            SyntaxHint::Block5 => 5,
+            SyntaxHint::Block6 => 6,
+            _ => return Ok(()),
+        };
+        match self.fence_len() {
+            Some(required) if required > fence => Err(TextError::FenceTooShort { hint, required }),
+            Some(_) => Ok(()),
+            None => Err(TextError::Unfenceable { hint }),
+        }
+    }
+}
+
+/// A [`SyntaxHint`] that cannot represent the text content.
+#[derive(Debug, PartialEq, Eq, Clone, Error)]
+pub enum TextError {
+    /// Single-line syntax used for content with a newline.
+    #[error("{hint:?} syntax cannot contain a newline")]
+    NewlineInInline { hint: SyntaxHint },
+    /// The content contains the closing delimiter of its syntax.
+    #[error("{hint:?} syntax cannot contain `{delimiter}`")]
+    DelimiterInContent {
+        hint: SyntaxHint,
+        delimiter: &'static str,
+    },
+    /// The block fence would be closed early by a backtick run in the content.
+    #[error("{hint:?} fence is too short, the content needs {required} backticks")]
+    FenceTooShort { hint: SyntaxHint, required: usize },
+    /// The content has a run of 6 or more backticks, which no fence can hold.
+    #[error("{hint:?} syntax cannot contain 6 or more backticks in a row")]
+    Unfenceable { hint: SyntaxHint },
 }
 
 /// Errors that can occur when parsing text.
@@ -382,7 +515,7 @@ impl Text {
     ///
     /// Handles escape sequences: `\\`, `\"`, `\'`, `\n`, `\r`, `\t`, `\0`, `\u{...}`.
     pub fn parse_quoted_string(s: &str) -> Result<Self, TextParseError> {
-        let content = parse_escape_sequences(s)?;
+        let content = unescape(s)?;
         Ok(Text::plaintext(content))
     }
 
@@ -464,8 +597,35 @@ impl Text {
     }
 }
 
-/// Parse escape sequences in a string.
-fn parse_escape_sequences(s: &str) -> Result<String, TextParseError> {
+/// Escape `s` for use inside a quoted Eure string.
+///
+/// Writes `\"`, `\\`, `\n`, `\r`, `\t` and `\0`, and `\u{...}` for other control
+/// characters. [`unescape`] reverses it.
+pub fn escape(s: &str) -> String {
+    use core::fmt::Write as _;
+
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            '\0' => result.push_str("\\0"),
+            c if c.is_control() => {
+                let _ = write!(result, "\\u{{{:x}}}", c as u32);
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Decode the escape sequences of a quoted Eure string.
+///
+/// Handles `\\`, `\"`, `\'`, `\n`, `\r`, `\t`, `\0` and `\u{...}`.
+pub fn unescape(s: &str) -> Result<String, TextParseError> {
     let mut result = String::with_capacity(s.len());
     let mut chars = s.chars().peekable();
 
@@ -565,6 +725,17 @@ mod tests {
         assert_eq!(Language::Other("rust".into()).as_str(), Some("rust"));
     }
 
+    #[test]
+    fn test_language_tag_round_trip() {
+        for language in [
+            Language::Plaintext,
+            Language::Implicit,
+            Language::Other("rust".into()),
+        ] {
+            assert_eq!(Language::from_tag(language.as_tag()), language);
+        }
+    }
+
     #[test]
     fn test_language_compatibility() {
         // Implicit is compatible with everything
@@ -585,6 +756,26 @@ mod tests {
         assert!(!Language::Other("rust".into()).is_compatible_with(&Language::Other("sql".into())));
     }
 
+    #[test]
+    fn test_fence_len() {
+        assert_eq!(Text::block_implicit("plain\n").fence_len(), Some(3));
+        assert_eq!(Text::block_implicit("a `b` ``c``\n").fence_len(), Some(3));
+        assert_eq!(
+            Text::block("```rust\nfn main() {}\n```\n", "markdown").fence_len(),
+            Some(4)
+        );
+        assert_eq!(Text::block_implicit("`````\n").fence_len(), Some(6));
+        assert_eq!(Text::block_implicit("``````\n").fence_len(), None);
+    }
+
+    #[test]
+    fn test_block_with_fence_len() {
+        assert_eq!(SyntaxHint::block_with_fence_len(3), SyntaxHint::Block3);
+        assert_eq!(SyntaxHint::block_with_fence_len(4), SyntaxHint::Block4);
+        assert_eq!(SyntaxHint::block_with_fence_len(5), SyntaxHint::Block5);
+        assert_eq!(SyntaxHint::block_with_fence_len(8), SyntaxHint::Block6);
+    }
+
     #[test]
     fn test_text_plaintext() {
         let text = Text::plaintext("hello");
@@ -609,6 +800,56 @@ mod tests {
         assert_eq!(text.syntax_hint, Some(SyntaxHint::Inline1));
     }
 
+    #[test]
+    fn test_trimmed_content() {
+        assert_eq!(
+            Text::block_implicit("fn main() {}").trimmed_content(),
+            "fn main() {}"
+        );
+        assert_eq!(Text::block("a\n\n", "rust").trimmed_content(), "a\n");
+        assert_eq!(Text::plaintext("line\n").trimmed_content(), "line\n");
+    }
+
+    #[test]
+    fn test_validate_consistency() {
+        assert_eq!(Text::inline("a + b", "rust").validate_consistency(), Ok(()));
+        assert_eq!(
+            Text::block("fn main() {}", "rust").validate_consistency(),
+            Ok(())
+        );
+        assert_eq!(Text::plaintext("a\nb").validate_consistency(), Ok(()));
+        let mut text = Text::new("x = \"```\"\n", Language::Implicit);
+        text.syntax_hint = Some(SyntaxHint::block_with_fence_len(text.fence_len().unwrap()));
+        assert_eq!(text.validate_consistency(), Ok(()));
+
+        assert_eq!(
+            Text::inline("fn a() {}\nfn b() {}", "rust").validate_consistency(),
+            Err(TextError::NewlineInInline {
+                hint: SyntaxHint::Inline1
+            })
+        );
+        assert_eq!(
+            Text::inline("a `b`", "md").validate_consistency(),
+            Err(TextError::DelimiterInContent {
+                hint: SyntaxHint::Inline1,
+                delimiter: "`",
+            })
+        );
+        assert_eq!(
+            Text::block("x = \"```\"", "rust").validate_consistency(),
+            Err(TextError::FenceTooShort {
+                hint: SyntaxHint::Block3,
+                required: 4,
+            })
+        );
+        assert_eq!(
+            Text::block("``````", "md").validate_consistency(),
+            Err(TextError::Unfenceable {
+                hint: SyntaxHint::Block3
+            })
+        );
+    }
+
     #[test]
     fn test_text_block_implicit() {
         let text = Text::block_implicit("fn main() {}");
@@ -936,6 +1177,22 @@ mod tests {
             }
         }
 
+        #[test]
+        fn test_escape_unescape_round_trip() {
+            let cases = [
+                ("tab\there", "tab\\there"),
+                ("say \"hi\"\n", "say \\\"hi\\\"\\n"),
+                ("C:\\dir", "C:\\\\dir"),
+                ("bell\u{7}", "bell\\u{7}"),
+                ("あ", "あ"),
+            ];
+            for (raw, escaped) in cases {
+                assert_eq!(escape(raw), escaped);
+                assert_eq!(unescape(escaped).unwrap(), raw);
+            }
+            assert_eq!(unescape("\\u{3042}\\t").unwrap(), "あ\t");
+        }
+
         #[test]
         fn test_parse_quoted_string_invalid_unicode_escapes() {
             // Missing closing brace
@@ -1,4 +1,5 @@
 use num_bigint::BigInt;
+use num_traits::ToPrimitive;
 
 use crate::{identifier::Identifier, prelude_internal::*, text::Text};
 
@@ -35,7 +36,10 @@ impl core::fmt::Display for ValueKind {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+/// Floats compare and hash by bit pattern, with every NaN treated as the same
+/// value, so `PrimitiveValue` (and documents containing it) can be used as
+/// `Eq` and `Hash` keys.
+#[derive(Debug, Clone)]
 pub enum PrimitiveValue {
     Null,
     Bool(bool),
@@ -50,7 +54,87 @@ pub enum PrimitiveValue {
     Text(Text),
 }
 
+/// Bit pattern of an `f32`, with NaN normalized to the canonical NaN.
+fn f32_bits(value: f32) -> u32 {
+    if value.is_nan() {
+        f32::NAN.to_bits()
+    } else {
+        value.to_bits()
+    }
+}
+
+/// Bit pattern of an `f64`, with NaN normalized to the canonical NaN.
+fn f64_bits(value: f64) -> u64 {
+    if value.is_nan() {
+        f64::NAN.to_bits()
+    } else {
+        value.to_bits()
+    }
+}
+
+impl PartialEq for PrimitiveValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Null, Self::Null) => true,
+            (Self::Bool(a), Self::Bool(b)) => a == b,
+            (Self::Integer(a), Self::Integer(b)) => a == b,
+            (Self::F32(a), Self::F32(b)) => f32_bits(*a) == f32_bits(*b),
+            (Self::F64(a), Self::F64(b)) => f64_bits(*a) == f64_bits(*b),
+            (Self::Text(a), Self::Text(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for PrimitiveValue {}
+
+impl core::hash::Hash for PrimitiveValue {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
+        match self {
+            Self::Null => {}
+            Self::Bool(b) => b.hash(state),
+            Self::Integer(n) => n.hash(state),
+            Self::F32(f) => f32_bits(*f).hash(state),
+            Self::F64(f) => f64_bits(*f).hash(state),
+            Self::Text(text) => text.hash(state),
+        }
+    }
+}
+
 impl PrimitiveValue {
+    /// Compare two values, treating numbers as equal by value regardless of
+    /// their variant.
+    ///
+    /// Two integers must be equal exactly; any other pair of numbers is equal
+    /// when it differs by at most `tolerance`. NaN equals NaN, as in
+    /// `PartialEq`. Non-numbers are compared as by `PartialEq`.
+    pub(crate) fn numeric_eq(&self, other: &Self, tolerance: f64) -> bool {
+        if let (Self::Integer(a), Self::Integer(b)) = (self, other) {
+            return a == b;
+        }
+        match (self.as_f64_lossy(), other.as_f64_lossy()) {
+            (Some(a), Some(b)) => {
+                if a.is_nan() || b.is_nan() {
+                    return a.is_nan() && b.is_nan();
+                }
+                let difference = if a > b { a - b } else { b - a };
+                a == b || difference <= tolerance
+            }
+            _ => self == other,
+        }
+    }
+
+    /// Any number converted to `f64`, rounding integers that do not fit.
+    fn as_f64_lossy(&self) -> Option<f64> {
+        match self {
+            Self::Integer(n) => n.to_f64(),
+            Self::F32(f) => Some(f64::from(*f)),
+            Self::F64(f) => Some(*f),
+            _ => None,
+        }
+    }
+
     /// Returns the text if this is a `Text` variant.
     pub fn as_text(&self) -> Option<&Text> {
         if let Self::Text(text) = self {
@@ -65,6 +149,38 @@ impl PrimitiveValue {
         self.as_text().map(|t| t.as_str())
     }
 
+    /// Returns the boolean if this is a `Bool` variant.
+    pub fn as_bool(&self) -> Option<bool> {
+        if let Self::Bool(b) = self {
+            Some(*b)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the integer if this is an `Integer` variant.
+    pub fn as_integer(&self) -> Option<&BigInt> {
+        if let Self::Integer(n) = self {
+            Some(n)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the integer as `i64` if this is an `Integer` variant within range.
+    pub fn as_i64(&self) -> Option<i64> {
+        self.as_integer().and_then(|n| i64::try_from(n).ok())
+    }
+
+    /// Returns the float as `f64` if this is an `F32` or `F64` variant.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::F32(f) => Some(*f as f64),
+            Self::F64(f) => Some(*f),
+            _ => None,
+        }
+    }
+
     pub(crate) fn kind(&self) -> ValueKind {
         match self {
             Self::Null => ValueKind::Null,
@@ -99,6 +215,12 @@ impl PrimitiveValue {
 /// Note: In key position, `true`, `false`, and `null` are parsed as string
 /// identifiers, not as boolean/null values. For example, `a.true = true`
 /// creates a key `"true"` with boolean value `true`.
+///
+/// Numeric keys are integers only. Integer keys compare by value regardless of
+/// the Rust type they came from (`1u8` and `1i64` are the same key), while
+/// floats are never keys, not even integral ones like `1.0`: converting a float
+/// [`PrimitiveValue`] into a key fails with
+/// [`PrimitiveConversionError::InvalidKey`].
 pub enum ObjectKey {
     Number(BigInt),
     String(String),
@@ -109,17 +231,7 @@ impl core::fmt::Display for ObjectKey {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             ObjectKey::Number(n) => write!(f, "{}", n),
-            ObjectKey::String(s) => {
-                write!(f, "\"")?;
-                for c in s.chars() {
-                    match c {
-                        '"' => write!(f, "\\\"")?,
-                        '\\' => write!(f, "\\\\")?,
-                        _ => write!(f, "{}", c)?,
-                    }
-                }
-                write!(f, "\"")
-            }
+            ObjectKey::String(s) => write!(f, "\"{}\"", crate::text::escape(s)),
             ObjectKey::Tuple(t) => write!(f, "{}", t),
         }
     }
@@ -205,17 +317,7 @@ impl core::fmt::Display for PartialObjectKey {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             PartialObjectKey::Number(n) => write!(f, "{}", n),
-            PartialObjectKey::String(s) => {
-                write!(f, "\"")?;
-                for c in s.chars() {
-                    match c {
-                        '"' => write!(f, "\\\"")?,
-                        '\\' => write!(f, "\\\\")?,
-                        _ => write!(f, "{}", c)?,
-                    }
-                }
-                write!(f, "\"")
-            }
+            PartialObjectKey::String(s) => write!(f, "\"{}\"", crate::text::escape(s)),
             PartialObjectKey::Hole(None) => write!(f, "!"),
             PartialObjectKey::Hole(Some(label)) => write!(f, "!{}", label),
             PartialObjectKey::Tuple(t) => write!(f, "{}", t),
@@ -260,6 +362,33 @@ impl TryFrom<PartialObjectKey> for ObjectKey {
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Plural, Default)]
 pub struct Tuple<T>(pub Vec<T>);
 
+/// An owned value of the Eure data model.
+///
+/// Unlike a document node, a `Value` owns its children, has no extensions, and
+/// cannot contain holes. Build one from a document subtree with
+/// [`Node::try_into_value`](crate::document::node::Node::try_into_value).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Primitive(PrimitiveValue),
+    Array(Vec<Value>),
+    Tuple(Tuple<Value>),
+    Map(Map<ObjectKey, Value>),
+}
+
+/// Error returned when a document subtree cannot be converted to a [`Value`].
+///
+/// Paths are relative to the node the conversion started from.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ValueError {
+    #[error("hole found at {path}")]
+    Hole {
+        path: EurePath,
+        label: Option<Identifier>,
+    },
+    #[error("map at {path} has a hole key")]
+    HoleKey { path: EurePath },
+}
+
 impl core::fmt::Display for Tuple<ObjectKey> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "(")?;
@@ -339,3 +468,169 @@ impl From<Text> for PrimitiveValue {
         PrimitiveValue::Text(t)
     }
 }
+
+// ============================================================================
+// TryFrom implementations for PrimitiveValue
+// ============================================================================
+
+/// Error returned when a [`PrimitiveValue`] cannot be converted to a Rust type.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum PrimitiveConversionError {
+    #[error("expected {expected}, found {actual}")]
+    TypeMismatch {
+        expected: ValueKind,
+        actual: ValueKind,
+    },
+    #[error("integer {0} is out of range for i64")]
+    IntegerOutOfRange(BigInt),
+    #[error("{0} value cannot be used as a map key; keys must be integers or text")]
+    InvalidKey(ValueKind),
+}
+
+impl PrimitiveConversionError {
+    fn mismatch(expected: ValueKind, value: &PrimitiveValue) -> Self {
+        Self::TypeMismatch {
+            expected,
+            actual: value.kind(),
+        }
+    }
+}
+
+impl TryFrom<&PrimitiveValue> for bool {
+    type Error = PrimitiveConversionError;
+
+    fn try_from(value: &PrimitiveValue) -> Result<Self, Self::Error> {
+        value
+            .as_bool()
+            .ok_or_else(|| PrimitiveConversionError::mismatch(ValueKind::Bool, value))
+    }
+}
+
+impl TryFrom<&PrimitiveValue> for i64 {
+    type Error = PrimitiveConversionError;
+
+    fn try_from(value: &PrimitiveValue) -> Result<Self, Self::Error> {
+        let n = value
+            .as_integer()
+            .ok_or_else(|| PrimitiveConversionError::mismatch(ValueKind::Integer, value))?;
+        i64::try_from(n).map_err(|_| PrimitiveConversionError::IntegerOutOfRange(n.clone()))
+    }
+}
+
+impl TryFrom<&PrimitiveValue> for f64 {
+    type Error = PrimitiveConversionError;
+
+    fn try_from(value: &PrimitiveValue) -> Result<Self, Self::Error> {
+        value
+            .as_f64()
+            .ok_or_else(|| PrimitiveConversionError::mismatch(ValueKind::F64, value))
+    }
+}
+
+impl TryFrom<&PrimitiveValue> for String {
+    type Error = PrimitiveConversionError;
+
+    fn try_from(value: &PrimitiveValue) -> Result<Self, Self::Error> {
+        value
+            .as_str()
+            .map(String::from)
+            .ok_or_else(|| PrimitiveConversionError::mismatch(ValueKind::Text, value))
+    }
+}
+
+/// Integers become [`ObjectKey::Number`] and text and bools become
+/// [`ObjectKey::String`], as in key position. Null and floats are rejected.
+impl TryFrom<&PrimitiveValue> for ObjectKey {
+    type Error = PrimitiveConversionError;
+
+    fn try_from(value: &PrimitiveValue) -> Result<Self, Self::Error> {
+        match value {
+            PrimitiveValue::Integer(n) => Ok(ObjectKey::Number(n.clone())),
+            PrimitiveValue::Text(text) => Ok(ObjectKey::String(text.content.clone())),
+            PrimitiveValue::Bool(b) => Ok(ObjectKey::from(*b)),
+            PrimitiveValue::Null | PrimitiveValue::F32(_) | PrimitiveValue::F64(_) => {
+                Err(PrimitiveConversionError::InvalidKey(value.kind()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_key_display_escapes_string() {
+        let key = ObjectKey::String("a\"b\\c\nd\te".to_string());
+        assert_eq!(key.to_string(), r#""a\"b\\c\nd\te""#);
+        let partial = PartialObjectKey::String("line\nbreak".to_string());
+        assert_eq!(partial.to_string(), r#""line\nbreak""#);
+    }
+
+    #[test]
+    fn test_as_accessors() {
+        assert_eq!(PrimitiveValue::Bool(true).as_bool(), Some(true));
+        assert_eq!(
+            PrimitiveValue::from(42).as_integer(),
+            Some(&BigInt::from(42))
+        );
+        assert_eq!(PrimitiveValue::from(-7i64).as_i64(), Some(-7));
+        assert_eq!(PrimitiveValue::F32(1.5).as_f64(), Some(1.5));
+        assert_eq!(PrimitiveValue::F64(2.5).as_f64(), Some(2.5));
+        assert_eq!(PrimitiveValue::Null.as_bool(), None);
+        assert_eq!(PrimitiveValue::from(1).as_f64(), None);
+    }
+
+    #[test]
+    fn test_as_i64_out_of_range() {
+        let value = PrimitiveValue::Integer(BigInt::from(i64::MAX) + 1);
+        assert_eq!(value.as_i64(), None);
+        assert_eq!(
+            i64::try_from(&value),
+            Err(PrimitiveConversionError::IntegerOutOfRange(
+                BigInt::from(i64::MAX) + 1
+            ))
+        );
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(bool::try_from(&PrimitiveValue::Bool(false)), Ok(false));
+        assert_eq!(i64::try_from(&PrimitiveValue::from(10)), Ok(10));
+        assert_eq!(f64::try_from(&PrimitiveValue::F64(0.25)), Ok(0.25));
+        assert_eq!(
+            String::try_from(&PrimitiveValue::from("hello")),
+            Ok("hello".to_string())
+        );
+        assert_eq!(
+            bool::try_from(&PrimitiveValue::from("true")),
+            Err(PrimitiveConversionError::TypeMismatch {
+                expected: ValueKind::Bool,
+                actual: ValueKind::Text,
+            })
+        );
+    }
+
+    #[test]
+    fn test_object_key_from_primitive() {
+        // Integer keys are equal whatever their source width
+        assert_eq!(ObjectKey::from(1u8), ObjectKey::from(1i64));
+        assert_eq!(
+            ObjectKey::try_from(&PrimitiveValue::from(1)),
+            Ok(ObjectKey::from(1u8))
+        );
+        assert_eq!(
+            ObjectKey::try_from(&PrimitiveValue::from("a")),
+            Ok(ObjectKey::from("a"))
+        );
+        // Floats are never keys, even when integral
+        assert_eq!(
+            ObjectKey::try_from(&PrimitiveValue::F64(1.0)),
+            Err(PrimitiveConversionError::InvalidKey(ValueKind::F64))
+        );
+        assert_eq!(
+            ObjectKey::try_from(&PrimitiveValue::F32(1.0)),
+            Err(PrimitiveConversionError::InvalidKey(ValueKind::F32))
+        );
+    }
+}
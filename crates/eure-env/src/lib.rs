@@ -8,7 +8,8 @@
 //! - `lint` - Include lint configuration types
 //! - `ls` - Include language server configuration types
 //! - `cli` - Include CLI configuration (enables `lint` and `ls`)
-//! - `native` - Include native I/O for remote schema caching (requires network/filesystem dependencies)
+//! - `serde` - Implement `Serialize`/`Deserialize` for the configuration types
+//! - `native` - Include native I/O for remote schema caching and config validation (requires network/filesystem dependencies)
 //! - `all` - Include all configuration types
 
 pub mod cache;
@@ -55,8 +56,50 @@ impl From<EureParseError> for ConfigError {
     }
 }
 
+/// A non-fatal problem found by [`EureConfig::validate`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ConfigWarning {
+    #[error("target `{target}`: schema file `{}` does not exist", .path.display())]
+    MissingSchema { target: String, path: PathBuf },
+
+    #[error("target `{target}`: cannot read schema file `{}`: {kind}", .path.display())]
+    UnreadableSchema {
+        target: String,
+        path: PathBuf,
+        kind: std::io::ErrorKind,
+    },
+
+    #[error("target `{target}`: schema file `{}` is not valid: {error}", .path.display())]
+    InvalidSchema {
+        target: String,
+        path: PathBuf,
+        error: eure_parol::EureParseError,
+    },
+
+    #[error("target `{target}`: invalid glob pattern `{pattern}`: {reason} at position {pos}")]
+    InvalidGlob {
+        target: String,
+        pattern: String,
+        pos: usize,
+        reason: &'static str,
+    },
+}
+
+impl ConfigWarning {
+    /// Name of the target this warning belongs to.
+    pub fn target(&self) -> &str {
+        match self {
+            ConfigWarning::MissingSchema { target, .. }
+            | ConfigWarning::UnreadableSchema { target, .. }
+            | ConfigWarning::InvalidSchema { target, .. }
+            | ConfigWarning::InvalidGlob { target, .. } => target,
+        }
+    }
+}
+
 /// A check target definition.
 #[derive(Debug, Clone, FromEure, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[eure(crate = eure_document, allow_unknown_fields)]
 pub struct Target {
     /// Glob patterns for files to include in this target.
@@ -69,6 +112,11 @@ pub struct Target {
 /// CLI-specific configuration.
 #[cfg(feature = "cli")]
 #[derive(Debug, Clone, Default, FromEure, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "kebab-case")
+)]
 #[eure(crate = eure_document, rename_all = "kebab-case", allow_unknown_fields)]
 pub struct CliConfig {
     /// Default targets to check when running `eure check` without arguments.
@@ -79,6 +127,11 @@ pub struct CliConfig {
 /// Language server configuration.
 #[cfg(feature = "ls")]
 #[derive(Debug, Clone, Default, FromEure, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "kebab-case")
+)]
 #[eure(crate = eure_document, rename_all = "kebab-case", allow_unknown_fields)]
 pub struct LsConfig {
     /// Whether to format on save.
@@ -88,6 +141,11 @@ pub struct LsConfig {
 
 /// Security configuration for remote URL access.
 #[derive(Debug, Clone, Default, FromEure, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, rename_all = "kebab-case")
+)]
 #[eure(crate = eure_document, rename_all = "kebab-case", allow_unknown_fields)]
 pub struct SecurityConfig {
     /// Additional allowed hosts for remote URL fetching (beyond eure.dev).
@@ -100,6 +158,11 @@ pub struct SecurityConfig {
 
 /// The main Eure configuration.
 #[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct EureConfig {
     /// Check targets (name -> target definition).
     pub targets: HashMap<String, Target>,
@@ -232,6 +295,67 @@ impl EureConfig {
         None
     }
 
+    /// Check targets for problems that would otherwise only surface at check time.
+    ///
+    /// Verifies that each local `schema` file exists and is syntactically valid
+    /// Eure, and that every glob pattern compiles. Remote schemas are not fetched.
+    /// `root` is the directory containing the config file. Warnings are sorted
+    /// by target name.
+    #[cfg(feature = "native")]
+    pub fn validate(&self, root: &Path) -> Vec<ConfigWarning> {
+        let mut names: Vec<&String> = self.targets.keys().collect();
+        names.sort();
+
+        let mut warnings = Vec::new();
+        for name in names {
+            let target = &self.targets[name];
+
+            if let Some(schema) = &target.schema
+                && !eure_document::document::schema_ref::is_remote_location(schema)
+            {
+                let path = root.join(schema);
+                match std::fs::read_to_string(&path) {
+                    Ok(source) => {
+                        if let Err(error) = eure_parol::parse(&source, &path) {
+                            warnings.push(ConfigWarning::InvalidSchema {
+                                target: name.clone(),
+                                path,
+                                error,
+                            });
+                        }
+                    }
+                    Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                        warnings.push(ConfigWarning::MissingSchema {
+                            target: name.clone(),
+                            path,
+                        });
+                    }
+                    Err(e) => warnings.push(ConfigWarning::UnreadableSchema {
+                        target: name.clone(),
+                        path,
+                        kind: e.kind(),
+                    }),
+                }
+            }
+
+            warnings.extend(target_glob_warnings(name, target));
+        }
+        warnings
+    }
+
+    /// Check that every glob pattern compiles.
+    ///
+    /// This is the part of [`validate`](Self::validate) that does not touch the
+    /// filesystem. Warnings are sorted by target name.
+    pub fn glob_warnings(&self) -> Vec<ConfigWarning> {
+        let mut names: Vec<&String> = self.targets.keys().collect();
+        names.sort();
+        names
+            .into_iter()
+            .flat_map(|name| target_glob_warnings(name, &self.targets[name]))
+            .collect()
+    }
+
     /// Get the allowed hosts for remote URL fetching from security config.
     ///
     /// Returns an empty slice if no security config is present.
@@ -243,4 +367,156 @@ impl EureConfig {
             .map(|s| s.allowed_hosts.as_slice())
             .unwrap_or(&[])
     }
+
+    /// Whether the language server should format documents on save.
+    #[cfg(feature = "ls")]
+    pub fn format_on_save(&self) -> bool {
+        self.ls.as_ref().is_some_and(|ls| ls.format_on_save)
+    }
+}
+
+fn target_glob_warnings<'a>(
+    name: &'a str,
+    target: &'a Target,
+) -> impl Iterator<Item = ConfigWarning> + 'a {
+    target.globs.iter().filter_map(move |pattern| {
+        glob::Pattern::new(pattern)
+            .err()
+            .map(|e| ConfigWarning::InvalidGlob {
+                target: name.to_string(),
+                pattern: pattern.clone(),
+                pos: e.pos,
+                reason: e.msg,
+            })
+    })
+}
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use super::*;
+
+    fn config_with_target(globs: &[&str], schema: Option<&str>) -> EureConfig {
+        let mut config = EureConfig::default();
+        config.targets.insert(
+            "docs".to_string(),
+            Target {
+                globs: globs.iter().map(|g| g.to_string()).collect(),
+                schema: schema.map(str::to_string),
+            },
+        );
+        config
+    }
+
+    #[test]
+    fn test_validate_ok() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("docs.schema.eure"), "title = `text`\n").unwrap();
+
+        let config = config_with_target(&["docs/**/*.eure"], Some("docs.schema.eure"));
+        assert_eq!(config.validate(dir.path()), vec![]);
+    }
+
+    #[test]
+    fn test_validate_missing_schema() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let config = config_with_target(&["docs/**/*.eure"], Some("missing.schema.eure"));
+        assert_eq!(
+            config.validate(dir.path()),
+            vec![ConfigWarning::MissingSchema {
+                target: "docs".to_string(),
+                path: dir.path().join("missing.schema.eure"),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_unparseable_schema() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("broken.schema.eure"), "title = = \n").unwrap();
+
+        let config = config_with_target(&["*.eure"], Some("broken.schema.eure"));
+        let warnings = config.validate(dir.path());
+        assert!(matches!(
+            warnings.as_slice(),
+            [ConfigWarning::InvalidSchema { target, .. }] if target == "docs"
+        ));
+    }
+
+    #[test]
+    fn test_validate_invalid_glob() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let config = config_with_target(&["docs/[*.eure", "ok/*.eure"], None);
+        assert_eq!(
+            config.validate(dir.path()),
+            vec![ConfigWarning::InvalidGlob {
+                target: "docs".to_string(),
+                pattern: "docs/[*.eure".to_string(),
+                pos: 5,
+                reason: "invalid range pattern",
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_skips_remote_schema() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let config = config_with_target(&["*.eure"], Some("https://eure.dev/v1/schema.eure"));
+        assert_eq!(config.validate(dir.path()), vec![]);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn test_config_json_round_trip() {
+        let config = EureConfig {
+            targets: HashMap::from([
+                (
+                    "docs".to_string(),
+                    Target {
+                        globs: vec!["docs/**/*.eure".to_string()],
+                        schema: Some("docs.schema.eure".to_string()),
+                    },
+                ),
+                (
+                    "misc".to_string(),
+                    Target {
+                        globs: vec!["*.eure".to_string()],
+                        schema: None,
+                    },
+                ),
+            ]),
+            security: Some(SecurityConfig {
+                allowed_hosts: vec!["*.example.com".to_string()],
+            }),
+            #[cfg(feature = "cli")]
+            cli: Some(CliConfig {
+                default_targets: vec!["docs".to_string()],
+            }),
+            #[cfg(feature = "ls")]
+            ls: Some(LsConfig {
+                format_on_save: true,
+            }),
+        };
+
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(
+            json["targets"]["docs"]["schema"],
+            serde_json::json!("docs.schema.eure")
+        );
+        assert_eq!(
+            json["security"]["allowed-hosts"],
+            serde_json::json!(["*.example.com"])
+        );
+        #[cfg(feature = "cli")]
+        assert_eq!(json["cli"]["default-targets"], serde_json::json!(["docs"]));
+
+        let parsed: EureConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, config);
+    }
 }
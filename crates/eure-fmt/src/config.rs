@@ -22,6 +22,10 @@ pub struct FormatConfig {
     /// Newline style.
     /// Default: NewlineStyle::Lf
     pub newline: NewlineStyle,
+
+    /// How float values are written when formatting a `SourceDocument`.
+    /// Default: FloatFormat::Shortest
+    pub float_format: FloatFormat,
 }
 
 impl Default for FormatConfig {
@@ -32,6 +36,7 @@ impl Default for FormatConfig {
             use_tabs: false,
             trailing_comma: TrailingComma::Always,
             newline: NewlineStyle::Lf,
+            float_format: FloatFormat::Shortest,
         }
     }
 }
@@ -71,6 +76,12 @@ impl FormatConfig {
         self.newline = style;
         self
     }
+
+    /// Set float format.
+    pub fn with_float_format(mut self, format: FloatFormat) -> Self {
+        self.float_format = format;
+        self
+    }
 }
 
 /// Trailing comma policy.
@@ -102,3 +113,18 @@ impl NewlineStyle {
         }
     }
 }
+
+/// Float output format.
+///
+/// NaN and infinities are always written as `nan`, `inf`, and `-inf`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FloatFormat {
+    /// Shortest representation that parses back to the same value.
+    /// Very large or very small magnitudes use scientific notation.
+    #[default]
+    Shortest,
+    /// Fixed number of digits after the decimal point (may lose precision).
+    Fixed(u8),
+    /// Shortest round-trippable mantissa in scientific notation (e.g. `3e-1`).
+    Scientific,
+}
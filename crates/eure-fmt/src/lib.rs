@@ -30,9 +30,9 @@ pub mod source;
 #[cfg(any(feature = "unformat", test))]
 pub mod unformat;
 
-pub use config::FormatConfig;
+pub use config::{FloatFormat, FormatConfig};
 pub use doc::Doc;
-pub use source::{build_source_doc, format_source_document};
+pub use source::{build_source_doc, format_source_document, format_source_document_with_config};
 
 use eure_tree::Cst;
 
@@ -6,7 +6,7 @@
 //! The implementation builds a `Doc` IR that integrates with eure-fmt's
 //! pretty-printing infrastructure.
 
-use crate::config::FormatConfig;
+use crate::config::{FloatFormat, FormatConfig};
 use crate::doc::Doc;
 use crate::printer::Printer;
 
@@ -18,14 +18,14 @@ use eure_document::source::{
     ArrayElementSource, BindSource, BindingSource, Comment, EureSource, SectionBody, SectionSource,
     SourceDocument, SourceId, SourceKey, SourcePathSegment, StringStyle, Trivia,
 };
-use eure_document::text::{Language, SyntaxHint};
+use eure_document::text::{Language, SyntaxHint, escape};
 use eure_document::value::{ObjectKey, PartialObjectKey, PrimitiveValue};
 
 /// Build a Doc IR from a SourceDocument.
 ///
 /// This produces a document IR that can be printed with the pretty-printer.
 pub fn build_source_doc(source: &SourceDocument) -> Doc {
-    SourceDocBuilder::new(source).build()
+    SourceDocBuilder::new(source, FloatFormat::default()).build()
 }
 
 /// Format a SourceDocument to Eure source string.
@@ -33,17 +33,29 @@ pub fn build_source_doc(source: &SourceDocument) -> Doc {
 /// This produces output that can be parsed back to an equivalent EureDocument.
 /// Comments and section ordering from the source structure are preserved.
 pub fn format_source_document(source: &SourceDocument) -> String {
-    let doc = build_source_doc(source);
-    Printer::new(FormatConfig::default()).print(&doc)
+    format_source_document_with_config(source, &FormatConfig::default())
+}
+
+/// Format a SourceDocument to Eure source string with the given config.
+pub fn format_source_document_with_config(
+    source: &SourceDocument,
+    config: &FormatConfig,
+) -> String {
+    let doc = SourceDocBuilder::new(source, config.float_format).build();
+    Printer::new(config.clone()).print(&doc)
 }
 
 struct SourceDocBuilder<'a> {
     source: &'a SourceDocument,
+    float_format: FloatFormat,
 }
 
 impl<'a> SourceDocBuilder<'a> {
-    fn new(source: &'a SourceDocument) -> Self {
-        Self { source }
+    fn new(source: &'a SourceDocument, float_format: FloatFormat) -> Self {
+        Self {
+            source,
+            float_format,
+        }
     }
 
     fn doc(&self) -> &EureDocument {
@@ -322,7 +334,7 @@ impl<'a> SourceDocBuilder<'a> {
             SourceKey::Hole(Some(label)) => Doc::text("!").concat(Doc::text(label.as_ref())),
             SourceKey::String(s, style) => match style {
                 StringStyle::Quoted => Doc::text("\"")
-                    .concat(Doc::text(escape_string(s)))
+                    .concat(Doc::text(escape(s)))
                     .concat(Doc::text("\"")),
                 StringStyle::Literal => Doc::text("'")
                     .concat(Doc::text(s.clone()))
@@ -380,12 +392,7 @@ impl<'a> SourceDocBuilder<'a> {
                 Doc::text("-inf")
             }
         } else {
-            let s = f.to_string();
-            if !s.contains('.') && !s.contains('e') && !s.contains('E') {
-                Doc::text(s).concat(Doc::text(".0"))
-            } else {
-                Doc::text(s)
-            }
+            Doc::text(format_float(f, f.abs(), self.float_format))
         }
     }
 
@@ -399,7 +406,7 @@ impl<'a> SourceDocBuilder<'a> {
                 Doc::text("-inf")
             }
         } else {
-            Doc::text(f.to_string())
+            Doc::text(format_float(f, f64::from(f.abs()), self.float_format))
         }
     }
 
@@ -413,7 +420,11 @@ impl<'a> SourceDocBuilder<'a> {
                 | Some(SyntaxHint::Block6)
         );
 
-        if is_block {
+        // Code whose hint cannot hold its content (e.g. a newline in inline
+        // code) is written as a block; plaintext is always escaped inline.
+        let fits_inline = text.language.is_plaintext() || text.validate_consistency().is_ok();
+
+        if is_block || !fits_inline {
             self.build_block_text(text)
         } else {
             self.build_inline_text(text)
@@ -421,14 +432,22 @@ impl<'a> SourceDocBuilder<'a> {
     }
 
     fn build_block_text(&self, text: &eure_document::text::Text) -> Doc {
-        let backticks = match text.syntax_hint {
-            Some(SyntaxHint::Block6) => "``````",
-            Some(SyntaxHint::Block5) => "`````",
-            Some(SyntaxHint::Block4) => "````",
-            _ => "```",
+        let hint_len = match text.syntax_hint {
+            Some(SyntaxHint::Block6) => 6,
+            Some(SyntaxHint::Block5) => 5,
+            Some(SyntaxHint::Block4) => 4,
+            _ => 3,
+        };
+        // Widen the fence if the content contains a backtick run that would close it.
+        // No fence can hold 6 backticks in a row, so such content is escaped instead.
+        let Some(fence_len) = text.fence_len() else {
+            return Doc::text("\"")
+                .concat(Doc::text(escape(&text.content)))
+                .concat(Doc::text("\""));
         };
+        let backticks = "`".repeat(hint_len.max(fence_len));
 
-        let mut doc = Doc::text(backticks);
+        let mut doc = Doc::text(backticks.clone());
 
         if let Language::Other(lang) = &text.language {
             doc = doc.concat(Doc::text(lang.clone()));
@@ -447,7 +466,7 @@ impl<'a> SourceDocBuilder<'a> {
     fn build_inline_text(&self, text: &eure_document::text::Text) -> Doc {
         match &text.language {
             Language::Plaintext => Doc::text("\"")
-                .concat(Doc::text(escape_string(&text.content)))
+                .concat(Doc::text(escape(&text.content)))
                 .concat(Doc::text("\"")),
             Language::Implicit => Doc::text("`")
                 .concat(Doc::text(text.content.clone()))
@@ -614,7 +633,7 @@ impl<'a> SourceDocBuilder<'a> {
                 } else {
                     // Not a valid identifier - must quote it
                     Doc::text("\"")
-                        .concat(Doc::text(escape_string(s)))
+                        .concat(Doc::text(escape(s)))
                         .concat(Doc::text("\""))
                 }
             }
@@ -636,7 +655,7 @@ impl<'a> SourceDocBuilder<'a> {
                     Doc::text(s.clone())
                 } else {
                     Doc::text("\"")
-                        .concat(Doc::text(escape_string(s)))
+                        .concat(Doc::text(escape(s)))
                         .concat(Doc::text("\""))
                 }
             }
@@ -673,18 +692,151 @@ fn strip_one_trailing_hardline(doc: Doc) -> (Doc, bool) {
     }
 }
 
-/// Escape a string for Eure output
-fn escape_string(s: &str) -> String {
-    let mut result = String::with_capacity(s.len());
-    for c in s.chars() {
-        match c {
-            '"' => result.push_str("\\\""),
-            '\\' => result.push_str("\\\\"),
-            '\n' => result.push_str("\\n"),
-            '\r' => result.push_str("\\r"),
-            '\t' => result.push_str("\\t"),
-            _ => result.push(c),
-        }
+/// Format a finite float. `abs` is the magnitude used to decide when
+/// `Shortest` switches to scientific notation.
+fn format_float<F>(f: F, abs: f64, format: FloatFormat) -> String
+where
+    F: std::fmt::Display + std::fmt::LowerExp,
+{
+    let s = match format {
+        FloatFormat::Shortest if abs != 0.0 && !(1e-6..1e21).contains(&abs) => {
+            format!("{f:e}")
+        }
+        FloatFormat::Shortest => f.to_string(),
+        FloatFormat::Fixed(digits) => format!("{f:.prec$}", prec = digits as usize),
+        FloatFormat::Scientific => format!("{f:e}"),
+    };
+    // Keep the value a float literal: `1` would be read back as an integer
+    if s.contains(['.', 'e', 'E']) {
+        s
+    } else {
+        s + ".0"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_float_modes() {
+        let f = 0.1 + 0.2;
+        assert_eq!(
+            format_float(f, f, FloatFormat::Shortest),
+            "0.30000000000000004"
+        );
+        assert_eq!(format_float(f, f, FloatFormat::Fixed(2)), "0.30");
+        assert_eq!(format_float(f, f, FloatFormat::Fixed(0)), "0.0");
+        assert_eq!(
+            format_float(f, f, FloatFormat::Scientific),
+            "3.0000000000000004e-1"
+        );
+    }
+
+    #[test]
+    fn test_format_float_shortest_keeps_float_literal() {
+        assert_eq!(format_float(1.0, 1.0, FloatFormat::Shortest), "1.0");
+        assert_eq!(format_float(-0.0, 0.0, FloatFormat::Shortest), "-0.0");
+        assert_eq!(format_float(1e300, 1e300, FloatFormat::Shortest), "1e300");
+        assert_eq!(
+            format_float(1.5e-7, 1.5e-7, FloatFormat::Shortest),
+            "1.5e-7"
+        );
+        assert_eq!(format_float(0.1f32, 0.1, FloatFormat::Shortest), "0.1");
+    }
+
+    fn format_ratio(value: f64, config: &FormatConfig) -> String {
+        let mut doc = EureDocument::new();
+        let root_id = doc.get_root_id();
+        let ratio_id = doc
+            .add_map_child(ObjectKey::String("ratio".to_string()), root_id)
+            .unwrap()
+            .node_id;
+        doc.node_mut(ratio_id).content = NodeValue::Primitive(PrimitiveValue::F64(value));
+        format_source_document_with_config(&SourceDocument::from_document(doc).unwrap(), config)
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    fn test_format_source_document_float_format() {
+        let f = 0.1 + 0.2;
+        assert_eq!(
+            format_ratio(f, &FormatConfig::default()),
+            "ratio = 0.30000000000000004"
+        );
+        assert_eq!(
+            format_ratio(
+                f,
+                &FormatConfig::default().with_float_format(FloatFormat::Fixed(3))
+            ),
+            "ratio = 0.300"
+        );
+        assert_eq!(
+            format_ratio(
+                f,
+                &FormatConfig::default().with_float_format(FloatFormat::Scientific)
+            ),
+            "ratio = 3.0000000000000004e-1"
+        );
+    }
+
+    #[test]
+    fn test_format_source_document_non_finite_floats() {
+        let config = FormatConfig::default().with_float_format(FloatFormat::Fixed(2));
+        assert_eq!(format_ratio(f64::NAN, &config), "ratio = nan");
+        assert_eq!(format_ratio(f64::INFINITY, &config), "ratio = inf");
+        assert_eq!(format_ratio(f64::NEG_INFINITY, &config), "ratio = -inf");
+    }
+
+    #[test]
+    fn test_build_object_key_nested_tuple() {
+        let source = SourceDocument::from_document(EureDocument::new()).unwrap();
+        let builder = SourceDocBuilder::new(&source, FloatFormat::Shortest);
+        let key = ObjectKey::Tuple(eure_document::value::Tuple(vec![
+            ObjectKey::Number(1.into()),
+            ObjectKey::String("a b".to_string()),
+            ObjectKey::Tuple(eure_document::value::Tuple(vec![ObjectKey::String(
+                "id".to_string(),
+            )])),
+        ]));
+        let output = Printer::new(FormatConfig::default()).print(&builder.build_object_key(&key));
+        assert_eq!(output, "(1, \"a b\", (id))");
+    }
+
+    #[test]
+    fn test_inconsistent_inline_code_is_written_as_block() {
+        let mut doc = EureDocument::new();
+        let root_id = doc.get_root_id();
+        let code_id = doc
+            .add_map_child(ObjectKey::String("code".to_string()), root_id)
+            .unwrap()
+            .node_id;
+        let text = eure_document::text::Text::inline("a()\nb()", "rust");
+        doc.node_mut(code_id).content = NodeValue::Primitive(PrimitiveValue::Text(text));
+
+        let output = format_source_document_with_config(
+            &SourceDocument::from_document(doc).unwrap(),
+            &FormatConfig::default(),
+        );
+        assert!(output.contains("```rust\na()\nb()\n```"), "{output}");
+    }
+
+    #[test]
+    fn test_unfenceable_block_is_written_as_string() {
+        let mut doc = EureDocument::new();
+        let root_id = doc.get_root_id();
+        let code_id = doc
+            .add_map_child(ObjectKey::String("code".to_string()), root_id)
+            .unwrap()
+            .node_id;
+        let text = eure_document::text::Text::block_implicit("a ``````\n");
+        doc.node_mut(code_id).content = NodeValue::Primitive(PrimitiveValue::Text(text));
+
+        let output = format_source_document_with_config(
+            &SourceDocument::from_document(doc).unwrap(),
+            &FormatConfig::default(),
+        );
+        assert_eq!(output, "code = \"a ``````\\n\"\n");
     }
-    result
 }
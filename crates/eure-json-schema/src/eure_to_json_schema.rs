@@ -8,11 +8,11 @@ use crate::json_schema::*;
 use eure_document::document::EureDocument;
 use eure_json::Config as JsonConfig;
 use eure_schema::interop::VariantRepr;
+use eure_schema::range::range_to_json;
 use eure_schema::{
-    ArraySchema as EureArraySchema, Bound, Description, FloatSchema,
-    IntegerSchema as EureIntegerSchema, MapSchema, RecordSchema, SchemaDocument,
-    SchemaMetadata as EureMetadata, SchemaNode, SchemaNodeContent, SchemaNodeId, TextSchema,
-    TupleSchema, UnionSchema, UnknownFieldsPolicy,
+    ArraySchema as EureArraySchema, Description, FloatSchema, IntegerSchema as EureIntegerSchema,
+    MapSchema, RecordSchema, SchemaDocument, SchemaMetadata as EureMetadata, SchemaNode,
+    SchemaNodeContent, SchemaNodeId, TextSchema, TupleSchema, UnionSchema, UnknownFieldsPolicy,
 };
 use indexmap::IndexMap;
 use num_traits::ToPrimitive;
@@ -277,20 +277,23 @@ const JSON_SCHEMA_FORMATS: &[&str] = &[
 /// Convert Eure Text schema to JSON Schema
 ///
 /// Text (which unifies the old String and Code types) maps to JSON Schema string type.
-/// If the language matches a known JSON Schema format, it's mapped to the format field.
+/// An explicit `format` is exported as is; otherwise, if the language matches a known
+/// JSON Schema format, it's mapped to the format field.
 fn convert_text_schema(
     eure: &TextSchema,
     eure_meta: &EureMetadata,
     metadata: SchemaMetadata,
 ) -> Result<JsonSchema, ConversionError> {
-    // Map language to format if it's a known JSON Schema format
-    let format = eure.language.as_ref().and_then(|lang| {
-        if JSON_SCHEMA_FORMATS.contains(&lang.as_str()) {
-            Some(lang.clone())
-        } else {
-            None
-        }
-    });
+    // Prefer the explicit format, falling back to a language that names a format
+    let format = eure
+        .format
+        .map(|format| format.as_str().to_string())
+        .or_else(|| {
+            eure.language
+                .as_ref()
+                .filter(|lang| JSON_SCHEMA_FORMATS.contains(&lang.as_str()))
+                .cloned()
+        });
 
     // Convert default value if present
     let default = eure_meta
@@ -325,17 +328,10 @@ fn convert_integer_schema(
     metadata: SchemaMetadata,
 ) -> Result<JsonSchema, ConversionError> {
     // Convert bounds
-    let (minimum, exclusive_minimum) = match &eure.min {
-        Bound::Unbounded => (None, None),
-        Bound::Inclusive(val) => (Some(bigint_to_i64(val)?), None),
-        Bound::Exclusive(val) => (None, Some(bigint_to_i64(val)?)),
-    };
-
-    let (maximum, exclusive_maximum) = match &eure.max {
-        Bound::Unbounded => (None, None),
-        Bound::Inclusive(val) => (Some(bigint_to_i64(val)?), None),
-        Bound::Exclusive(val) => (None, Some(bigint_to_i64(val)?)),
-    };
+    let (min, max, min_exclusive, max_exclusive) =
+        range_to_json(&eure.min, &eure.max, bigint_to_i64)?;
+    let (minimum, exclusive_minimum) = split_exclusive(min, min_exclusive);
+    let (maximum, exclusive_maximum) = split_exclusive(max, max_exclusive);
 
     let multiple_of = eure.multiple_of.as_ref().map(bigint_to_i64).transpose()?;
 
@@ -366,6 +362,15 @@ fn convert_integer_schema(
     })))
 }
 
+/// Route a bound value to the inclusive or exclusive JSON Schema keyword.
+fn split_exclusive<V>(value: Option<V>, exclusive: bool) -> (Option<V>, Option<V>) {
+    if exclusive {
+        (None, value)
+    } else {
+        (value, None)
+    }
+}
+
 /// Convert BigInt to i64, returning error if out of range
 fn bigint_to_i64(val: &num_bigint::BigInt) -> Result<i64, ConversionError> {
     val.to_i64()
@@ -388,17 +393,10 @@ fn convert_float_schema(
     };
 
     // Convert bounds
-    let (minimum, exclusive_minimum) = match &eure.min {
-        Bound::Unbounded => (None, None),
-        Bound::Inclusive(val) => (Some(validate_float(*val)?), None),
-        Bound::Exclusive(val) => (None, Some(validate_float(*val)?)),
-    };
-
-    let (maximum, exclusive_maximum) = match &eure.max {
-        Bound::Unbounded => (None, None),
-        Bound::Inclusive(val) => (Some(validate_float(*val)?), None),
-        Bound::Exclusive(val) => (None, Some(validate_float(*val)?)),
-    };
+    let (min, max, min_exclusive, max_exclusive) =
+        range_to_json(&eure.min, &eure.max, |val| validate_float(*val))?;
+    let (minimum, exclusive_minimum) = split_exclusive(min, min_exclusive);
+    let (maximum, exclusive_maximum) = split_exclusive(max, max_exclusive);
 
     let multiple_of = eure.multiple_of.map(validate_float).transpose()?;
 
@@ -787,7 +785,7 @@ mod tests {
     use eure_schema::interop::{UnionInterop, VariantRepr};
     use eure_schema::{
         Bound, IntegerSchema as EureIntegerSchema, RecordFieldSchema, RecordSchema, SchemaDocument,
-        SchemaNodeContent, UnknownFieldsPolicy,
+        SchemaNodeContent, TextFormat, UnknownFieldsPolicy,
     };
 
     #[test]
@@ -799,6 +797,21 @@ mod tests {
         assert!(matches!(result, JsonSchema::Typed(TypedSchema::String(_))));
     }
 
+    #[test]
+    fn test_convert_text_with_format() {
+        let mut doc = SchemaDocument::new();
+        doc.root = doc.create_node(SchemaNodeContent::Text(TextSchema {
+            format: Some(TextFormat::Email),
+            ..Default::default()
+        }));
+
+        let result = eure_to_json_schema(&doc).unwrap();
+        let JsonSchema::Typed(TypedSchema::String(string)) = result else {
+            panic!("expected string schema, got {result:?}");
+        };
+        assert_eq!(string.format.as_deref(), Some("email"));
+    }
+
     #[test]
     fn test_convert_text_with_language() {
         // Text with language (e.g., code) should still convert to JSON Schema string
@@ -831,6 +844,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_convert_integer_inclusive_exclusive_range() {
+        let mut doc = SchemaDocument::new();
+        doc.root = doc.create_node(SchemaNodeContent::Integer(EureIntegerSchema {
+            min: Bound::Inclusive(1.into()),
+            max: Bound::Exclusive(10.into()),
+            multiple_of: None,
+        }));
+
+        let result = eure_to_json_schema(&doc).unwrap();
+        let JsonSchema::Typed(TypedSchema::Integer(schema)) = result else {
+            panic!("Expected Integer schema");
+        };
+        assert_eq!(schema.minimum, Some(1));
+        assert_eq!(schema.exclusive_minimum, None);
+        assert_eq!(schema.maximum, None);
+        assert_eq!(schema.exclusive_maximum, Some(10));
+    }
+
     #[test]
     fn test_convert_record_to_object() {
         let mut doc = SchemaDocument::new();
@@ -1,14 +1,192 @@
 use eure_schema::interop::VariantRepr;
 
+use crate::dedup::REF_KEY;
+
+/// Options for converting between Eure and JSON.
+///
+/// Tagged representations reserve object keys such as `$tuple`. A map key
+/// that spells an active tag is exported with one more leading `$` (`$$tuple`)
+/// and unescaped on import, so user maps round-trip unchanged.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Config {
     pub variant_repr: VariantRepr,
+    /// Sort map entries by their canonical `ObjectKey` ordering before emitting
+    /// JSON objects, producing deterministic output.
+    pub sort_keys: bool,
+    /// When converting JSON to Eure, read object keys that spell an integer
+    /// (`"42"`), optionally prefixed with `#` (`"#42"`), as `ObjectKey::Number`.
+    ///
+    /// Two JSON keys that produce the same `ObjectKey` are reported as
+    /// `JsonToEureError::DuplicateKey`.
+    pub disambiguate_keys: bool,
+    /// How `VariantRepr::Internal` handles variant content that is not an object.
+    pub internal_variant_on_scalar: InternalVariantOnScalar,
+    /// When converting Eure to JSON, drop the trailing newline that block text
+    /// always carries (see `Text::trimmed_content`).
+    pub strip_block_trailing_newline: bool,
+    /// How Eure tuples are represented in JSON.
+    pub tuple_repr: TupleRepr,
+    /// When converting Eure to JSON, emit a tuple of length 1 as its only
+    /// element instead of a one-element array. When converting JSON to Eure
+    /// with a schema, a value whose schema is a 1-tuple is wrapped back into
+    /// one. Takes precedence over `tuple_repr` for 1-tuples.
+    pub unwrap_singleton_tuple: bool,
+    /// How whole-number Eure floats are represented in JSON.
+    pub float_tag: FloatTag,
+    /// How the language of Eure text is represented in JSON.
+    pub text_tag: TextTag,
+    /// How JSON object keys starting with `$` are read when converting JSON to Eure.
+    pub dollar_key_policy: DollarKeyPolicy,
+    /// When converting Eure to JSON, replace each object or array that is
+    /// structurally identical to an earlier one with `{"$ref": "#/pointer"}`,
+    /// a JSON Pointer to that first occurrence. When converting JSON to Eure,
+    /// such references are resolved back into copies of their targets.
+    pub dedup_refs: bool,
+}
+
+/// JSON representation of Eure tuples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TupleRepr {
+    /// Emit tuples as plain JSON arrays. Lossy: JSON to Eure conversion
+    /// reconstructs them as arrays. Use this for strict JSON consumers.
+    #[default]
+    Array,
+    /// Emit tuples as `{"$tuple": [...]}` and reconstruct such objects as
+    /// tuples when converting JSON to Eure. Arrays stay plain JSON arrays.
+    Tagged,
+}
+
+impl TupleRepr {
+    /// The object key wrapping tuple elements in [`TupleRepr::Tagged`].
+    pub const TAG: &'static str = "$tuple";
+}
+
+/// JSON representation of whole-number Eure floats such as `2.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FloatTag {
+    /// Emit floats as plain JSON numbers. Lossy: consumers may read `2.0` as
+    /// the integer `2`.
+    #[default]
+    Plain,
+    /// Emit whole-number floats as `{"$float": 2.0}` and reconstruct such
+    /// objects as floats when converting JSON to Eure. Other floats stay plain
+    /// JSON numbers.
+    Tagged,
+}
+
+impl FloatTag {
+    /// The object key wrapping the value in [`FloatTag::Tagged`].
+    pub const TAG: &'static str = "$float";
+}
+
+/// JSON representation of the language of Eure text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TextTag {
+    /// Emit all text as plain JSON strings. Lossy: JSON to Eure conversion
+    /// reads every string as plaintext.
+    #[default]
+    Plain,
+    /// Emit plaintext as JSON strings and other text as
+    /// `{"$text": content, "$language": tag}`, omitting `$language` for
+    /// implicit code (see `Language::as_tag`). Such objects are reconstructed
+    /// as text when converting JSON to Eure.
+    Tagged,
+    /// Emit all text as plain JSON strings, and record the language of each
+    /// non-plaintext text field of a map in a sibling
+    /// `"$languages": {"field": tag}` object, with `null` for implicit code.
+    /// The languages are restored when converting JSON to Eure.
+    Sidecar,
+}
+
+impl TextTag {
+    /// The object key holding the content in [`TextTag::Tagged`].
+    pub const TAG: &'static str = "$text";
+    /// The object key holding the language tag in [`TextTag::Tagged`].
+    pub const LANGUAGE: &'static str = "$language";
+    /// The object key holding the field languages in [`TextTag::Sidecar`].
+    pub const SIDECAR: &'static str = "$languages";
+}
+
+/// Handling of `$`-prefixed JSON object keys such as `$schema` or `$ref`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DollarKeyPolicy {
+    /// Keep them as ordinary map keys, including the `$`.
+    #[default]
+    AsMapKey,
+    /// Read `"$name"` as the extension `$name` of the enclosing node. Keys
+    /// whose remainder is not a valid identifier stay map keys.
+    AsExtension,
+}
+
+/// Behavior of `VariantRepr::Internal` when the variant content is not an
+/// object, so the tag field cannot be merged into it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum InternalVariantOnScalar {
+    /// Emit the External representation instead: `{"variant-name": content}`.
+    #[default]
+    FallbackExternal,
+    /// Report `EureToJsonError::InternalVariantNotObject`.
+    Error,
+    /// Wrap the content in an object under the given field:
+    /// `{"type": "variant-name", "value": content}`.
+    WrapObject { content: String },
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
             variant_repr: VariantRepr::External,
+            sort_keys: false,
+            disambiguate_keys: false,
+            internal_variant_on_scalar: InternalVariantOnScalar::default(),
+            strip_block_trailing_newline: false,
+            tuple_repr: TupleRepr::default(),
+            unwrap_singleton_tuple: false,
+            float_tag: FloatTag::default(),
+            text_tag: TextTag::default(),
+            dollar_key_policy: DollarKeyPolicy::default(),
+            dedup_refs: false,
+        }
+    }
+}
+
+impl Config {
+    /// The object keys reserved by the tagged representations enabled in this config.
+    fn reserved_keys(&self) -> impl Iterator<Item = &'static str> {
+        [
+            (self.tuple_repr == TupleRepr::Tagged).then_some(TupleRepr::TAG),
+            (self.float_tag == FloatTag::Tagged).then_some(FloatTag::TAG),
+            (self.text_tag == TextTag::Tagged).then_some(TextTag::TAG),
+            self.dedup_refs.then_some(REF_KEY),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// Whether `key` is a reserved key behind zero or more escaping `$`s.
+    fn is_escapable_key(&self, key: &str) -> bool {
+        let name = key.trim_start_matches('$');
+        name.len() < key.len()
+            && self
+                .reserved_keys()
+                .any(|reserved| reserved.trim_start_matches('$') == name)
+    }
+
+    /// Escape a map key that would be read back as a tag, e.g. `$tuple` to `$$tuple`.
+    pub(crate) fn escape_key(&self, key: String) -> String {
+        if self.is_escapable_key(&key) {
+            format!("${key}")
+        } else {
+            key
+        }
+    }
+
+    /// Undo [`Config::escape_key`].
+    pub(crate) fn unescape_key<'a>(&self, key: &'a str) -> &'a str {
+        if key.starts_with("$$") && self.is_escapable_key(key) {
+            &key[1..]
+        } else {
+            key
         }
     }
 }
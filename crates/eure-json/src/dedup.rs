@@ -0,0 +1,193 @@
+//! Reference-based deduplication of repeated JSON subtrees (see [`Config::dedup_refs`]).
+//!
+//! [`Config::dedup_refs`]: crate::Config::dedup_refs
+
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use serde_json::Value as JsonValue;
+
+use crate::JsonToEureError;
+
+/// The object key holding the JSON Pointer to the referenced subtree.
+pub(crate) const REF_KEY: &str = "$ref";
+
+/// Replace every non-empty object or array that equals an earlier one (in
+/// document order) with `{"$ref": "#/pointer"}` to that first occurrence.
+///
+/// First occurrences are never replaced themselves, so every emitted pointer
+/// resolves against the returned value.
+pub(crate) fn dedup_subtrees(root: &JsonValue) -> JsonValue {
+    let mut seen = HashMap::new();
+    let hashes = HashTree::new(root);
+    dedup_node(root, root, &hashes, &mut String::new(), &mut seen)
+}
+
+fn dedup_node(
+    root: &JsonValue,
+    value: &JsonValue,
+    hashes: &HashTree,
+    pointer: &mut String,
+    seen: &mut HashMap<u64, Vec<String>>,
+) -> JsonValue {
+    let is_candidate = match value {
+        JsonValue::Array(items) => !items.is_empty(),
+        JsonValue::Object(obj) => !obj.is_empty(),
+        _ => false,
+    };
+    if is_candidate {
+        let occurrences = seen.entry(hashes.hash).or_default();
+        if let Some(target) = occurrences
+            .iter()
+            .find(|target| root.pointer(target) == Some(value))
+        {
+            return ref_object(target);
+        }
+        occurrences.push(pointer.clone());
+    }
+
+    let len = pointer.len();
+    match value {
+        JsonValue::Array(items) => {
+            let mut result = Vec::with_capacity(items.len());
+            for (index, (item, hashes)) in items.iter().zip(&hashes.children).enumerate() {
+                pointer.push('/');
+                pointer.push_str(&index.to_string());
+                result.push(dedup_node(root, item, hashes, pointer, seen));
+                pointer.truncate(len);
+            }
+            JsonValue::Array(result)
+        }
+        JsonValue::Object(obj) => {
+            let mut result = serde_json::Map::new();
+            for ((key, item), hashes) in obj.iter().zip(&hashes.children) {
+                pointer.push('/');
+                pointer.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                result.insert(key.clone(), dedup_node(root, item, hashes, pointer, seen));
+                pointer.truncate(len);
+            }
+            JsonValue::Object(result)
+        }
+        _ => value.clone(),
+    }
+}
+
+fn ref_object(pointer: &str) -> JsonValue {
+    let mut map = serde_json::Map::new();
+    map.insert(
+        REF_KEY.to_string(),
+        JsonValue::String(format!("#{pointer}")),
+    );
+    JsonValue::Object(map)
+}
+
+/// Structural hashes of a JSON value and, in the same order, its children.
+///
+/// Each hash is computed once from the hashes of the children, so hashing a
+/// whole document is linear in its size. Equal values hash equally as long as
+/// their objects iterate keys in the same order.
+struct HashTree {
+    hash: u64,
+    children: Vec<HashTree>,
+}
+
+impl HashTree {
+    fn new(value: &JsonValue) -> Self {
+        let mut hasher = DefaultHasher::new();
+        std::mem::discriminant(value).hash(&mut hasher);
+        let children = match value {
+            JsonValue::Null => Vec::new(),
+            JsonValue::Bool(b) => {
+                b.hash(&mut hasher);
+                Vec::new()
+            }
+            JsonValue::Number(n) => {
+                n.hash(&mut hasher);
+                Vec::new()
+            }
+            JsonValue::String(s) => {
+                s.hash(&mut hasher);
+                Vec::new()
+            }
+            JsonValue::Array(items) => {
+                items.len().hash(&mut hasher);
+                items
+                    .iter()
+                    .map(|item| {
+                        let child = HashTree::new(item);
+                        child.hash.hash(&mut hasher);
+                        child
+                    })
+                    .collect()
+            }
+            JsonValue::Object(obj) => {
+                obj.len().hash(&mut hasher);
+                obj.iter()
+                    .map(|(key, item)| {
+                        let child = HashTree::new(item);
+                        key.hash(&mut hasher);
+                        child.hash.hash(&mut hasher);
+                        child
+                    })
+                    .collect()
+            }
+        };
+        HashTree {
+            hash: hasher.finish(),
+            children,
+        }
+    }
+}
+
+/// Replace every `{"$ref": "#/pointer"}` object with a copy of the value the
+/// pointer names in `root`, resolving references inside the copy as well.
+pub(crate) fn resolve_refs(root: &JsonValue) -> Result<JsonValue, JsonToEureError> {
+    resolve_node(root, root, &mut Vec::new())
+}
+
+fn resolve_node<'a>(
+    root: &'a JsonValue,
+    value: &'a JsonValue,
+    active: &mut Vec<&'a str>,
+) -> Result<JsonValue, JsonToEureError> {
+    match value {
+        JsonValue::Object(obj) if let Some(reference) = as_ref(obj) => {
+            if active.contains(&reference) {
+                return Err(JsonToEureError::CyclicRef {
+                    reference: reference.to_string(),
+                });
+            }
+            let target = reference
+                .strip_prefix('#')
+                .and_then(|pointer| root.pointer(pointer))
+                .ok_or_else(|| JsonToEureError::UnresolvedRef {
+                    reference: reference.to_string(),
+                })?;
+            active.push(reference);
+            let resolved = resolve_node(root, target, active)?;
+            active.pop();
+            Ok(resolved)
+        }
+        JsonValue::Array(items) => items
+            .iter()
+            .map(|item| resolve_node(root, item, active))
+            .collect::<Result<_, _>>()
+            .map(JsonValue::Array),
+        JsonValue::Object(obj) => {
+            let mut result = serde_json::Map::new();
+            for (key, item) in obj {
+                result.insert(key.clone(), resolve_node(root, item, active)?);
+            }
+            Ok(JsonValue::Object(result))
+        }
+        _ => Ok(value.clone()),
+    }
+}
+
+/// The pointer of an object that is a `{"$ref": "..."}` reference.
+pub(crate) fn as_ref(obj: &serde_json::Map<String, JsonValue>) -> Option<&str> {
+    if obj.len() != 1 {
+        return None;
+    }
+    obj.get(REF_KEY)?.as_str()
+}
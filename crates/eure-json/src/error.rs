@@ -20,6 +20,12 @@ pub enum EureToJsonError {
 
     #[error("Variant content already contains field '{field}' in Adjacent representation")]
     VariantAdjacentConflict { field: String, node_id: NodeId },
+
+    #[error("Variant '{variant}' content is not an object in Internal representation")]
+    InternalVariantNotObject { variant: String, node_id: NodeId },
+
+    #[error("Map key '$languages' collides with the text language sidecar")]
+    SidecarKeyConflict { node_id: NodeId },
 }
 
 impl EureToJsonError {
@@ -32,17 +38,33 @@ impl EureToJsonError {
             EureToJsonError::NonFiniteFloat { node_id } => *node_id,
             EureToJsonError::VariantTagConflict { node_id, .. } => *node_id,
             EureToJsonError::VariantAdjacentConflict { node_id, .. } => *node_id,
+            EureToJsonError::InternalVariantNotObject { node_id, .. } => *node_id,
+            EureToJsonError::SidecarKeyConflict { node_id } => *node_id,
         }
     }
 }
 
 /// Errors that can occur when converting JSON to Eure.
-/// Currently this is infallible, but the error type is provided for future extensibility
-/// and API consistency.
 #[derive(Debug, Error, PartialEq)]
 pub enum JsonToEureError {
-    // Currently no error cases - JSON to Eure conversion is infallible.
-    // This enum is provided for:
-    // 1. API consistency with EureToJsonError
-    // 2. Future extensibility (e.g., schema-guided conversion constraints)
+    #[error("JSON object key '{key}' collides with another key after disambiguation")]
+    DuplicateKey { key: String },
+
+    #[error("JSON array has {actual} elements but the tuple schema expects {expected}")]
+    TupleArityMismatch { expected: usize, actual: usize },
+
+    #[error("JSON reference '{reference}' does not point to a value in the document")]
+    UnresolvedRef { reference: String },
+
+    #[error("JSON reference '{reference}' refers to a value that contains it")]
+    CyclicRef { reference: String },
+
+    #[error("JSON '$languages' sidecar must be an object")]
+    SidecarNotObject,
+
+    #[error("JSON '$languages' entry for '{key}' must be a string or null")]
+    InvalidLanguageTag { key: String },
+
+    #[error("JSON '$languages' entry '{key}' does not name a text field of the object")]
+    UnknownSidecarField { key: String },
 }
@@ -1,19 +1,23 @@
 #![doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/README.md"))]
 
 mod config;
+mod dedup;
 mod error;
 
-pub use config::Config;
+pub use config::{Config, DollarKeyPolicy, FloatTag, InternalVariantOnScalar, TextTag, TupleRepr};
 pub use error::{EureToJsonError, JsonToEureError};
 use eure::document::OriginMap;
-use eure::document::node::NodeValue;
+use eure::document::node::{NodeMap, NodeTuple, NodeValue};
 use eure::document::{EureDocument, NodeId};
 use eure::query::{ParseDocument, TextFile, ValidCst};
 use eure::report::{ErrorReport, ErrorReports, Origin, OriginHints};
 use eure::tree::{Cst, InputSpan};
 use eure::value::{ObjectKey, PrimitiveValue};
-use eure_document::text::Text;
+use eure_document::identifier::Identifier;
+use eure_document::path::EurePath;
+use eure_document::text::{Language, Text};
 use eure_schema::interop::VariantRepr;
+use eure_schema::{RecordSchema, SchemaDocument, SchemaNodeContent, SchemaNodeId};
 use num_bigint::BigInt;
 use query_flow::{Db, QueryError, query};
 use serde_json::Value as JsonValue;
@@ -97,15 +101,76 @@ pub fn document_to_value(
     doc: &EureDocument,
     config: &Config,
 ) -> Result<JsonValue, EureToJsonError> {
-    let root_id = doc.get_root_id();
-    convert_node(doc, root_id, config)
+    let mut cx = ToJson {
+        doc,
+        config,
+        collected: None,
+    };
+    let value = convert_node(&mut cx, doc.get_root_id())?;
+    Ok(finish_value(value, config))
 }
 
-fn convert_node(
+/// Convert an Eure document to JSON, reporting every conversion problem.
+///
+/// Unlike [`document_to_value`], which stops at the first error, this keeps
+/// converting past unsupported nodes and returns all errors, each with the
+/// path of the node it concerns.
+pub fn document_to_value_collecting(
     doc: &EureDocument,
-    node_id: NodeId,
     config: &Config,
-) -> Result<JsonValue, EureToJsonError> {
+) -> Result<JsonValue, Vec<(EurePath, EureToJsonError)>> {
+    let mut cx = ToJson {
+        doc,
+        config,
+        collected: Some(Vec::new()),
+    };
+    let result = convert_node(&mut cx, doc.get_root_id());
+    let mut errors = cx.collected.unwrap_or_default();
+    match result {
+        Ok(value) if errors.is_empty() => return Ok(finish_value(value, config)),
+        Ok(_) => {}
+        Err(error) => errors.push(error),
+    }
+    // Conversion only visits nodes reachable from the root, so every error
+    // concerns a node with a path.
+    let paths: std::collections::HashMap<NodeId, EurePath> = doc.paths().collect();
+    Err(errors
+        .into_iter()
+        .map(|error| (paths[&error.node_id()].clone(), error))
+        .collect())
+}
+
+/// Apply whole-document post-processing to a converted value.
+fn finish_value(value: JsonValue, config: &Config) -> JsonValue {
+    if config.dedup_refs {
+        return dedup::dedup_subtrees(&value);
+    }
+    value
+}
+
+/// State shared by the Eure to JSON conversion functions.
+struct ToJson<'a> {
+    doc: &'a EureDocument,
+    config: &'a Config,
+    /// `Some` when errors are collected instead of failing on the first one.
+    collected: Option<Vec<EureToJsonError>>,
+}
+
+impl ToJson<'_> {
+    /// Report `error`: fail fast, or record it and emit `null` in place of the node.
+    fn fail(&mut self, error: EureToJsonError) -> Result<JsonValue, EureToJsonError> {
+        match &mut self.collected {
+            Some(errors) => {
+                errors.push(error);
+                Ok(JsonValue::Null)
+            }
+            None => Err(error),
+        }
+    }
+}
+
+fn convert_node(cx: &mut ToJson<'_>, node_id: NodeId) -> Result<JsonValue, EureToJsonError> {
+    let doc = cx.doc;
     let node = doc.node(node_id);
 
     // Check for $variant extension
@@ -123,40 +188,17 @@ fn convert_node(
 
     // If this node has a $variant extension, handle it as a variant
     if let Some(tag) = variant_ext {
-        return convert_variant_node(doc, node_id, tag, config);
+        return convert_variant_node(cx, node_id, tag);
     }
 
-    match &node.content {
-        NodeValue::Hole(_) => Err(EureToJsonError::HoleNotSupported { node_id }),
-        NodeValue::PartialMap(_) => Err(EureToJsonError::PartialMapNotSupported { node_id }),
-        NodeValue::Primitive(prim) => convert_primitive(prim, node_id),
-        NodeValue::Array(arr) => {
-            let mut result = Vec::new();
-            for &child_id in arr.iter() {
-                result.push(convert_node(doc, child_id, config)?);
-            }
-            Ok(JsonValue::Array(result))
-        }
-        NodeValue::Tuple(tuple) => {
-            let mut result = Vec::new();
-            for &child_id in tuple.iter() {
-                result.push(convert_node(doc, child_id, config)?);
-            }
-            Ok(JsonValue::Array(result))
-        }
-        NodeValue::Map(map) => {
-            let mut result = serde_json::Map::new();
-            for (key, &child_id) in map.iter() {
-                let key_string = convert_object_key(key)?;
-                let value = convert_node(doc, child_id, config)?;
-                result.insert(key_string, value);
-            }
-            Ok(JsonValue::Object(result))
-        }
-    }
+    convert_node_content_only(cx, node_id)
 }
 
-fn convert_primitive(prim: &PrimitiveValue, node_id: NodeId) -> Result<JsonValue, EureToJsonError> {
+fn convert_primitive(
+    prim: &PrimitiveValue,
+    node_id: NodeId,
+    config: &Config,
+) -> Result<JsonValue, EureToJsonError> {
     match prim {
         PrimitiveValue::Null => Ok(JsonValue::Null),
         PrimitiveValue::Bool(b) => Ok(JsonValue::Bool(*b)),
@@ -175,35 +217,71 @@ fn convert_primitive(prim: &PrimitiveValue, node_id: NodeId) -> Result<JsonValue
 
             Err(EureToJsonError::BigIntOutOfRange { node_id })
         }
-        PrimitiveValue::F32(f) => {
-            if let Some(num) = serde_json::Number::from_f64(*f as f64) {
-                Ok(JsonValue::Number(num))
-            } else {
-                // NaN or infinity - not supported in JSON
-                Err(EureToJsonError::NonFiniteFloat { node_id })
+        PrimitiveValue::F32(f) => convert_float(*f as f64, node_id, config),
+        PrimitiveValue::F64(f) => convert_float(*f, node_id, config),
+        PrimitiveValue::Text(text) => Ok(convert_text(text, config)),
+    }
+}
+
+fn convert_text(text: &Text, config: &Config) -> JsonValue {
+    let content = if config.strip_block_trailing_newline {
+        text.trimmed_content().to_string()
+    } else {
+        text.content.clone()
+    };
+    match config.text_tag {
+        TextTag::Tagged if !text.language.is_plaintext() => {
+            let mut map = serde_json::Map::new();
+            map.insert(TextTag::TAG.to_string(), JsonValue::String(content));
+            if let Some(tag) = text.language.as_tag() {
+                map.insert(
+                    TextTag::LANGUAGE.to_string(),
+                    JsonValue::String(tag.to_string()),
+                );
             }
+            JsonValue::Object(map)
         }
-        PrimitiveValue::F64(f) => {
-            if let Some(num) = serde_json::Number::from_f64(*f) {
-                Ok(JsonValue::Number(num))
-            } else {
-                // NaN or infinity - not supported in JSON
-                Err(EureToJsonError::NonFiniteFloat { node_id })
-            }
+        _ => JsonValue::String(content),
+    }
+}
+
+/// Read a [`TextTag::Tagged`] object back into text.
+fn tagged_text(obj: &serde_json::Map<String, JsonValue>) -> Option<Text> {
+    let content = obj.get(TextTag::TAG)?.as_str()?;
+    let language = match obj.get(TextTag::LANGUAGE) {
+        Some(tag) => Some(tag.as_str()?),
+        None => None,
+    };
+    if obj.len() != 1 + usize::from(language.is_some()) {
+        return None;
+    }
+    Some(Text::new(content, Language::from_tag(language)))
+}
+
+fn convert_float(f: f64, node_id: NodeId, config: &Config) -> Result<JsonValue, EureToJsonError> {
+    let Some(num) = serde_json::Number::from_f64(f) else {
+        // NaN or infinity - not supported in JSON
+        return Err(EureToJsonError::NonFiniteFloat { node_id });
+    };
+    match config.float_tag {
+        FloatTag::Tagged if f.fract() == 0.0 => {
+            let mut map = serde_json::Map::new();
+            map.insert(FloatTag::TAG.to_string(), JsonValue::Number(num));
+            Ok(JsonValue::Object(map))
         }
-        PrimitiveValue::Text(text) => Ok(JsonValue::String(text.content.clone())),
+        _ => Ok(JsonValue::Number(num)),
     }
 }
 
 /// Convert a node that has a $variant extension
 fn convert_variant_node(
-    doc: &EureDocument,
+    cx: &mut ToJson<'_>,
     node_id: NodeId,
     tag: &str,
-    config: &Config,
 ) -> Result<JsonValue, EureToJsonError> {
+    let config = cx.config;
     // Convert the content (the node itself minus the $variant extension)
-    let content_json = convert_node_content_only(doc, node_id, config)?;
+    let content_json = convert_node_content_only(cx, node_id)?;
 
     match &config.variant_repr {
         VariantRepr::External => {
@@ -218,18 +296,42 @@ fn convert_variant_node(
             if let JsonValue::Object(mut content_map) = content_json {
                 // Check if tag field already exists in content
                 if content_map.contains_key(tag_field) {
-                    return Err(EureToJsonError::VariantTagConflict {
+                    return cx.fail(EureToJsonError::VariantTagConflict {
                         tag: tag_field.clone(),
                         node_id,
                     });
                 }
                 content_map.insert(tag_field.clone(), JsonValue::String(tag.to_string()));
-                Ok(JsonValue::Object(content_map))
-            } else {
-                // If content is not an object, use External representation as fallback
-                let mut map = serde_json::Map::new();
-                map.insert(tag.to_string(), content_json);
-                Ok(JsonValue::Object(map))
+                return Ok(JsonValue::Object(content_map));
+            }
+
+            match &config.internal_variant_on_scalar {
+                InternalVariantOnScalar::FallbackExternal => {
+                    let mut map = serde_json::Map::new();
+                    map.insert(tag.to_string(), content_json);
+                    Ok(JsonValue::Object(map))
+                }
+                InternalVariantOnScalar::Error => {
+                    cx.fail(EureToJsonError::InternalVariantNotObject {
+                        variant: tag.to_string(),
+                        node_id,
+                    })
+                }
+                InternalVariantOnScalar::WrapObject {
+                    content: content_key,
+                } => {
+                    // {"type": "variant-name", "value": content}
+                    if tag_field == content_key {
+                        return cx.fail(EureToJsonError::VariantAdjacentConflict {
+                            field: tag_field.clone(),
+                            node_id,
+                        });
+                    }
+                    let mut map = serde_json::Map::new();
+                    map.insert(tag_field.clone(), JsonValue::String(tag.to_string()));
+                    map.insert(content_key.clone(), content_json);
+                    Ok(JsonValue::Object(map))
+                }
             }
         }
         VariantRepr::Adjacent {
@@ -239,7 +341,7 @@ fn convert_variant_node(
             // {"type": "variant-name", "content": {...}}
             // Check if tag and content keys are the same
             if tag_field == content_key {
-                return Err(EureToJsonError::VariantAdjacentConflict {
+                return cx.fail(EureToJsonError::VariantAdjacentConflict {
                     field: tag_field.clone(),
                     node_id,
                 });
@@ -258,40 +360,90 @@ fn convert_variant_node(
 
 /// Convert a node's content without checking for $variant extension (to avoid infinite recursion)
 fn convert_node_content_only(
-    doc: &EureDocument,
+    cx: &mut ToJson<'_>,
     node_id: NodeId,
-    config: &Config,
 ) -> Result<JsonValue, EureToJsonError> {
+    let doc = cx.doc;
     let node = doc.node(node_id);
 
     match &node.content {
-        NodeValue::Hole(_) => Err(EureToJsonError::HoleNotSupported { node_id }),
-        NodeValue::PartialMap(_) => Err(EureToJsonError::PartialMapNotSupported { node_id }),
-        NodeValue::Primitive(prim) => convert_primitive(prim, node_id),
+        NodeValue::Hole(_) => cx.fail(EureToJsonError::HoleNotSupported { node_id }),
+        NodeValue::PartialMap(_) => cx.fail(EureToJsonError::PartialMapNotSupported { node_id }),
+        NodeValue::Primitive(prim) => {
+            convert_primitive(prim, node_id, cx.config).or_else(|error| cx.fail(error))
+        }
         NodeValue::Array(arr) => {
             let mut result = Vec::new();
             for &child_id in arr.iter() {
-                result.push(convert_node(doc, child_id, config)?);
+                result.push(convert_node(cx, child_id)?);
             }
             Ok(JsonValue::Array(result))
         }
-        NodeValue::Tuple(tuple) => {
-            let mut result = Vec::new();
-            for &child_id in tuple.iter() {
-                result.push(convert_node(doc, child_id, config)?);
-            }
-            Ok(JsonValue::Array(result))
+        NodeValue::Tuple(tuple) => convert_tuple(cx, tuple),
+        NodeValue::Map(map) => convert_map(cx, node_id, map),
+    }
+}
+
+fn convert_tuple(cx: &mut ToJson<'_>, tuple: &NodeTuple) -> Result<JsonValue, EureToJsonError> {
+    if cx.config.unwrap_singleton_tuple
+        && tuple.len() == 1
+        && let Some(child_id) = tuple.get(0)
+    {
+        return convert_node(cx, child_id);
+    }
+    let mut result = Vec::new();
+    for &child_id in tuple.iter() {
+        result.push(convert_node(cx, child_id)?);
+    }
+    match cx.config.tuple_repr {
+        TupleRepr::Array => Ok(JsonValue::Array(result)),
+        TupleRepr::Tagged => {
+            let mut map = serde_json::Map::new();
+            map.insert(TupleRepr::TAG.to_string(), JsonValue::Array(result));
+            Ok(JsonValue::Object(map))
         }
-        NodeValue::Map(map) => {
-            let mut result = serde_json::Map::new();
-            for (key, &child_id) in map.iter() {
-                let key_string = convert_object_key(key)?;
-                let value = convert_node(doc, child_id, config)?;
-                result.insert(key_string, value);
+    }
+}
+
+fn convert_map(
+    cx: &mut ToJson<'_>,
+    node_id: NodeId,
+    map: &NodeMap,
+) -> Result<JsonValue, EureToJsonError> {
+    let mut entries: Vec<_> = map.iter().collect();
+    if cx.config.sort_keys {
+        entries.sort_by_key(|&(key, _)| key);
+    }
+
+    let mut result = serde_json::Map::new();
+    let mut languages = serde_json::Map::new();
+    for (key, &child_id) in entries {
+        let key_string = match convert_object_key(key) {
+            Ok(key_string) => cx.config.escape_key(key_string),
+            Err(error) => {
+                // The entry is dropped when errors are collected
+                cx.fail(error)?;
+                continue;
             }
-            Ok(JsonValue::Object(result))
+        };
+        if cx.config.text_tag == TextTag::Sidecar && key_string == TextTag::SIDECAR {
+            return cx.fail(EureToJsonError::SidecarKeyConflict { node_id });
         }
+        if cx.config.text_tag == TextTag::Sidecar
+            && let NodeValue::Primitive(PrimitiveValue::Text(text)) = &cx.doc.node(child_id).content
+            && !text.language.is_plaintext()
+        {
+            let tag = text.language.as_tag();
+            let tag = tag.map_or(JsonValue::Null, |tag| JsonValue::String(tag.to_string()));
+            languages.insert(key_string.clone(), tag);
+        }
+        let value = convert_node(cx, child_id)?;
+        result.insert(key_string, value);
+    }
+    if !languages.is_empty() {
+        result.insert(TextTag::SIDECAR.to_string(), JsonValue::Object(languages));
     }
+    Ok(JsonValue::Object(result))
 }
 
 fn convert_object_key(key: &ObjectKey) -> Result<String, EureToJsonError> {
@@ -318,8 +470,9 @@ fn convert_object_key(key: &ObjectKey) -> Result<String, EureToJsonError> {
 /// JSON objects become Eure maps, arrays become arrays, and primitives are converted
 /// directly. Variant reconstruction is not possible without schema information.
 ///
-/// The `config` parameter is accepted for API consistency but is not used for
-/// JSON to Eure conversion (variant detection requires schema information).
+/// Of the `config` options, only `disambiguate_keys`, `tuple_repr`,
+/// `float_tag`, `text_tag`, `dollar_key_policy`, and `dedup_refs` apply to
+/// this direction (variant detection requires schema information).
 ///
 /// # Example
 ///
@@ -332,16 +485,187 @@ fn convert_object_key(key: &ObjectKey) -> Result<String, EureToJsonError> {
 /// ```
 pub fn value_to_document(
     value: &JsonValue,
-    _config: &Config,
+    config: &Config,
+) -> Result<EureDocument, JsonToEureError> {
+    let resolved;
+    let value = if config.dedup_refs {
+        resolved = dedup::resolve_refs(value)?;
+        &resolved
+    } else {
+        value
+    };
+    let mut doc = EureDocument::new();
+    let root_id = doc.get_root_id();
+    convert_json_to_node(&mut doc, root_id, value, config, None)?;
+    Ok(doc)
+}
+
+/// Convert an Eure document to JSON using [`Config::default`].
+///
+/// Shorthand for [`document_to_value`] in the common case. A
+/// `From<&EureDocument> for serde_json::Value` impl is not possible here
+/// because both types are foreign to this crate.
+///
+/// # Example
+///
+/// ```
+/// use eure::eure;
+/// use serde_json::json;
+///
+/// let doc = eure!({ name = "Alice" });
+/// assert_eq!(eure_json::to_json(&doc).unwrap(), json!({"name": "Alice"}));
+/// ```
+pub fn to_json(doc: &EureDocument) -> Result<JsonValue, EureToJsonError> {
+    document_to_value(doc, &Config::default())
+}
+
+/// Convert a JSON value to an Eure document using [`Config::default`].
+///
+/// Shorthand for [`value_to_document`] in the common case.
+///
+/// # Example
+///
+/// ```
+/// use serde_json::json;
+///
+/// let doc = eure_json::from_json(&json!({"name": "Alice"})).unwrap();
+/// assert_eq!(eure_json::to_json(&doc).unwrap(), json!({"name": "Alice"}));
+/// ```
+pub fn from_json(value: &JsonValue) -> Result<EureDocument, JsonToEureError> {
+    value_to_document(value, &Config::default())
+}
+
+/// Convert a JSON value to an Eure document, guided by `schema`.
+///
+/// JSON has no tuple type, so arrays are converted to arrays by
+/// [`value_to_document`]. Here a JSON array whose schema node is a tuple
+/// becomes a tuple instead, after checking that the lengths agree. Array and
+/// tuple element schemas are followed into nested arrays, and record field
+/// schemas into the fields of JSON objects; everything else is converted as
+/// [`value_to_document`] would.
+///
+/// With `config.unwrap_singleton_tuple`, any value whose schema node is a
+/// 1-tuple is wrapped into a tuple, reversing the unwrapping done by
+/// [`document_to_value`].
+pub fn value_to_document_with_schema(
+    value: &JsonValue,
+    schema: &SchemaDocument,
+    config: &Config,
 ) -> Result<EureDocument, JsonToEureError> {
+    let resolved;
+    let value = if config.dedup_refs {
+        resolved = dedup::resolve_refs(value)?;
+        &resolved
+    } else {
+        value
+    };
     let mut doc = EureDocument::new();
     let root_id = doc.get_root_id();
-    convert_json_to_node(&mut doc, root_id, value);
+    convert_json_to_node_with_schema(&mut doc, root_id, value, schema, schema.root, config)?;
     Ok(doc)
 }
 
+fn convert_json_to_node_with_schema(
+    doc: &mut EureDocument,
+    node_id: NodeId,
+    value: &JsonValue,
+    schema: &SchemaDocument,
+    schema_id: SchemaNodeId,
+    config: &Config,
+) -> Result<(), JsonToEureError> {
+    if config.unwrap_singleton_tuple
+        && let Some(SchemaNodeContent::Tuple(tuple_schema)) =
+            resolve_schema_content(schema, schema_id)
+        && let &[element_schema] = tuple_schema.elements.as_slice()
+    {
+        doc.node_mut(node_id).content = NodeValue::empty_tuple();
+        let child_id = doc.create_node(NodeValue::hole());
+        convert_json_to_node_with_schema(doc, child_id, value, schema, element_schema, config)?;
+        if let NodeValue::Tuple(ref mut tuple) = doc.node_mut(node_id).content {
+            let _ = tuple.push(child_id);
+        }
+        return Ok(());
+    }
+    match (value, resolve_schema_content(schema, schema_id)) {
+        (JsonValue::Array(items), Some(SchemaNodeContent::Tuple(tuple_schema))) => {
+            if tuple_schema.elements.len() != items.len() {
+                return Err(JsonToEureError::TupleArityMismatch {
+                    expected: tuple_schema.elements.len(),
+                    actual: items.len(),
+                });
+            }
+            doc.node_mut(node_id).content = NodeValue::empty_tuple();
+            for (item, &element_schema) in items.iter().zip(&tuple_schema.elements) {
+                let child_id = doc.create_node(NodeValue::hole());
+                convert_json_to_node_with_schema(
+                    doc,
+                    child_id,
+                    item,
+                    schema,
+                    element_schema,
+                    config,
+                )?;
+                if let NodeValue::Tuple(ref mut tuple) = doc.node_mut(node_id).content {
+                    let _ = tuple.push(child_id);
+                }
+            }
+        }
+        (JsonValue::Array(items), Some(SchemaNodeContent::Array(array_schema))) => {
+            doc.node_mut(node_id).content = NodeValue::empty_array();
+            for item in items {
+                let child_id = doc.create_node(NodeValue::hole());
+                convert_json_to_node_with_schema(
+                    doc,
+                    child_id,
+                    item,
+                    schema,
+                    array_schema.item,
+                    config,
+                )?;
+                if let NodeValue::Array(ref mut array) = doc.node_mut(node_id).content {
+                    let _ = array.push(child_id);
+                }
+            }
+        }
+        (_, Some(SchemaNodeContent::Record(record))) => {
+            convert_json_to_node(doc, node_id, value, config, Some((schema, record)))?
+        }
+        _ => convert_json_to_node(doc, node_id, value, config, None)?,
+    }
+    Ok(())
+}
+
+/// Follow local type references to the schema content they name.
+///
+/// Returns `None` for references that cannot be resolved locally (including
+/// cross-schema references) or that form a cycle.
+fn resolve_schema_content(
+    schema: &SchemaDocument,
+    mut schema_id: SchemaNodeId,
+) -> Option<&SchemaNodeContent> {
+    for _ in 0..=schema.types.len() {
+        match &schema.node(schema_id).content {
+            SchemaNodeContent::Reference(type_ref) if type_ref.namespace.is_none() => {
+                schema_id = schema.get_type(&type_ref.name)?;
+            }
+            SchemaNodeContent::Reference(_) => return None,
+            content => return Some(content),
+        }
+    }
+    None
+}
+
 /// Convert a JSON value and set it as the content of the given node.
-fn convert_json_to_node(doc: &mut EureDocument, node_id: NodeId, value: &JsonValue) {
+///
+/// When `record` is given, the fields of a JSON object are converted with
+/// [`convert_json_to_node_with_schema`] against their field schemas.
+fn convert_json_to_node(
+    doc: &mut EureDocument,
+    node_id: NodeId,
+    value: &JsonValue,
+    config: &Config,
+    record: Option<(&SchemaDocument, &RecordSchema)>,
+) -> Result<(), JsonToEureError> {
     match value {
         JsonValue::Null => {
             doc.node_mut(node_id).content = NodeValue::Primitive(PrimitiveValue::Null);
@@ -368,23 +692,118 @@ fn convert_json_to_node(doc: &mut EureDocument, node_id: NodeId, value: &JsonVal
             doc.node_mut(node_id).content = NodeValue::empty_array();
             for item in arr {
                 let child_id = doc.create_node(NodeValue::hole());
-                convert_json_to_node(doc, child_id, item);
+                convert_json_to_node(doc, child_id, item, config, None)?;
                 if let NodeValue::Array(ref mut array) = doc.node_mut(node_id).content {
                     let _ = array.push(child_id);
                 }
             }
         }
+        JsonValue::Object(obj)
+            if config.tuple_repr == TupleRepr::Tagged
+                && obj.len() == 1
+                && let Some(JsonValue::Array(items)) = obj.get(TupleRepr::TAG) =>
+        {
+            doc.node_mut(node_id).content = NodeValue::empty_tuple();
+            for item in items {
+                let child_id = doc.create_node(NodeValue::hole());
+                convert_json_to_node(doc, child_id, item, config, None)?;
+                if let NodeValue::Tuple(ref mut tuple) = doc.node_mut(node_id).content {
+                    let _ = tuple.push(child_id);
+                }
+            }
+        }
+        JsonValue::Object(obj)
+            if config.float_tag == FloatTag::Tagged
+                && obj.len() == 1
+                && let Some(f) = obj.get(FloatTag::TAG).and_then(JsonValue::as_f64) =>
+        {
+            doc.node_mut(node_id).content = NodeValue::Primitive(PrimitiveValue::F64(f));
+        }
+        JsonValue::Object(obj)
+            if config.text_tag == TextTag::Tagged
+                && let Some(text) = tagged_text(obj) =>
+        {
+            doc.node_mut(node_id).content = NodeValue::Primitive(PrimitiveValue::Text(text));
+        }
         JsonValue::Object(obj) => {
             doc.node_mut(node_id).content = NodeValue::empty_map();
+            let languages = match obj.get(TextTag::SIDECAR) {
+                Some(sidecar) if config.text_tag == TextTag::Sidecar => {
+                    Some(sidecar_languages(obj, sidecar)?)
+                }
+                _ => None,
+            };
             for (key, val) in obj {
+                if languages.is_some() && key == TextTag::SIDECAR {
+                    continue;
+                }
                 let child_id = doc.create_node(NodeValue::hole());
-                convert_json_to_node(doc, child_id, val);
-                if let NodeValue::Map(ref mut map) = doc.node_mut(node_id).content {
-                    map.insert(ObjectKey::String(key.clone()), child_id);
+                match record.and_then(|(schema, record)| {
+                    Some((schema, record.properties.get(config.unescape_key(key))?))
+                }) {
+                    Some((schema, field)) => convert_json_to_node_with_schema(
+                        doc,
+                        child_id,
+                        val,
+                        schema,
+                        field.schema,
+                        config,
+                    )?,
+                    None => convert_json_to_node(doc, child_id, val, config, None)?,
+                }
+                if let Some(tag) = languages.and_then(|languages| languages.get(key))
+                    && let NodeValue::Primitive(PrimitiveValue::Text(text)) =
+                        &mut doc.node_mut(child_id).content
+                {
+                    text.language = Language::from_tag(tag.as_str());
+                }
+                if config.dollar_key_policy == DollarKeyPolicy::AsExtension
+                    && let Some(ident) = key
+                        .strip_prefix('$')
+                        .and_then(|name| name.parse::<Identifier>().ok())
+                {
+                    doc.node_mut(node_id).extensions.insert(ident, child_id);
+                } else if let NodeValue::Map(ref mut map) = doc.node_mut(node_id).content {
+                    let key = config.unescape_key(key);
+                    map.add(json_key_to_object_key(key, config), child_id)
+                        .map_err(|_| JsonToEureError::DuplicateKey {
+                            key: key.to_string(),
+                        })?;
                 }
             }
         }
     }
+    Ok(())
+}
+
+/// Check the `$languages` sidecar of `obj`: an object whose entries tag the
+/// object's own text fields with a language string, or null for implicit code.
+fn sidecar_languages<'a>(
+    obj: &serde_json::Map<String, JsonValue>,
+    sidecar: &'a JsonValue,
+) -> Result<&'a serde_json::Map<String, JsonValue>, JsonToEureError> {
+    let JsonValue::Object(languages) = sidecar else {
+        return Err(JsonToEureError::SidecarNotObject);
+    };
+    for (key, tag) in languages {
+        if !matches!(tag, JsonValue::String(_) | JsonValue::Null) {
+            return Err(JsonToEureError::InvalidLanguageTag { key: key.clone() });
+        }
+        if !matches!(obj.get(key), Some(JsonValue::String(_))) {
+            return Err(JsonToEureError::UnknownSidecarField { key: key.clone() });
+        }
+    }
+    Ok(languages)
+}
+
+fn json_key_to_object_key(key: &str, config: &Config) -> ObjectKey {
+    if config.disambiguate_keys {
+        let digits = key.strip_prefix('#').unwrap_or(key);
+        if let Ok(n) = digits.parse::<BigInt>() {
+            return ObjectKey::Number(n);
+        }
+    }
+    ObjectKey::String(key.to_string())
 }
 
 #[cfg(test)]
@@ -447,6 +866,25 @@ mod tests {
         assert_eq!(document_to_value(&eure, &Config::default()).unwrap(), json);
     }
 
+    #[test]
+    fn test_block_text_keeps_trailing_newline() {
+        let eure = eure!({ = @block("x") });
+        assert_eq!(
+            document_to_value(&eure, &Config::default()).unwrap(),
+            json!("x\n")
+        );
+    }
+
+    #[test]
+    fn test_block_text_strip_trailing_newline() {
+        let eure = eure!({ = @block("x") });
+        let config = Config {
+            strip_block_trailing_newline: true,
+            ..Config::default()
+        };
+        assert_eq!(document_to_value(&eure, &config).unwrap(), json!("x"));
+    }
+
     #[test]
     fn test_array() {
         let eure = eure!({
@@ -468,6 +906,282 @@ mod tests {
         assert_eq!(document_to_value(&eure, &Config::default()).unwrap(), json);
     }
 
+    #[test]
+    fn test_tuple_tagged() {
+        let eure = eure!({
+            point.#0 = 1,
+            point.#1 = "a",
+        });
+        let config = Config {
+            tuple_repr: TupleRepr::Tagged,
+            ..Config::default()
+        };
+        let json = json!({"point": {"$tuple": [1, "a"]}});
+        assert_eq!(document_to_value(&eure, &config).unwrap(), json);
+    }
+
+    #[test]
+    fn test_tuple_tagged_round_trip() {
+        let eure = eure!({
+            items[] = 1,
+            items[] = 2,
+            point.#0 = true,
+            point.#1 = "b",
+        });
+        let config = Config {
+            tuple_repr: TupleRepr::Tagged,
+            ..Config::default()
+        };
+        let json = document_to_value(&eure, &config).unwrap();
+        assert_eq!(
+            json,
+            json!({"items": [1, 2], "point": {"$tuple": [true, "b"]}})
+        );
+        let doc = value_to_document(&json, &config).unwrap();
+        assert_eq!(doc, eure);
+    }
+
+    #[test]
+    fn test_tagged_round_trip_escapes_reserved_keys() {
+        let eure = eure!({
+            point."$tuple"[] = 1,
+            whole."$float" = 2,
+            code."$text" = "x",
+            escaped."$$tuple" = true,
+        });
+        let config = Config {
+            tuple_repr: TupleRepr::Tagged,
+            float_tag: FloatTag::Tagged,
+            text_tag: TextTag::Tagged,
+            ..Config::default()
+        };
+        let json = document_to_value(&eure, &config).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "point": {"$$tuple": [1]},
+                "whole": {"$$float": 2},
+                "code": {"$$text": "x"},
+                "escaped": {"$$$tuple": true},
+            })
+        );
+        let doc = value_to_document(&json, &config).unwrap();
+        assert_eq!(doc, eure);
+
+        // Keys are only escaped for the tags in use
+        assert_eq!(
+            document_to_value(&eure, &Config::default()).unwrap()["point"],
+            json!({"$tuple": [1]})
+        );
+    }
+
+    #[test]
+    fn test_unwrap_singleton_tuple() {
+        let eure = eure!({
+            single.#0 = "a",
+            pair.#0 = 1,
+            pair.#1 = 2,
+        });
+        let config = Config {
+            unwrap_singleton_tuple: true,
+            ..Config::default()
+        };
+        assert_eq!(
+            document_to_value(&eure, &config).unwrap(),
+            json!({"single": "a", "pair": [1, 2]})
+        );
+        assert_eq!(
+            document_to_value(&eure, &Config::default()).unwrap(),
+            json!({"single": ["a"], "pair": [1, 2]})
+        );
+    }
+
+    #[test]
+    fn test_float_tag_round_trip() {
+        let eure = eure!({
+            count = 2,
+            fraction = 2.5f64,
+            whole = 2.0f64,
+        });
+        let config = Config {
+            float_tag: FloatTag::Tagged,
+            ..Config::default()
+        };
+        let json = document_to_value(&eure, &config).unwrap();
+        assert_eq!(
+            json,
+            json!({"count": 2, "fraction": 2.5, "whole": {"$float": 2.0}})
+        );
+
+        let text = serde_json::to_string(&json).unwrap();
+        let parsed: JsonValue = serde_json::from_str(&text).unwrap();
+        let doc = value_to_document(&parsed, &config).unwrap();
+        assert_eq!(doc, eure);
+    }
+
+    #[test]
+    fn test_text_tag_round_trip() {
+        let eure = eure!({
+            name = "plain",
+            snippet = @code("let x = 1"),
+            query = @code("sql", "SELECT 1"),
+        });
+        let config = Config {
+            text_tag: TextTag::Tagged,
+            ..Config::default()
+        };
+        let json = document_to_value(&eure, &config).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "name": "plain",
+                "snippet": {"$text": "let x = 1"},
+                "query": {"$text": "SELECT 1", "$language": "sql"},
+            })
+        );
+
+        let text = serde_json::to_string(&json).unwrap();
+        let parsed: JsonValue = serde_json::from_str(&text).unwrap();
+        let doc = value_to_document(&parsed, &config).unwrap();
+        assert_eq!(doc, eure);
+    }
+
+    #[test]
+    fn test_text_tag_distinguishes_plaintext_from_implicit() {
+        let plaintext = eure!({ value = "x" });
+        let implicit = eure!({ value = @code("x") });
+        let config = Config {
+            text_tag: TextTag::Tagged,
+            ..Config::default()
+        };
+
+        let plaintext_json = document_to_value(&plaintext, &config).unwrap();
+        let implicit_json = document_to_value(&implicit, &config).unwrap();
+        assert_ne!(plaintext_json, implicit_json);
+        assert_eq!(
+            value_to_document(&plaintext_json, &config).unwrap(),
+            plaintext
+        );
+        assert_eq!(
+            value_to_document(&implicit_json, &config).unwrap(),
+            implicit
+        );
+
+        // Without tagging both collapse to the same JSON string.
+        assert_eq!(
+            document_to_value(&plaintext, &Config::default()).unwrap(),
+            document_to_value(&implicit, &Config::default()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_text_tag_sidecar_round_trip() {
+        let eure = eure!({
+            name = "plain",
+            build = @code("rust", "fn main() {}"),
+            query = @code("sql", "SELECT 1"),
+            snippet = @code("let x = 1"),
+        });
+        let config = Config {
+            text_tag: TextTag::Sidecar,
+            ..Config::default()
+        };
+        let json = document_to_value(&eure, &config).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "name": "plain",
+                "build": "fn main() {}",
+                "query": "SELECT 1",
+                "snippet": "let x = 1",
+                "$languages": {"build": "rust", "query": "sql", "snippet": null},
+            })
+        );
+
+        let text = serde_json::to_string(&json).unwrap();
+        let parsed: JsonValue = serde_json::from_str(&text).unwrap();
+        let doc = value_to_document(&parsed, &config).unwrap();
+        assert_eq!(doc, eure);
+
+        // Without the sidecar mode `$languages` stays an ordinary key.
+        let doc = value_to_document(&parsed, &Config::default()).unwrap();
+        assert_ne!(doc, eure);
+    }
+
+    #[test]
+    fn test_text_tag_sidecar_rejects_real_languages_key() {
+        let eure = eure!({ "$languages" = "en" });
+        let config = Config {
+            text_tag: TextTag::Sidecar,
+            ..Config::default()
+        };
+        assert_eq!(
+            document_to_value(&eure, &config),
+            Err(EureToJsonError::SidecarKeyConflict {
+                node_id: eure.get_root_id()
+            })
+        );
+    }
+
+    #[test]
+    fn test_text_tag_sidecar_rejects_malformed_sidecar() {
+        let config = Config {
+            text_tag: TextTag::Sidecar,
+            ..Config::default()
+        };
+        let cases = [
+            (
+                json!({"name": "x", "$languages": "rust"}),
+                JsonToEureError::SidecarNotObject,
+            ),
+            (
+                json!({"name": "x", "$languages": {"name": 1}}),
+                JsonToEureError::InvalidLanguageTag {
+                    key: "name".to_string(),
+                },
+            ),
+            (
+                json!({"name": "x", "$languages": {"missing": "rust"}}),
+                JsonToEureError::UnknownSidecarField {
+                    key: "missing".to_string(),
+                },
+            ),
+            (
+                json!({"count": 1, "$languages": {"count": "rust"}}),
+                JsonToEureError::UnknownSidecarField {
+                    key: "count".to_string(),
+                },
+            ),
+        ];
+        for (json, expected) in cases {
+            assert_eq!(value_to_document(&json, &config), Err(expected), "{json}");
+        }
+    }
+
+    #[test]
+    fn test_dollar_key_as_map_key() {
+        let json = json!({"$ref": "#/definitions/user"});
+        let doc = value_to_document(&json, &Config::default()).unwrap();
+        assert_eq!(doc, eure!({ "$ref" = "#/definitions/user" }));
+    }
+
+    #[test]
+    fn test_dollar_key_as_extension() {
+        let json = json!({"$ref": "#/definitions/user", "name": "x"});
+        let config = Config {
+            dollar_key_policy: DollarKeyPolicy::AsExtension,
+            ..Config::default()
+        };
+        let doc = value_to_document(&json, &config).unwrap();
+        assert_eq!(
+            doc,
+            eure!({
+                %ref = "#/definitions/user"
+                name = "x"
+            })
+        );
+    }
+
     #[test]
     fn test_empty_map() {
         let eure = eure!({});
@@ -511,6 +1225,7 @@ mod tests {
         });
         let config = Config {
             variant_repr: VariantRepr::External,
+            ..Config::default()
         };
         let json = json!({"Success": true});
         assert_eq!(document_to_value(&eure, &config).unwrap(), json);
@@ -524,6 +1239,7 @@ mod tests {
         });
         let config = Config {
             variant_repr: VariantRepr::Untagged,
+            ..Config::default()
         };
         let json = json!(true);
         assert_eq!(document_to_value(&eure, &config).unwrap(), json);
@@ -539,11 +1255,71 @@ mod tests {
             variant_repr: VariantRepr::Internal {
                 tag: "type".to_string(),
             },
+            ..Config::default()
         };
         let json = json!({"type": "Success", "field": 42});
         assert_eq!(document_to_value(&eure, &config).unwrap(), json);
     }
 
+    fn internal_scalar_config(on_scalar: InternalVariantOnScalar) -> Config {
+        Config {
+            variant_repr: VariantRepr::Internal {
+                tag: "type".to_string(),
+            },
+            internal_variant_on_scalar: on_scalar,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn test_variant_internal_scalar_fallback_external() {
+        let eure = eure!({
+            = 42,
+            %variant = "Count",
+        });
+        let config = internal_scalar_config(InternalVariantOnScalar::FallbackExternal);
+        let json = json!({"Count": 42});
+        assert_eq!(document_to_value(&eure, &config).unwrap(), json);
+    }
+
+    #[test]
+    fn test_variant_internal_scalar_error() {
+        let eure = eure!({
+            = 42,
+            %variant = "Count",
+        });
+        let config = internal_scalar_config(InternalVariantOnScalar::Error);
+        let result = document_to_value(&eure, &config);
+        assert!(matches!(
+            result,
+            Err(EureToJsonError::InternalVariantNotObject { ref variant, .. }) if variant == "Count"
+        ));
+    }
+
+    #[test]
+    fn test_variant_internal_scalar_wrap_object() {
+        let eure = eure!({
+            = 42,
+            %variant = "Count",
+        });
+        let config = internal_scalar_config(InternalVariantOnScalar::WrapObject {
+            content: "value".to_string(),
+        });
+        let json = json!({"type": "Count", "value": 42});
+        assert_eq!(document_to_value(&eure, &config).unwrap(), json);
+    }
+
+    #[test]
+    fn test_variant_internal_object_ignores_on_scalar() {
+        let eure = eure!({
+            field = 42,
+            %variant = "Success",
+        });
+        let config = internal_scalar_config(InternalVariantOnScalar::Error);
+        let json = json!({"type": "Success", "field": 42});
+        assert_eq!(document_to_value(&eure, &config).unwrap(), json);
+    }
+
     #[test]
     fn test_variant_adjacent() {
         let eure = eure!({
@@ -555,11 +1331,34 @@ mod tests {
                 tag: "tag".to_string(),
                 content: "content".to_string(),
             },
+            ..Config::default()
         };
         let json = json!({"tag": "Success", "content": true});
         assert_eq!(document_to_value(&eure, &config).unwrap(), json);
     }
 
+    #[test]
+    fn test_sort_keys_deterministic() {
+        let eure = eure!({
+            "key-03" = 3
+            "key-02" = 2
+            "key-01" = 1
+            "key-00" = 0
+        });
+        let config = Config {
+            sort_keys: true,
+            ..Config::default()
+        };
+
+        let first = serde_json::to_string(&document_to_value(&eure, &config).unwrap()).unwrap();
+        for _ in 0..8 {
+            let output =
+                serde_json::to_string(&document_to_value(&eure, &config).unwrap()).unwrap();
+            assert_eq!(output, first);
+        }
+        assert_eq!(first, r#"{"key-00":0,"key-01":1,"key-02":2,"key-03":3}"#);
+    }
+
     // Error tests
     #[test]
     fn test_hole_error() {
@@ -593,6 +1392,112 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_dedup_refs_round_trip() {
+        let eure = eure!({
+            primary.host = "localhost",
+            primary.port = 8080,
+            replica.host = "localhost",
+            replica.port = 8080,
+        });
+        let config = Config {
+            dedup_refs: true,
+            ..Config::default()
+        };
+        let json = document_to_value(&eure, &config).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "primary": {"host": "localhost", "port": 8080},
+                "replica": {"$ref": "#/primary"},
+            })
+        );
+        assert_eq!(value_to_document(&json, &config).unwrap(), eure);
+
+        // Standard JSON repeats the subtree
+        assert_eq!(
+            document_to_value(&eure, &Config::default()).unwrap()["replica"],
+            json!({"host": "localhost", "port": 8080})
+        );
+    }
+
+    #[test]
+    fn test_dedup_refs_cyclic_ref() {
+        let json = json!({"a": {"b": {"$ref": "#/a"}}});
+        let config = Config {
+            dedup_refs: true,
+            ..Config::default()
+        };
+        assert_eq!(
+            value_to_document(&json, &config),
+            Err(JsonToEureError::CyclicRef {
+                reference: "#/a".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_dedup_refs_escapes_ref_like_map() {
+        let eure = eure!({ target.name = "x", link."$ref" = "#/target" });
+        let config = Config {
+            dedup_refs: true,
+            ..Config::default()
+        };
+        let json = document_to_value(&eure, &config).unwrap();
+        assert_eq!(json["link"], json!({"$$ref": "#/target"}));
+        assert_eq!(value_to_document(&json, &config).unwrap(), eure);
+
+        // Without references the map is exported as is
+        assert_eq!(
+            document_to_value(&eure, &Config::default()).unwrap()["link"],
+            json!({"$ref": "#/target"})
+        );
+    }
+
+    #[test]
+    fn test_collecting_reports_every_non_finite_float() {
+        use eure_document::path::PathSegment;
+
+        let nan_value = f64::NAN;
+        let inf_value = f64::INFINITY;
+        let eure = eure!({
+            ratio = nan_value,
+            ok = 1,
+            limits.upper = inf_value,
+        });
+
+        let errors = document_to_value_collecting(&eure, &Config::default()).unwrap_err();
+        let ident = |name: &str| PathSegment::Ident(name.parse().unwrap());
+        let paths: Vec<_> = errors.iter().map(|(path, _)| path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                EurePath(vec![ident("ratio")]),
+                EurePath(vec![ident("limits"), ident("upper")]),
+            ]
+        );
+        assert!(
+            errors
+                .iter()
+                .all(|(_, e)| matches!(e, EureToJsonError::NonFiniteFloat { .. }))
+        );
+
+        // The fail-fast version stops at the first one
+        assert!(matches!(
+            document_to_value(&eure, &Config::default()),
+            Err(EureToJsonError::NonFiniteFloat { .. })
+        ));
+    }
+
+    #[test]
+    fn test_collecting_without_errors_matches_document_to_value() {
+        let eure = eure!({ user.name = "Alice", tags = ["a", "b"] });
+        assert_eq!(
+            document_to_value_collecting(&eure, &Config::default()).unwrap(),
+            document_to_value(&eure, &Config::default()).unwrap()
+        );
+    }
+
     // ========================================================================
     // JSON to Eure conversion tests
     // ========================================================================
@@ -702,6 +1607,123 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_json_to_eure_disambiguate_keys() {
+        let json = json!({"#1": "a", "2": "b", "name": "c"});
+        let config = Config {
+            disambiguate_keys: true,
+            ..Config::default()
+        };
+        let doc = value_to_document(&json, &config).unwrap();
+        let map = doc.root().as_map().unwrap();
+        assert!(map.contains_key(&ObjectKey::Number(1.into())));
+        assert!(map.contains_key(&ObjectKey::Number(2.into())));
+        assert!(map.contains_key(&ObjectKey::String("name".to_string())));
+    }
+
+    #[test]
+    fn test_json_to_eure_disambiguated_key_collision() {
+        let json = json!({"42": "natural", "#42": "explicit"});
+        let config = Config {
+            disambiguate_keys: true,
+            ..Config::default()
+        };
+        // Which of the two keys is reported depends on JSON object iteration order
+        assert!(matches!(
+            value_to_document(&json, &config),
+            Err(JsonToEureError::DuplicateKey { key }) if key == "42" || key == "#42"
+        ));
+
+        // Without disambiguation both keys stay distinct strings
+        let doc = value_to_document(&json, &Config::default()).unwrap();
+        assert_eq!(doc.root().as_map().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_json_to_eure_source_with_inferred_layout() {
+        use eure_document::source::SourceDocument;
+
+        let json = json!({
+            "name": "app",
+            "server": {"host": "localhost", "port": 8080, "tls": {"enabled": true}},
+            "users": [{"name": "a"}, {"name": "b"}]
+        });
+        let doc = value_to_document(&json, &Config::default()).unwrap();
+        let formatted =
+            eure_fmt::format_source_document(&SourceDocument::from_document(doc.clone()).unwrap());
+
+        assert!(formatted.contains("name = \"app\""), "{formatted}");
+        assert!(formatted.contains("@ server"), "{formatted}");
+        assert!(formatted.contains("@ users[]"), "{formatted}");
+
+        let reparsed = eure::document::parse_to_document(&formatted, "test.eure").unwrap();
+        assert_eq!(reparsed, doc);
+    }
+
+    fn schema_of(source: &str) -> SchemaDocument {
+        let doc = eure::document::parse_to_document(source, "test.schema.eure").unwrap();
+        eure_schema::convert::document_to_schema(&doc).unwrap().0
+    }
+
+    #[test]
+    fn test_json_to_eure_array_as_tuple_with_schema() {
+        let schema = schema_of("= (`text`, `integer`)\n");
+        let json = json!(["localhost", 8080]);
+        assert_eq!(
+            value_to_document_with_schema(&json, &schema, &Config::default()).unwrap(),
+            eure!({ = ("localhost", 8080) })
+        );
+
+        // Without a schema the same array stays an array
+        assert_eq!(
+            value_to_document(&json, &Config::default()).unwrap(),
+            eure!({ = ["localhost", 8080] })
+        );
+    }
+
+    #[test]
+    fn test_json_to_eure_record_field_tuple_with_schema() {
+        let schema = schema_of("name = `text`\naddr = (`text`, `integer`)\n");
+        let json = json!({"name": "web", "addr": ["localhost", 8080], "tags": ["a", "b"]});
+        assert_eq!(
+            value_to_document_with_schema(&json, &schema, &Config::default()).unwrap(),
+            eure!({ name = "web", addr = ("localhost", 8080), tags = ["a", "b"] })
+        );
+    }
+
+    #[test]
+    fn test_json_to_eure_singleton_tuple_with_schema() {
+        let schema = schema_of("= (`text`,)\n");
+        let config = Config {
+            unwrap_singleton_tuple: true,
+            ..Config::default()
+        };
+
+        let json = json!("localhost");
+        let doc = value_to_document_with_schema(&json, &schema, &config).unwrap();
+        assert_eq!(doc, eure!({ = ("localhost",) }));
+        assert_eq!(document_to_value(&doc, &config).unwrap(), json);
+
+        // Without the option a bare value is not wrapped
+        assert_eq!(
+            value_to_document_with_schema(&json, &schema, &Config::default()).unwrap(),
+            eure!({ = "localhost" })
+        );
+    }
+
+    #[test]
+    fn test_json_to_eure_tuple_arity_mismatch() {
+        let schema = schema_of("= (`text`, `integer`)\n");
+        let json = json!(["localhost", 8080, true]);
+        assert_eq!(
+            value_to_document_with_schema(&json, &schema, &Config::default()),
+            Err(JsonToEureError::TupleArityMismatch {
+                expected: 2,
+                actual: 3
+            })
+        );
+    }
+
     // ========================================================================
     // Roundtrip tests
     // ========================================================================
@@ -737,6 +1759,26 @@ mod tests {
         assert_eq!(json, roundtrip);
     }
 
+    #[test]
+    fn test_to_json_nested_object() {
+        let eure = eure!({
+            user.name = "Alice",
+            user.tags = ["admin", "ops"],
+        });
+        assert_eq!(
+            to_json(&eure).unwrap(),
+            json!({"user": {"name": "Alice", "tags": ["admin", "ops"]}})
+        );
+    }
+
+    #[test]
+    fn test_from_json_nested_object() {
+        let json = json!({"user": {"name": "Alice", "age": 30}});
+        let doc = from_json(&json).unwrap();
+        assert_eq!(doc, value_to_document(&json, &Config::default()).unwrap());
+        assert_eq!(to_json(&doc).unwrap(), json);
+    }
+
     #[test]
     fn test_roundtrip_deeply_nested() {
         let json = json!({"Ok": {"Some": {"method": "add"}}});
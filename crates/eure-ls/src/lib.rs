@@ -5,6 +5,7 @@
 
 mod capabilities;
 pub mod queries;
+mod trace;
 pub mod types;
 mod uri_utils;
 
@@ -20,32 +21,49 @@ pub use wasm::WasmCore;
 
 // Public exports for shared functionality
 pub use capabilities::server_capabilities;
-pub use queries::{LspDiagnostics, LspFileDiagnostics, LspSemanticTokens};
+pub use queries::{
+    LspCodeActions, LspDiagnostics, LspDocumentHighlight, LspFileDiagnostics, LspFormatOnSave,
+    LspPrepareRename, LspRename, LspSelectionRanges, LspSemanticTokens, LspWorkspaceSymbols,
+};
 pub use types::{CoreRequestId, Effect, LspError, LspOutput};
 
 use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
+use eure::document::identifier::Identifier;
 use eure::query::{
-    CollectDiagnosticTargets, Glob, GlobResult, OpenDocuments, OpenDocumentsList, TextFile,
-    TextFileContent, Workspace, WorkspaceId, build_runtime,
+    CollectDiagnosticTargets, CollectSchemaDependents, Glob, GlobResult, OpenDocuments,
+    OpenDocumentsList, TextFile, TextFileContent, Workspace, WorkspaceId, build_runtime,
 };
 use lsp_types::InitializeParams;
 use query_flow::{DurabilityLevel, QueryRuntime};
 
-use crate::types::{CommandQuery, CommandResult, FileDiagnosticsSubscription, PendingRequest};
+use crate::types::{
+    CommandQuery, CommandResult, DiagnosticsProgress, FileDiagnosticsSubscription, PendingRequest,
+};
 use crate::uri_utils::uri_to_text_file;
 
 use lsp_types::{
-    DidChangeTextDocumentParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
-    InitializeResult, PublishDiagnosticsParams, SemanticTokensParams,
+    ClientCapabilities, CodeActionParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, DidSaveTextDocumentParams, DocumentHighlightParams,
+    InitializeResult, NumberOrString, ProgressParams, ProgressParamsValue,
+    PublishDiagnosticsParams, RenameParams, SelectionRangeParams, SemanticTokensParams,
+    TextDocumentPositionParams, WillSaveTextDocumentParams, WorkDoneProgress,
+    WorkDoneProgressBegin, WorkDoneProgressCreateParams, WorkDoneProgressEnd,
+    WorkDoneProgressReport, WorkspaceSymbolParams,
     notification::{
-        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
-        Notification as LspNotification, PublishDiagnostics,
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, DidSaveTextDocument,
+        Notification as LspNotification, Progress, PublishDiagnostics,
+    },
+    request::{
+        CodeActionRequest, DocumentHighlightRequest, Initialize, PrepareRenameRequest,
+        Rename as RenameRequest, Request as LspRequest, SelectionRangeRequest,
+        SemanticTokensFullRequest, Shutdown, WillSaveWaitUntil, WorkDoneProgressCreate,
+        WorkspaceSymbolRequest,
     },
-    request::{Initialize, Request as LspRequest, SemanticTokensFullRequest, Shutdown},
 };
 
+use crate::trace::{HandlerSpan, RequestTrace};
 use crate::uri_utils::text_file_to_uri;
 use query_flow::QueryError;
 use serde_json::Value;
@@ -100,6 +118,9 @@ pub fn register_workspaces_from_init(runtime: &mut QueryRuntime, params: &Initia
     }
 }
 
+/// How long edits must pause before diagnostics are recomputed.
+const DIAGNOSTICS_DEBOUNCE_MS: u64 = 200;
+
 /// The headless LSP core state machine.
 ///
 /// This struct contains all the state and logic for the language server,
@@ -116,12 +137,27 @@ pub struct LspCore {
     pending_globs: HashMap<String, Glob>,
     /// Per-file diagnostics subscriptions with revision tracking.
     diagnostics_subscriptions: HashMap<TextFile, FileDiagnosticsSubscription>,
+    /// Saved files whose dependents have not been collected yet.
+    pending_saves: HashSet<TextFile>,
+    /// Documents on disk to diagnose because a schema they depend on was saved.
+    ///
+    /// Each is diagnosed once and then dropped. Its diagnostics stay published
+    /// until the next save re-diagnoses it or it becomes a diagnostic target.
+    saved_dependents: HashSet<TextFile>,
     /// URIs we've published diagnostics to (for stale clearing).
     published_uris: HashSet<String>,
     /// Cached content of open documents (keyed by URI string).
     documents: HashMap<String, String>,
     /// Whether the server has been initialized.
     initialized: bool,
+    /// Whether the client advertised `window.workDoneProgress`.
+    work_done_progress: bool,
+    /// Progress of the diagnostics refresh currently waiting on assets.
+    diagnostics_progress: Option<DiagnosticsProgress>,
+    /// Counter for server-created progress tokens.
+    next_progress_token: u32,
+    /// Whether a document changed since diagnostics were last refreshed.
+    diagnostics_dirty: bool,
 }
 
 impl LspCore {
@@ -135,9 +171,15 @@ impl LspCore {
             pending_assets: HashSet::new(),
             pending_globs: HashMap::new(),
             diagnostics_subscriptions: HashMap::new(),
+            pending_saves: HashSet::new(),
+            saved_dependents: HashSet::new(),
             published_uris: HashSet::new(),
             documents: HashMap::new(),
             initialized: false,
+            work_done_progress: false,
+            diagnostics_progress: None,
+            next_progress_token: 0,
+            diagnostics_dirty: false,
         }
     }
 
@@ -158,6 +200,18 @@ impl LspCore {
         self.initialized = true;
     }
 
+    /// Record the capabilities the client sent in `initialize`.
+    ///
+    /// Progress of long diagnostics refreshes is only reported to clients
+    /// that advertise `window.workDoneProgress`.
+    pub fn set_client_capabilities(&mut self, capabilities: &ClientCapabilities) {
+        self.work_done_progress = capabilities
+            .window
+            .as_ref()
+            .and_then(|window| window.work_done_progress)
+            .unwrap_or(false);
+    }
+
     /// Get pending files that need to be fetched.
     pub fn pending_files(&self) -> impl Iterator<Item = &TextFile> {
         self.pending_assets.iter()
@@ -191,7 +245,15 @@ impl LspCore {
     /// Open a document and cache its content.
     ///
     /// This should be called when a `textDocument/didOpen` notification is received.
+    ///
+    /// Parse results are cached per file by the query runtime, so an edit only
+    /// reparses the edited file. If the document is already open with identical
+    /// content, the asset is not re-resolved and its cached results are kept.
     pub fn open_document(&mut self, uri: &str, content: String) {
+        if self.documents.get(uri) == Some(&content) {
+            return;
+        }
+
         // Update document cache
         self.documents.insert(uri.to_string(), content.clone());
 
@@ -246,6 +308,7 @@ impl LspCore {
         method: &str,
         params: Value,
     ) -> (Vec<LspOutput>, Vec<Effect>) {
+        let _span = HandlerSpan::request(method);
         let mut outputs = Vec::new();
         let mut effects = Vec::new();
 
@@ -264,6 +327,7 @@ impl LspCore {
 
                 // Register workspaces from initialization
                 register_workspaces_from_init(&mut self.runtime, &init_params);
+                self.set_client_capabilities(&init_params.capabilities);
 
                 let result = InitializeResult {
                     capabilities: server_capabilities(),
@@ -313,38 +377,227 @@ impl LspCore {
 
                 let query = LspSemanticTokens::new(file, source.clone());
                 let command = CommandQuery::SemanticTokensFull(query);
+                self.execute_command(id, command, "SemanticTokens", &mut outputs, &mut effects);
+            }
+            DocumentHighlightRequest::METHOD => {
+                let params: DocumentHighlightParams = match serde_json::from_value(params) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        outputs.push(LspOutput::Response {
+                            id,
+                            result: Err(LspError::invalid_params(format!("Invalid params: {}", e))),
+                        });
+                        return (outputs, effects);
+                    }
+                };
 
-                match self.try_execute(&command) {
-                    Ok(result) => {
-                        let json = self.result_to_value(result);
+                let position_params = params.text_document_position_params;
+                let uri = position_params.text_document.uri;
+                let uri_str = uri.as_str();
+                let file = match uri_to_text_file(uri_str) {
+                    Ok(f) => f,
+                    Err(e) => {
                         outputs.push(LspOutput::Response {
                             id,
-                            result: Ok(json),
+                            result: Err(LspError::invalid_params(format!("Invalid URI: {}", e))),
                         });
+                        return (outputs, effects);
                     }
-                    Err(QueryError::Suspend { .. }) => {
-                        // Query is pending - collect effects and store request
-                        let (new_effects, waiting_for) = self.collect_pending_assets();
-                        effects.extend(new_effects);
+                };
+                let source = self.documents.get(uri_str).cloned().unwrap_or_default();
 
-                        self.pending_requests.insert(
-                            id.clone(),
-                            PendingRequest {
-                                id,
-                                command,
-                                waiting_for,
-                            },
-                        );
+                let query = LspDocumentHighlight::new(file, source, position_params.position);
+                let command = CommandQuery::DocumentHighlight(query);
+                self.execute_command(id, command, "DocumentHighlight", &mut outputs, &mut effects);
+            }
+            SelectionRangeRequest::METHOD => {
+                let params: SelectionRangeParams = match serde_json::from_value(params) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        outputs.push(LspOutput::Response {
+                            id,
+                            result: Err(LspError::invalid_params(format!("Invalid params: {}", e))),
+                        });
+                        return (outputs, effects);
                     }
+                };
+
+                let uri_str = params.text_document.uri.as_str();
+                let file = match uri_to_text_file(uri_str) {
+                    Ok(f) => f,
                     Err(e) => {
-                        if let Some(lsp_err) = Self::handle_query_error("SemanticTokens", e) {
-                            outputs.push(LspOutput::Response {
-                                id,
-                                result: Err(lsp_err),
-                            });
-                        }
+                        outputs.push(LspOutput::Response {
+                            id,
+                            result: Err(LspError::invalid_params(format!("Invalid URI: {}", e))),
+                        });
+                        return (outputs, effects);
+                    }
+                };
+                let source = self.documents.get(uri_str).cloned().unwrap_or_default();
+
+                let query = LspSelectionRanges::new(file, source, params.positions);
+                let command = CommandQuery::SelectionRange(query);
+                self.execute_command(id, command, "SelectionRange", &mut outputs, &mut effects);
+            }
+            WillSaveWaitUntil::METHOD => {
+                let params: WillSaveTextDocumentParams = match serde_json::from_value(params) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        outputs.push(LspOutput::Response {
+                            id,
+                            result: Err(LspError::invalid_params(format!("Invalid params: {}", e))),
+                        });
+                        return (outputs, effects);
+                    }
+                };
+
+                let file = match uri_to_text_file(params.text_document.uri.as_str()) {
+                    Ok(f) => f,
+                    Err(e) => {
+                        outputs.push(LspOutput::Response {
+                            id,
+                            result: Err(LspError::invalid_params(format!("Invalid URI: {}", e))),
+                        });
+                        return (outputs, effects);
+                    }
+                };
+
+                let query = LspFormatOnSave::new(file);
+                let command = CommandQuery::FormatOnSave(query);
+                self.execute_command(id, command, "FormatOnSave", &mut outputs, &mut effects);
+            }
+            CodeActionRequest::METHOD => {
+                let params: CodeActionParams = match serde_json::from_value(params) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        outputs.push(LspOutput::Response {
+                            id,
+                            result: Err(LspError::invalid_params(format!("Invalid params: {}", e))),
+                        });
+                        return (outputs, effects);
+                    }
+                };
+
+                let uri_str = params.text_document.uri.as_str();
+                let file = match uri_to_text_file(uri_str) {
+                    Ok(f) => f,
+                    Err(e) => {
+                        outputs.push(LspOutput::Response {
+                            id,
+                            result: Err(LspError::invalid_params(format!("Invalid URI: {}", e))),
+                        });
+                        return (outputs, effects);
+                    }
+                };
+                let source = self.documents.get(uri_str).cloned().unwrap_or_default();
+
+                let query = LspCodeActions::new(file, source, params.range);
+                let command = CommandQuery::CodeAction(query);
+                self.execute_command(id, command, "CodeAction", &mut outputs, &mut effects);
+            }
+            WorkspaceSymbolRequest::METHOD => {
+                let params: WorkspaceSymbolParams = match serde_json::from_value(params) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        outputs.push(LspOutput::Response {
+                            id,
+                            result: Err(LspError::invalid_params(format!("Invalid params: {}", e))),
+                        });
+                        return (outputs, effects);
+                    }
+                };
+
+                let query = LspWorkspaceSymbols::new(params.query);
+                let command = CommandQuery::WorkspaceSymbol(query);
+                self.execute_command(id, command, "WorkspaceSymbol", &mut outputs, &mut effects);
+            }
+            PrepareRenameRequest::METHOD => {
+                let params: TextDocumentPositionParams = match serde_json::from_value(params) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        outputs.push(LspOutput::Response {
+                            id,
+                            result: Err(LspError::invalid_params(format!("Invalid params: {}", e))),
+                        });
+                        return (outputs, effects);
+                    }
+                };
+
+                let uri_str = params.text_document.uri.as_str();
+                let file = match uri_to_text_file(uri_str) {
+                    Ok(f) => f,
+                    Err(e) => {
+                        outputs.push(LspOutput::Response {
+                            id,
+                            result: Err(LspError::invalid_params(format!("Invalid URI: {}", e))),
+                        });
+                        return (outputs, effects);
+                    }
+                };
+                let Some(source) = self.documents.get(uri_str).cloned() else {
+                    outputs.push(LspOutput::Response {
+                        id,
+                        result: Err(LspError::invalid_params(format!(
+                            "Document is not open: {}",
+                            uri_str
+                        ))),
+                    });
+                    return (outputs, effects);
+                };
+
+                let query = LspPrepareRename::new(file, source, params.position);
+                let command = CommandQuery::PrepareRename(query);
+                self.execute_command(id, command, "PrepareRename", &mut outputs, &mut effects);
+            }
+            RenameRequest::METHOD => {
+                let params: RenameParams = match serde_json::from_value(params) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        outputs.push(LspOutput::Response {
+                            id,
+                            result: Err(LspError::invalid_params(format!("Invalid params: {}", e))),
+                        });
+                        return (outputs, effects);
                     }
+                };
+
+                if let Err(e) = params.new_name.parse::<Identifier>() {
+                    outputs.push(LspOutput::Response {
+                        id,
+                        result: Err(LspError::invalid_params(format!(
+                            "Invalid type name '{}': {}",
+                            params.new_name, e
+                        ))),
+                    });
+                    return (outputs, effects);
                 }
+
+                let position_params = params.text_document_position;
+                let uri_str = position_params.text_document.uri.as_str();
+                let file = match uri_to_text_file(uri_str) {
+                    Ok(f) => f,
+                    Err(e) => {
+                        outputs.push(LspOutput::Response {
+                            id,
+                            result: Err(LspError::invalid_params(format!("Invalid URI: {}", e))),
+                        });
+                        return (outputs, effects);
+                    }
+                };
+                let Some(source) = self.documents.get(uri_str).cloned() else {
+                    outputs.push(LspOutput::Response {
+                        id,
+                        result: Err(LspError::invalid_params(format!(
+                            "Document is not open: {}",
+                            uri_str
+                        ))),
+                    });
+                    return (outputs, effects);
+                };
+
+                let query = LspRename::new(file, source, position_params.position, params.new_name);
+                let command = CommandQuery::Rename(query);
+                self.execute_command(id, command, "Rename", &mut outputs, &mut effects);
             }
             _ => {
                 outputs.push(LspOutput::Response {
@@ -357,6 +610,54 @@ impl LspCore {
         (outputs, effects)
     }
 
+    /// Execute a command, deferring it as a pending request if its assets are not ready.
+    fn execute_command(
+        &mut self,
+        id: CoreRequestId,
+        command: CommandQuery,
+        context: &str,
+        outputs: &mut Vec<LspOutput>,
+        effects: &mut Vec<Effect>,
+    ) {
+        if let Some(file) = command.file() {
+            trace::record_uri(&text_file_to_uri(file));
+        }
+        match self.try_execute(&command) {
+            Ok(result) => {
+                let json = self.result_to_value(result);
+                outputs.push(LspOutput::Response {
+                    id,
+                    result: Ok(json),
+                });
+            }
+            Err(QueryError::Suspend { .. }) => {
+                // Query is pending - collect effects and store request
+                let (new_effects, waiting_for) = self.collect_pending_assets();
+                effects.extend(new_effects);
+
+                let mut trace = RequestTrace::current();
+                trace.suspended();
+                self.pending_requests.insert(
+                    id.clone(),
+                    PendingRequest {
+                        id,
+                        command,
+                        waiting_for,
+                        trace,
+                    },
+                );
+            }
+            Err(e) => {
+                if let Some(lsp_err) = Self::handle_query_error(context, e) {
+                    outputs.push(LspOutput::Response {
+                        id,
+                        result: Err(lsp_err),
+                    });
+                }
+            }
+        }
+    }
+
     /// Cancel a pending request.
     pub fn cancel_request(&mut self, id: &CoreRequestId) {
         self.pending_requests.remove(id);
@@ -372,6 +673,7 @@ impl LspCore {
         method: &str,
         params: Value,
     ) -> (Vec<LspOutput>, Vec<Effect>) {
+        let _span = HandlerSpan::notification(method);
         let mut outputs = Vec::new();
         let mut effects = Vec::new();
 
@@ -379,13 +681,14 @@ impl LspCore {
             DidOpenTextDocument::METHOD => {
                 if let Ok(params) = serde_json::from_value::<DidOpenTextDocumentParams>(params) {
                     let uri = params.text_document.uri;
+                    trace::record_uri(uri.as_str());
                     let content = params.text_document.text;
 
                     // Open document in core
                     self.open_document(uri.as_str(), content);
 
                     // Refresh diagnostics for all targets
-                    let (diag_outputs, diag_effects) = self.refresh_diagnostics();
+                    let (diag_outputs, diag_effects) = self.start_diagnostics_refresh();
                     outputs.extend(diag_outputs);
                     effects.extend(diag_effects);
                 }
@@ -393,6 +696,7 @@ impl LspCore {
             DidChangeTextDocument::METHOD => {
                 if let Ok(params) = serde_json::from_value::<DidChangeTextDocumentParams>(params) {
                     let uri = params.text_document.uri;
+                    trace::record_uri(uri.as_str());
                     // We use FULL sync, so there's only one change with the full content
                     if let Some(change) = params.content_changes.into_iter().next() {
                         let content = change.text;
@@ -400,10 +704,11 @@ impl LspCore {
                         // Change document in core
                         self.change_document(uri.as_str(), content);
 
-                        // Refresh diagnostics for all targets
-                        let (diag_outputs, diag_effects) = self.refresh_diagnostics();
-                        outputs.extend(diag_outputs);
-                        effects.extend(diag_effects);
+                        // Defer diagnostics until the burst of edits is over
+                        self.diagnostics_dirty = true;
+                        effects.push(Effect::ScheduleDiagnostics {
+                            delay_ms: DIAGNOSTICS_DEBOUNCE_MS,
+                        });
                     }
                 }
             }
@@ -411,21 +716,37 @@ impl LspCore {
                 if let Ok(params) = serde_json::from_value::<DidCloseTextDocumentParams>(params) {
                     let uri = params.text_document.uri;
                     let uri_str = uri.as_str();
+                    trace::record_uri(uri_str);
 
                     // Close document in core
                     self.close_document(uri_str);
 
                     // Also remove any pending requests for this document
-                    self.pending_requests
-                        .retain(|_, pending| match &pending.command {
-                            CommandQuery::SemanticTokensFull(q) => {
-                                let pending_uri = text_file_to_uri(&q.file);
-                                pending_uri != uri_str
-                            }
-                        });
+                    self.pending_requests.retain(|_, pending| {
+                        pending
+                            .command
+                            .file()
+                            .is_none_or(|file| text_file_to_uri(file) != uri_str)
+                    });
 
                     // Refresh diagnostics - stale files will be cleared automatically
-                    let (diag_outputs, diag_effects) = self.refresh_diagnostics();
+                    let (diag_outputs, diag_effects) = self.start_diagnostics_refresh();
+                    outputs.extend(diag_outputs);
+                    effects.extend(diag_effects);
+                }
+            }
+            DidSaveTextDocument::METHOD => {
+                if let Ok(params) = serde_json::from_value::<DidSaveTextDocumentParams>(params) {
+                    let uri = params.text_document.uri;
+                    trace::record_uri(uri.as_str());
+
+                    // Re-validate documents that depend on the saved file
+                    if let Ok(file) = uri_to_text_file(uri.as_str()) {
+                        self.pending_saves.insert(file);
+                    }
+                    effects.extend(self.collect_saved_dependents());
+
+                    let (diag_outputs, diag_effects) = self.start_diagnostics_refresh();
                     outputs.extend(diag_outputs);
                     effects.extend(diag_effects);
                 }
@@ -447,24 +768,61 @@ impl LspCore {
         (outputs, effects)
     }
 
+    /// Run work deferred while the client was busy.
+    ///
+    /// The platform calls this once the delay of the latest
+    /// [`Effect::ScheduleDiagnostics`] has elapsed. Diagnostics are refreshed if
+    /// a document changed since the last refresh; otherwise nothing happens.
+    pub fn tick(&mut self) -> (Vec<LspOutput>, Vec<Effect>) {
+        if !self.diagnostics_dirty {
+            return (Vec::new(), Vec::new());
+        }
+        self.start_diagnostics_refresh()
+    }
+
+    /// Refresh diagnostics in response to a new change, superseding any
+    /// refresh still waiting on assets.
+    ///
+    /// The progress of the superseded refresh is ended; the new refresh begins
+    /// its own if it has to wait.
+    fn start_diagnostics_refresh(&mut self) -> (Vec<LspOutput>, Vec<Effect>) {
+        let mut outputs = Vec::new();
+        self.end_diagnostics_progress("Superseded", &mut outputs);
+        let (refresh_outputs, effects) = self.refresh_diagnostics();
+        outputs.extend(refresh_outputs);
+        (outputs, effects)
+    }
+
     /// Refresh diagnostics for all diagnostic targets.
     ///
     /// Uses `CollectDiagnosticTargets` to discover all files needing diagnostics,
     /// then polls `LspFileDiagnostics` for each file with per-file revision tracking.
     ///
+    /// When a refresh of several targets has to wait for files to be fetched,
+    /// work-done progress is reported to the client across the refreshes that
+    /// follow each fetch, ending once every target has been diagnosed.
+    ///
     /// Returns notifications for all changed files and any effects needed.
     fn refresh_diagnostics(&mut self) -> (Vec<LspOutput>, Vec<Effect>) {
+        let _span = HandlerSpan::diagnostics();
         let mut outputs = Vec::new();
         let mut effects = Vec::new();
 
         debug!("[LspCore] refresh_diagnostics");
+        self.diagnostics_dirty = false;
 
-        // 1. Collect all files to diagnose (includes open docs + schema files)
-        let all_files = match self.runtime.poll(CollectDiagnosticTargets::new()) {
+        // 1. Collect all files to diagnose (includes open docs + schema files),
+        //    plus on-disk dependents of saved files
+        let (targets, all_files) = match self.runtime.poll(CollectDiagnosticTargets::new()) {
             Ok(polled) => match polled.value {
-                Ok(files) => files,
+                Ok(targets) => {
+                    let mut files = targets.as_ref().clone();
+                    files.extend(self.saved_dependents.iter().cloned());
+                    (targets, files)
+                }
                 Err(e) => {
                     error!("CollectDiagnosticTargets error: {}", e);
+                    self.end_diagnostics_progress("Failed", &mut outputs);
                     return (outputs, effects);
                 }
             },
@@ -476,6 +834,7 @@ impl LspCore {
             }
             Err(e) => {
                 Self::handle_query_error("CollectDiagnosticTargets", e);
+                self.end_diagnostics_progress("Failed", &mut outputs);
                 return (outputs, effects);
             }
         };
@@ -484,6 +843,7 @@ impl LspCore {
 
         // 2. Poll LspFileDiagnostics for each file
         let mut current_uris = HashSet::new();
+        let mut completed = 0;
         for file in all_files.iter() {
             let query = LspFileDiagnostics::new(file.clone());
 
@@ -496,8 +856,14 @@ impl LspCore {
 
             match self.runtime.poll(query.clone()) {
                 Ok(polled) => {
+                    completed += 1;
                     let uri = text_file_to_uri(file);
-                    current_uris.insert(uri.clone());
+                    // A saved dependent is diagnosed once; its diagnostics are
+                    // not cleared as stale when it leaves the set.
+                    let saved_only = self.saved_dependents.remove(file) && !targets.contains(file);
+                    if !saved_only {
+                        current_uris.insert(uri.clone());
+                    }
 
                     // Only publish if revision changed
                     if polled.revision != last_revision {
@@ -562,6 +928,8 @@ impl LspCore {
                     effects.extend(new_effects);
                 }
                 Err(e) => {
+                    completed += 1;
+                    self.saved_dependents.remove(file);
                     Self::handle_query_error(&format!("LspFileDiagnostics({:?})", file), e);
                 }
             }
@@ -593,9 +961,95 @@ impl LspCore {
         self.diagnostics_subscriptions
             .retain(|f, _| all_files.contains(f));
 
+        // 5. Report progress, wrapping this refresh's publications if it begins here
+        if self.work_done_progress {
+            let begun = self.update_diagnostics_progress(completed, all_files.len(), &mut outputs);
+            outputs.rotate_right(begun);
+        }
+
         (outputs, effects)
     }
 
+    /// Append work-done progress for a refresh that completed `completed` of
+    /// `total` targets.
+    ///
+    /// Returns how many outputs were appended to begin a new progress, which
+    /// the caller moves in front of the refresh's own outputs.
+    fn update_diagnostics_progress(
+        &mut self,
+        completed: usize,
+        total: usize,
+        outputs: &mut Vec<LspOutput>,
+    ) -> usize {
+        let percentage = (completed * 100).checked_div(total).unwrap_or(100) as u32;
+        let message = Some(format!("{completed}/{total} files"));
+        let mut begun = 0;
+
+        if self.diagnostics_progress.is_none() {
+            if completed >= total || total < 2 {
+                return 0;
+            }
+            let index = self.next_progress_token;
+            self.next_progress_token += 1;
+            let token = NumberOrString::String(format!("eure-ls/diagnostics/{index}"));
+
+            outputs.push(LspOutput::Request {
+                id: CoreRequestId::Str(format!("eure-ls/progress/{index}")),
+                method: WorkDoneProgressCreate::METHOD.to_string(),
+                params: serde_json::to_value(WorkDoneProgressCreateParams {
+                    token: token.clone(),
+                })
+                .unwrap(),
+            });
+            outputs.push(progress_notification(
+                &token,
+                WorkDoneProgress::Begin(WorkDoneProgressBegin {
+                    title: "Validating Eure files".to_string(),
+                    cancellable: Some(false),
+                    message: message.clone(),
+                    percentage: Some(percentage),
+                }),
+            ));
+            begun = 2;
+            self.diagnostics_progress = Some(DiagnosticsProgress { token, completed });
+        }
+        let Some(progress) = &mut self.diagnostics_progress else {
+            return begun;
+        };
+
+        if completed >= total {
+            outputs.push(progress_notification(
+                &progress.token,
+                WorkDoneProgress::End(WorkDoneProgressEnd { message }),
+            ));
+            self.diagnostics_progress = None;
+        } else if completed != progress.completed {
+            progress.completed = completed;
+            outputs.push(progress_notification(
+                &progress.token,
+                WorkDoneProgress::Report(WorkDoneProgressReport {
+                    cancellable: Some(false),
+                    message,
+                    percentage: Some(percentage),
+                }),
+            ));
+        }
+
+        begun
+    }
+
+    /// End the open work-done progress, if any, with `message`.
+    fn end_diagnostics_progress(&mut self, message: &str, outputs: &mut Vec<LspOutput>) {
+        if let Some(progress) = self.diagnostics_progress.take() {
+            outputs.push(progress_notification(
+                &progress.token,
+                WorkDoneProgress::End(WorkDoneProgressEnd {
+                    message: Some(message.to_string()),
+                }),
+            ));
+        }
+    }
+
     // === Asset Resolution ===
 
     /// Resolve a file asset with its content.
@@ -606,6 +1060,8 @@ impl LspCore {
         file: TextFile,
         content: Result<String, String>,
     ) -> (Vec<LspOutput>, Vec<Effect>) {
+        let mut outputs = Vec::new();
+
         // Resolve in runtime
         match content {
             Ok(text) => {
@@ -616,6 +1072,8 @@ impl LspCore {
                 );
             }
             Err(error) => {
+                // The refresh waiting on this file cannot complete as reported
+                self.end_diagnostics_progress(&format!("Failed to fetch {file}"), &mut outputs);
                 self.runtime.resolve_asset_error::<TextFile>(
                     file.clone(),
                     anyhow::anyhow!("{}", error),
@@ -626,7 +1084,9 @@ impl LspCore {
         self.pending_assets.remove(&file);
 
         // Process pending requests and diagnostics
-        self.process_after_asset_change()
+        let (after_outputs, effects) = self.process_after_asset_change();
+        outputs.extend(after_outputs);
+        (outputs, effects)
     }
 
     /// Resolve a glob pattern with matching files.
@@ -656,6 +1116,9 @@ impl LspCore {
         outputs.extend(req_outputs);
         effects.extend(req_effects);
 
+        // Retry dependents of saved files
+        effects.extend(self.collect_saved_dependents());
+
         // Check diagnostics subscriptions
         let (diag_outputs, diag_effects) = self.check_diagnostics_subscriptions();
         outputs.extend(diag_outputs);
@@ -675,20 +1138,23 @@ impl LspCore {
         for id in request_ids {
             if let Some(pending) = self.pending_requests.get(&id) {
                 let command = pending.command.clone();
+                let mut trace = pending.trace.clone();
 
-                match self.try_execute(&command) {
+                match trace.in_scope(|| self.try_execute(&command)) {
                     Ok(result) => {
                         let json = self.result_to_value(result);
                         outputs.push(LspOutput::Response {
                             id: id.clone(),
                             result: Ok(json),
                         });
-                        completed_ids.push(id);
+                        trace.finish();
+                        completed_ids.push(id.clone());
                     }
                     Err(QueryError::Suspend { .. }) => {
                         // Still waiting - collect more effects
                         let (new_effects, _) = self.collect_pending_assets();
                         effects.extend(new_effects);
+                        trace.suspended();
                     }
                     Err(e) => {
                         if let Some(lsp_err) = Self::handle_query_error("RetryQuery", e) {
@@ -696,10 +1162,15 @@ impl LspCore {
                                 id: id.clone(),
                                 result: Err(lsp_err),
                             });
-                            completed_ids.push(id);
+                            trace.finish();
+                            completed_ids.push(id.clone());
                         }
                     }
                 }
+
+                if let Some(pending) = self.pending_requests.get_mut(&id) {
+                    pending.trace = trace;
+                }
             }
         }
 
@@ -710,6 +1181,46 @@ impl LspCore {
         (outputs, effects)
     }
 
+    /// Collect the dependents of saved files into `saved_dependents`.
+    ///
+    /// Saves whose dependents are still waiting on assets stay pending and are
+    /// retried after the next asset change.
+    fn collect_saved_dependents(&mut self) -> Vec<Effect> {
+        let mut effects = Vec::new();
+
+        for file in self.pending_saves.clone() {
+            match self
+                .runtime
+                .poll(CollectSchemaDependents::new(file.clone()))
+            {
+                Ok(polled) => {
+                    match polled.value {
+                        Ok(dependents) => {
+                            debug!(
+                                "[LspCore] {} dependents of saved {:?}",
+                                dependents.len(),
+                                file
+                            );
+                            self.saved_dependents.extend(dependents.iter().cloned());
+                        }
+                        Err(e) => error!("CollectSchemaDependents error: {}", e),
+                    }
+                    self.pending_saves.remove(&file);
+                }
+                Err(QueryError::Suspend { .. }) => {
+                    let (new_effects, _) = self.collect_pending_assets();
+                    effects.extend(new_effects);
+                }
+                Err(e) => {
+                    Self::handle_query_error(&format!("CollectSchemaDependents({:?})", file), e);
+                    self.pending_saves.remove(&file);
+                }
+            }
+        }
+
+        effects
+    }
+
     /// Check diagnostics subscriptions and send updates.
     ///
     /// This simply calls `refresh_diagnostics` to re-poll all targets.
@@ -753,6 +1264,34 @@ impl LspCore {
                 let result = self.runtime.query(query.clone())?;
                 Ok(CommandResult::SemanticTokens(Some((*result).clone())))
             }
+            CommandQuery::DocumentHighlight(query) => {
+                let result = self.runtime.query(query.clone())?;
+                Ok(CommandResult::DocumentHighlight((*result).clone()))
+            }
+            CommandQuery::CodeAction(query) => {
+                let result = self.runtime.query(query.clone())?;
+                Ok(CommandResult::CodeAction((*result).clone()))
+            }
+            CommandQuery::WorkspaceSymbol(query) => {
+                let result = self.runtime.query(query.clone())?;
+                Ok(CommandResult::WorkspaceSymbol((*result).clone()))
+            }
+            CommandQuery::PrepareRename(query) => {
+                let result = self.runtime.query(query.clone())?;
+                Ok(CommandResult::PrepareRename((*result).clone()))
+            }
+            CommandQuery::Rename(query) => {
+                let result = self.runtime.query(query.clone())?;
+                Ok(CommandResult::Rename((*result).clone()))
+            }
+            CommandQuery::SelectionRange(query) => {
+                let result = self.runtime.query(query.clone())?;
+                Ok(CommandResult::SelectionRange((*result).clone()))
+            }
+            CommandQuery::FormatOnSave(query) => {
+                let result = self.runtime.query(query.clone())?;
+                Ok(CommandResult::FormatOnSave((*result).clone()))
+            }
         }
     }
 
@@ -762,6 +1301,25 @@ impl LspCore {
             CommandResult::SemanticTokens(tokens) => {
                 serde_json::to_value(tokens).unwrap_or(Value::Null)
             }
+            CommandResult::DocumentHighlight(highlights) => {
+                serde_json::to_value(highlights).unwrap_or(Value::Null)
+            }
+            CommandResult::CodeAction(actions) => {
+                serde_json::to_value(actions).unwrap_or(Value::Null)
+            }
+            CommandResult::WorkspaceSymbol(symbols) => {
+                serde_json::to_value(symbols).unwrap_or(Value::Null)
+            }
+            CommandResult::PrepareRename(response) => {
+                serde_json::to_value(response).unwrap_or(Value::Null)
+            }
+            CommandResult::Rename(edit) => serde_json::to_value(edit).unwrap_or(Value::Null),
+            CommandResult::SelectionRange(ranges) => {
+                serde_json::to_value(ranges).unwrap_or(Value::Null)
+            }
+            CommandResult::FormatOnSave(edits) => {
+                serde_json::to_value(edits).unwrap_or(Value::Null)
+            }
         }
     }
 
@@ -798,8 +1356,404 @@ impl LspCore {
     }
 }
 
+/// Build a `$/progress` notification for `token`.
+fn progress_notification(token: &NumberOrString, value: WorkDoneProgress) -> LspOutput {
+    let params = ProgressParams {
+        token: token.clone(),
+        value: ProgressParamsValue::WorkDone(value),
+    };
+    LspOutput::Notification {
+        method: Progress::METHOD.to_string(),
+        params: serde_json::to_value(params).unwrap(),
+    }
+}
+
 impl Default for LspCore {
     fn default() -> Self {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Perform effects until none are left, answering file fetches from `files`
+    /// and every glob with `glob_matches`. Returns every output in order.
+    fn drain_effects(
+        core: &mut LspCore,
+        (mut outputs, mut effects): (Vec<LspOutput>, Vec<Effect>),
+        files: &HashMap<TextFile, String>,
+        glob_matches: &[TextFile],
+    ) -> Vec<LspOutput> {
+        while let Some(effect) = effects.pop() {
+            let (new_outputs, new_effects) = match effect {
+                Effect::FetchFile(file) => {
+                    let content = files
+                        .get(&file)
+                        .cloned()
+                        .ok_or_else(|| "not found".to_string());
+                    core.resolve_file(file, content)
+                }
+                Effect::ExpandGlob { id, .. } => core.resolve_glob(&id, glob_matches.to_vec()),
+                Effect::ScheduleDiagnostics { .. } => core.tick(),
+            };
+            outputs.extend(new_outputs);
+            effects.extend(new_effects);
+        }
+        outputs
+    }
+
+    /// Like [`drain_effects`], but returns the diagnostics published per URI,
+    /// last publication winning.
+    fn run_effects(
+        core: &mut LspCore,
+        outputs: (Vec<LspOutput>, Vec<Effect>),
+        files: &HashMap<TextFile, String>,
+        glob_matches: &[TextFile],
+    ) -> HashMap<String, Value> {
+        let mut published = HashMap::new();
+        for output in drain_effects(core, outputs, files, glob_matches) {
+            if let LspOutput::Notification { method, params } = output
+                && method == PublishDiagnostics::METHOD
+            {
+                published.insert(
+                    params["uri"].as_str().unwrap().to_string(),
+                    params["diagnostics"].clone(),
+                );
+            }
+        }
+        published
+    }
+
+    #[test]
+    fn test_saving_schema_republishes_dependent_diagnostics() {
+        let mut core = LspCore::new();
+        core.runtime_mut().resolve_asset(
+            WorkspaceId("/ws".to_string()),
+            Workspace {
+                path: PathBuf::from("/ws"),
+                config_path: PathBuf::from("/ws/Eure.eure"),
+            },
+            DurabilityLevel::Static,
+        );
+        let doc = TextFile::from_path(PathBuf::from("/ws/docs/a.eure"));
+        let files = HashMap::from([
+            (
+                TextFile::from_path(PathBuf::from("/ws/Eure.eure")),
+                "@ targets.docs\nglobs = [\"docs/*.eure\"]\nschema = \"schema.eure\"\n".to_string(),
+            ),
+            (doc.clone(), "name = 1\n".to_string()),
+        ]);
+        let schema_uri = "file:///ws/schema.eure";
+        let doc_uri = "file:///ws/docs/a.eure";
+
+        let opened = core.handle_notification(
+            DidOpenTextDocument::METHOD,
+            json!({
+                "textDocument": {
+                    "uri": schema_uri,
+                    "languageId": "eure",
+                    "version": 1,
+                    "text": "name = `text`\n",
+                }
+            }),
+        );
+        let published = run_effects(&mut core, opened, &files, std::slice::from_ref(&doc));
+        assert!(!published.contains_key(doc_uri));
+
+        // Saving the schema validates the on-disk document that uses it
+        let saved = core.handle_notification(
+            DidSaveTextDocument::METHOD,
+            json!({ "textDocument": { "uri": schema_uri } }),
+        );
+        let published = run_effects(&mut core, saved, &files, std::slice::from_ref(&doc));
+        let diagnostics = published[doc_uri].as_array().unwrap();
+        assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+
+        // Saved dependents are diagnosed once: editing the schema keeps their
+        // diagnostics, and saving it again re-publishes them
+        let changed = core.handle_notification(
+            DidChangeTextDocument::METHOD,
+            json!({
+                "textDocument": { "uri": schema_uri, "version": 2 },
+                "contentChanges": [{ "text": "name = `integer`\n" }],
+            }),
+        );
+        let published = run_effects(&mut core, changed, &files, std::slice::from_ref(&doc));
+        assert!(!published.contains_key(doc_uri));
+        assert!(core.saved_dependents.is_empty());
+
+        let saved = core.handle_notification(
+            DidSaveTextDocument::METHOD,
+            json!({ "textDocument": { "uri": schema_uri } }),
+        );
+        let published = run_effects(&mut core, saved, &files, std::slice::from_ref(&doc));
+        assert_eq!(published[doc_uri], json!([]));
+    }
+
+    #[test]
+    fn test_rapid_changes_refresh_diagnostics_once() {
+        let mut core = LspCore::new();
+        let files = HashMap::new();
+        let uri = "file:///ws/a.eure";
+
+        let opened = core.handle_notification(
+            DidOpenTextDocument::METHOD,
+            json!({
+                "textDocument": {
+                    "uri": uri,
+                    "languageId": "eure",
+                    "version": 1,
+                    "text": "a = 1\n",
+                }
+            }),
+        );
+        drain_effects(&mut core, opened, &files, &[]);
+
+        for (version, text) in [(2, "a = 12\n"), (3, "a = 123\n"), (4, "a = \n")] {
+            let (outputs, effects) = core.handle_notification(
+                DidChangeTextDocument::METHOD,
+                json!({
+                    "textDocument": { "uri": uri, "version": version },
+                    "contentChanges": [{ "text": text }],
+                }),
+            );
+            assert!(outputs.is_empty(), "{outputs:?}");
+            assert!(
+                matches!(effects[..], [Effect::ScheduleDiagnostics { .. }]),
+                "{effects:?}"
+            );
+        }
+
+        let ticked = core.tick();
+        let outputs = drain_effects(&mut core, ticked, &files, &[]);
+        let published: Vec<&Value> = outputs
+            .iter()
+            .filter_map(|output| match output {
+                LspOutput::Notification { method, params }
+                    if method == PublishDiagnostics::METHOD && params["uri"] == uri =>
+                {
+                    Some(&params["diagnostics"])
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(published.len(), 1, "{outputs:?}");
+        assert!(!published[0].as_array().unwrap().is_empty());
+
+        // Nothing changed since, so a late tick does nothing
+        let (outputs, effects) = core.tick();
+        assert!(outputs.is_empty() && effects.is_empty());
+    }
+
+    /// Open `schema.eure` in a workspace whose two on-disk documents are
+    /// validated against it. Returns the files on disk and the documents.
+    fn open_schema_with_two_dependents(
+        core: &mut LspCore,
+    ) -> (HashMap<TextFile, String>, [TextFile; 2]) {
+        core.runtime_mut().resolve_asset(
+            WorkspaceId("/ws".to_string()),
+            Workspace {
+                path: PathBuf::from("/ws"),
+                config_path: PathBuf::from("/ws/Eure.eure"),
+            },
+            DurabilityLevel::Static,
+        );
+        let docs = [
+            TextFile::from_path(PathBuf::from("/ws/docs/a.eure")),
+            TextFile::from_path(PathBuf::from("/ws/docs/b.eure")),
+        ];
+        let files = HashMap::from([
+            (
+                TextFile::from_path(PathBuf::from("/ws/Eure.eure")),
+                "@ targets.docs\nglobs = [\"docs/*.eure\"]\nschema = \"schema.eure\"\n".to_string(),
+            ),
+            (docs[0].clone(), "name = 1\n".to_string()),
+            (docs[1].clone(), "name = 2\n".to_string()),
+        ]);
+        let schema_uri = "file:///ws/schema.eure";
+
+        let opened = core.handle_notification(
+            DidOpenTextDocument::METHOD,
+            json!({
+                "textDocument": {
+                    "uri": schema_uri,
+                    "languageId": "eure",
+                    "version": 1,
+                    "text": "name = `text`\n",
+                }
+            }),
+        );
+        drain_effects(core, opened, &files, &docs);
+        (files, docs)
+    }
+
+    fn save_schema(core: &mut LspCore) -> (Vec<LspOutput>, Vec<Effect>) {
+        core.handle_notification(
+            DidSaveTextDocument::METHOD,
+            json!({ "textDocument": { "uri": "file:///ws/schema.eure" } }),
+        )
+    }
+
+    /// Open `schema.eure` and save it, so that the two on-disk documents
+    /// validated against it are fetched and diagnosed.
+    fn save_schema_with_two_dependents(core: &mut LspCore) -> Vec<LspOutput> {
+        let (files, docs) = open_schema_with_two_dependents(core);
+        let saved = save_schema(core);
+        drain_effects(core, saved, &files, &docs)
+    }
+
+    /// The `kind` of each `$/progress` notification, with the index of its output.
+    fn progress_kinds(outputs: &[LspOutput]) -> Vec<(usize, String)> {
+        outputs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, output)| match output {
+                LspOutput::Notification { method, params } if method == Progress::METHOD => {
+                    Some((index, params["value"]["kind"].as_str().unwrap().to_string()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_progress_brackets_multi_file_refresh() {
+        let mut core = LspCore::new();
+        core.set_client_capabilities(
+            &serde_json::from_value(json!({ "window": { "workDoneProgress": true } })).unwrap(),
+        );
+        let outputs = save_schema_with_two_dependents(&mut core);
+
+        let progress = progress_kinds(&outputs);
+        let (begin, _) = progress.first().unwrap();
+        let (end, _) = progress.last().unwrap();
+        let kinds: Vec<&str> = progress.iter().map(|(_, kind)| kind.as_str()).collect();
+        assert_eq!(kinds.first(), Some(&"begin"), "{kinds:?}");
+        assert_eq!(kinds.last(), Some(&"end"), "{kinds:?}");
+        assert!(
+            kinds[1..kinds.len() - 1]
+                .iter()
+                .all(|kind| *kind == "report")
+        );
+
+        // The token is created before it is used
+        let create = outputs
+            .iter()
+            .position(|output| match output {
+                LspOutput::Request { method, .. } => method == WorkDoneProgressCreate::METHOD,
+                _ => false,
+            })
+            .unwrap();
+        assert!(create < *begin);
+
+        // Both dependents are published while the progress is open
+        let published: Vec<usize> = outputs
+            .iter()
+            .enumerate()
+            .filter_map(|(index, output)| match output {
+                LspOutput::Notification { method, params }
+                    if method == PublishDiagnostics::METHOD
+                        && params["uri"]
+                            .as_str()
+                            .unwrap()
+                            .starts_with("file:///ws/docs/") =>
+                {
+                    Some(index)
+                }
+                _ => None,
+            })
+            .collect();
+        assert!(published.len() >= 2, "{outputs:?}");
+        assert!(published.iter().all(|index| begin < index && index < end));
+    }
+
+    #[test]
+    fn test_no_progress_without_client_capability() {
+        let mut core = LspCore::new();
+        let outputs = save_schema_with_two_dependents(&mut core);
+        assert!(progress_kinds(&outputs).is_empty());
+        assert!(
+            !outputs
+                .iter()
+                .any(|output| matches!(output, LspOutput::Request { .. }))
+        );
+    }
+
+    /// Like [`drain_effects`], but stops once a progress has begun, returning
+    /// the outputs so far and the effects left.
+    fn run_until_progress_begins(
+        core: &mut LspCore,
+        (mut outputs, mut effects): (Vec<LspOutput>, Vec<Effect>),
+        files: &HashMap<TextFile, String>,
+        glob_matches: &[TextFile],
+    ) -> (Vec<LspOutput>, Vec<Effect>) {
+        while !progress_kinds(&outputs)
+            .iter()
+            .any(|(_, kind)| kind == "begin")
+        {
+            let Some(effect) = effects.pop() else {
+                panic!("no progress began: {outputs:?}");
+            };
+            let (new_outputs, new_effects) = match effect {
+                Effect::FetchFile(file) => {
+                    let content = files[&file].clone();
+                    core.resolve_file(file, Ok(content))
+                }
+                Effect::ExpandGlob { id, .. } => core.resolve_glob(&id, glob_matches.to_vec()),
+                Effect::ScheduleDiagnostics { .. } => core.tick(),
+            };
+            outputs.extend(new_outputs);
+            effects.extend(new_effects);
+        }
+        (outputs, effects)
+    }
+
+    #[test]
+    fn test_superseded_refresh_ends_its_progress() {
+        let mut core = LspCore::new();
+        core.set_client_capabilities(
+            &serde_json::from_value(json!({ "window": { "workDoneProgress": true } })).unwrap(),
+        );
+        let (files, docs) = open_schema_with_two_dependents(&mut core);
+
+        // Resolve only part of the refresh, leaving the progress open
+        let saved = save_schema(&mut core);
+        let (outputs, effects) = run_until_progress_begins(&mut core, saved, &files, &docs);
+
+        // Saving again supersedes the waiting refresh
+        let resaved = save_schema(&mut core);
+        let kinds = progress_kinds(&resaved.0);
+        assert_eq!(kinds.first().map(|(_, kind)| kind.as_str()), Some("end"));
+
+        let rest = drain_effects(&mut core, (resaved.0, effects), &files, &docs);
+        let kinds: Vec<String> = progress_kinds(&outputs)
+            .into_iter()
+            .chain(progress_kinds(&rest))
+            .map(|(_, kind)| kind)
+            .collect();
+        let begins = kinds.iter().filter(|kind| *kind == "begin").count();
+        let ends = kinds.iter().filter(|kind| *kind == "end").count();
+        assert_eq!(begins, ends, "{kinds:?}");
+        assert!(core.diagnostics_progress.is_none());
+    }
+
+    #[test]
+    fn test_failed_fetch_ends_progress() {
+        let mut core = LspCore::new();
+        core.set_client_capabilities(
+            &serde_json::from_value(json!({ "window": { "workDoneProgress": true } })).unwrap(),
+        );
+        let (files, docs) = open_schema_with_two_dependents(&mut core);
+        let saved = save_schema(&mut core);
+        run_until_progress_begins(&mut core, saved, &files, &docs);
+
+        // The refresh waiting on the remaining dependent cannot finish as planned
+        let pending = core.pending_files().next().unwrap().clone();
+        let (failed, _) = core.resolve_file(pending, Err("connection reset".to_string()));
+        let kinds = progress_kinds(&failed);
+        assert_eq!(kinds.first().map(|(_, kind)| kind.as_str()), Some("end"));
+    }
+}
@@ -2,6 +2,8 @@
 
 pub mod io_pool;
 
+use std::time::{Duration, Instant};
+
 use crate::io_pool::IoPool;
 use anyhow::Result;
 use crossbeam_channel::select;
@@ -39,10 +41,15 @@ fn main() -> Result<()> {
 
     // Register workspaces from initialization
     eure_ls::register_workspaces_from_init(core.runtime_mut(), &init_params);
+    core.set_client_capabilities(&init_params.capabilities);
     core.set_initialized();
 
+    // When the core next wants `tick` called, if ever
+    let mut tick_deadline = None;
+
     // Main event loop
     loop {
+        let tick_timer = tick_deadline.map_or_else(crossbeam_channel::never, crossbeam_channel::at);
         select! {
             recv(connection.receiver) -> msg => {
                 match msg {
@@ -51,13 +58,17 @@ fn main() -> Result<()> {
                             info!("Shutdown requested");
                             break;
                         }
-                        handle_request(req, &connection, &mut core, &io_pool);
+                        handle_request(
+                            req, &connection, &mut core, &io_pool, &mut tick_deadline,
+                        );
                     }
                     Ok(Message::Notification(not)) => {
-                        handle_notification(not, &connection, &mut core, &io_pool);
+                        handle_notification(
+                            not, &connection, &mut core, &io_pool, &mut tick_deadline,
+                        );
                     }
                     Ok(Message::Response(_)) => {
-                        // We don't send requests, so we shouldn't receive responses
+                        // Responses to our requests (progress token creation) are not needed
                     }
                     Err(e) => {
                         error!("Error receiving message: {}", e);
@@ -73,9 +84,15 @@ fn main() -> Result<()> {
                     };
                     let (outputs, effects) = core.resolve_file(response.file, result);
                     send_outputs(&connection, outputs);
-                    process_effects(&io_pool, &mut core, effects);
+                    process_effects(&connection, &io_pool, &mut core, effects, &mut tick_deadline);
                 }
             }
+            recv(tick_timer) -> _ => {
+                tick_deadline = None;
+                let (outputs, effects) = core.tick();
+                send_outputs(&connection, outputs);
+                process_effects(&connection, &io_pool, &mut core, effects, &mut tick_deadline);
+            }
         }
     }
 
@@ -85,7 +102,13 @@ fn main() -> Result<()> {
 }
 
 /// Handle an incoming LSP request.
-fn handle_request(req: Request, connection: &Connection, core: &mut LspCore, io_pool: &IoPool) {
+fn handle_request(
+    req: Request,
+    connection: &Connection,
+    core: &mut LspCore,
+    io_pool: &IoPool,
+    tick_deadline: &mut Option<Instant>,
+) {
     let id = CoreRequestId::from(req.id.clone());
     let (outputs, effects) = core.handle_request(id, &req.method, req.params);
 
@@ -93,7 +116,7 @@ fn handle_request(req: Request, connection: &Connection, core: &mut LspCore, io_
     send_outputs(connection, outputs);
 
     // Process effects (file fetches, glob expansions)
-    process_effects(io_pool, core, effects);
+    process_effects(connection, io_pool, core, effects, tick_deadline);
 }
 
 /// Handle an incoming LSP notification.
@@ -102,6 +125,7 @@ fn handle_notification(
     connection: &Connection,
     core: &mut LspCore,
     io_pool: &IoPool,
+    tick_deadline: &mut Option<Instant>,
 ) {
     let (outputs, effects) = core.handle_notification(&not.method, not.params);
 
@@ -109,7 +133,7 @@ fn handle_notification(
     send_outputs(connection, outputs);
 
     // Process effects
-    process_effects(io_pool, core, effects);
+    process_effects(connection, io_pool, core, effects, tick_deadline);
 }
 
 /// Send LspOutputs to the client.
@@ -126,6 +150,9 @@ fn send_outputs(connection: &Connection, outputs: Vec<LspOutput>) {
             LspOutput::Notification { method, params } => {
                 Message::Notification(Notification::new(method, params))
             }
+            LspOutput::Request { id, method, params } => {
+                Message::Request(Request::new(lsp_request_id(&id), method, params))
+            }
         };
 
         if let Err(e) = connection.sender.send(msg) {
@@ -135,7 +162,13 @@ fn send_outputs(connection: &Connection, outputs: Vec<LspOutput>) {
 }
 
 /// Process effects by dispatching them to the appropriate handler.
-fn process_effects(io_pool: &IoPool, core: &mut LspCore, effects: Vec<Effect>) {
+fn process_effects(
+    connection: &Connection,
+    io_pool: &IoPool,
+    core: &mut LspCore,
+    effects: Vec<Effect>,
+    tick_deadline: &mut Option<Instant>,
+) {
     for effect in effects {
         match effect {
             Effect::FetchFile(file) => {
@@ -144,17 +177,18 @@ fn process_effects(io_pool: &IoPool, core: &mut LspCore, effects: Vec<Effect>) {
             Effect::ExpandGlob { id, glob } => {
                 // Expand glob synchronously on native
                 let files = expand_glob(&glob);
-                // Resolve immediately - this may trigger more effects
+                // Resolve immediately - this may answer requests and publish
+                // diagnostics, and trigger more effects
                 let (outputs, new_effects) = core.resolve_glob(&id, files);
-                // We can't send outputs here since we don't have connection,
-                // but glob expansion shouldn't produce immediate outputs anyway.
-                // The effects will be processed when this function returns.
-                drop(outputs);
+                send_outputs(connection, outputs);
                 // Recursively process new effects
                 if !new_effects.is_empty() {
-                    process_effects(io_pool, core, new_effects);
+                    process_effects(connection, io_pool, core, new_effects, tick_deadline);
                 }
             }
+            Effect::ScheduleDiagnostics { delay_ms } => {
+                *tick_deadline = Some(Instant::now() + Duration::from_millis(delay_ms));
+            }
         }
     }
 }
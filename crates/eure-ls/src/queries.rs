@@ -1,12 +1,22 @@
 //! LSP-specific queries that convert to LSP types.
 
+use std::collections::HashMap;
+
 use eure::query::{
-    DiagnosticMessage, DiagnosticSeverity, GetFileDiagnostics, GetSemanticTokens, SemanticToken,
-    TextFile,
+    CollectSchemaDependents, DiagnosticMessage, DiagnosticSeverity, DiagnosticTag,
+    GetFileDiagnostics, GetKeyPathHighlights, GetMissingFieldFixes, GetNamedSymbols,
+    GetSelectionSpans, GetSemanticTokens, GetTypeOccurrences, NamedSymbol, NamedSymbolKind,
+    OpenDocuments, OpenDocumentsList, ResolveConfig, SemanticToken, TextFile, TextFileContent,
+    TypeOccurrence, TypeOccurrenceKind, ValidCst,
 };
+use eure::tree::LineNumbers;
+use eure_fmt::FormatConfig;
 use lsp_types::{
-    Diagnostic, DiagnosticSeverity as LspSeverity, Position, Range,
-    SemanticToken as LspSemanticToken, SemanticTokens,
+    CodeAction, CodeActionKind, CodeActionOrCommand, Diagnostic, DiagnosticSeverity as LspSeverity,
+    DiagnosticTag as LspDiagnosticTag, DocumentHighlight, DocumentHighlightKind, Location,
+    NumberOrString, Position, PrepareRenameResponse, Range, SelectionRange,
+    SemanticToken as LspSemanticToken, SemanticTokens, SymbolInformation, SymbolKind, TextEdit,
+    Uri, WorkspaceEdit,
 };
 use query_flow::{Db, QueryError, query};
 
@@ -23,6 +33,256 @@ pub fn lsp_semantic_tokens(
     Ok(convert_tokens(&tokens, &source))
 }
 
+/// LSP-formatted document highlight query.
+///
+/// Wraps `GetKeyPathHighlights` and converts the cursor position and the
+/// resulting spans between LSP positions and byte offsets.
+#[query]
+pub fn lsp_document_highlight(
+    db: &impl Db,
+    file: TextFile,
+    source: String,
+    position: Position,
+) -> Result<Vec<DocumentHighlight>, QueryError> {
+    let line_numbers = LineNumbers::new(&source);
+    let offset = position_to_offset(position, &source, &line_numbers);
+    let spans = db.query(GetKeyPathHighlights::new(file.clone(), offset as u32))?;
+    Ok(spans
+        .iter()
+        .map(|span| DocumentHighlight {
+            range: Range {
+                start: offset_to_lsp_position(span.start as usize, &source, &line_numbers),
+                end: offset_to_lsp_position(span.end as usize, &source, &line_numbers),
+            },
+            kind: Some(DocumentHighlightKind::TEXT),
+        })
+        .collect())
+}
+
+/// LSP-formatted selection range query.
+///
+/// Wraps `GetSelectionSpans` for each position, nesting the spans from the
+/// innermost node outwards. A position outside every node gets an empty range
+/// at the position itself, since the response must have one entry per position.
+#[query]
+pub fn lsp_selection_ranges(
+    db: &impl Db,
+    file: TextFile,
+    source: String,
+    positions: Vec<Position>,
+) -> Result<Vec<SelectionRange>, QueryError> {
+    let line_numbers = LineNumbers::new(&source);
+    let mut ranges = Vec::with_capacity(positions.len());
+    for &position in &positions {
+        let offset = position_to_offset(position, &source, &line_numbers);
+        let spans = db.query(GetSelectionSpans::new(file.clone(), offset as u32))?;
+        let nested = spans.iter().rev().fold(None, |parent, span| {
+            Some(SelectionRange {
+                range: Range {
+                    start: offset_to_lsp_position(span.start as usize, &source, &line_numbers),
+                    end: offset_to_lsp_position(span.end as usize, &source, &line_numbers),
+                },
+                parent: parent.map(Box::new),
+            })
+        });
+        ranges.push(nested.unwrap_or(SelectionRange {
+            range: Range {
+                start: position,
+                end: position,
+            },
+            parent: None,
+        }));
+    }
+    Ok(ranges)
+}
+
+/// LSP-formatted code actions query.
+///
+/// Offers a quick fix inserting the required fields missing from each record
+/// whose span overlaps `range`.
+#[query]
+pub fn lsp_code_actions(
+    db: &impl Db,
+    file: TextFile,
+    source: String,
+    range: Range,
+) -> Result<Vec<CodeActionOrCommand>, QueryError> {
+    let Ok(uri) = crate::uri_utils::text_file_to_uri(&file).parse::<Uri>() else {
+        return Ok(vec![]);
+    };
+    let line_numbers = LineNumbers::new(&source);
+    let start = position_to_offset(range.start, &source, &line_numbers) as u32;
+    let end = position_to_offset(range.end, &source, &line_numbers) as u32;
+    let fixes = db.query(GetMissingFieldFixes::new(file.clone()))?;
+    Ok(fixes
+        .iter()
+        .filter(|fix| fix.span.start <= end && start <= fix.span.end)
+        .map(|fix| {
+            let position = offset_to_lsp_position(fix.insert_at as usize, &source, &line_numbers);
+            let edit = TextEdit {
+                range: Range {
+                    start: position,
+                    end: position,
+                },
+                new_text: fix.text.clone(),
+            };
+            let plural = if fix.fields.len() == 1 { "" } else { "s" };
+            CodeActionOrCommand::CodeAction(CodeAction {
+                title: format!("Add missing field{plural} {}", fix.fields.join(", ")),
+                kind: Some(CodeActionKind::QUICKFIX),
+                edit: Some(WorkspaceEdit {
+                    changes: Some(HashMap::from([(uri.clone(), vec![edit])])),
+                    ..Default::default()
+                }),
+                is_preferred: Some(true),
+                ..Default::default()
+            })
+        })
+        .collect())
+}
+
+/// LSP-formatted workspace symbols query.
+///
+/// Collects the top-level sections and `$types` definitions of every open
+/// document whose name contains `pattern`, ignoring case.
+#[query]
+pub fn lsp_workspace_symbols(
+    db: &impl Db,
+    pattern: String,
+) -> Result<Vec<SymbolInformation>, QueryError> {
+    let pattern = pattern.to_lowercase();
+    let open_docs: std::sync::Arc<OpenDocumentsList> = db.asset(OpenDocuments)?;
+    let mut result = Vec::new();
+    for file in open_docs.0.iter() {
+        let Ok(uri) = crate::uri_utils::text_file_to_uri(file).parse::<Uri>() else {
+            continue;
+        };
+        let symbols = db.query(GetNamedSymbols::new(file.clone()))?;
+        let source: std::sync::Arc<TextFileContent> = db.asset(file.clone())?;
+        let line_numbers = LineNumbers::new(source.get());
+        result.extend(
+            symbols
+                .iter()
+                .filter(|symbol| symbol.name.to_lowercase().contains(&pattern))
+                .map(|symbol| convert_symbol(symbol, &uri, source.get(), &line_numbers)),
+        );
+    }
+    Ok(result)
+}
+
+/// LSP-formatted prepare rename query.
+///
+/// Returns the range of the `$types` name under the cursor, or `None` if the
+/// cursor is not on a type definition or reference.
+#[query]
+pub fn lsp_prepare_rename(
+    db: &impl Db,
+    file: TextFile,
+    source: String,
+    position: Position,
+) -> Result<Option<PrepareRenameResponse>, QueryError> {
+    let line_numbers = LineNumbers::new(&source);
+    let offset = position_to_offset(position, &source, &line_numbers) as u32;
+    let occurrences = db.query(GetTypeOccurrences::new(file.clone()))?;
+    Ok(occurrence_at(&occurrences, offset).map(|occurrence| {
+        PrepareRenameResponse::Range(span_range(occurrence, &source, &line_numbers))
+    }))
+}
+
+/// LSP-formatted rename query.
+///
+/// Renames the `$types` name under the cursor. Type references are local to
+/// the schema that defines them, so the rename covers the definition and
+/// references in `file`, plus any references in the open documents whose
+/// resolved schema is `file`. Returns `None` unless `file` defines the type.
+/// `new_name` must already be a valid identifier.
+#[query]
+pub fn lsp_rename(
+    db: &impl Db,
+    file: TextFile,
+    source: String,
+    position: Position,
+    new_name: String,
+) -> Result<Option<WorkspaceEdit>, QueryError> {
+    let line_numbers = LineNumbers::new(&source);
+    let offset = position_to_offset(position, &source, &line_numbers) as u32;
+    let occurrences = db.query(GetTypeOccurrences::new(file.clone()))?;
+    let Some(target) = occurrence_at(&occurrences, offset) else {
+        return Ok(None);
+    };
+    let is_defined = occurrences.iter().any(|occurrence| {
+        occurrence.kind == TypeOccurrenceKind::Definition && occurrence.name == target.name
+    });
+    if !is_defined {
+        return Ok(None);
+    }
+
+    let open_docs: std::sync::Arc<OpenDocumentsList> = db.asset(OpenDocuments)?;
+    let dependents = db.query(CollectSchemaDependents::new(file.clone()))?;
+    let mut files = vec![file];
+    files.extend(
+        open_docs
+            .0
+            .iter()
+            .filter(|open| dependents.contains(*open))
+            .cloned(),
+    );
+
+    let mut changes = Vec::new();
+    for file in files {
+        let Ok(uri) = crate::uri_utils::text_file_to_uri(&file).parse::<Uri>() else {
+            continue;
+        };
+        let occurrences = db.query(GetTypeOccurrences::new(file.clone()))?;
+        let source: std::sync::Arc<TextFileContent> = db.asset(file)?;
+        let line_numbers = LineNumbers::new(source.get());
+        let edits: Vec<TextEdit> = occurrences
+            .iter()
+            .filter(|occurrence| occurrence.name == target.name)
+            .map(|occurrence| TextEdit {
+                range: span_range(occurrence, source.get(), &line_numbers),
+                new_text: new_name.clone(),
+            })
+            .collect();
+        if !edits.is_empty() {
+            changes.push((uri, edits));
+        }
+    }
+    Ok(Some(WorkspaceEdit {
+        changes: Some(changes.into_iter().collect()),
+        ..Default::default()
+    }))
+}
+
+/// LSP-formatted format-on-save query.
+///
+/// Returns the edits formatting `file` when its workspace config enables
+/// `ls.format-on-save`, and no edits otherwise. Files outside any workspace
+/// are left untouched. A workspace config that fails to load, or a file with
+/// syntax errors, is reported as an error so the client can show it.
+#[query]
+pub fn lsp_format_on_save(db: &impl Db, file: TextFile) -> Result<Vec<TextEdit>, QueryError> {
+    let resolved = db.query(ResolveConfig::new(file.clone()))?;
+    if !matches!(&*resolved, Some(resolved) if resolved.config.format_on_save()) {
+        return Ok(vec![]);
+    }
+
+    let cst = db.query(ValidCst::new(file.clone()))?;
+    let source: std::sync::Arc<TextFileContent> = db.asset(file)?;
+    let formatted = eure_fmt::format_cst(source.get(), &cst, &FormatConfig::default());
+    let line_numbers = LineNumbers::new(source.get());
+    Ok(eure_fmt::compute_edits(source.get(), &formatted)
+        .into_iter()
+        .map(|edit| TextEdit {
+            range: Range {
+                start: offset_to_lsp_position(edit.start, source.get(), &line_numbers),
+                end: offset_to_lsp_position(edit.end, source.get(), &line_numbers),
+            },
+            new_text: edit.new_text,
+        })
+        .collect())
+}
+
 /// LSP-formatted diagnostics query, grouped by file.
 ///
 /// Wraps `GetFileDiagnostics` and converts to LSP `Diagnostic` format.
@@ -50,10 +310,10 @@ pub fn lsp_diagnostics(
     let mut result = Vec::new();
     for (diag_file, file_diagnostics) in by_file {
         let source: std::sync::Arc<eure::query::TextFileContent> = db.asset(diag_file.clone())?;
-        let line_offsets = compute_line_offsets(source.get());
+        let line_numbers = LineNumbers::new(source.get());
         let lsp_diagnostics: Vec<Diagnostic> = file_diagnostics
             .iter()
-            .map(|d| convert_diagnostic(d, source.get(), &line_offsets))
+            .map(|d| convert_diagnostic(d, source.get(), &line_numbers))
             .collect();
         result.push((diag_file, lsp_diagnostics));
     }
@@ -71,13 +331,13 @@ pub fn lsp_file_diagnostics(db: &impl Db, file: TextFile) -> Result<Vec<Diagnost
 
     // Get source for position conversion
     let source: std::sync::Arc<eure::query::TextFileContent> = db.asset(file.clone())?;
-    let line_offsets = compute_line_offsets(source.get());
+    let line_numbers = LineNumbers::new(source.get());
 
     // Convert to LSP diagnostics
     let lsp_diagnostics: Vec<Diagnostic> = diagnostics
         .iter()
         .filter(|d| d.file == file) // Only include diagnostics for this file
-        .map(|d| convert_diagnostic(d, source.get(), &line_offsets))
+        .map(|d| convert_diagnostic(d, source.get(), &line_numbers))
         .collect();
 
     Ok(lsp_diagnostics)
@@ -91,7 +351,7 @@ pub fn lsp_file_diagnostics(db: &impl Db, file: TextFile) -> Result<Vec<Diagnost
 /// - deltaStartChar is relative to the previous token's start (or line start if on new line)
 /// - All character positions and lengths are in UTF-16 code units
 fn convert_tokens(tokens: &[SemanticToken], source: &str) -> SemanticTokens {
-    let line_offsets = compute_line_offsets(source);
+    let line_numbers = LineNumbers::new(source);
 
     let mut data = Vec::new();
     let mut prev_line = 0u32;
@@ -100,7 +360,7 @@ fn convert_tokens(tokens: &[SemanticToken], source: &str) -> SemanticTokens {
     for token in tokens {
         let start = token.start as usize;
         let end = start + token.length as usize;
-        let (line, char) = offset_to_position(start, source, &line_offsets);
+        let (line, char) = offset_to_position(start, source, &line_numbers);
         let length = byte_len_to_utf16_len(source, start, end);
 
         let delta_line = line - prev_line;
@@ -129,19 +389,24 @@ fn convert_tokens(tokens: &[SemanticToken], source: &str) -> SemanticTokens {
 }
 
 /// Convert internal diagnostic to LSP format.
-fn convert_diagnostic(msg: &DiagnosticMessage, source: &str, line_offsets: &[usize]) -> Diagnostic {
-    let start = offset_to_lsp_position(msg.start, source, line_offsets);
-    let end = offset_to_lsp_position(msg.end, source, line_offsets);
+fn convert_diagnostic(
+    msg: &DiagnosticMessage,
+    source: &str,
+    line_numbers: &LineNumbers,
+) -> Diagnostic {
+    let start = offset_to_lsp_position(msg.start, source, line_numbers);
+    let end = offset_to_lsp_position(msg.end, source, line_numbers);
 
     Diagnostic {
         range: Range { start, end },
         severity: Some(convert_severity(msg.severity)),
-        code: None,
+        code: msg.code.clone().map(NumberOrString::String),
         code_description: None,
         source: Some("eure".to_string()),
         message: msg.message.clone(),
         related_information: None,
-        tags: None,
+        tags: (!msg.tags.is_empty())
+            .then(|| msg.tags.iter().map(|&tag| convert_tag(tag)).collect()),
         data: None,
     }
 }
@@ -156,38 +421,92 @@ fn convert_severity(severity: DiagnosticSeverity) -> LspSeverity {
     }
 }
 
-/// Compute line offsets for a source string.
-///
-/// Returns a vector where `line_offsets[i]` is the byte offset of line `i`.
-fn compute_line_offsets(source: &str) -> Vec<usize> {
-    let mut offsets = vec![0];
-    for (i, c) in source.char_indices() {
-        if c == '\n' {
-            offsets.push(i + 1);
-        }
+/// Convert internal diagnostic tag to LSP tag.
+fn convert_tag(tag: DiagnosticTag) -> LspDiagnosticTag {
+    match tag {
+        DiagnosticTag::Deprecated => LspDiagnosticTag::DEPRECATED,
+    }
+}
+
+/// Convert a named symbol to LSP symbol information.
+// The struct literal must still initialize the deprecated `deprecated` field.
+#[allow(deprecated)]
+fn convert_symbol(
+    symbol: &NamedSymbol,
+    uri: &Uri,
+    source: &str,
+    line_numbers: &LineNumbers,
+) -> SymbolInformation {
+    SymbolInformation {
+        name: symbol.name.clone(),
+        kind: match symbol.kind {
+            NamedSymbolKind::Section => SymbolKind::NAMESPACE,
+            NamedSymbolKind::Type => SymbolKind::STRUCT,
+        },
+        tags: None,
+        deprecated: None,
+        location: Location {
+            uri: uri.clone(),
+            range: Range {
+                start: offset_to_lsp_position(symbol.span.start as usize, source, line_numbers),
+                end: offset_to_lsp_position(symbol.span.end as usize, source, line_numbers),
+            },
+        },
+        container_name: None,
+    }
+}
+
+/// Find the type occurrence whose name contains `offset`, including its end.
+fn occurrence_at(occurrences: &[TypeOccurrence], offset: u32) -> Option<&TypeOccurrence> {
+    occurrences
+        .iter()
+        .find(|occurrence| occurrence.span.start <= offset && offset <= occurrence.span.end)
+}
+
+fn span_range(occurrence: &TypeOccurrence, source: &str, line_numbers: &LineNumbers) -> Range {
+    Range {
+        start: offset_to_lsp_position(occurrence.span.start as usize, source, line_numbers),
+        end: offset_to_lsp_position(occurrence.span.end as usize, source, line_numbers),
     }
-    offsets
 }
 
 /// Convert a byte offset to (line, character) position.
 ///
 /// Line is 0-indexed. Character is in UTF-16 code units (as required by LSP).
-fn offset_to_position(offset: usize, source: &str, line_offsets: &[usize]) -> (u32, u32) {
-    let line = line_offsets.iter().rposition(|&o| o <= offset).unwrap_or(0);
-    let line_start = line_offsets[line];
-    // Count UTF-16 code units from line start to offset
+fn offset_to_position(offset: usize, source: &str, line_numbers: &LineNumbers) -> (u32, u32) {
     let end = offset.min(source.len());
+    let (line, _) = line_numbers.line_col(end as u32);
+    let line_start = line_numbers.line_start(line).unwrap_or(0) as usize;
+    // Count UTF-16 code units from line start to offset
     let line_content = &source[line_start..end];
     let utf16_offset: usize = line_content.chars().map(|c| c.len_utf16()).sum();
-    (line as u32, utf16_offset as u32)
+    (line, utf16_offset as u32)
 }
 
 /// Convert a byte offset to LSP Position with UTF-16 character position.
-fn offset_to_lsp_position(offset: usize, source: &str, line_offsets: &[usize]) -> Position {
-    let (line, character) = offset_to_position(offset, source, line_offsets);
+fn offset_to_lsp_position(offset: usize, source: &str, line_numbers: &LineNumbers) -> Position {
+    let (line, character) = offset_to_position(offset, source, line_numbers);
     Position { line, character }
 }
 
+/// Convert an LSP Position (UTF-16 character position) to a byte offset.
+///
+/// Positions past the end of a line are clamped to the line end.
+fn position_to_offset(position: Position, source: &str, line_numbers: &LineNumbers) -> usize {
+    let Some(line_start) = line_numbers.line_start(position.line) else {
+        return source.len();
+    };
+    let line_start = line_start as usize;
+    let mut utf16_offset = 0;
+    for (i, c) in source[line_start..].char_indices() {
+        if utf16_offset >= position.character as usize || c == '\n' {
+            return line_start + i;
+        }
+        utf16_offset += c.len_utf16();
+    }
+    source.len()
+}
+
 /// Convert a byte length to UTF-16 code unit length.
 fn byte_len_to_utf16_len(source: &str, start: usize, end: usize) -> u32 {
     let end = end.min(source.len());
@@ -201,52 +520,578 @@ fn byte_len_to_utf16_len(source: &str, start: usize, end: usize) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use eure::query::{TextFileContent, build_runtime};
+    use query_flow::tracer::{ExecutionResult, SpanContext, SpanId, TraceId, Tracer};
+    use query_flow::{DurabilityLevel, QueryCacheKey, QueryRuntimeBuilder};
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    /// Records the cache key of every `ParseCst` execution.
+    #[derive(Default)]
+    struct ParseCountTracer {
+        ids: AtomicU64,
+        parsed: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Tracer for ParseCountTracer {
+        fn new_span_id(&self) -> SpanId {
+            SpanId(self.ids.fetch_add(1, Ordering::SeqCst))
+        }
+
+        fn new_trace_id(&self) -> TraceId {
+            TraceId(self.ids.fetch_add(1, Ordering::SeqCst))
+        }
+
+        fn on_query_end(&self, _ctx: &SpanContext, query: &QueryCacheKey, result: ExecutionResult) {
+            if query.type_name().ends_with("::ParseCst")
+                && matches!(
+                    result,
+                    ExecutionResult::Changed | ExecutionResult::Unchanged
+                )
+            {
+                self.parsed.lock().unwrap().push(query.debug_repr());
+            }
+        }
+    }
+
+    #[test]
+    fn test_editing_one_file_does_not_reparse_another() {
+        let tracer = ParseCountTracer::default();
+        let parsed = tracer.parsed.clone();
+        let runtime = QueryRuntimeBuilder::new().tracer(tracer).build();
+        let a = TextFile::from_path(PathBuf::from("a.eure"));
+        let b = TextFile::from_path(PathBuf::from("b.eure"));
+        for (file, source) in [(&a, "x = 1\n"), (&b, "y = 2\n")] {
+            runtime.resolve_asset(
+                file.clone(),
+                TextFileContent(source.to_string()),
+                DurabilityLevel::Volatile,
+            );
+            runtime
+                .query(LspSemanticTokens::new(file.clone(), source.to_string()))
+                .unwrap();
+        }
+        assert_eq!(parsed.lock().unwrap().len(), 2);
+        parsed.lock().unwrap().clear();
+
+        runtime.resolve_asset(
+            a.clone(),
+            TextFileContent("x = 10\n".to_string()),
+            DurabilityLevel::Volatile,
+        );
+        runtime
+            .query(LspSemanticTokens::new(a, "x = 10\n".to_string()))
+            .unwrap();
+        runtime
+            .query(LspSemanticTokens::new(b, "y = 2\n".to_string()))
+            .unwrap();
+
+        let parsed = parsed.lock().unwrap();
+        assert_eq!(parsed.len(), 1);
+        assert!(
+            parsed[0].contains("a.eure"),
+            "unexpected reparse: {parsed:?}"
+        );
+    }
+
+    #[test]
+    fn test_document_highlight_array_pushes() {
+        let runtime = build_runtime();
+        let file = TextFile::from_path(PathBuf::from("test.eure"));
+        let source = "items[] = 1\nname = \"x\"\nitems[] = 2\n".to_string();
+        runtime.resolve_asset(
+            file.clone(),
+            TextFileContent(source.clone()),
+            DurabilityLevel::Volatile,
+        );
+
+        let position = Position {
+            line: 2,
+            character: 1,
+        };
+        let highlights = runtime
+            .query(LspDocumentHighlight::new(file, source, position))
+            .unwrap();
+
+        let ranges: Vec<_> = highlights.iter().map(|h| h.range).collect();
+        assert_eq!(
+            ranges,
+            vec![
+                Range {
+                    start: Position::new(0, 0),
+                    end: Position::new(0, 7),
+                },
+                Range {
+                    start: Position::new(2, 0),
+                    end: Position::new(2, 7),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_selection_range_nested_section_value() {
+        let runtime = build_runtime();
+        let file = TextFile::from_path(PathBuf::from("test.eure"));
+        let source = "@ server\nhost = \"localhost\"\nport = 8080\n".to_string();
+        runtime.resolve_asset(
+            file.clone(),
+            TextFileContent(source.clone()),
+            DurabilityLevel::Volatile,
+        );
+
+        let position = Position::new(2, 8);
+        let ranges = runtime
+            .query(LspSelectionRanges::new(file, source, vec![position]))
+            .unwrap();
+        assert_eq!(ranges.len(), 1);
+
+        let mut chain = vec![];
+        let mut current = Some(&ranges[0]);
+        while let Some(selection) = current {
+            chain.push(selection.range);
+            current = selection.parent.as_deref();
+        }
+
+        // value -> binding -> section -> document
+        assert_eq!(
+            chain.first(),
+            Some(&Range::new(Position::new(2, 7), Position::new(2, 11)))
+        );
+        assert!(chain.contains(&Range::new(Position::new(2, 0), Position::new(2, 11))));
+        assert!(chain.contains(&Range::new(Position::new(0, 0), Position::new(2, 11))));
+        for pair in chain.windows(2) {
+            assert!(pair[1].start <= pair[0].start && pair[0].end <= pair[1].end);
+        }
+    }
+
+    #[test]
+    fn test_code_action_inserts_missing_field() {
+        let runtime = build_runtime();
+        let file = TextFile::from_path(PathBuf::from("test.eure"));
+        let source = "$schema = \"test.schema.eure\"\nage = 1\n".to_string();
+        runtime.resolve_asset(
+            file.clone(),
+            TextFileContent(source.clone()),
+            DurabilityLevel::Volatile,
+        );
+        runtime.resolve_asset(
+            TextFile::from_path(PathBuf::from("test.schema.eure")),
+            TextFileContent("name = `text`\nage = `integer`\n".to_string()),
+            DurabilityLevel::Volatile,
+        );
+
+        let range = Range {
+            start: Position::new(1, 0),
+            end: Position::new(1, 0),
+        };
+        let actions = runtime
+            .query(LspCodeActions::new(file, source, range))
+            .unwrap();
+
+        assert_eq!(actions.len(), 1);
+        let CodeActionOrCommand::CodeAction(action) = &actions[0] else {
+            panic!("expected a code action");
+        };
+        assert_eq!(action.title, "Add missing field name");
+        let edits: Vec<_> = action
+            .edit
+            .iter()
+            .flat_map(|edit| edit.changes.iter().flatten())
+            .flat_map(|(_, edits)| edits)
+            .collect();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "\nname = !");
+        assert_eq!(edits[0].range.start, Position::new(1, 7));
+    }
+
+    #[test]
+    fn test_workspace_symbols_across_documents() {
+        let runtime = build_runtime();
+        let a = TextFile::from_path(PathBuf::from("/workspace/a.eure"));
+        let b = TextFile::from_path(PathBuf::from("/workspace/b.eure"));
+        for (file, source) in [
+            (&a, "$types.user-id = `integer`\n"),
+            (
+                &b,
+                "$types.order = `text`\n@ user-settings\ntheme = \"dark\"\n",
+            ),
+        ] {
+            runtime.resolve_asset(
+                file.clone(),
+                TextFileContent(source.to_string()),
+                DurabilityLevel::Volatile,
+            );
+        }
+        runtime.resolve_asset(
+            OpenDocuments,
+            OpenDocumentsList(vec![a, b]),
+            DurabilityLevel::Volatile,
+        );
+
+        let symbols = runtime
+            .query(LspWorkspaceSymbols::new("USER".to_string()))
+            .unwrap();
+        let mut found: Vec<_> = symbols
+            .iter()
+            .map(|symbol| {
+                (
+                    symbol.name.as_str(),
+                    symbol.kind,
+                    symbol.location.uri.as_str().ends_with("a.eure"),
+                )
+            })
+            .collect();
+        found.sort_by_key(|(name, _, _)| *name);
+        assert_eq!(
+            found,
+            vec![
+                ("user-id", SymbolKind::STRUCT, true),
+                ("user-settings", SymbolKind::NAMESPACE, false),
+            ]
+        );
+        let user_id = symbols.iter().find(|s| s.name == "user-id").unwrap();
+        assert_eq!(
+            user_id.location.range,
+            Range {
+                start: Position::new(0, 7),
+                end: Position::new(0, 14),
+            }
+        );
+    }
 
     #[test]
-    fn test_compute_line_offsets() {
+    fn test_rename_type_across_documents() {
+        let runtime = build_runtime();
+        let a = TextFile::from_path(PathBuf::from("/workspace/a.schema.eure"));
+        let b = TextFile::from_path(PathBuf::from("/workspace/b.schema.eure"));
+        let a_source = "$types.user = `text`\nowner = `$types.user`\n".to_string();
+        for (file, source) in [
+            (&a, a_source.as_str()),
+            (&b, "author = `$types.user`\nother = `$types.group`\n"),
+        ] {
+            runtime.resolve_asset(
+                file.clone(),
+                TextFileContent(source.to_string()),
+                DurabilityLevel::Volatile,
+            );
+        }
+        runtime.resolve_asset(
+            OpenDocuments,
+            OpenDocumentsList(vec![a.clone(), b.clone()]),
+            DurabilityLevel::Volatile,
+        );
+
+        let prepared = runtime
+            .query(LspPrepareRename::new(
+                a.clone(),
+                a_source.clone(),
+                Position::new(0, 8),
+            ))
+            .unwrap();
+        assert_eq!(
+            *prepared,
+            Some(PrepareRenameResponse::Range(Range {
+                start: Position::new(0, 7),
+                end: Position::new(0, 11),
+            }))
+        );
+
+        let edit = runtime
+            .query(LspRename::new(
+                a,
+                a_source,
+                Position::new(0, 8),
+                "account".to_string(),
+            ))
+            .unwrap();
+        let mut edits: Vec<_> = edit
+            .as_ref()
+            .as_ref()
+            .unwrap()
+            .changes
+            .iter()
+            .flatten()
+            .flat_map(|(uri, edits)| {
+                let file = uri.as_str().rsplit('/').next().unwrap().to_string();
+                edits
+                    .iter()
+                    .map(move |edit| (file.clone(), edit.range, edit.new_text.as_str()))
+            })
+            .collect();
+        edits.sort_by_key(|(file, range, _)| (file.clone(), range.start));
+        assert_eq!(
+            edits,
+            vec![
+                (
+                    "a.schema.eure".to_string(),
+                    Range::new(Position::new(0, 7), Position::new(0, 11)),
+                    "account"
+                ),
+                (
+                    "a.schema.eure".to_string(),
+                    Range::new(Position::new(1, 16), Position::new(1, 20)),
+                    "account"
+                ),
+            ]
+        );
+
+        // `b` references a `user` type it does not define, so it is not renamed
+        // along with `a`, and renaming from `b` finds nothing to rename.
+        let b_source = "author = `$types.user`\nother = `$types.group`\n".to_string();
+        let edit = runtime
+            .query(LspRename::new(
+                b,
+                b_source,
+                Position::new(0, 17),
+                "account".to_string(),
+            ))
+            .unwrap();
+        assert_eq!(*edit, None);
+    }
+
+    #[test]
+    fn test_format_on_save_follows_workspace_config() {
+        use eure::query::{Workspace, WorkspaceId};
+
+        let runtime = build_runtime();
+        runtime.resolve_asset(
+            WorkspaceId("/workspace".to_string()),
+            Workspace {
+                path: PathBuf::from("/workspace"),
+                config_path: PathBuf::from("/workspace/Eure.eure"),
+            },
+            DurabilityLevel::Static,
+        );
+        let config = TextFile::from_path(PathBuf::from("/workspace/Eure.eure"));
+        let file = TextFile::from_path(PathBuf::from("/workspace/a.eure"));
+        runtime.resolve_asset(
+            file.clone(),
+            TextFileContent("name   =   \"app\"".to_string()),
+            DurabilityLevel::Volatile,
+        );
+
+        runtime.resolve_asset(
+            config.clone(),
+            TextFileContent("@ ls\nformat-on-save = true\n".to_string()),
+            DurabilityLevel::Volatile,
+        );
+        let edits = runtime.query(LspFormatOnSave::new(file.clone())).unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "name = \"app\"\n");
+
+        runtime.resolve_asset(
+            config.clone(),
+            TextFileContent("@ ls\nformat-on-save = false\n".to_string()),
+            DurabilityLevel::Volatile,
+        );
+        let edits = runtime.query(LspFormatOnSave::new(file.clone())).unwrap();
+        assert!(edits.is_empty());
+
+        // A broken config is reported instead of silently skipping the format
+        runtime.resolve_asset(
+            config.clone(),
+            TextFileContent("@ ls\nformat-on-save = = true\n".to_string()),
+            DurabilityLevel::Volatile,
+        );
+        assert!(matches!(
+            runtime.query(LspFormatOnSave::new(file.clone())),
+            Err(QueryError::UserError(_))
+        ));
+
+        // So is a document that cannot be parsed
+        runtime.resolve_asset(
+            config,
+            TextFileContent("@ ls\nformat-on-save = true\n".to_string()),
+            DurabilityLevel::Volatile,
+        );
+        runtime.resolve_asset(
+            file.clone(),
+            TextFileContent("name = = \"app\"".to_string()),
+            DurabilityLevel::Volatile,
+        );
+        assert!(matches!(
+            runtime.query(LspFormatOnSave::new(file)),
+            Err(QueryError::UserError(_))
+        ));
+    }
+
+    #[test]
+    fn test_config_file_diagnostics() {
+        use eure::query::{CollectDiagnosticTargets, Workspace, WorkspaceId};
+
+        let runtime = build_runtime();
+        runtime.resolve_asset(
+            WorkspaceId("/workspace".to_string()),
+            Workspace {
+                path: PathBuf::from("/workspace"),
+                config_path: PathBuf::from("/workspace/Eure.eure"),
+            },
+            DurabilityLevel::Static,
+        );
+        runtime.resolve_asset(
+            OpenDocuments,
+            OpenDocumentsList(vec![]),
+            DurabilityLevel::Volatile,
+        );
+        let config = TextFile::from_path(PathBuf::from("/workspace/Eure.eure"));
+        // `glob` is a typo for `globs`
+        runtime.resolve_asset(
+            config.clone(),
+            TextFileContent("@ targets.docs\nglob = [\"docs/**/*.eure\"]\n".to_string()),
+            DurabilityLevel::Volatile,
+        );
+
+        let targets = runtime.query(CollectDiagnosticTargets::new()).unwrap();
+        assert!(targets.contains(&config));
+
+        let diagnostics = runtime.query(LspFileDiagnostics::new(config)).unwrap();
+        assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+        assert_eq!(diagnostics[0].severity, Some(LspSeverity::ERROR));
+        assert!(
+            diagnostics[0].message.contains("globs"),
+            "{}",
+            diagnostics[0].message
+        );
+    }
+
+    #[test]
+    fn test_config_missing_schema_points_at_schema_value() {
+        use eure::query::{Workspace, WorkspaceId};
+
+        let runtime = build_runtime();
+        runtime.resolve_asset(
+            WorkspaceId("/workspace".to_string()),
+            Workspace {
+                path: PathBuf::from("/workspace"),
+                config_path: PathBuf::from("/workspace/Eure.eure"),
+            },
+            DurabilityLevel::Static,
+        );
+        runtime.resolve_asset(
+            OpenDocuments,
+            OpenDocumentsList(vec![]),
+            DurabilityLevel::Volatile,
+        );
+        let config = TextFile::from_path(PathBuf::from("/workspace/Eure.eure"));
+        runtime.resolve_asset(
+            config.clone(),
+            TextFileContent(
+                "@ targets.docs\nglobs = [\"docs/**/*.eure\"]\nschema = \"missing.schema.eure\"\n"
+                    .to_string(),
+            ),
+            DurabilityLevel::Volatile,
+        );
+        runtime.resolve_asset_error::<TextFile>(
+            TextFile::from_path(PathBuf::from("/workspace/missing.schema.eure")),
+            anyhow::anyhow!("not found"),
+            DurabilityLevel::Volatile,
+        );
+
+        let diagnostics = runtime.query(LspFileDiagnostics::new(config)).unwrap();
+        assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+        assert_eq!(diagnostics[0].severity, Some(LspSeverity::WARNING));
+        assert_eq!(diagnostics[0].range.start, Position::new(2, 9));
+        assert_eq!(diagnostics[0].range.end, Position::new(2, 30));
+    }
+
+    #[test]
+    fn test_deprecated_field_is_tagged_warning() {
+        let runtime = build_runtime();
+        let file = TextFile::from_path(PathBuf::from("test.eure"));
+        runtime.resolve_asset(
+            file.clone(),
+            TextFileContent("$schema = \"test.schema.eure\"\naddr = \"localhost\"\n".to_string()),
+            DurabilityLevel::Volatile,
+        );
+        runtime.resolve_asset(
+            TextFile::from_path(PathBuf::from("test.schema.eure")),
+            TextFileContent(
+                "addr = `text`\naddr.$deprecated = true\naddr.$description = \"use host\"\n"
+                    .to_string(),
+            ),
+            DurabilityLevel::Volatile,
+        );
+        runtime.resolve_asset(
+            OpenDocuments,
+            OpenDocumentsList(vec![file.clone()]),
+            DurabilityLevel::Volatile,
+        );
+
+        let diagnostics = runtime.query(LspFileDiagnostics::new(file)).unwrap();
+        assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+        let diagnostic = &diagnostics[0];
+        assert_eq!(diagnostic.severity, Some(LspSeverity::WARNING));
+        assert_eq!(diagnostic.tags, Some(vec![LspDiagnosticTag::DEPRECATED]));
+        assert_eq!(diagnostic.message, "field `addr` is deprecated: use host");
+        assert_eq!(diagnostic.range.start, Position::new(1, 0));
+        assert_eq!(diagnostic.range.end, Position::new(1, 4));
+    }
+
+    #[test]
+    fn test_line_starts() {
         let source = "hello\nworld\n";
-        let offsets = compute_line_offsets(source);
-        assert_eq!(offsets, vec![0, 6, 12]);
+        let line_numbers = LineNumbers::new(source);
+        let starts: Vec<_> = (0..4).map(|line| line_numbers.line_start(line)).collect();
+        assert_eq!(starts, vec![Some(0), Some(6), Some(12), None]);
     }
 
     #[test]
     fn test_offset_to_position_ascii() {
         let source = "hello\nworld\n";
-        let offsets = compute_line_offsets(source);
-        assert_eq!(offset_to_position(0, source, &offsets), (0, 0));
-        assert_eq!(offset_to_position(5, source, &offsets), (0, 5));
-        assert_eq!(offset_to_position(6, source, &offsets), (1, 0));
-        assert_eq!(offset_to_position(11, source, &offsets), (1, 5));
+        let line_numbers = LineNumbers::new(source);
+        assert_eq!(offset_to_position(0, source, &line_numbers), (0, 0));
+        assert_eq!(offset_to_position(5, source, &line_numbers), (0, 5));
+        assert_eq!(offset_to_position(6, source, &line_numbers), (1, 0));
+        assert_eq!(offset_to_position(11, source, &line_numbers), (1, 5));
     }
 
     #[test]
     fn test_offset_to_position_utf8() {
         // "日本語" is 9 bytes (3 chars × 3 bytes each), but 3 UTF-16 code units
         let source = "日本語\ntest";
-        let offsets = compute_line_offsets(source);
+        let line_numbers = LineNumbers::new(source);
         // Byte offset 0 -> (line 0, char 0)
-        assert_eq!(offset_to_position(0, source, &offsets), (0, 0));
+        assert_eq!(offset_to_position(0, source, &line_numbers), (0, 0));
         // Byte offset 3 (after 日) -> (line 0, char 1)
-        assert_eq!(offset_to_position(3, source, &offsets), (0, 1));
+        assert_eq!(offset_to_position(3, source, &line_numbers), (0, 1));
         // Byte offset 6 (after 日本) -> (line 0, char 2)
-        assert_eq!(offset_to_position(6, source, &offsets), (0, 2));
+        assert_eq!(offset_to_position(6, source, &line_numbers), (0, 2));
         // Byte offset 9 (after 日本語) -> (line 0, char 3)
-        assert_eq!(offset_to_position(9, source, &offsets), (0, 3));
+        assert_eq!(offset_to_position(9, source, &line_numbers), (0, 3));
         // Byte offset 10 (after \n) -> (line 1, char 0)
-        assert_eq!(offset_to_position(10, source, &offsets), (1, 0));
+        assert_eq!(offset_to_position(10, source, &line_numbers), (1, 0));
     }
 
     #[test]
     fn test_offset_to_position_emoji() {
         // "😀" is 4 bytes in UTF-8, but 2 UTF-16 code units (surrogate pair)
         let source = "😀a";
-        let offsets = compute_line_offsets(source);
+        let line_numbers = LineNumbers::new(source);
         // Byte offset 0 -> (line 0, char 0)
-        assert_eq!(offset_to_position(0, source, &offsets), (0, 0));
+        assert_eq!(offset_to_position(0, source, &line_numbers), (0, 0));
         // Byte offset 4 (after 😀) -> (line 0, char 2) because emoji is 2 UTF-16 units
-        assert_eq!(offset_to_position(4, source, &offsets), (0, 2));
+        assert_eq!(offset_to_position(4, source, &line_numbers), (0, 2));
         // Byte offset 5 (after 😀a) -> (line 0, char 3)
-        assert_eq!(offset_to_position(5, source, &offsets), (0, 3));
+        assert_eq!(offset_to_position(5, source, &line_numbers), (0, 3));
+    }
+
+    #[test]
+    fn test_position_to_offset() {
+        let source = "日本語\n😀a";
+        let line_numbers = LineNumbers::new(source);
+        let pos = |line, character| Position { line, character };
+        assert_eq!(position_to_offset(pos(0, 0), source, &line_numbers), 0);
+        assert_eq!(position_to_offset(pos(0, 2), source, &line_numbers), 6);
+        // Past the end of the line clamps to the newline
+        assert_eq!(position_to_offset(pos(0, 10), source, &line_numbers), 9);
+        // Emoji is 2 UTF-16 units and 4 bytes
+        assert_eq!(position_to_offset(pos(1, 2), source, &line_numbers), 14);
+        assert_eq!(
+            position_to_offset(pos(5, 0), source, &line_numbers),
+            source.len()
+        );
     }
 
     #[test]
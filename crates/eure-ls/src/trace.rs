@@ -0,0 +1,192 @@
+//! Tracing spans for LSP request handling.
+//!
+//! On native targets, requests, notifications, and diagnostics refreshes run
+//! inside `tracing` spans that record the method, the file URI, and the elapsed
+//! time. A request that suspends on unresolved assets keeps its span in
+//! [`RequestTrace`], so retries are logged under the original request.
+//!
+//! On WASM the spans are no-ops; only the suspension warning is logged, to the
+//! browser console.
+
+/// Number of suspensions after which a pending request logs a warning.
+pub const SUSPEND_WARN_THRESHOLD: u32 = 8;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod imp {
+    use std::time::Instant;
+
+    use tracing::Span;
+    use tracing::span::EnteredSpan;
+
+    use super::SUSPEND_WARN_THRESHOLD;
+
+    /// A span entered for one handler call; logs the elapsed time when dropped.
+    pub struct HandlerSpan {
+        span: EnteredSpan,
+        started: Instant,
+    }
+
+    impl HandlerSpan {
+        pub fn request(method: &str) -> Self {
+            Self::enter(tracing::info_span!(
+                "lsp_request",
+                method,
+                uri = tracing::field::Empty
+            ))
+        }
+
+        pub fn notification(method: &str) -> Self {
+            Self::enter(tracing::info_span!(
+                "lsp_notification",
+                method,
+                uri = tracing::field::Empty
+            ))
+        }
+
+        pub fn diagnostics() -> Self {
+            Self::enter(tracing::debug_span!("lsp_diagnostics"))
+        }
+
+        fn enter(span: Span) -> Self {
+            Self {
+                span: span.entered(),
+                started: Instant::now(),
+            }
+        }
+    }
+
+    impl Drop for HandlerSpan {
+        fn drop(&mut self) {
+            let elapsed_ms = self.started.elapsed().as_secs_f64() * 1000.0;
+            tracing::debug!(parent: self.span.id(), elapsed_ms, "handled");
+        }
+    }
+
+    /// Record the file URI on the current handler span.
+    pub fn record_uri(uri: &str) {
+        Span::current().record("uri", uri);
+    }
+
+    /// Span context carried by a pending request across suspend/retry cycles.
+    #[derive(Clone)]
+    pub struct RequestTrace {
+        span: Span,
+        started: Instant,
+        suspends: u32,
+    }
+
+    impl RequestTrace {
+        /// Capture the span of the handler that is deferring the request.
+        pub fn current() -> Self {
+            Self {
+                span: Span::current(),
+                started: Instant::now(),
+                suspends: 0,
+            }
+        }
+
+        /// Run `f` inside the request's original span.
+        pub fn in_scope<R>(&self, f: impl FnOnce() -> R) -> R {
+            self.span.in_scope(f)
+        }
+
+        pub fn suspends(&self) -> u32 {
+            self.suspends
+        }
+
+        /// Count one more suspension, warning once the threshold is exceeded.
+        pub fn suspended(&mut self) {
+            self.suspends += 1;
+            if self.suspends == SUSPEND_WARN_THRESHOLD + 1 {
+                tracing::warn!(
+                    parent: &self.span,
+                    suspends = self.suspends,
+                    "request suspended more than {} times",
+                    SUSPEND_WARN_THRESHOLD
+                );
+            }
+        }
+
+        /// Log completion of the request with its total elapsed time.
+        pub fn finish(&self) {
+            let elapsed_ms = self.started.elapsed().as_secs_f64() * 1000.0;
+            tracing::debug!(
+                parent: &self.span,
+                elapsed_ms,
+                suspends = self.suspends,
+                "pending request completed"
+            );
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod imp {
+    use super::SUSPEND_WARN_THRESHOLD;
+
+    pub struct HandlerSpan;
+
+    impl HandlerSpan {
+        pub fn request(_method: &str) -> Self {
+            Self
+        }
+
+        pub fn notification(_method: &str) -> Self {
+            Self
+        }
+
+        pub fn diagnostics() -> Self {
+            Self
+        }
+    }
+
+    pub fn record_uri(_uri: &str) {}
+
+    #[derive(Clone)]
+    pub struct RequestTrace {
+        suspends: u32,
+    }
+
+    impl RequestTrace {
+        pub fn current() -> Self {
+            Self { suspends: 0 }
+        }
+
+        pub fn in_scope<R>(&self, f: impl FnOnce() -> R) -> R {
+            f()
+        }
+
+        pub fn suspends(&self) -> u32 {
+            self.suspends
+        }
+
+        pub fn suspended(&mut self) {
+            self.suspends += 1;
+            if self.suspends == SUSPEND_WARN_THRESHOLD + 1 {
+                web_sys::console::warn_1(
+                    &format!("request suspended more than {SUSPEND_WARN_THRESHOLD} times").into(),
+                );
+            }
+        }
+
+        pub fn finish(&self) {}
+    }
+}
+
+pub use imp::{HandlerSpan, RequestTrace, record_uri};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_trace_counts_suspends() {
+        let mut trace = RequestTrace::current();
+        for _ in 0..=SUSPEND_WARN_THRESHOLD {
+            trace.suspended();
+        }
+        assert_eq!(trace.suspends(), SUSPEND_WARN_THRESHOLD + 1);
+        assert_eq!(trace.in_scope(|| 42), 42);
+        trace.finish();
+    }
+}
@@ -10,7 +10,11 @@ use eure::query::{Glob, TextFile};
 use query_flow::RevisionCounter;
 use serde_json::Value;
 
-use crate::queries::{LspDiagnostics, LspFileDiagnostics, LspSemanticTokens};
+use crate::queries::{
+    LspCodeActions, LspDiagnostics, LspDocumentHighlight, LspFileDiagnostics, LspFormatOnSave,
+    LspPrepareRename, LspRename, LspSelectionRanges, LspSemanticTokens, LspWorkspaceSymbols,
+};
+use crate::trace::RequestTrace;
 
 /// Platform-agnostic request ID.
 ///
@@ -81,6 +85,14 @@ pub enum Effect {
         /// The glob pattern to expand.
         glob: Glob,
     },
+    /// Request to call [`LspCore::tick`](crate::LspCore::tick) once no further
+    /// input has arrived for `delay_ms` milliseconds.
+    ///
+    /// A newer request supersedes an older one, restarting the delay.
+    ScheduleDiagnostics {
+        /// How long to wait for the client to go quiet.
+        delay_ms: u64,
+    },
 }
 
 /// LSP error information.
@@ -121,17 +133,56 @@ pub enum LspOutput {
     },
     /// Notification to send to client.
     Notification { method: String, params: Value },
+    /// Request sent from the server to the client.
+    ///
+    /// The client's response carries no information the core needs, so it is
+    /// not routed back.
+    Request {
+        id: CoreRequestId,
+        method: String,
+        params: Value,
+    },
 }
 
 /// LSP commands wrapping query structs generated by #[query] macro.
 #[derive(Clone)]
 pub enum CommandQuery {
     SemanticTokensFull(LspSemanticTokens),
+    DocumentHighlight(LspDocumentHighlight),
+    CodeAction(LspCodeActions),
+    WorkspaceSymbol(LspWorkspaceSymbols),
+    PrepareRename(LspPrepareRename),
+    Rename(LspRename),
+    SelectionRange(LspSelectionRanges),
+    FormatOnSave(LspFormatOnSave),
+}
+
+impl CommandQuery {
+    /// The file this command operates on, if it is scoped to a single file.
+    pub fn file(&self) -> Option<&TextFile> {
+        match self {
+            CommandQuery::SemanticTokensFull(q) => Some(&q.file),
+            CommandQuery::DocumentHighlight(q) => Some(&q.file),
+            CommandQuery::CodeAction(q) => Some(&q.file),
+            CommandQuery::WorkspaceSymbol(_) => None,
+            CommandQuery::PrepareRename(q) => Some(&q.file),
+            CommandQuery::Rename(q) => Some(&q.file),
+            CommandQuery::SelectionRange(q) => Some(&q.file),
+            CommandQuery::FormatOnSave(q) => Some(&q.file),
+        }
+    }
 }
 
 /// Result of executing a command query.
 pub enum CommandResult {
     SemanticTokens(Option<lsp_types::SemanticTokens>),
+    DocumentHighlight(Vec<lsp_types::DocumentHighlight>),
+    CodeAction(Vec<lsp_types::CodeActionOrCommand>),
+    WorkspaceSymbol(Vec<lsp_types::SymbolInformation>),
+    PrepareRename(Option<lsp_types::PrepareRenameResponse>),
+    Rename(Option<lsp_types::WorkspaceEdit>),
+    SelectionRange(Vec<lsp_types::SelectionRange>),
+    FormatOnSave(Vec<lsp_types::TextEdit>),
 }
 
 /// A pending LSP request waiting for assets to be resolved.
@@ -142,6 +193,8 @@ pub struct PendingRequest {
     pub command: CommandQuery,
     /// Assets this request is waiting for.
     pub waiting_for: HashSet<TextFile>,
+    /// Span context and suspension count carried across retries.
+    pub trace: RequestTrace,
 }
 
 /// Subscription for diagnostics with revision tracking (legacy per-URI).
@@ -162,6 +215,17 @@ pub struct FileDiagnosticsSubscription {
     pub last_revision: RevisionCounter,
 }
 
+/// Work-done progress of a diagnostics refresh that is waiting on assets.
+///
+/// Created when a multi-file refresh first has to wait for files to be fetched
+/// and dropped once every diagnostic target has been polled.
+pub struct DiagnosticsProgress {
+    /// The token the progress was created with.
+    pub token: lsp_types::ProgressToken,
+    /// Number of targets whose diagnostics were last reported as completed.
+    pub completed: usize,
+}
+
 // Native-only types for I/O operations
 
 /// Request to read a file from disk.
@@ -13,17 +13,63 @@ use eure::query::{EureQueryError, TextFile};
 /// Convert a URI string to a TextFile.
 ///
 /// Handles both file:// URIs and https:// URLs.
+/// Local paths are normalized so that equivalent URIs map to the same TextFile.
 pub fn uri_to_text_file(uri: &str) -> Result<TextFile, EureQueryError> {
     if uri.starts_with("https://") {
         // Remote URL
         TextFile::parse(uri)
     } else {
         // Local file
-        let path = uri_to_path(uri);
+        let path = normalize_path(&uri_to_path(uri));
         Ok(TextFile::from_path(PathBuf::from(path)))
     }
 }
 
+/// Lexically normalize a local path.
+///
+/// - Converts `\` separators to `/`
+/// - Collapses repeated separators
+/// - Resolves `.` and `..` segments (never above the root)
+/// - Strips trailing separators
+///
+/// This does not touch the filesystem, so it works the same on WASM builds.
+pub fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+
+    // Split off the root: `/` for Unix, `C:/` for Windows drive paths.
+    let (root, rest) = if let Some(rest) = path.strip_prefix('/') {
+        ("/".to_string(), rest)
+    } else if path.as_bytes().get(1) == Some(&b':') {
+        let (drive, rest) = path.split_at(2);
+        (format!("{}/", drive), rest.trim_start_matches('/'))
+    } else {
+        (String::new(), path.as_str())
+    };
+
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in rest.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                if segments.last().is_some_and(|last| *last != "..") {
+                    segments.pop();
+                } else if root.is_empty() {
+                    // Relative paths keep leading `..` segments
+                    segments.push("..");
+                }
+            }
+            segment => segments.push(segment),
+        }
+    }
+
+    let joined = segments.join("/");
+    if root.is_empty() && joined.is_empty() {
+        ".".to_string()
+    } else {
+        format!("{}{}", root, joined)
+    }
+}
+
 /// Extract path from a file:// URI.
 ///
 /// Handles both Unix-style (file:///path) and Windows-style (file:///C:/path) URIs.
@@ -155,6 +201,33 @@ mod tests {
             );
         }
 
+        #[test]
+        fn dot_segments_are_resolved() {
+            let canonical = uri_to_text_file("file:///a/b.eure").unwrap();
+            assert_eq!(uri_to_text_file("file:///a/./b.eure").unwrap(), canonical);
+            assert_eq!(
+                uri_to_text_file("file:///a/c/../b.eure").unwrap(),
+                canonical
+            );
+            assert_eq!(uri_to_text_file("file:///a//b.eure").unwrap(), canonical);
+        }
+
+        #[test]
+        fn trailing_slash_is_stripped() {
+            let file = uri_to_text_file("file:///a/dir/").unwrap();
+            assert_eq!(file, uri_to_text_file("file:///a/dir").unwrap());
+            assert_eq!(text_file_to_uri(&file), "file:///a/dir");
+        }
+
+        #[test]
+        fn normalized_file_maps_back_to_uri() {
+            let file = uri_to_text_file("file:///a/./b/../c.eure").unwrap();
+            assert_eq!(text_file_to_uri(&file), "file:///a/c.eure");
+
+            let file = uri_to_text_file("file:///C:/Users/./test.eure").unwrap();
+            assert_eq!(text_file_to_uri(&file), "file:///C:/Users/test.eure");
+        }
+
         #[test]
         fn invalid_url_returns_error() {
             let result = uri_to_text_file("https://");
@@ -162,6 +235,33 @@ mod tests {
         }
     }
 
+    mod normalize_path_tests {
+        use super::*;
+
+        #[test]
+        fn backslash_separators() {
+            assert_eq!(
+                normalize_path("C:\\Users\\.\\file.eure"),
+                "C:/Users/file.eure"
+            );
+        }
+
+        #[test]
+        fn parent_does_not_escape_root() {
+            assert_eq!(normalize_path("/../a.eure"), "/a.eure");
+        }
+
+        #[test]
+        fn relative_path_keeps_leading_parent() {
+            assert_eq!(normalize_path("../a/./b.eure"), "../a/b.eure");
+        }
+
+        #[test]
+        fn root_only() {
+            assert_eq!(normalize_path("/"), "/");
+        }
+    }
+
     mod text_file_to_uri_tests {
         use super::*;
 
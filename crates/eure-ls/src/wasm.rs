@@ -10,7 +10,7 @@ use serde_json::Value;
 use wasm_bindgen::prelude::*;
 
 use crate::uri_utils::{text_file_to_uri, uri_to_text_file};
-use crate::{CoreRequestId, LspCore, LspOutput};
+use crate::{CoreRequestId, Effect, LspCore, LspOutput};
 
 // =========================================================================
 // WASM-exported types for TypeScript
@@ -114,6 +114,8 @@ impl From<eure_env::cache::CacheAction> for CacheAction {
 pub struct WasmCore {
     core: LspCore,
     outbox: Vec<Value>,
+    /// Delay of the latest [`Effect::ScheduleDiagnostics`] not yet taken by the host.
+    scheduled_tick_ms: Option<u64>,
 }
 
 #[wasm_bindgen]
@@ -127,6 +129,7 @@ impl WasmCore {
         Self {
             core: LspCore::new(),
             outbox: Vec::new(),
+            scheduled_tick_ms: None,
         }
     }
 
@@ -148,18 +151,19 @@ impl WasmCore {
                 let params = msg.get("params").cloned().unwrap_or(Value::Null);
                 let core_id = CoreRequestId::from(id);
 
-                let (outputs, _effects) = self.core.handle_request(core_id, method, params);
+                let (outputs, effects) = self.core.handle_request(core_id, method, params);
                 self.process_outputs(outputs);
-                // Effects are handled by TypeScript polling pending_files/pending_globs
+                self.process_effects(effects);
             }
-            // Response - we don't send requests, so ignore responses
+            // Response - replies to our progress token requests, nothing to do
         } else if msg.get("method").is_some() {
             // Notification
             let method = msg.get("method").and_then(|m| m.as_str()).unwrap_or("");
             let params = msg.get("params").cloned().unwrap_or(Value::Null);
 
-            let (outputs, _effects) = self.core.handle_notification(method, params);
+            let (outputs, effects) = self.core.handle_notification(method, params);
             self.process_outputs(outputs);
+            self.process_effects(effects);
         }
     }
 
@@ -220,8 +224,9 @@ impl WasmCore {
             .filter_map(|uri| uri_to_text_file(&uri).ok())
             .collect();
 
-        let (outputs, _effects) = self.core.resolve_glob(id, text_files);
+        let (outputs, effects) = self.core.resolve_glob(id, text_files);
         self.process_outputs(outputs);
+        self.process_effects(effects);
     }
 
     /// Resolve a text file content.
@@ -238,14 +243,31 @@ impl WasmCore {
             (None, None) => Err("File not found".to_string()),
         };
 
-        let (outputs, _effects) = self.core.resolve_file(file, result);
+        let (outputs, effects) = self.core.resolve_file(file, result);
         self.process_outputs(outputs);
+        self.process_effects(effects);
+    }
+
+    /// Take the delay in milliseconds after which the host should call [`Self::tick`].
+    ///
+    /// Returns `undefined` unless a tick was scheduled since the last call. A
+    /// newer schedule supersedes an older one, so the host restarts its timer.
+    #[wasm_bindgen]
+    pub fn take_scheduled_tick(&mut self) -> Option<f64> {
+        self.scheduled_tick_ms
+            .take()
+            .map(|delay_ms| delay_ms as f64)
     }
 
     /// Tick the event loop.
+    ///
+    /// Runs diagnostics deferred by document changes. The host calls this once
+    /// the delay from [`Self::take_scheduled_tick`] has passed without input.
     #[wasm_bindgen]
     pub fn tick(&mut self) {
-        // Currently a no-op
+        let (outputs, effects) = self.core.tick();
+        self.process_outputs(outputs);
+        self.process_effects(effects);
     }
 
     // =========================================================================
@@ -308,6 +330,18 @@ impl WasmCore {
 }
 
 impl WasmCore {
+    /// Record effects the host does not poll for.
+    ///
+    /// File and glob fetches are picked up through `get_pending_text_files`
+    /// and `get_pending_globs`.
+    fn process_effects(&mut self, effects: Vec<Effect>) {
+        for effect in effects {
+            if let Effect::ScheduleDiagnostics { delay_ms } = effect {
+                self.scheduled_tick_ms = Some(delay_ms);
+            }
+        }
+    }
+
     /// Process LspOutputs and add to outbox.
     fn process_outputs(&mut self, outputs: Vec<LspOutput>) {
         for output in outputs {
@@ -332,6 +366,12 @@ impl WasmCore {
                     "method": method,
                     "params": params
                 }),
+                LspOutput::Request { id, method, params } => serde_json::json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "method": method,
+                    "params": params
+                }),
             };
             self.outbox.push(json);
         }
@@ -1,7 +1,7 @@
 use darling::FromField;
 use syn::Type;
 
-use super::DefaultValue;
+use super::{DefaultValue, MapEntryAttrs};
 
 #[derive(Debug, Default, Clone, FromField)]
 #[darling(default, attributes(eure))]
@@ -35,4 +35,16 @@ pub struct FieldAttrs {
     /// optional_timeout: Option<Duration>,
     /// ```
     pub via: Option<Type>,
+    /// Constraints on the keys of a `HashMap`/`BTreeMap`/`IndexMap` field.
+    ///
+    /// Example: `#[eure(key(pattern = "^[a-z]+$"))]`
+    pub key: Option<MapEntryAttrs>,
+    /// Constraints on the values of a `HashMap`/`BTreeMap`/`IndexMap` field.
+    ///
+    /// Example: `#[eure(value(range = "1..=8080"))]`
+    pub value: Option<MapEntryAttrs>,
+    /// Text format of a `String` field (`email`, `uri`, `uuid`, `date-time`, `ipv4`, `ipv6`).
+    ///
+    /// Example: `#[eure(format = "email")]`
+    pub format: Option<String>,
 }
@@ -0,0 +1,93 @@
+use darling::FromMeta;
+use eure_codegen_ir::{BoundIr, DecimalInt};
+
+/// Schema constraints for the key or value of a map field.
+///
+/// Parsed from `#[eure(key(pattern = "..."), value(range = "..."))]`.
+#[derive(Debug, Default, Clone, FromMeta)]
+#[darling(default)]
+pub struct MapEntryAttrs {
+    /// Regex the text must match.
+    pub pattern: Option<String>,
+    /// Integer range, in Rust-style (`0..=100`) or interval (`[0, 100)`) notation.
+    pub range: Option<String>,
+}
+
+impl MapEntryAttrs {
+    /// Parse `range` into lower and upper bounds.
+    pub fn integer_range(&self) -> Result<Option<IntegerRange>, String> {
+        self.range.as_deref().map(parse_integer_range).transpose()
+    }
+}
+
+pub type IntegerRange = (BoundIr<DecimalInt>, BoundIr<DecimalInt>);
+
+fn parse_integer_range(s: &str) -> Result<IntegerRange, String> {
+    let s = s.trim();
+    let invalid = || format!("invalid integer range `{s}`");
+    let bound = |text: &str, inclusive: bool| -> Result<BoundIr<DecimalInt>, String> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Ok(BoundIr::Unbounded);
+        }
+        let value = text.parse::<DecimalInt>().map_err(|_| invalid())?;
+        Ok(if inclusive {
+            BoundIr::Inclusive(value)
+        } else {
+            BoundIr::Exclusive(value)
+        })
+    };
+
+    // Interval notation: [a, b], (a, b), [a, b), (a, b]
+    if s.starts_with('[') || s.starts_with('(') {
+        if !(s.ends_with(']') || s.ends_with(')')) {
+            return Err(invalid());
+        }
+        let (min, max) = s[1..s.len() - 1].split_once(',').ok_or_else(invalid)?;
+        return Ok((
+            bound(min, s.starts_with('['))?,
+            bound(max, s.ends_with(']'))?,
+        ));
+    }
+
+    // Rust-style: a..b, a..=b, a.., ..b, ..=b
+    if let Some((min, max)) = s.split_once("..=") {
+        Ok((bound(min, true)?, bound(max, true)?))
+    } else if let Some((min, max)) = s.split_once("..") {
+        Ok((bound(min, true)?, bound(max, false)?))
+    } else {
+        Err(invalid())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(s: &str) -> Result<Option<IntegerRange>, String> {
+        MapEntryAttrs {
+            range: Some(s.to_string()),
+            ..Default::default()
+        }
+        .integer_range()
+    }
+
+    #[test]
+    fn test_parse_integer_range() {
+        assert_eq!(
+            range("1..=8080"),
+            Ok(Some((
+                BoundIr::Inclusive(DecimalInt::new("1")),
+                BoundIr::Inclusive(DecimalInt::new("8080")),
+            )))
+        );
+        assert_eq!(
+            range("(0, )"),
+            Ok(Some((
+                BoundIr::Exclusive(DecimalInt::new("0")),
+                BoundIr::Unbounded
+            )))
+        );
+        assert!(range("1-2").is_err());
+    }
+}
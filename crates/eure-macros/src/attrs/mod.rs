@@ -1,6 +1,7 @@
 mod container;
 mod default_value;
 mod field;
+mod map_entry;
 mod rename_all;
 mod span_extract;
 mod variant;
@@ -8,6 +9,7 @@ mod variant;
 pub use container::ContainerAttrs;
 pub use default_value::DefaultValue;
 pub use field::FieldAttrs;
+pub use map_entry::MapEntryAttrs;
 pub use rename_all::RenameAll;
 pub use span_extract::{
     extract_container_attr_spans, extract_eure_attr_spans, extract_variant_attr_spans,
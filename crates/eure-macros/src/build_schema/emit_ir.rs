@@ -1,9 +1,12 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 
+use indexmap::IndexMap;
+
 use eure_codegen_ir::{
-    FieldModeIr, IrModule, RecordSchemaIr, RustFieldIr, RustTypeKindIr, RustVariantIr,
-    SchemaNodeContentIr, UnknownFieldsPolicyIr, VariantShapeIr,
+    BoundIr, DecimalInt, FieldModeIr, IrModule, RecordSchemaIr, RustFieldIr, RustTypeExprIr,
+    RustTypeKindIr, RustVariantIr, SchemaNodeContentIr, SchemaNodeIr, SchemaNodeIrId, TextSchemaIr,
+    UnknownFieldsPolicyIr, VariantShapeIr,
 };
 
 use crate::emit_ir_common::{self, DeriveIrType};
@@ -138,6 +141,7 @@ fn emit_record_schema(emit: &DeriveIrType<'_>) -> syn::Result<(TokenStream, Opti
     } = emit_record_content(
         emit.binding().fields(),
         record_schema,
+        emit.ty().schema_nodes(),
         &schema_crate,
         &document_crate,
         "field",
@@ -365,6 +369,7 @@ fn emit_variant_schema(
             } = emit_record_content(
                 fields,
                 record_schema,
+                emit.ty().schema_nodes(),
                 schema_crate,
                 document_crate,
                 &format!("variant_{variant_index}_field"),
@@ -385,6 +390,7 @@ fn emit_variant_schema(
 fn emit_record_content(
     fields: &[RustFieldIr],
     record_schema: &RecordSchemaIr,
+    schema_nodes: &IndexMap<SchemaNodeIrId, SchemaNodeIr>,
     schema_crate: &TokenStream,
     document_crate: &TokenStream,
     field_prefix: &str,
@@ -408,23 +414,60 @@ fn emit_record_content(
             });
         } else {
             let schema_var = format_ident!("{}_{}_schema", field_prefix, idx);
-            let optional = record_schema
-                .properties()
-                .get(field.wire_name())
-                .map(|prop| prop.optional())
-                .unwrap_or(false);
-            regular.push((
-                field.wire_name().to_string(),
-                schema_var,
-                field_ty,
-                optional,
-            ));
+            let property = record_schema.properties().get(field.wire_name());
+            let optional = property.map(|prop| prop.optional()).unwrap_or(false);
+            let build = match property
+                .and_then(|prop| schema_nodes.get(&prop.schema()))
+                .map(|node| node.content())
+            {
+                Some(SchemaNodeContentIr::Map(map)) => {
+                    let key = emit_map_entry_build(
+                        field.ty(),
+                        schema_nodes.get(&map.key),
+                        true,
+                        schema_crate,
+                        document_crate,
+                    )?;
+                    let value = emit_map_entry_build(
+                        field.ty(),
+                        schema_nodes.get(&map.value),
+                        false,
+                        schema_crate,
+                        document_crate,
+                    )?;
+                    quote! {
+                        let #schema_var = {
+                            let key = #key;
+                            let value = #value;
+                            ctx.create_node(#schema_crate::SchemaNodeContent::Map(
+                                #schema_crate::MapSchema {
+                                    key,
+                                    value,
+                                    min_size: None,
+                                    max_size: None,
+                                }
+                            ))
+                        };
+                    }
+                }
+                Some(SchemaNodeContentIr::Text(text)) => {
+                    let text = emit_text_node(text, schema_crate);
+                    let text = if matches!(field.ty(), RustTypeExprIr::Option(_)) {
+                        emit_option_node(text, schema_crate)
+                    } else {
+                        text
+                    };
+                    quote! { let #schema_var = #text; }
+                }
+                _ => quote! { let #schema_var = ctx.build::<#field_ty>(); },
+            };
+            regular.push((field.wire_name().to_string(), schema_var, build, optional));
         }
     }
 
     let field_builds = regular
         .iter()
-        .map(|(_, schema_var, field_ty, _)| quote! { let #schema_var = ctx.build::<#field_ty>(); })
+        .map(|(_, _, build, _)| build.clone())
         .collect::<Vec<_>>();
     let flatten_builds = flatten
         .iter()
@@ -479,3 +522,95 @@ fn emit_record_content(
         ext_fields,
     })
 }
+
+/// Build expression for the key (`is_key`) or value schema of a constrained map field.
+///
+/// Constrained entries get a fresh node; unconstrained ones reuse the entry type's schema.
+fn emit_map_entry_build(
+    map_ty: &RustTypeExprIr,
+    node: Option<&SchemaNodeIr>,
+    is_key: bool,
+    schema_crate: &TokenStream,
+    document_crate: &TokenStream,
+) -> syn::Result<TokenStream> {
+    let RustTypeExprIr::Map { key, value, .. } = map_ty else {
+        return Err(syn::Error::new(
+            proc_macro2::Span::call_site(),
+            format!("map schema emitted for non-map field type `{map_ty:?}`"),
+        ));
+    };
+    let entry_ty = if is_key { key } else { value };
+    match node.map(|node| node.content()) {
+        Some(SchemaNodeContentIr::Text(text)) => Ok(emit_text_node(text, schema_crate)),
+        Some(SchemaNodeContentIr::Integer(integer)) => {
+            let min = emit_integer_bound(&integer.min, schema_crate);
+            let max = emit_integer_bound(&integer.max, schema_crate);
+            Ok(quote! {
+                ctx.create_node(#schema_crate::SchemaNodeContent::Integer(
+                    #schema_crate::IntegerSchema {
+                        min: #min,
+                        max: #max,
+                        multiple_of: None,
+                    }
+                ))
+            })
+        }
+        _ => {
+            let entry_ty = emit_ir_common::rust_type_tokens(entry_ty, document_crate)?;
+            Ok(quote! { ctx.build::<#entry_ty>() })
+        }
+    }
+}
+
+/// Build a `Text` schema node carrying the `pattern` and `format` constraints of `text`.
+fn emit_text_node(text: &TextSchemaIr, schema_crate: &TokenStream) -> TokenStream {
+    let pattern = match &text.pattern {
+        Some(pattern) => quote! { Some(#pattern.parse().expect("valid `pattern` regex")) },
+        None => quote! { None },
+    };
+    let format = match &text.format {
+        Some(format) => quote! {
+            Some(#schema_crate::TextFormat::from_name(#format).expect("known text `format`"))
+        },
+        None => quote! { None },
+    };
+    quote! {
+        ctx.create_node(#schema_crate::SchemaNodeContent::Text(#schema_crate::TextSchema {
+            pattern: #pattern,
+            format: #format,
+            ..::core::default::Default::default()
+        }))
+    }
+}
+
+/// Wrap the node built by `some` in the `some | none` union that `Option<T>` builds.
+fn emit_option_node(some: TokenStream, schema_crate: &TokenStream) -> TokenStream {
+    quote! {
+        {
+            let some = #some;
+            let none = ctx.create_node(#schema_crate::SchemaNodeContent::Null);
+            ctx.create_node(#schema_crate::SchemaNodeContent::Union(#schema_crate::UnionSchema {
+                variants: [("some".to_string(), some), ("none".to_string(), none)]
+                    .into_iter()
+                    .collect(),
+                unambiguous: Default::default(),
+                interop: #schema_crate::interop::UnionInterop::default(),
+                deny_untagged: Default::default(),
+            }))
+        }
+    }
+}
+
+fn emit_integer_bound(bound: &BoundIr<DecimalInt>, schema_crate: &TokenStream) -> TokenStream {
+    match bound {
+        BoundIr::Unbounded => quote! { #schema_crate::Bound::Unbounded },
+        BoundIr::Inclusive(value) => {
+            let value = value.to_string();
+            quote! { #schema_crate::Bound::Inclusive(#value.parse().expect("valid integer bound")) }
+        }
+        BoundIr::Exclusive(value) => {
+            let value = value.to_string();
+            quote! { #schema_crate::Bound::Exclusive(#value.parse().expect("valid integer bound")) }
+        }
+    }
+}
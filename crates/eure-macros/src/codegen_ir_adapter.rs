@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use syn::spanned::Spanned;
 
 use crate::attrs::{
-    ContainerAttrs, DefaultValue, FieldAttrs, RenameAll, VariantAttrs,
+    ContainerAttrs, DefaultValue, FieldAttrs, MapEntryAttrs, RenameAll, VariantAttrs,
     extract_container_attr_spans, extract_eure_attr_spans, extract_variant_attr_spans,
 };
 use crate::config::MacroConfig;
@@ -16,13 +16,13 @@ use crate::ir_spans::DeriveSpanTable;
 
 use eure_codegen_ir::{
     ConstParamIr, ContainerAttrsIr, DefaultValueIr, FieldCodegenIr, FieldModeIr,
-    FieldSourceAttrsIr, IrBuildError, IrModule, LifetimeParamIr, MapImplTypeIr,
-    PrimitiveRustTypeIr, QualifiedTypeName, RecordFieldSchemaIr, RecordSchemaIr, RenameRuleIr,
-    RustBindingIr, RustFieldIr, RustGenericsIr, RustPathIr, RustTypeExprIr, RustTypeKindIr,
-    RustVariantIr, SchemaMetadataIr, SchemaNodeContentIr, SchemaNodeIr, SchemaNodeIrId,
-    TupleElementIr, TupleSchemaIr, TypeCodegenIr, TypeDefIr, TypeId, TypeNamesIr, TypeOriginIr,
-    TypeParamIr, UnionInteropIr, UnionSchemaIr, UnknownFieldsPolicyIr, VariantShapeIr,
-    WhereClauseIr, WrapperKindIr,
+    FieldSourceAttrsIr, IntegerSchemaIr, IrBuildError, IrModule, LifetimeParamIr, MapImplTypeIr,
+    MapSchemaIr, PrimitiveRustTypeIr, QualifiedTypeName, RecordFieldSchemaIr, RecordSchemaIr,
+    RenameRuleIr, RustBindingIr, RustFieldIr, RustGenericsIr, RustPathIr, RustTypeExprIr,
+    RustTypeKindIr, RustVariantIr, SchemaMetadataIr, SchemaNodeContentIr, SchemaNodeIr,
+    SchemaNodeIrId, TextSchemaIr, TupleElementIr, TupleSchemaIr, TypeCodegenIr, TypeDefIr, TypeId,
+    TypeNamesIr, TypeOriginIr, TypeParamIr, UnionInteropIr, UnionSchemaIr, UnknownFieldsPolicyIr,
+    VariantShapeIr, WhereClauseIr, WrapperKindIr,
 };
 
 #[derive(Debug, Clone)]
@@ -96,7 +96,13 @@ pub(crate) fn derive_input_to_ir_artifacts(
                         &common_field.ty,
                         &attrs,
                     );
-                    let node_id = alloc_any_node(&mut schema_nodes, &mut next_node);
+                    let node_id = alloc_field_node(
+                        &mut schema_nodes,
+                        &mut next_node,
+                        field,
+                        rust_field.ty(),
+                        &attrs,
+                    )?;
 
                     if matches!(
                         rust_field.mode(),
@@ -282,7 +288,13 @@ pub(crate) fn derive_input_to_ir_artifacts(
                                 &common_field.ty,
                                 &attrs,
                             );
-                            let node_id = alloc_any_node(&mut schema_nodes, &mut next_node);
+                            let node_id = alloc_field_node(
+                                &mut schema_nodes,
+                                &mut next_node,
+                                field,
+                                rust_field.ty(),
+                                &attrs,
+                            )?;
 
                             if matches!(
                                 rust_field.mode(),
@@ -669,6 +681,168 @@ fn alloc_any_node(
     alloc_schema_node(schema_nodes, next_node, schema_node_any())
 }
 
+/// Names accepted by `TextFormat::from_name` in eure-schema.
+const TEXT_FORMATS: &[&str] = &["email", "uri", "uuid", "date-time", "ipv4", "ipv6"];
+
+/// Allocate the schema node of a record field.
+///
+/// Map fields with `#[eure(key(...))]` or `#[eure(value(...))]` get a `Map` node whose
+/// key and value nodes carry the constraints, and `String` or `Option<String>` fields with
+/// `#[eure(format = "...")]` get a `Text` node with that format; every other field gets an `Any` node and takes its
+/// schema from the field type.
+fn alloc_field_node(
+    schema_nodes: &mut IndexMap<SchemaNodeIrId, SchemaNodeIr>,
+    next_node: &mut usize,
+    field: &syn::Field,
+    ty: &RustTypeExprIr,
+    attrs: &FieldAttrs,
+) -> syn::Result<SchemaNodeIrId> {
+    if let Some(format) = &attrs.format {
+        if attrs.key.is_some() || attrs.value.is_some() {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "`format` cannot be used together with `key(...)` or `value(...)`",
+            ));
+        }
+        let text_ty = match ty {
+            RustTypeExprIr::Option(inner) => inner.as_ref(),
+            ty => ty,
+        };
+        if !matches!(
+            text_ty,
+            RustTypeExprIr::Primitive(PrimitiveRustTypeIr::String)
+        ) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                "`format` requires a String or Option<String> type",
+            ));
+        }
+        if !TEXT_FORMATS.contains(&format.as_str()) {
+            return Err(syn::Error::new(
+                field.ty.span(),
+                format!(
+                    "unknown text `format` `{format}`, expected one of: {}",
+                    TEXT_FORMATS.join(", ")
+                ),
+            ));
+        }
+        return Ok(alloc_schema_node(
+            schema_nodes,
+            next_node,
+            SchemaNodeIr::new(
+                SchemaNodeContentIr::Text(TextSchemaIr {
+                    language: None,
+                    min_length: None,
+                    max_length: None,
+                    pattern: None,
+                    format: Some(format.clone()),
+                    unknown_fields: IndexMap::new(),
+                }),
+                SchemaMetadataIr::default(),
+                IndexMap::new(),
+            ),
+        ));
+    }
+    if attrs.key.is_none() && attrs.value.is_none() {
+        return Ok(alloc_any_node(schema_nodes, next_node));
+    }
+    let RustTypeExprIr::Map { key, value, .. } = ty else {
+        return Err(syn::Error::new(
+            field.ty.span(),
+            "`key(...)` and `value(...)` can only be used on HashMap, BTreeMap, or IndexMap fields",
+        ));
+    };
+    let key = map_entry_node(field, key, attrs.key.as_ref())?;
+    let value = map_entry_node(field, value, attrs.value.as_ref())?;
+    let key = alloc_schema_node(schema_nodes, next_node, key);
+    let value = alloc_schema_node(schema_nodes, next_node, value);
+    Ok(alloc_schema_node(
+        schema_nodes,
+        next_node,
+        SchemaNodeIr::new(
+            SchemaNodeContentIr::Map(MapSchemaIr {
+                key,
+                value,
+                min_size: None,
+                max_size: None,
+            }),
+            SchemaMetadataIr::default(),
+            IndexMap::new(),
+        ),
+    ))
+}
+
+/// Schema node for a map key or value, constrained by `attrs` when present.
+fn map_entry_node(
+    field: &syn::Field,
+    ty: &RustTypeExprIr,
+    attrs: Option<&MapEntryAttrs>,
+) -> syn::Result<SchemaNodeIr> {
+    let Some(attrs) = attrs else {
+        return Ok(schema_node_any());
+    };
+    let error = |message: String| syn::Error::new(field.ty.span(), message);
+    let content = match (
+        attrs.pattern.as_ref(),
+        attrs.integer_range().map_err(error)?,
+    ) {
+        (None, None) => SchemaNodeContentIr::Any,
+        (Some(pattern), None)
+            if matches!(ty, RustTypeExprIr::Primitive(PrimitiveRustTypeIr::String)) =>
+        {
+            regex::Regex::new(pattern)
+                .map_err(|err| error(format!("invalid `pattern` regex: {err}")))?;
+            SchemaNodeContentIr::Text(TextSchemaIr {
+                language: None,
+                min_length: None,
+                max_length: None,
+                pattern: Some(pattern.clone()),
+                format: None,
+                unknown_fields: IndexMap::new(),
+            })
+        }
+        (None, Some((min, max))) if is_integer_type(ty) => {
+            SchemaNodeContentIr::Integer(IntegerSchemaIr {
+                min,
+                max,
+                multiple_of: None,
+            })
+        }
+        (Some(_), None) => return Err(error("`pattern` requires a String type".to_string())),
+        (None, Some(_)) => return Err(error("`range` requires an integer type".to_string())),
+        (Some(_), Some(_)) => {
+            return Err(error(
+                "`pattern` and `range` cannot be used together".to_string(),
+            ));
+        }
+    };
+    Ok(SchemaNodeIr::new(
+        content,
+        SchemaMetadataIr::default(),
+        IndexMap::new(),
+    ))
+}
+
+fn is_integer_type(ty: &RustTypeExprIr) -> bool {
+    matches!(
+        ty,
+        RustTypeExprIr::Primitive(
+            PrimitiveRustTypeIr::I8
+                | PrimitiveRustTypeIr::I16
+                | PrimitiveRustTypeIr::I32
+                | PrimitiveRustTypeIr::I64
+                | PrimitiveRustTypeIr::I128
+                | PrimitiveRustTypeIr::Isize
+                | PrimitiveRustTypeIr::U8
+                | PrimitiveRustTypeIr::U16
+                | PrimitiveRustTypeIr::U32
+                | PrimitiveRustTypeIr::U64
+                | PrimitiveRustTypeIr::U128
+                | PrimitiveRustTypeIr::Usize
+        )
+    )
+}
+
 fn alloc_schema_node(
     schema_nodes: &mut IndexMap<SchemaNodeIrId, SchemaNodeIr>,
     next_node: &mut usize,
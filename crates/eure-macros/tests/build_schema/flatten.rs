@@ -1,7 +1,7 @@
 //! Test BuildSchema derive for flatten
 
 use eure::{BuildSchema, SchemaDocument};
-use eure_schema::SchemaNodeContent;
+use eure_schema::{SchemaDefect, SchemaKind, SchemaNodeContent};
 
 #[derive(BuildSchema)]
 struct Address {
@@ -117,3 +117,50 @@ fn test_nested_flatten() {
     assert!(level1.properties.contains_key("field_b"));
     assert_eq!(level1.flatten.len(), 1);
 }
+
+#[derive(BuildSchema)]
+struct Labels {
+    name: String,
+    #[eure(flatten)]
+    extra: indexmap::IndexMap<String, String>,
+}
+
+#[test]
+fn test_flatten_map() {
+    let schema = SchemaDocument::of::<Labels>();
+
+    let SchemaNodeContent::Record(record) = &schema.node(schema.root).content else {
+        panic!("Expected Record");
+    };
+
+    assert!(record.properties.contains_key("name"));
+    assert!(!record.properties.contains_key("extra"));
+    assert_eq!(record.flatten.len(), 1);
+    assert!(matches!(
+        schema.node(record.flatten[0]).content,
+        SchemaNodeContent::Map(_)
+    ));
+}
+
+#[derive(BuildSchema)]
+struct Counter {
+    name: String,
+    #[eure(flatten)]
+    count: i32,
+}
+
+#[test]
+fn test_flatten_non_object_is_a_defect() {
+    let schema = SchemaDocument::of::<Counter>();
+    let SchemaNodeContent::Record(record) = &schema.node(schema.root).content else {
+        panic!("Expected Record");
+    };
+    assert_eq!(
+        schema.validate_self(),
+        vec![SchemaDefect::InvalidFlattenTarget {
+            node_id: schema.root,
+            target: record.flatten[0],
+            kind: SchemaKind::Integer,
+        }]
+    );
+}
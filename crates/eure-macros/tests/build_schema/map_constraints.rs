@@ -0,0 +1,71 @@
+//! Test BuildSchema derive for map fields with key/value constraints
+
+use std::collections::{BTreeMap, HashMap};
+
+use eure::{BuildSchema, SchemaDocument};
+use eure_schema::{Bound, SchemaNodeContent, SchemaNodeId};
+
+fn map_entries(schema: &SchemaDocument, id: SchemaNodeId) -> (SchemaNodeId, SchemaNodeId) {
+    let SchemaNodeContent::Map(map) = &schema.node(id).content else {
+        panic!("Expected Map, got {:?}", schema.node(id).content);
+    };
+    (map.key, map.value)
+}
+
+#[derive(BuildSchema)]
+struct Ports {
+    #[eure(key(pattern = "^[a-z]+$"), value(range = "1..=8080"))]
+    ports: HashMap<String, u16>,
+    #[eure(value(range = "[0, 100)"))]
+    weights: BTreeMap<String, i32>,
+    plain: HashMap<String, u16>,
+}
+
+#[test]
+fn test_map_field_with_key_and_value_constraints() {
+    let schema = SchemaDocument::of::<Ports>();
+    let SchemaNodeContent::Record(record) = &schema.node(schema.root).content else {
+        panic!("Expected Record");
+    };
+
+    let (key, value) = map_entries(&schema, record.properties["ports"].schema);
+    let SchemaNodeContent::Text(text) = &schema.node(key).content else {
+        panic!("Expected Text key, got {:?}", schema.node(key).content);
+    };
+    assert_eq!(
+        text.pattern.as_ref().map(|pattern| pattern.as_str()),
+        Some("^[a-z]+$")
+    );
+    let SchemaNodeContent::Integer(integer) = &schema.node(value).content else {
+        panic!(
+            "Expected Integer value, got {:?}",
+            schema.node(value).content
+        );
+    };
+    assert_eq!(integer.min, Bound::Inclusive(1.into()));
+    assert_eq!(integer.max, Bound::Inclusive(8080.into()));
+
+    let (key, value) = map_entries(&schema, record.properties["weights"].schema);
+    assert!(matches!(
+        &schema.node(key).content,
+        SchemaNodeContent::Text(text) if text.pattern.is_none()
+    ));
+    let SchemaNodeContent::Integer(integer) = &schema.node(value).content else {
+        panic!(
+            "Expected Integer value, got {:?}",
+            schema.node(value).content
+        );
+    };
+    assert_eq!(integer.min, Bound::Inclusive(0.into()));
+    assert_eq!(integer.max, Bound::Exclusive(100.into()));
+
+    // Without constraints the field keeps the schema of its type.
+    let (_, value) = map_entries(&schema, record.properties["plain"].schema);
+    let SchemaNodeContent::Integer(integer) = &schema.node(value).content else {
+        panic!(
+            "Expected Integer value, got {:?}",
+            schema.node(value).content
+        );
+    };
+    assert_eq!(integer.max, Bound::Unbounded);
+}
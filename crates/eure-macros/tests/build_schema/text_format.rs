@@ -0,0 +1,48 @@
+//! Test BuildSchema derive for text fields with a `format` constraint
+
+use eure::{BuildSchema, SchemaDocument};
+use eure_schema::{SchemaNodeContent, TextFormat};
+
+#[derive(BuildSchema)]
+struct Contact {
+    #[eure(format = "email")]
+    email: String,
+    #[eure(format = "uuid")]
+    id: String,
+    name: String,
+    #[eure(format = "uri")]
+    homepage: Option<String>,
+}
+
+#[test]
+fn test_text_field_with_format() {
+    let schema = SchemaDocument::of::<Contact>();
+    let SchemaNodeContent::Record(record) = &schema.node(schema.root).content else {
+        panic!("Expected Record");
+    };
+
+    let format = |field: &str| {
+        let id = record.properties[field].schema;
+        let SchemaNodeContent::Text(text) = &schema.node(id).content else {
+            panic!("Expected Text, got {:?}", schema.node(id).content);
+        };
+        text.format
+    };
+    assert_eq!(format("email"), Some(TextFormat::Email));
+    assert_eq!(format("id"), Some(TextFormat::Uuid));
+    assert_eq!(format("name"), None);
+
+    let homepage = &record.properties["homepage"];
+    assert!(homepage.optional);
+    let SchemaNodeContent::Union(union) = &schema.node(homepage.schema).content else {
+        panic!("Expected Union, got {:?}", schema.node(homepage.schema).content);
+    };
+    let SchemaNodeContent::Text(text) = &schema.node(union.variants["some"]).content else {
+        panic!("Expected Text");
+    };
+    assert_eq!(text.format, Some(TextFormat::Uri));
+    assert_eq!(
+        schema.node(union.variants["none"]).content,
+        SchemaNodeContent::Null
+    );
+}
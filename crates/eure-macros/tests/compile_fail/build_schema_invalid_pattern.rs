@@ -0,0 +1,11 @@
+use std::collections::HashMap;
+
+use eure::BuildSchema;
+
+#[derive(BuildSchema)]
+struct Ports {
+    #[eure(key(pattern = "[a-z"))]
+    ports: HashMap<String, u16>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use eure::BuildSchema;
+
+#[derive(BuildSchema)]
+struct Contact {
+    #[eure(format = "phone")]
+    phone: String,
+}
+
+fn main() {}
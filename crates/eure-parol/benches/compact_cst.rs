@@ -0,0 +1,86 @@
+//! Memory comparison between a freshly built CST and its compacted form.
+//!
+//! Parses a large generated document and reports the heap held by the
+//! hash-map based [ConcreteSyntaxTree] and by the [CompactCst] it converts to.
+//!
+//! Run with: cargo bench -p eure-parol --bench compact_cst
+//!
+//! [ConcreteSyntaxTree]: eure_tree::tree::ConcreteSyntaxTree
+//! [CompactCst]: eure_tree::tree::CompactCst
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use eure_parol::parse;
+
+/// Tracks the number of live heap bytes.
+struct CountingAlloc;
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        LIVE_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        LIVE_BYTES.fetch_add(new_size, Ordering::Relaxed);
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+fn live_bytes() -> usize {
+    LIVE_BYTES.load(Ordering::Relaxed)
+}
+
+/// A document with `sections` sections of mixed bindings.
+fn large_input(sections: usize) -> String {
+    let mut input = String::new();
+    for i in 0..sections {
+        input.push_str(&format!("@ section{i}\n"));
+        input.push_str(&format!("name = \"item {i}\"\n"));
+        input.push_str(&format!("count = {i}\n"));
+        input.push_str("enabled = true\n");
+        input.push_str(&format!("tags = [\"a{i}\", \"b{i}\", \"c{i}\"]\n"));
+        input.push_str(&format!("nested.value = {{ x => {i}, y => \"{i}\" }}\n\n"));
+    }
+    input
+}
+
+fn main() {
+    for sections in [1_000, 10_000] {
+        let input = large_input(sections);
+
+        let before = live_bytes();
+        let cst = black_box(parse(&input, "bench.eure").expect("generated input should parse"));
+        let cst_bytes = live_bytes() - before;
+        drop(cst);
+
+        let before = live_bytes();
+        let compact = black_box(
+            parse(&input, "bench.eure")
+                .expect("generated input should parse")
+                .into_compact(),
+        );
+        let compact_bytes = live_bytes() - before;
+        println!(
+            "{sections:>6} sections ({} KiB input, {} nodes): Cst {} KiB, CompactCst {} KiB ({:.1}%)",
+            input.len() / 1024,
+            compact.len(),
+            cst_bytes / 1024,
+            compact_bytes / 1024,
+            compact_bytes as f64 * 100.0 / cst_bytes as f64,
+        );
+    }
+}
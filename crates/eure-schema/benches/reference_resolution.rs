@@ -0,0 +1,103 @@
+//! Benchmarks comparing type reference resolution by name against interned ids.
+//!
+//! The schema is a chain of named types `t0 <- t1 <- ... <- tN`, where `t0` is
+//! an integer and every other type references the previous one:
+//! 1. By name: each hop hashes the referenced type name (before interning)
+//! 2. Interned: each hop follows the position stored by `intern_references`
+//!
+//! Run with: cargo bench -p eure-schema
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use eure_document::document::EureDocument;
+use eure_document::document::node::NodeValue;
+use eure_document::value::PrimitiveValue;
+use eure_schema::validate::validate;
+use eure_schema::{
+    ArraySchema, IntegerSchema, SchemaDocument, SchemaNodeContent, SchemaNodeId, TypeReference,
+};
+
+/// Build a reference chain of `depth` types whose root is an array of the last one.
+fn chain_schema(depth: usize) -> (SchemaDocument, SchemaNodeId) {
+    let mut schema = SchemaDocument::new();
+    let base = schema.create_node(SchemaNodeContent::Integer(IntegerSchema::default()));
+    schema.register_type("t0".parse().unwrap(), base);
+    for i in 1..depth {
+        let reference = schema.create_node(SchemaNodeContent::Reference(TypeReference {
+            namespace: None,
+            name: format!("t{}", i - 1).parse().unwrap(),
+        }));
+        schema.register_type(format!("t{i}").parse().unwrap(), reference);
+    }
+    let item = schema.create_node(SchemaNodeContent::Reference(TypeReference {
+        namespace: None,
+        name: format!("t{}", depth - 1).parse().unwrap(),
+    }));
+    schema.root = schema.create_node(SchemaNodeContent::Array(ArraySchema {
+        item,
+        min_length: None,
+        max_length: None,
+        unique: false,
+        contains: None,
+        binding_style: None,
+    }));
+    (schema, item)
+}
+
+fn integer_array(len: usize) -> EureDocument {
+    let mut doc = EureDocument::new();
+    let root_id = doc.get_root_id();
+    doc.node_mut(root_id).content = NodeValue::empty_array();
+    for i in 0..len {
+        let child = doc.add_array_element(None, root_id).unwrap().node_id;
+        doc.node_mut(child).content = NodeValue::Primitive(PrimitiveValue::Integer(i.into()));
+    }
+    doc
+}
+
+/// Follow references from `id` until a non-reference node.
+fn resolve_chain(schema: &SchemaDocument, mut id: SchemaNodeId) -> SchemaNodeId {
+    while let Some(next) = schema.resolve_reference(id) {
+        id = next;
+    }
+    id
+}
+
+fn bench_resolve(c: &mut Criterion) {
+    let mut group = c.benchmark_group("resolve_chain");
+    for depth in [8, 64] {
+        let (by_name, item) = chain_schema(depth);
+        let mut interned = by_name.clone();
+        interned.intern_references();
+
+        group.bench_with_input(BenchmarkId::new("by_name", depth), &by_name, |b, schema| {
+            b.iter(|| resolve_chain(black_box(schema), item))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("interned", depth),
+            &interned,
+            |b, schema| b.iter(|| resolve_chain(black_box(schema), item)),
+        );
+    }
+    group.finish();
+}
+
+fn bench_validate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("validate_references");
+    let (by_name, _) = chain_schema(16);
+    let mut interned = by_name.clone();
+    interned.intern_references();
+    let doc = integer_array(1000);
+
+    group.bench_function("by_name", |b| {
+        b.iter(|| validate(black_box(&doc), black_box(&by_name)))
+    });
+    group.bench_function("interned", |b| {
+        b.iter(|| validate(black_box(&doc), black_box(&interned)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_resolve, bench_validate);
+criterion_main!(benches);
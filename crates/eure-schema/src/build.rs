@@ -115,6 +115,7 @@ impl SchemaBuilder {
                 types: Default::default(),
                 root_codegen: RootCodegen::default(),
                 codegen_defaults: CodegenDefaults::default(),
+                ref_targets: Vec::new(),
             },
             cache: HashMap::new(),
         }
@@ -244,6 +245,7 @@ impl SchemaBuilder {
     /// Consume the builder and produce the final schema document.
     pub fn finish(mut self, root: SchemaNodeId) -> SchemaDocument {
         self.doc.root = root;
+        self.doc.intern_references();
         self.doc
     }
 }
@@ -344,6 +346,7 @@ impl BuildSchema for Text {
             min_length: None,
             max_length: None,
             pattern: None,
+            format: None,
             unknown_fields: IndexMap::new(),
         })
     }
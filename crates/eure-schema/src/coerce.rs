@@ -0,0 +1,231 @@
+//! Schema-driven coercion of primitive values for `FromEure` parsing.
+//!
+//! Parsing is strict by default: a field typed as an integer fails to parse
+//! from the text `"8080"`, and `` `...` `` text keeps [`Language::Implicit`].
+//! [`schema_coercions`] walks a document alongside its schema and collects
+//! replacement values that a [`ParseContext`] applies once attached with
+//! [`ParseContext::with_coercions`]:
+//!
+//! - Implicit-language text becomes text in the schema's language.
+//! - Text that parses as a number becomes an integer or float where the
+//!   schema expects one.
+//!
+//! Union variants are not followed, since choosing a variant requires full
+//! validation.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let coercions = schema_coercions(&doc, &schema);
+//! let config: Config = doc
+//!     .parse_context(doc.get_root_id())
+//!     .with_coercions(&coercions)
+//!     .parse()?;
+//! // Nodes whose values were coerced while parsing
+//! let coerced = coercions.applied();
+//! ```
+//!
+//! [`ParseContext`]: eure_document::parse::ParseContext
+//! [`ParseContext::with_coercions`]: eure_document::parse::ParseContext::with_coercions
+
+use eure_document::document::node::NodeValue;
+use eure_document::document::{EureDocument, NodeId};
+use eure_document::parse::Coercions;
+use eure_document::text::{Language, Text};
+use eure_document::value::{ObjectKey, PrimitiveValue};
+use num_bigint::BigInt;
+
+use crate::{FloatPrecision, SchemaDocument, SchemaNodeContent, SchemaNodeId};
+
+/// Collect the coercions `schema` allows for the values in `doc`.
+pub fn schema_coercions(doc: &EureDocument, schema: &SchemaDocument) -> Coercions {
+    let mut coercions = Coercions::new();
+    collect(doc, doc.get_root_id(), schema, schema.root, &mut coercions);
+    coercions
+}
+
+fn collect(
+    doc: &EureDocument,
+    node_id: NodeId,
+    schema: &SchemaDocument,
+    schema_id: SchemaNodeId,
+    coercions: &mut Coercions,
+) {
+    let Some(schema_id) = resolve(schema, schema_id) else {
+        return;
+    };
+    match (&schema.node(schema_id).content, &doc.node(node_id).content) {
+        (SchemaNodeContent::Record(record), NodeValue::Map(map)) => {
+            for (name, field) in &record.properties {
+                if let Some(&child) = map.get(&ObjectKey::String(name.clone())) {
+                    collect(doc, child, schema, field.schema, coercions);
+                }
+            }
+        }
+        (SchemaNodeContent::Map(map_schema), NodeValue::Map(map)) => {
+            for (_, &child) in map.iter() {
+                collect(doc, child, schema, map_schema.value, coercions);
+            }
+        }
+        (SchemaNodeContent::Array(array), NodeValue::Array(items)) => {
+            for &child in items.iter() {
+                collect(doc, child, schema, array.item, coercions);
+            }
+        }
+        (SchemaNodeContent::Tuple(tuple), NodeValue::Tuple(items)) => {
+            for (&element, &child) in tuple.elements.iter().zip(items.iter()) {
+                collect(doc, child, schema, element, coercions);
+            }
+        }
+        (content, NodeValue::Primitive(PrimitiveValue::Text(text))) => {
+            if let Some(value) = coerce_text(content, text) {
+                coercions.insert(node_id, value);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Follow local type references to the referenced schema node.
+fn resolve(schema: &SchemaDocument, mut schema_id: SchemaNodeId) -> Option<SchemaNodeId> {
+    // Bounded by the number of type definitions to stop on reference cycles.
+    for _ in 0..=schema.types.len() {
+        match &schema.node(schema_id).content {
+            SchemaNodeContent::Reference(reference) if reference.namespace.is_none() => {
+                schema_id = schema.get_type(&reference.name)?;
+            }
+            SchemaNodeContent::Reference(_) => return None,
+            _ => return Some(schema_id),
+        }
+    }
+    None
+}
+
+fn coerce_text(content: &SchemaNodeContent, text: &Text) -> Option<PrimitiveValue> {
+    match content {
+        SchemaNodeContent::Text(text_schema) => {
+            let language = text_schema.language.as_ref()?;
+            (text.language == Language::Implicit).then(|| {
+                PrimitiveValue::Text(Text {
+                    language: Language::new(language.clone()),
+                    ..text.clone()
+                })
+            })
+        }
+        SchemaNodeContent::Integer(_) => text
+            .as_str()
+            .trim()
+            .parse::<BigInt>()
+            .ok()
+            .map(PrimitiveValue::Integer),
+        SchemaNodeContent::Float(float) => {
+            let value = text.as_str().trim().parse::<f64>().ok()?;
+            Some(match float.precision {
+                FloatPrecision::F32 => PrimitiveValue::F32(value as f32),
+                FloatPrecision::F64 => PrimitiveValue::F64(value),
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntegerSchema, RecordFieldSchema, RecordSchema, TextSchema};
+    use eure_document::eure;
+    use eure_document::parse::{ParseContext, ParseError};
+    use indexmap::IndexMap;
+
+    fn record_schema(fields: Vec<(&str, SchemaNodeContent)>) -> SchemaDocument {
+        let mut schema = SchemaDocument::new();
+        let mut properties = IndexMap::new();
+        for (name, content) in fields {
+            let node = schema.create_node(content);
+            properties.insert(
+                name.to_string(),
+                RecordFieldSchema {
+                    schema: node,
+                    optional: false,
+                    binding_style: None,
+                    field_codegen: Default::default(),
+                },
+            );
+        }
+        schema.root = schema.create_node(SchemaNodeContent::Record(RecordSchema {
+            properties,
+            ..Default::default()
+        }));
+        schema
+    }
+
+    fn field<'doc, T>(ctx: &ParseContext<'doc>, name: &str) -> Result<T, ParseError>
+    where
+        T: eure_document::parse::FromEure<'doc, Error = ParseError>,
+    {
+        ctx.parse_record()?.parse_field(name)
+    }
+
+    #[test]
+    fn test_implicit_text_takes_schema_language() {
+        let doc = eure!({ query = @code("SELECT 1") });
+        let schema = record_schema(vec![(
+            "query",
+            SchemaNodeContent::Text(TextSchema {
+                language: Some("sql".to_string()),
+                ..Default::default()
+            }),
+        )]);
+        let coercions = schema_coercions(&doc, &schema);
+
+        let strict = doc.parse_context(doc.get_root_id());
+        let text: Text = field(&strict, "query").unwrap();
+        assert_eq!(text.language, Language::Implicit);
+        assert!(coercions.applied().is_empty());
+
+        let ctx = doc
+            .parse_context(doc.get_root_id())
+            .with_coercions(&coercions);
+        let text: Text = field(&ctx, "query").unwrap();
+        assert_eq!(text.language, Language::new("sql"));
+        assert_eq!(text.as_str(), "SELECT 1");
+        assert_eq!(coercions.applied().len(), 1);
+    }
+
+    #[test]
+    fn test_string_to_integer_only_with_coercions() {
+        let doc = eure!({ port = "8080" });
+        let schema = record_schema(vec![(
+            "port",
+            SchemaNodeContent::Integer(IntegerSchema::default()),
+        )]);
+        let coercions = schema_coercions(&doc, &schema);
+
+        let strict = doc.parse_context(doc.get_root_id());
+        assert!(field::<u16>(&strict, "port").is_err());
+
+        let ctx = doc
+            .parse_context(doc.get_root_id())
+            .with_coercions(&coercions);
+        assert_eq!(field::<u16>(&ctx, "port").unwrap(), 8080);
+
+        let port_node = doc
+            .parse_context(doc.get_root_id())
+            .parse_record()
+            .unwrap()
+            .field("port")
+            .unwrap()
+            .node_id();
+        assert_eq!(coercions.applied(), vec![port_node]);
+    }
+
+    #[test]
+    fn test_non_numeric_text_is_not_coerced() {
+        let doc = eure!({ port = "http" });
+        let schema = record_schema(vec![(
+            "port",
+            SchemaNodeContent::Integer(IntegerSchema::default()),
+        )]);
+        assert!(schema_coercions(&doc, &schema).is_empty());
+    }
+}
@@ -57,7 +57,7 @@ use crate::{
 use eure_document::document::node::{Node, NodeValue};
 use eure_document::document::{EureDocument, InsertErrorKind, NodeId};
 use eure_document::identifier::Identifier;
-use eure_document::parse::ParseError;
+use eure_document::parse::{ParseError, ParseErrorKind};
 use eure_document::path::{ArrayIndexKind, EurePath, PathSegment};
 use eure_document::value::{ObjectKey, ValueKind};
 use indexmap::IndexMap;
@@ -99,8 +99,37 @@ pub enum ConversionError {
         schema_kind: String,
     },
 
+    #[error("Invalid pattern '{pattern}' at node {node_id:?}: {source}")]
+    InvalidPattern {
+        node_id: NodeId,
+        pattern: String,
+        #[source]
+        source: regex::Error,
+    },
+
     #[error("Parse error: {0}")]
-    ParseError(#[from] ParseError),
+    ParseError(#[source] ParseError),
+}
+
+impl From<ParseError> for ConversionError {
+    fn from(error: ParseError) -> Self {
+        match error.kind {
+            // Surface regex compilation failures with the node of the pattern value,
+            // so reporters can point at the exact `pattern = "..."` location.
+            ParseErrorKind::InvalidRegex {
+                pattern,
+                error: source,
+            } => ConversionError::InvalidPattern {
+                node_id: error.node_id,
+                pattern,
+                source,
+            },
+            kind => ConversionError::ParseError(ParseError {
+                node_id: error.node_id,
+                kind,
+            }),
+        }
+    }
 }
 
 /// Mapping from schema node IDs to their source document node IDs.
@@ -142,6 +171,7 @@ impl<'a> Converter<'a> {
         // Validate all type references exist
         self.validate_type_references()?;
         self.validate_non_productive_reference_cycles()?;
+        self.schema.intern_references();
 
         Ok((self.schema, self.source_map))
     }
@@ -1505,4 +1535,38 @@ mod tests {
             other => panic!("expected NonProductiveReferenceCycle, got {:?}", other),
         }
     }
+
+    #[test]
+    fn interned_references_follow_later_type_edits() {
+        let doc = eure!({
+            %types.a = @code("integer")
+            %types.b = @code("text")
+            data = @code("$types.a")
+        });
+        let (mut schema, _) = document_to_schema(&doc).unwrap();
+        let SchemaNodeContent::Record(record) = &schema.node(schema.root).content else {
+            panic!("expected record root");
+        };
+        let data = record.properties["data"].schema;
+        let a = schema.get_type(&"a".parse().unwrap()).unwrap();
+        let b = schema.get_type(&"b".parse().unwrap()).unwrap();
+        assert_eq!(schema.resolve_reference(data), Some(a));
+
+        // Interning is not observable through equality
+        let mut interned = schema.clone();
+        interned.intern_references();
+        assert_eq!(interned, schema);
+
+        // Retargeting the type, or the reference itself, is seen without re-interning
+        schema.types.insert("a".parse().unwrap(), b);
+        assert_eq!(schema.resolve_reference(data), Some(b));
+        schema.node_mut(data).content = SchemaNodeContent::Reference(crate::TypeReference {
+            namespace: None,
+            name: "b".parse().unwrap(),
+        });
+        schema
+            .types
+            .shift_remove(&"a".parse::<Identifier>().unwrap());
+        assert_eq!(schema.resolve_reference(data), Some(b));
+    }
 }
@@ -30,10 +30,14 @@
 
 pub mod build;
 pub mod codegen;
+pub mod coerce;
 pub mod convert;
 pub mod identifiers;
 pub mod interop;
+pub mod lint;
 pub mod parse;
+pub mod range;
+pub mod self_check;
 pub mod synth;
 pub mod type_path_trace;
 pub mod validate;
@@ -43,6 +47,7 @@ pub use build::{BuildSchema, SchemaBuilder, SchemaNodeSpec};
 pub use codegen::{
     CodegenDefaults, FieldCodegen, RecordCodegen, RootCodegen, TypeCodegen, UnionCodegen,
 };
+pub use self_check::SchemaDefect;
 
 use eure_document::Text;
 use eure_document::constructor::DocumentConstructor;
@@ -62,7 +67,10 @@ use crate::interop::UnionInterop;
 // ============================================================================
 
 /// Schema document with arena-based node storage
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Build one with [`SchemaDocument::new`] or [`Default`]: the interned
+/// reference cache is private.
+#[derive(Debug, Clone)]
 pub struct SchemaDocument {
     /// All schema nodes stored in a flat vector
     pub nodes: Vec<SchemaNode>,
@@ -74,6 +82,24 @@ pub struct SchemaDocument {
     pub root_codegen: RootCodegen,
     /// Root-level default codegen settings from `$codegen-defaults`.
     pub codegen_defaults: CodegenDefaults,
+    /// Index into `types` of the type each local reference names, indexed by
+    /// `SchemaNodeId`.
+    ///
+    /// Filled by [`SchemaDocument::intern_references`]. A cached index is only
+    /// used while the entry there still has the referenced name, so editing
+    /// `types` or `nodes` directly never yields a stale target.
+    ref_targets: Vec<Option<usize>>,
+}
+
+/// Compares the schema itself, ignoring whether references were interned.
+impl PartialEq for SchemaDocument {
+    fn eq(&self, other: &Self) -> bool {
+        self.nodes == other.nodes
+            && self.root == other.root
+            && self.types == other.types
+            && self.root_codegen == other.root_codegen
+            && self.codegen_defaults == other.codegen_defaults
+    }
 }
 
 /// Extension type definition with optionality
@@ -268,12 +294,16 @@ pub enum Bound<T> {
 /// - `.text` - any text (language=None)
 /// - `.text.X` - text with language X (e.g., `.text.rust`, `.text.email`)
 ///
+/// When X names a [`TextFormat`] (e.g. `email`, `uuid`), the shorthand also
+/// sets `format`, so the content is checked against that format.
+///
 /// # Validation Rules
 ///
 /// When validating a `Text` value against a `TextSchema`:
 /// - `Language::Plaintext` matches schema with `language=None` or `language=Some("plaintext")`
 /// - `Language::Implicit` matches any schema (the schema's language is applied)
 /// - `Language::Other(lang)` matches schema with `language=None` or `language=Some(lang)`
+/// - With `format` set, the content must be a valid instance of the format
 ///
 /// ```eure
 /// @variants.text
@@ -281,6 +311,7 @@ pub enum Bound<T> {
 /// min-length = .integer (optional)
 /// max-length = .integer (optional)
 /// pattern = .text (optional)
+/// format = "email" | "uri" | "uuid" | "date-time" | "ipv4" | "ipv6" (optional)
 /// ```
 #[derive(Debug, Clone, Default, FromEure, IntoEure)]
 #[eure(crate = eure_document, rename_all = "kebab-case", allow_unknown_fields, allow_unknown_extensions)]
@@ -304,6 +335,9 @@ pub struct TextSchema {
     /// Pre-compiled at schema parse time for efficiency.
     #[eure(default)]
     pub pattern: Option<Regex>,
+    /// Format constraint (applied to the text content)
+    #[eure(default)]
+    pub format: Option<TextFormat>,
     /// Unknown fields (for future extensions like "flatten")
     #[eure(flatten)]
     pub unknown_fields: IndexMap<String, EureDocument>,
@@ -318,9 +352,13 @@ impl TextSchema {
                 min_length: None,
                 max_length: None,
                 pattern: None,
+                format: _,
                 unknown_fields: _
             }
         ) && self.unknown_fields.is_empty()
+            && self
+                .format
+                .is_none_or(|format| self.language.as_deref() == Some(format.as_str()))
     }
     pub fn shorthand(&self) -> Option<Text> {
         self.is_shorthand_compatible().then(|| {
@@ -349,6 +387,7 @@ impl PartialEq for TextSchema {
         self.language == other.language
             && self.min_length == other.min_length
             && self.max_length == other.max_length
+            && self.format == other.format
             && self.unknown_fields == other.unknown_fields
             && match (&self.pattern, &other.pattern) {
                 (None, None) => true,
@@ -358,6 +397,66 @@ impl PartialEq for TextSchema {
     }
 }
 
+/// Well-known text formats checked by [`TextSchema::format`].
+///
+/// Names follow the JSON Schema `format` keyword.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextFormat {
+    /// `email` - an email address (`local@domain`)
+    Email,
+    /// `uri` - an absolute URI with a scheme (`https://...`)
+    Uri,
+    /// `uuid` - a hyphenated UUID
+    Uuid,
+    /// `date-time` - an RFC 3339 timestamp
+    DateTime,
+    /// `ipv4` - an IPv4 address
+    Ipv4,
+    /// `ipv6` - an IPv6 address
+    Ipv6,
+}
+
+impl TextFormat {
+    /// The format's name as used in `.text.X` shorthands and JSON Schema.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Email => "email",
+            Self::Uri => "uri",
+            Self::Uuid => "uuid",
+            Self::DateTime => "date-time",
+            Self::Ipv4 => "ipv4",
+            Self::Ipv6 => "ipv6",
+        }
+    }
+
+    /// Look up a format by name, returning `None` for unknown names.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "email" => Some(Self::Email),
+            "uri" => Some(Self::Uri),
+            "uuid" => Some(Self::Uuid),
+            "date-time" => Some(Self::DateTime),
+            "ipv4" => Some(Self::Ipv4),
+            "ipv6" => Some(Self::Ipv6),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TextFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl IntoEure for TextFormat {
+    type Error = WriteError;
+
+    fn write(value: Self, c: &mut DocumentConstructor) -> Result<(), Self::Error> {
+        c.write(value.as_str())
+    }
+}
+
 /// Integer type constraints
 ///
 /// Spec: lines 360-364
@@ -660,6 +759,7 @@ impl SchemaDocument {
             types: IndexMap::new(),
             root_codegen: RootCodegen::default(),
             codegen_defaults: CodegenDefaults::default(),
+            ref_targets: Vec::new(),
         }
     }
 
@@ -694,6 +794,47 @@ impl SchemaDocument {
     pub fn get_type(&self, name: &Identifier) -> Option<SchemaNodeId> {
         self.types.get(name).copied()
     }
+
+    /// Resolve every local reference node to the position of the type it
+    /// names, once, so that [`Self::resolve_reference`] compares names instead
+    /// of hashing them.
+    ///
+    /// Schemas produced by `document_to_schema` and `SchemaBuilder` are
+    /// already interned. Interning is only an optimization: references added
+    /// or retargeted afterwards are still resolved, by name.
+    pub fn intern_references(&mut self) {
+        self.ref_targets = self
+            .nodes
+            .iter()
+            .map(|node| match &node.content {
+                SchemaNodeContent::Reference(type_ref) if type_ref.namespace.is_none() => {
+                    self.types.get_index_of(&type_ref.name)
+                }
+                _ => None,
+            })
+            .collect();
+    }
+
+    /// The node of the type named by the local reference node `id`.
+    ///
+    /// Returns `None` if `id` is not a reference, is a cross-schema reference,
+    /// or names an undefined type. Uses the interned position when it still
+    /// holds the referenced name and looks the name up otherwise.
+    pub fn resolve_reference(&self, id: SchemaNodeId) -> Option<SchemaNodeId> {
+        let SchemaNodeContent::Reference(type_ref) = &self.node(id).content else {
+            return None;
+        };
+        if type_ref.namespace.is_some() {
+            return None;
+        }
+        if let Some(&Some(index)) = self.ref_targets.get(id.0)
+            && let Some((name, &target)) = self.types.get_index(index)
+            && *name == type_ref.name
+        {
+            return Some(target);
+        }
+        self.get_type(&type_ref.name)
+    }
 }
 
 impl Default for SchemaDocument {
@@ -0,0 +1,151 @@
+//! Non-fatal findings about a document that validates against its schema.
+//!
+//! Lints are derived from [`ValidationWarning`]s and carry enough context
+//! (document node, path, schema message) for editors to show them as
+//! warnings.
+
+use eure_document::document::NodeId;
+use eure_document::path::EurePath;
+
+use crate::validate::ValidationWarning;
+use crate::{Description, SchemaDocument};
+
+/// A lint found in a document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Lint {
+    /// The document sets a field whose schema is marked `$deprecated`.
+    Deprecated {
+        /// Name of the deprecated field.
+        field: String,
+        /// Path of the record containing the field.
+        path: EurePath,
+        /// The record node containing the field.
+        node_id: NodeId,
+        /// The field schema's description, if any.
+        message: Option<String>,
+    },
+}
+
+impl Lint {
+    /// Build a lint from a validation warning, if the warning has one.
+    pub fn from_warning(warning: &ValidationWarning, schema: &SchemaDocument) -> Option<Self> {
+        match warning {
+            ValidationWarning::DeprecatedField {
+                field,
+                path,
+                node_id,
+                schema_node_id,
+            } => {
+                let message = schema
+                    .node(*schema_node_id)
+                    .metadata
+                    .description
+                    .as_ref()
+                    .map(|description| match description {
+                        Description::String(text) | Description::Markdown(text) => text.clone(),
+                    });
+                Some(Lint::Deprecated {
+                    field: field.clone(),
+                    path: path.clone(),
+                    node_id: *node_id,
+                    message,
+                })
+            }
+            ValidationWarning::UnknownExtension { .. } => None,
+        }
+    }
+
+    /// Stable code for this kind of lint.
+    ///
+    /// Lint codes live in the `E2xxx` range.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Lint::Deprecated { .. } => "E2001",
+        }
+    }
+
+    /// The document node the lint is attached to.
+    pub fn node_id(&self) -> NodeId {
+        match self {
+            Lint::Deprecated { node_id, .. } => *node_id,
+        }
+    }
+}
+
+impl core::fmt::Display for Lint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Lint::Deprecated {
+                field,
+                message: Some(message),
+                ..
+            } => write!(f, "field `{field}` is deprecated: {message}"),
+            Lint::Deprecated { field, .. } => write!(f, "field `{field}` is deprecated"),
+        }
+    }
+}
+
+/// Collect the lints among the warnings of a validation pass.
+pub fn collect_lints(warnings: &[ValidationWarning], schema: &SchemaDocument) -> Vec<Lint> {
+    warnings
+        .iter()
+        .filter_map(|warning| Lint::from_warning(warning, schema))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate::validate;
+    use crate::{
+        FieldCodegen, RecordFieldSchema, RecordSchema, SchemaNodeContent, TextSchema,
+        UnknownFieldsPolicy,
+    };
+    use eure_document::document::EureDocument;
+    use eure_document::eure;
+    use indexmap::IndexMap;
+
+    #[test]
+    fn test_collect_deprecated_field() {
+        let mut schema = SchemaDocument::new();
+        let host = schema.create_node(SchemaNodeContent::Text(TextSchema::default()));
+        let addr = schema.create_node(SchemaNodeContent::Text(TextSchema::default()));
+        schema.node_mut(addr).metadata.deprecated = true;
+        schema.node_mut(addr).metadata.description =
+            Some(Description::String("use `host` instead".to_string()));
+
+        let field = |schema| RecordFieldSchema {
+            schema,
+            optional: true,
+            binding_style: None,
+            field_codegen: FieldCodegen::default(),
+        };
+        schema.node_mut(schema.root).content = SchemaNodeContent::Record(RecordSchema {
+            properties: IndexMap::from([
+                ("host".to_string(), field(host)),
+                ("addr".to_string(), field(addr)),
+            ]),
+            flatten: vec![],
+            unknown_fields: UnknownFieldsPolicy::Deny,
+        });
+        let lints = |doc: &EureDocument| collect_lints(&validate(doc, &schema).warnings, &schema);
+
+        assert_eq!(lints(&eure!({ host = "localhost" })), vec![]);
+
+        let doc = eure!({ addr = "localhost" });
+        let lints = lints(&doc);
+        assert_eq!(
+            lints,
+            vec![Lint::Deprecated {
+                field: "addr".to_string(),
+                path: EurePath::root(),
+                node_id: doc.get_root_id(),
+                message: Some("use `host` instead".to_string()),
+            }]
+        );
+        assert_eq!(
+            lints[0].to_string(),
+            "field `addr` is deprecated: use `host` instead"
+        );
+    }
+}
@@ -25,7 +25,19 @@ use indexmap::{IndexMap, IndexSet};
 use num_bigint::BigInt;
 
 use crate::interop::UnionInterop;
-use crate::{BindingStyle, Description, FieldCodegen, TextSchema, TypeReference};
+use crate::{BindingStyle, Description, FieldCodegen, TextFormat, TextSchema, TypeReference};
+
+impl FromEure<'_> for TextFormat {
+    type Error = ParseError;
+
+    fn parse(ctx: &ParseContext<'_>) -> Result<Self, Self::Error> {
+        let name: &str = ctx.parse()?;
+        TextFormat::from_name(name).ok_or_else(|| ParseError {
+            node_id: ctx.node_id(),
+            kind: ParseErrorKind::UnknownVariant(name.to_string()),
+        })
+    }
+}
 
 impl FromEure<'_> for TypeReference {
     type Error = ParseError;
@@ -510,6 +522,7 @@ fn parse_type_reference_string(
         // Text with language: text.rust, text.email, etc.
         ["text", lang] => Ok(ParsedSchemaNodeContent::Text(TextSchema {
             language: Some((*lang).to_string()),
+            format: TextFormat::from_name(lang),
             ..Default::default()
         })),
 
@@ -0,0 +1,108 @@
+//! Shared rendering of numeric [`Bound`] pairs.
+//!
+//! Both the schema writer and the JSON Schema exporter turn a `min`/`max`
+//! pair into their own output format. Keeping the decomposition here ensures
+//! the two never disagree on which side is inclusive.
+
+use crate::Bound;
+
+/// Render a bound pair as a `range` string.
+///
+/// Uses the Rust-style form (`a..b`, `a..=b`, `a..`, `..=b`, `..`) whenever the
+/// lower bound is inclusive or unbounded, and falls back to interval notation
+/// (`(a, b]`) for an exclusive lower bound, which the Rust-style form cannot
+/// express.
+pub fn render_range<T>(min: &Bound<T>, max: &Bound<T>, format_value: fn(&T) -> String) -> String {
+    if let Bound::Exclusive(min) = min {
+        let (max_str, right) = match max {
+            Bound::Unbounded => (String::new(), ')'),
+            Bound::Inclusive(v) => (format_value(v), ']'),
+            Bound::Exclusive(v) => (format_value(v), ')'),
+        };
+        return format!("({}, {max_str}{right}", format_value(min));
+    }
+
+    let min_str = match min {
+        Bound::Inclusive(v) => format_value(v),
+        _ => String::new(),
+    };
+    match max {
+        Bound::Unbounded => format!("{min_str}.."),
+        Bound::Inclusive(v) => format!("{min_str}..={}", format_value(v)),
+        Bound::Exclusive(v) => format!("{min_str}..{}", format_value(v)),
+    }
+}
+
+/// Decompose a bound pair for JSON Schema output.
+///
+/// Returns `(min, max, min_exclusive, max_exclusive)`, with each value
+/// converted by `convert`. Unbounded sides yield `None`, so the corresponding
+/// JSON Schema keyword is omitted.
+pub fn range_to_json<T, V, E>(
+    min: &Bound<T>,
+    max: &Bound<T>,
+    convert: impl Fn(&T) -> Result<V, E>,
+) -> Result<(Option<V>, Option<V>, bool, bool), E> {
+    let (min, min_exclusive) = match min {
+        Bound::Unbounded => (None, false),
+        Bound::Inclusive(v) => (Some(convert(v)?), false),
+        Bound::Exclusive(v) => (Some(convert(v)?), true),
+    };
+    let (max, max_exclusive) = match max {
+        Bound::Unbounded => (None, false),
+        Bound::Inclusive(v) => (Some(convert(v)?), false),
+        Bound::Exclusive(v) => (Some(convert(v)?), true),
+    };
+    Ok((min, max, min_exclusive, max_exclusive))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+
+    fn display(value: &i64) -> String {
+        value.to_string()
+    }
+
+    #[test]
+    fn test_render_range() {
+        assert_eq!(
+            render_range(&Bound::Inclusive(1), &Bound::Exclusive(10), display),
+            "1..10"
+        );
+        assert_eq!(
+            render_range(&Bound::Inclusive(1), &Bound::Inclusive(10), display),
+            "1..=10"
+        );
+        assert_eq!(
+            render_range(&Bound::Unbounded, &Bound::Inclusive(10), display),
+            "..=10"
+        );
+        assert_eq!(
+            render_range(&Bound::Inclusive(1), &Bound::Unbounded, display),
+            "1.."
+        );
+        assert_eq!(
+            render_range(&Bound::Exclusive(1), &Bound::Inclusive(10), display),
+            "(1, 10]"
+        );
+        assert_eq!(
+            render_range(&Bound::Exclusive(1), &Bound::Unbounded, display),
+            "(1, )"
+        );
+    }
+
+    #[test]
+    fn test_range_to_json() {
+        let result = range_to_json(&Bound::Inclusive(1), &Bound::Exclusive(10), |v: &i64| {
+            Ok::<_, Infallible>(*v)
+        });
+        assert_eq!(result, Ok((Some(1), Some(10), false, true)));
+
+        let result = range_to_json(&Bound::Unbounded, &Bound::Unbounded, |v: &i64| {
+            Ok::<_, Infallible>(*v)
+        });
+        assert_eq!(result, Ok((None, None, false, false)));
+    }
+}
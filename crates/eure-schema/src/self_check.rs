@@ -0,0 +1,455 @@
+//! Meta-validation of a [`SchemaDocument`].
+//!
+//! Checks that a schema is internally consistent before it is used to
+//! validate documents. Schemas converted from Eure documents already reject
+//! undefined references, but schemas assembled by hand or through
+//! [`SchemaBuilder`](crate::SchemaBuilder) can still contain authoring
+//! mistakes.
+
+use eure_document::document::EureDocument;
+use eure_document::identifier::Identifier;
+use eure_document::path::EurePath;
+use indexmap::IndexMap;
+use thiserror::Error;
+
+use crate::validate::{ValidationError, validate_node};
+use crate::{SchemaDocument, SchemaKind, SchemaNodeContent, SchemaNodeId};
+
+/// An internal inconsistency found by [`SchemaDocument::validate_self`].
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum SchemaDefect {
+    #[error("reference to undefined type `{name}` at schema node {node_id:?}")]
+    DanglingReference {
+        node_id: SchemaNodeId,
+        name: Identifier,
+    },
+
+    #[error("schema node {node_id:?} refers to nonexistent schema node {target:?}")]
+    NodeOutOfBounds {
+        node_id: SchemaNodeId,
+        target: SchemaNodeId,
+    },
+
+    #[error("union at schema node {node_id:?} marks unknown variant `{variant}` as unambiguous")]
+    UnknownUnambiguousVariant {
+        node_id: SchemaNodeId,
+        variant: String,
+    },
+
+    #[error(
+        "union at schema node {node_id:?} denies untagged matching for unknown variant `{variant}`"
+    )]
+    UnknownDenyUntaggedVariant {
+        node_id: SchemaNodeId,
+        variant: String,
+    },
+
+    #[error(
+        "record at schema node {node_id:?} renames fields `{first}` and `{second}` to the same name `{name}`"
+    )]
+    DuplicateFieldName {
+        node_id: SchemaNodeId,
+        name: String,
+        first: String,
+        second: String,
+    },
+
+    #[error(
+        "record at schema node {node_id:?} flattens schema node {target:?}, which is {kind}; only records, maps, and unions can be flattened"
+    )]
+    InvalidFlattenTarget {
+        node_id: SchemaNodeId,
+        target: SchemaNodeId,
+        /// Kind of the flattened schema, after following local references.
+        kind: SchemaKind,
+    },
+
+    #[error(
+        "default value at schema node {node_id:?} does not match its schema: expected {expected} at {path}"
+    )]
+    DefaultTypeMismatch {
+        node_id: SchemaNodeId,
+        /// Path of the mismatching value within the default.
+        path: EurePath,
+        /// Kind of the schema node that rejected the value.
+        expected: SchemaKind,
+    },
+
+    #[error("example #{index} at schema node {node_id:?} does not match its schema: {error}")]
+    InvalidExample {
+        node_id: SchemaNodeId,
+        /// Position of the example in `$examples`.
+        index: usize,
+        /// The first error from validating the example against its schema node.
+        error: ValidationError,
+    },
+}
+
+impl SchemaDefect {
+    /// The schema node the defect was found on.
+    pub fn node_id(&self) -> SchemaNodeId {
+        match self {
+            SchemaDefect::DanglingReference { node_id, .. }
+            | SchemaDefect::NodeOutOfBounds { node_id, .. }
+            | SchemaDefect::UnknownUnambiguousVariant { node_id, .. }
+            | SchemaDefect::UnknownDenyUntaggedVariant { node_id, .. }
+            | SchemaDefect::DuplicateFieldName { node_id, .. }
+            | SchemaDefect::InvalidFlattenTarget { node_id, .. }
+            | SchemaDefect::DefaultTypeMismatch { node_id, .. }
+            | SchemaDefect::InvalidExample { node_id, .. } => *node_id,
+        }
+    }
+}
+
+impl SchemaDocument {
+    /// Check that this schema is internally consistent.
+    ///
+    /// Reports local references to undefined types, child node ids outside
+    /// the arena, `unambiguous`/`deny-untagged` entries naming variants that the
+    /// union does not define, record fields whose `$codegen.name` renames
+    /// collide, flattened schemas that are not records, maps, or unions, and
+    /// `$default` and `$examples` values that do not validate
+    /// against their own schema node. Cross-schema references (with a
+    /// namespace) are not checked.
+    ///
+    /// Returns an empty vector if no defects were found.
+    pub fn validate_self(&self) -> Vec<SchemaDefect> {
+        let mut defects = Vec::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            let node_id = SchemaNodeId(index);
+            let mut children = Vec::new();
+            match &node.content {
+                SchemaNodeContent::Reference(reference) => {
+                    if reference.namespace.is_none() && self.get_type(&reference.name).is_none() {
+                        defects.push(SchemaDefect::DanglingReference {
+                            node_id,
+                            name: reference.name.clone(),
+                        });
+                    }
+                }
+                SchemaNodeContent::Union(union) => {
+                    for variant in &union.unambiguous {
+                        if !union.variants.contains_key(variant) {
+                            defects.push(SchemaDefect::UnknownUnambiguousVariant {
+                                node_id,
+                                variant: variant.clone(),
+                            });
+                        }
+                    }
+                    for variant in &union.deny_untagged {
+                        if !union.variants.contains_key(variant) {
+                            defects.push(SchemaDefect::UnknownDenyUntaggedVariant {
+                                node_id,
+                                variant: variant.clone(),
+                            });
+                        }
+                    }
+                    children.extend(union.variants.values().copied());
+                }
+                SchemaNodeContent::Record(record) => {
+                    let mut seen: IndexMap<&str, &str> = IndexMap::new();
+                    for (field, field_schema) in &record.properties {
+                        let name = field_schema
+                            .field_codegen
+                            .name
+                            .as_deref()
+                            .unwrap_or(field.as_str());
+                        if let Some(first) = seen.insert(name, field.as_str()) {
+                            defects.push(SchemaDefect::DuplicateFieldName {
+                                node_id,
+                                name: name.to_string(),
+                                first: first.to_string(),
+                                second: field.clone(),
+                            });
+                        }
+                        children.push(field_schema.schema);
+                    }
+                    children.extend(record.flatten.iter().copied());
+                }
+                SchemaNodeContent::Array(array) => children.push(array.item),
+                SchemaNodeContent::Map(map) => {
+                    children.push(map.key);
+                    children.push(map.value);
+                }
+                SchemaNodeContent::Tuple(tuple) => children.extend(tuple.elements.iter().copied()),
+                _ => {}
+            }
+            children.extend(node.ext_types.values().map(|ext| ext.schema));
+            for target in children {
+                if target.0 >= self.nodes.len() {
+                    defects.push(SchemaDefect::NodeOutOfBounds { node_id, target });
+                }
+            }
+        }
+        // Validating a default follows child ids, so it needs an intact arena.
+        if !defects
+            .iter()
+            .any(|defect| matches!(defect, SchemaDefect::NodeOutOfBounds { .. }))
+        {
+            defects.extend(self.invalid_flatten_targets());
+            defects.extend(self.default_type_mismatches());
+            defects.extend(self.invalid_examples());
+        }
+        defects
+    }
+
+    fn invalid_flatten_targets(&self) -> Vec<SchemaDefect> {
+        let mut defects = Vec::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            let SchemaNodeContent::Record(record) = &node.content else {
+                continue;
+            };
+            for &target in &record.flatten {
+                // Bounded, since a reference cycle would never reach content.
+                let mut resolved = target;
+                for _ in 0..self.nodes.len() {
+                    match self.resolve_reference(resolved) {
+                        Some(next) => resolved = next,
+                        None => break,
+                    }
+                }
+                let content = &self.node(resolved).content;
+                if !matches!(
+                    content,
+                    SchemaNodeContent::Record(_)
+                        | SchemaNodeContent::Map(_)
+                        | SchemaNodeContent::Union(_)
+                        | SchemaNodeContent::Reference(_)
+                ) {
+                    defects.push(SchemaDefect::InvalidFlattenTarget {
+                        node_id: SchemaNodeId(index),
+                        target,
+                        kind: content.kind(),
+                    });
+                }
+            }
+        }
+        defects
+    }
+
+    fn default_type_mismatches(&self) -> Vec<SchemaDefect> {
+        let mut defects = Vec::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            let node_id = SchemaNodeId(index);
+            let Some(default) = &node.metadata.default else {
+                continue;
+            };
+            let Some(error) = self.first_value_error(default, node_id) else {
+                continue;
+            };
+            let (_, schema_id) = error.node_ids();
+            defects.push(SchemaDefect::DefaultTypeMismatch {
+                node_id,
+                path: error.path().clone(),
+                expected: self.node(schema_id).content.kind(),
+            });
+        }
+        defects
+    }
+
+    fn invalid_examples(&self) -> Vec<SchemaDefect> {
+        let mut defects = Vec::new();
+        for (index, node) in self.nodes.iter().enumerate() {
+            let node_id = SchemaNodeId(index);
+            let Some(examples) = &node.metadata.examples else {
+                continue;
+            };
+            for (example_index, example) in examples.iter().enumerate() {
+                let Some(error) = self.first_value_error(example, node_id) else {
+                    continue;
+                };
+                defects.push(SchemaDefect::InvalidExample {
+                    node_id,
+                    index: example_index,
+                    error,
+                });
+            }
+        }
+        defects
+    }
+
+    /// Validate `value` against `node_id` and return its first error.
+    fn first_value_error(
+        &self,
+        value: &EureDocument,
+        node_id: SchemaNodeId,
+    ) -> Option<ValidationError> {
+        let output = validate_node(value, self, value.get_root_id(), node_id);
+        // Unresolved references are reported as `DanglingReference` or are
+        // cross-schema, which this check does not follow.
+        output.errors.into_iter().find(|error| {
+            !matches!(
+                error,
+                ValidationError::UndefinedTypeReference { .. }
+                    | ValidationError::UnresolvableReference { .. }
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TypeReference, UnionSchema, interop::UnionInterop};
+    use indexmap::IndexSet;
+
+    fn union_with(doc: &mut SchemaDocument, unambiguous: &[&str]) -> SchemaNodeId {
+        let flag = doc.create_node(SchemaNodeContent::Boolean);
+        doc.create_node(SchemaNodeContent::Union(UnionSchema {
+            variants: IndexMap::from([("flag".to_string(), flag)]),
+            unambiguous: unambiguous.iter().map(|v| v.to_string()).collect(),
+            interop: UnionInterop::default(),
+            deny_untagged: IndexSet::new(),
+        }))
+    }
+
+    #[test]
+    fn test_consistent_schema_has_no_defects() {
+        let mut doc = SchemaDocument::new();
+        doc.root = union_with(&mut doc, &["flag"]);
+        assert_eq!(doc.validate_self(), vec![]);
+    }
+
+    #[test]
+    fn test_dangling_reference() {
+        let mut doc = SchemaDocument::new();
+        let name = Identifier::new_unchecked("missing");
+        let node_id = doc.create_node(SchemaNodeContent::Reference(TypeReference {
+            namespace: None,
+            name: name.clone(),
+        }));
+        doc.root = node_id;
+        assert_eq!(
+            doc.validate_self(),
+            vec![SchemaDefect::DanglingReference { node_id, name }]
+        );
+    }
+
+    #[test]
+    fn test_unambiguous_names_unknown_variant() {
+        let mut doc = SchemaDocument::new();
+        let node_id = union_with(&mut doc, &["flag", "nope"]);
+        doc.root = node_id;
+        assert_eq!(
+            doc.validate_self(),
+            vec![SchemaDefect::UnknownUnambiguousVariant {
+                node_id,
+                variant: "nope".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_deny_untagged_names_unknown_variant() {
+        let mut doc = SchemaDocument::new();
+        let node_id = union_with(&mut doc, &[]);
+        doc.root = node_id;
+        let SchemaNodeContent::Union(union) = &mut doc.nodes[node_id.0].content else {
+            unreachable!();
+        };
+        union.deny_untagged = IndexSet::from(["flag".to_string(), "nope".to_string()]);
+        assert_eq!(
+            doc.validate_self(),
+            vec![SchemaDefect::UnknownDenyUntaggedVariant {
+                node_id,
+                variant: "nope".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_field_name() {
+        use crate::convert::document_to_schema;
+        use eure_document::eure;
+
+        let (doc, _) = document_to_schema(&eure!({
+            first = @code("text")
+            first.%codegen.name = "second"
+            second = @code("integer")
+        }))
+        .unwrap();
+        assert_eq!(
+            doc.validate_self(),
+            vec![SchemaDefect::DuplicateFieldName {
+                node_id: doc.root,
+                name: "second".to_string(),
+                first: "first".to_string(),
+                second: "second".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_default_type_mismatch() {
+        use eure_document::eure;
+
+        let mut doc = SchemaDocument::new();
+        let node_id = doc.create_node(SchemaNodeContent::Integer(Default::default()));
+        doc.root = node_id;
+
+        doc.nodes[node_id.0].metadata.default = Some(eure!({ = 8080 }));
+        assert_eq!(doc.validate_self(), vec![]);
+
+        doc.nodes[node_id.0].metadata.default = Some(eure!({ = "hello" }));
+        assert_eq!(
+            doc.validate_self(),
+            vec![SchemaDefect::DefaultTypeMismatch {
+                node_id,
+                path: EurePath::root(),
+                expected: SchemaKind::Integer,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_default_type_mismatch_reports_nested_path() {
+        use crate::convert::document_to_schema;
+        use eure_document::eure;
+        use eure_document::path::PathSegment;
+
+        let (doc, _) = document_to_schema(&eure!({
+            port = @code("integer")
+            %default.port = "http"
+        }))
+        .unwrap();
+        assert_eq!(
+            doc.validate_self(),
+            vec![SchemaDefect::DefaultTypeMismatch {
+                node_id: doc.root,
+                path: EurePath(vec![PathSegment::Ident(Identifier::new_unchecked("port"))]),
+                expected: SchemaKind::Integer,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_invalid_example() {
+        use crate::TextSchema;
+        use eure_document::eure;
+
+        let mut doc = SchemaDocument::new();
+        let node_id = doc.create_node(SchemaNodeContent::Text(TextSchema {
+            max_length: Some(5),
+            ..Default::default()
+        }));
+        doc.root = node_id;
+        let invalid = eure!({ = "goodbye" });
+        doc.nodes[node_id.0].metadata.examples = Some(vec![eure!({ = "hello" }), invalid.clone()]);
+
+        assert_eq!(
+            doc.validate_self(),
+            vec![SchemaDefect::InvalidExample {
+                node_id,
+                index: 1,
+                error: ValidationError::StringLengthOutOfBounds {
+                    length: 7,
+                    min: None,
+                    max: Some(5),
+                    path: EurePath::root(),
+                    node_id: invalid.get_root_id(),
+                    schema_node_id: node_id,
+                },
+            }]
+        );
+    }
+}
@@ -26,11 +26,13 @@ mod key;
 mod primitive;
 mod record;
 mod reference;
+mod resolver;
 mod trace;
 mod union;
 
 pub use context::{ValidationContext, ValidationOutput, ValidationState};
 pub use error::{ValidationError, ValidationWarning, ValidatorError};
+pub use resolver::{SchemaResolveError, SchemaResolver};
 pub use trace::resolve_node_type_traces;
 
 use eure_document::document::node::NodeValue;
@@ -72,6 +74,20 @@ pub fn validate(document: &EureDocument, schema: &SchemaDocument) -> ValidationO
     validate_node(document, schema, root_id, schema.root)
 }
 
+/// Validate a document against a schema, resolving cross-schema references
+/// (`$types.namespace.type-name`) with `resolver`.
+///
+/// [`validate`] reports every cross-schema reference as
+/// [`ValidationError::UnresolvableReference`].
+pub fn validate_with_resolver(
+    document: &EureDocument,
+    schema: &SchemaDocument,
+    resolver: &dyn SchemaResolver,
+) -> ValidationOutput {
+    let ctx = ValidationContext::new(document, schema).with_resolver(resolver);
+    validate_with_context(ctx, document.get_root_id(), schema.root)
+}
+
 /// Validation output with node-level schema trace mapping.
 #[derive(Debug, Clone, Default)]
 pub struct ValidationTraceOutput {
@@ -103,6 +119,14 @@ pub fn validate_node(
     schema_id: SchemaNodeId,
 ) -> ValidationOutput {
     let ctx = ValidationContext::new(document, schema);
+    validate_with_context(ctx, node_id, schema_id)
+}
+
+fn validate_with_context(
+    ctx: ValidationContext<'_>,
+    node_id: NodeId,
+    schema_id: SchemaNodeId,
+) -> ValidationOutput {
     let parse_ctx = ctx.parse_context(node_id);
 
     let validator = SchemaValidator {
@@ -133,6 +157,7 @@ impl<'a, 'doc> DocumentParser<'doc> for SchemaValidator<'a, 'doc> {
     type Error = ValidatorError;
 
     fn parse(&mut self, parse_ctx: &ParseContext<'doc>) -> Result<(), ValidatorError> {
+        self.ctx.state.borrow_mut().visited_nodes += 1;
         let node = parse_ctx.node();
 
         if node.get_extension(&identifiers::TYPE).is_some() {
@@ -154,6 +179,7 @@ impl<'a, 'doc> DocumentParser<'doc> for SchemaValidator<'a, 'doc> {
         // Dispatch to type-specific validator
         match &schema_node.content {
             SchemaNodeContent::Any => {
+                // Accept without descending: the subtree is never visited.
                 self.warn_unknown_extensions(parse_ctx);
                 let mut v = AnyValidator;
                 v.parse(parse_ctx)
@@ -367,10 +393,11 @@ mod tests {
     use crate::type_path_trace::{ResolvedTypeTrace, TypeTraceUnresolvedReason};
     use crate::{
         ArraySchema, Bound, CodegenDefaults, FieldCodegen, IntegerSchema, MapSchema,
-        RecordFieldSchema, RecordSchema, RootCodegen, TextSchema, TypeReference, UnionSchema,
-        UnknownFieldsPolicy,
+        RecordFieldSchema, RecordSchema, RootCodegen, TextFormat, TextSchema, TypeReference,
+        UnionSchema, UnknownFieldsPolicy,
     };
     use eure_document::identifier::Identifier;
+    use eure_document::path::{ArrayIndexKind, EurePath, PathSegment};
     use eure_document::text::Text;
     use eure_document::value::{ObjectKey, PrimitiveValue};
     use indexmap::{IndexMap, IndexSet};
@@ -383,6 +410,7 @@ mod tests {
             types: IndexMap::new(),
             root_codegen: RootCodegen::default(),
             codegen_defaults: CodegenDefaults::default(),
+            ref_targets: Vec::new(),
         };
         let id = schema.create_node(content);
         schema.root = id;
@@ -424,6 +452,62 @@ mod tests {
         assert!(!result.is_valid);
     }
 
+    #[test]
+    fn test_validate_text_format_email() {
+        let (schema, _) = create_simple_schema(SchemaNodeContent::Text(TextSchema {
+            format: Some(TextFormat::Email),
+            ..Default::default()
+        }));
+
+        let doc = create_doc_with_primitive(PrimitiveValue::Text(Text::plaintext(
+            "alice@example.com".to_string(),
+        )));
+        let result = validate(&doc, &schema);
+        assert!(result.is_valid, "{:?}", result.errors);
+
+        let doc = create_doc_with_primitive(PrimitiveValue::Text(Text::plaintext(
+            "alice.example.com".to_string(),
+        )));
+        let result = validate(&doc, &schema);
+        assert!(!result.is_valid);
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ValidationError::FormatMismatch {
+                format: TextFormat::Email,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn test_validate_text_formats() {
+        let cases = [
+            (TextFormat::Uri, "https://eure.dev/docs", "eure.dev/docs"),
+            (
+                TextFormat::Uuid,
+                "67e55044-10b1-426f-9247-bb680e5fe0c8",
+                "67e55044-10b1-426f-9247",
+            ),
+            (
+                TextFormat::DateTime,
+                "2024-02-29T12:30:00.5+09:00",
+                "2023-02-29T12:30:00Z",
+            ),
+            (TextFormat::Ipv4, "192.168.0.1", "192.168.0.256"),
+            (TextFormat::Ipv6, "::1", "::g"),
+        ];
+        for (format, valid, invalid) in cases {
+            let (schema, _) = create_simple_schema(SchemaNodeContent::Text(TextSchema {
+                format: Some(format),
+                ..Default::default()
+            }));
+            let doc = create_doc_with_primitive(PrimitiveValue::Text(Text::plaintext(valid)));
+            assert!(validate(&doc, &schema).is_valid, "{format}: {valid}");
+            let doc = create_doc_with_primitive(PrimitiveValue::Text(Text::plaintext(invalid)));
+            assert!(!validate(&doc, &schema).is_valid, "{format}: {invalid}");
+        }
+    }
+
     #[test]
     fn test_validate_integer() {
         let (schema, _) = create_simple_schema(SchemaNodeContent::Integer(IntegerSchema {
@@ -482,6 +566,176 @@ mod tests {
         assert!(result.is_valid);
     }
 
+    /// Convert a schema written in Eure into a [SchemaDocument].
+    fn schema_of(doc: &EureDocument) -> SchemaDocument {
+        let (schema, _, _) =
+            document_to_schema_with_layout(doc).expect("schema conversion should succeed");
+        schema
+    }
+
+    #[test]
+    fn test_validate_array_reports_every_bad_element() {
+        use eure_document::eure;
+
+        let schema = schema_of(&eure!({ = [@code("integer")] }));
+
+        let result = validate(&eure!({ = ["zero", 1, "two"] }), &schema);
+        let paths: Vec<_> = result
+            .errors
+            .iter()
+            .filter_map(|e| match e {
+                ValidationError::TypeMismatch { path, .. } => path.0.last().cloned(),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                PathSegment::ArrayIndex(ArrayIndexKind::Specific(0)),
+                PathSegment::ArrayIndex(ArrayIndexKind::Specific(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_array_min_length_and_unique() {
+        use eure_document::eure;
+
+        let schema = schema_of(&eure!({
+            %variant = "array"
+            item = @code("integer")
+            "min-length" = 3
+            unique = true
+        }));
+
+        let result = validate(&eure!({ = [7, 7] }), &schema);
+        assert!(matches!(
+            result.errors.as_slice(),
+            [
+                ValidationError::ArrayLengthOutOfBounds {
+                    length: 2,
+                    min: Some(3),
+                    ..
+                },
+                ValidationError::ArrayNotUnique { .. },
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_validate_any_does_not_descend() {
+        let (schema, _) = create_simple_schema(SchemaNodeContent::Any);
+
+        let mut doc = EureDocument::new();
+        let mut parent = doc.get_root_id();
+        for _ in 0..1000 {
+            doc.node_mut(parent).content = NodeValue::Array(Default::default());
+            parent = doc.add_array_element(None, parent).unwrap().node_id;
+        }
+        doc.node_mut(parent).content =
+            NodeValue::Primitive(PrimitiveValue::Integer(BigInt::from(1)));
+
+        let ctx = ValidationContext::new(&doc, &schema);
+        let validator = SchemaValidator {
+            ctx: &ctx,
+            schema_node_id: schema.root,
+        };
+        ctx.parse_context(doc.get_root_id())
+            .parse_with(validator)
+            .unwrap();
+
+        assert_eq!(ctx.state.borrow().visited_nodes, 1);
+        assert!(ctx.finish().is_valid);
+    }
+
+    fn map_schema(min_size: Option<u32>, max_size: Option<u32>) -> SchemaDocument {
+        let (mut schema, _) = create_simple_schema(SchemaNodeContent::Any);
+        let key_schema_id = schema.create_node(SchemaNodeContent::Text(TextSchema::default()));
+        let value_schema_id =
+            schema.create_node(SchemaNodeContent::Integer(IntegerSchema::default()));
+        schema.node_mut(schema.root).content = SchemaNodeContent::Map(MapSchema {
+            key: key_schema_id,
+            value: value_schema_id,
+            min_size,
+            max_size,
+        });
+        schema
+    }
+
+    fn map_document(entries: &[(&str, PrimitiveValue)]) -> EureDocument {
+        let mut doc = EureDocument::new();
+        let root_id = doc.get_root_id();
+        for (key, value) in entries {
+            let child = doc
+                .add_map_child(ObjectKey::String(key.to_string()), root_id)
+                .unwrap()
+                .node_id;
+            doc.node_mut(child).content = NodeValue::Primitive(value.clone());
+        }
+        doc
+    }
+
+    #[test]
+    fn test_validate_map_below_min_size() {
+        let schema = map_schema(Some(2), None);
+        let doc = map_document(&[("a", PrimitiveValue::Integer(BigInt::from(1)))]);
+
+        let result = validate(&doc, &schema);
+        assert!(result.errors.iter().any(|e| matches!(
+            e,
+            ValidationError::MapSizeOutOfBounds {
+                size: 1,
+                min: Some(2),
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_validate_map_above_max_size() {
+        let schema = map_schema(None, Some(1));
+        let doc = map_document(&[
+            ("a", PrimitiveValue::Integer(BigInt::from(1))),
+            ("b", PrimitiveValue::Integer(BigInt::from(2))),
+        ]);
+
+        let result = validate(&doc, &schema);
+        assert!(result.errors.iter().any(|e| matches!(
+            e,
+            ValidationError::MapSizeOutOfBounds {
+                size: 2,
+                max: Some(1),
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_validate_map_value_error_reports_key_in_path() {
+        let schema = map_schema(None, None);
+        let doc = map_document(&[
+            ("a", PrimitiveValue::Integer(BigInt::from(1))),
+            (
+                "b",
+                PrimitiveValue::Text(Text::plaintext("two".to_string())),
+            ),
+        ]);
+
+        let result = validate(&doc, &schema);
+        let path = result
+            .errors
+            .iter()
+            .find_map(|e| match e {
+                ValidationError::TypeMismatch { path, .. } => Some(path.clone()),
+                _ => None,
+            })
+            .expect("expected a type mismatch for the value of key b");
+        assert_eq!(
+            path.0.last(),
+            Some(&PathSegment::Value(ObjectKey::String("b".to_string())))
+        );
+    }
+
     #[test]
     fn test_validate_map_with_union_key_schema() {
         let (mut schema, _) = create_simple_schema(SchemaNodeContent::Any);
@@ -661,6 +915,74 @@ mod tests {
         assert!(result.is_valid);
     }
 
+    #[test]
+    fn test_validate_record_unknown_fields_deny() {
+        use eure_document::eure;
+
+        let schema = schema_of(&eure!({
+            name = @code("text")
+            %"unknown-fields" = "deny"
+        }));
+
+        let result = validate(&eure!({ name = "Alice" }), &schema);
+        assert!(result.is_valid);
+
+        let result = validate(&eure!({ name = "Alice", extra = 1 }), &schema);
+        assert!(!result.is_valid);
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ValidationError::UnknownField { field, .. }] if field == "extra"
+        ));
+    }
+
+    #[test]
+    fn test_validate_record_unknown_fields_allow() {
+        use eure_document::eure;
+
+        let schema = schema_of(&eure!({
+            name = @code("text")
+            %"unknown-fields" = "allow"
+        }));
+
+        let result = validate(
+            &eure!({ name = "Alice", extra = 1, other = "anything" }),
+            &schema,
+        );
+        assert!(result.is_valid, "errors: {:?}", result.errors);
+    }
+
+    #[test]
+    fn test_validate_record_unknown_fields_schema() {
+        use eure_document::eure;
+
+        let schema = schema_of(&eure!({
+            name = @code("text")
+            %"unknown-fields".%variant = "integer"
+            %"unknown-fields".range = "[0, 10]"
+        }));
+
+        let result = validate(&eure!({ name = "Alice", extra = 5 }), &schema);
+        assert!(result.is_valid, "errors: {:?}", result.errors);
+
+        // Every unknown field is checked against the policy schema.
+        let result = validate(&eure!({ name = "Alice", extra = 5, other = 10 }), &schema);
+        assert!(result.is_valid, "errors: {:?}", result.errors);
+
+        // Unknown field with the wrong type.
+        let result = validate(&eure!({ name = "Alice", extra = "five" }), &schema);
+        assert!(!result.is_valid);
+        assert!(
+            !result
+                .errors
+                .iter()
+                .any(|e| matches!(e, ValidationError::UnknownField { .. }))
+        );
+
+        // Unknown field outside the integer range.
+        let result = validate(&eure!({ name = "Alice", extra = 42 }), &schema);
+        assert!(!result.is_valid);
+    }
+
     #[test]
     fn test_validate_record_with_sibling_flatten_targets() {
         let (mut schema, _) = create_simple_schema(SchemaNodeContent::Any);
@@ -947,6 +1269,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_ignores_schema_extension() {
+        use eure_document::eure;
+
+        let schema = schema_of(&eure!({ = @code("integer") }));
+        let doc = eure!({ = 1, %schema = "config.schema.eure" });
+
+        let result = validate(&doc, &schema);
+        assert!(result.is_valid, "{:?}", result.errors);
+        assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+    }
+
+    #[test]
+    fn test_validate_undeclared_extension_is_a_warning() {
+        use eure_document::eure;
+
+        let schema = schema_of(&eure!({ = @code("integer") }));
+        let doc = eure!({ = 1, %note = "hi" });
+
+        let result = validate(&doc, &schema);
+        assert!(result.is_valid, "{:?}", result.errors);
+        assert!(matches!(
+            result.warnings.as_slice(),
+            [ValidationWarning::UnknownExtension { name, .. }] if name == "note"
+        ));
+    }
+
+    #[test]
+    fn test_validate_declared_extension_type_mismatch() {
+        use eure_document::eure;
+
+        let schema = schema_of(&eure!({
+            = @code("integer")
+            %"ext-type".unit = @code("text")
+            %"ext-type".unit.%optional = true
+        }));
+
+        assert!(validate(&eure!({ = 1, %unit = "ms" }), &schema).is_valid);
+
+        let result = validate(&eure!({ = 1, %unit = 5 }), &schema);
+        assert!(!result.is_valid);
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ValidationError::TypeMismatch { path, .. }] if path.to_string() == "$unit"
+        ));
+    }
+
+    #[test]
+    fn test_validate_declared_extension_optionality() {
+        use eure_document::eure;
+
+        let doc = eure!({ = 1 });
+
+        let optional = schema_of(&eure!({
+            = @code("integer")
+            %"ext-type".unit = @code("text")
+            %"ext-type".unit.%optional = true
+        }));
+        assert!(validate(&doc, &optional).is_valid);
+
+        let required = schema_of(&eure!({
+            = @code("integer")
+            %"ext-type".unit = @code("text")
+        }));
+        let result = validate(&doc, &required);
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ValidationError::MissingRequiredExtension { extension, .. }] if extension == "unit"
+        ));
+    }
+
     #[test]
     fn test_validate_hole() {
         let (schema, _) =
@@ -1094,6 +1487,69 @@ mod tests {
         );
     }
 
+    /// A union of `small` (an integer in `0..=10`) and `any` (any integer),
+    /// in that order, with the given variants marked unambiguous.
+    fn overlapping_integer_union(unambiguous: &[&str]) -> (SchemaDocument, SchemaNodeId) {
+        let (mut schema, _) = create_simple_schema(SchemaNodeContent::Any);
+        let small_id = schema.create_node(SchemaNodeContent::Integer(IntegerSchema {
+            min: Bound::Inclusive(BigInt::from(0)),
+            max: Bound::Inclusive(BigInt::from(10)),
+            multiple_of: None,
+        }));
+        let any_id = schema.create_node(SchemaNodeContent::Integer(IntegerSchema::default()));
+        schema.node_mut(schema.root).content = SchemaNodeContent::Union(UnionSchema {
+            variants: IndexMap::from([
+                ("small".to_string(), small_id),
+                ("any".to_string(), any_id),
+            ]),
+            unambiguous: unambiguous.iter().map(|name| name.to_string()).collect(),
+            interop: crate::interop::UnionInterop::default(),
+            deny_untagged: IndexSet::new(),
+        });
+        (schema, small_id)
+    }
+
+    #[test]
+    fn test_validate_union_ambiguous_without_priority() {
+        let (schema, _) = overlapping_integer_union(&["small", "any"]);
+        let doc = create_doc_with_primitive(PrimitiveValue::Integer(BigInt::from(5)));
+
+        let result = validate(&doc, &schema);
+        assert!(!result.is_valid);
+        assert!(
+            result.errors.iter().any(|e| matches!(
+                e,
+                ValidationError::AmbiguousUnion { path, variants, .. }
+                    if path.0.is_empty() && variants == &["small", "any"]
+            )),
+            "{:?}",
+            result.errors
+        );
+
+        // Only one unambiguous variant matches a value outside `small`
+        let doc = create_doc_with_primitive(PrimitiveValue::Integer(BigInt::from(50)));
+        assert!(validate(&doc, &schema).is_valid);
+    }
+
+    #[test]
+    fn test_validate_union_priority_picks_first_listed() {
+        // `small` has priority, so it wins over the unambiguous `any`
+        let (schema, small_id) = overlapping_integer_union(&["any"]);
+        let doc = create_doc_with_primitive(PrimitiveValue::Integer(BigInt::from(5)));
+
+        let traced = validate_with_trace(&doc, &schema, &IndexMap::new());
+        assert!(traced.output.is_valid, "{:?}", traced.output.errors);
+        let ResolvedTypeTrace::Resolved(trace) = &traced.node_type_traces[&doc.get_root_id()]
+        else {
+            panic!("root not resolved: {:?}", traced.node_type_traces);
+        };
+        let small_path = EurePath(vec![PathSegment::Value(ObjectKey::String(format!(
+            "schema-node-{}",
+            small_id.0
+        )))]);
+        assert_eq!(trace.current(), &small_path);
+    }
+
     #[test]
     fn test_validate_union_internal_interop_does_not_count_as_explicit_tag() {
         use eure_document::eure;
@@ -1180,6 +1636,121 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_literal_text() {
+        use eure_document::eure;
+
+        let mut schema = SchemaDocument::new();
+        schema.node_mut(schema.root).content = SchemaNodeContent::Literal(eure!({ = "active" }));
+
+        assert!(validate(&eure!({ = "active" }), &schema).is_valid);
+
+        let result = validate(&eure!({ = "inactive" }), &schema);
+        assert!(matches!(
+            result.errors.as_slice(),
+            [ValidationError::LiteralMismatch { .. }]
+        ));
+    }
+
+    #[test]
+    fn test_validate_literal_map_requires_exact_fields() {
+        use eure_document::eure;
+
+        let mut schema = SchemaDocument::new();
+        schema.node_mut(schema.root).content = SchemaNodeContent::Literal(eure!({
+            kind = "point"
+            coords = [1, 2]
+        }));
+
+        let matching = eure!({
+            kind = "point"
+            coords = [1, 2]
+            %note = "extensions are ignored"
+        });
+        let result = validate(&matching, &schema);
+        assert!(result.is_valid, "errors: {:?}", result.errors);
+
+        for mismatched in [
+            eure!({ kind = "point" }),
+            eure!({
+                kind = "point"
+                coords = [1, 3]
+            }),
+            eure!({
+                kind = "point"
+                coords = [1, 2]
+                extra = true
+            }),
+        ] {
+            let result = validate(&mismatched, &schema);
+            assert!(
+                matches!(
+                    result.errors.as_slice(),
+                    [ValidationError::LiteralMismatch { .. }]
+                ),
+                "{mismatched:?}: {:?}",
+                result.errors
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_cross_schema_reference() {
+        use eure_document::eure;
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        let network_doc = eure!({
+            %types.port = @code("integer")
+        });
+        let (network, _, _) =
+            document_to_schema_with_layout(&network_doc).expect("schema conversion should succeed");
+        let server_doc = eure!({
+            host = @code("text")
+            port = @code("$types.network.port")
+        });
+        let (server, _, _) =
+            document_to_schema_with_layout(&server_doc).expect("schema conversion should succeed");
+        let resolver = HashMap::from([("network".to_string(), Arc::new(network))]);
+
+        let valid = eure!({
+            host = "localhost"
+            port = 8080
+        });
+        let result = validate_with_resolver(&valid, &server, &resolver);
+        assert!(result.is_valid, "errors: {:?}", result.errors);
+
+        let invalid = eure!({
+            host = "localhost"
+            port = "http"
+        });
+        let result = validate_with_resolver(&invalid, &server, &resolver);
+        assert!(!result.is_valid);
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| matches!(e, ValidationError::TypeMismatch { .. }))
+        );
+
+        let result = validate(&valid, &server);
+        assert!(result.errors.iter().any(|e| matches!(
+            e,
+            ValidationError::UnresolvableReference { namespace, name, cause: None, .. }
+                if namespace == "network" && name == "port"
+        )));
+
+        let empty: HashMap<String, Arc<SchemaDocument>> = HashMap::new();
+        let result = validate_with_resolver(&valid, &server, &empty);
+        assert!(result.errors.iter().any(|e| matches!(
+            e,
+            ValidationError::UnresolvableReference {
+                cause: Some(SchemaResolveError::UnknownNamespace { namespace }),
+                ..
+            } if namespace == "network"
+        )));
+    }
+
     #[test]
     fn test_validate_with_trace_covers_all_node_ids_and_is_deterministic() {
         use eure_document::eure;
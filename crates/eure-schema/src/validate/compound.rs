@@ -48,8 +48,10 @@ impl<'a, 'doc, 's> DocumentParser<'doc> for ArrayValidator<'a, 'doc, 's> {
         };
 
         let len = arr.len();
+        let items: Vec<_> = arr.iter().copied().collect();
 
-        // Validate length constraints
+        // Array-level constraints run in a fixed order: min_length, max_length,
+        // unique, contains. Each reports its own error and none stops the rest.
         if let Some(min) = self.schema.min_length
             && len < min as usize
         {
@@ -76,15 +78,19 @@ impl<'a, 'doc, 's> DocumentParser<'doc> for ArrayValidator<'a, 'doc, 's> {
                     schema_node_id: self.schema_node_id,
                 });
         }
+        if self.schema.unique {
+            self.validate_unique(&items, node_id);
+        }
+        if let Some(contains_schema) = self.schema.contains {
+            self.validate_contains(&items, contains_schema, node_id);
+        }
 
-        // Collect items to avoid borrowing issues
-        let items: Vec<_> = arr.iter().copied().collect();
-
-        // Validate each item using SchemaValidator
-        for (i, item_id) in items.iter().enumerate() {
+        // Validate every item, even after a bad one, so that each failing
+        // element is reported under its own `[i]` path segment
+        for (i, &item_id) in items.iter().enumerate() {
             self.ctx.push_path_index(i);
 
-            let item_ctx = self.ctx.parse_context(*item_id);
+            let item_ctx = self.ctx.parse_context(item_id);
             let child_validator = SchemaValidator {
                 ctx: self.ctx,
                 schema_node_id: self.schema.item,
@@ -94,16 +100,6 @@ impl<'a, 'doc, 's> DocumentParser<'doc> for ArrayValidator<'a, 'doc, 's> {
             self.ctx.pop_path();
         }
 
-        // Validate unique constraint
-        if self.schema.unique {
-            self.validate_unique(&items, node_id);
-        }
-
-        // Validate contains constraint
-        if let Some(contains_schema) = self.schema.contains {
-            self.validate_contains(&items, contains_schema, node_id);
-        }
-
         Ok(())
     }
 }
@@ -115,11 +111,13 @@ impl<'a, 'doc, 's> ArrayValidator<'a, 'doc, 's> {
         array_node_id: eure_document::document::NodeId,
     ) {
         // O(n²) comparison - could be optimized with hashing
-        for i in 0..items.len() {
-            for j in (i + 1)..items.len() {
-                let doc_i = self.ctx.document.node_subtree_to_document(items[i]);
-                let doc_j = self.ctx.document.node_subtree_to_document(items[j]);
-                if doc_i == doc_j {
+        let docs: Vec<_> = items
+            .iter()
+            .map(|&item| self.ctx.document.node_subtree_to_document(item))
+            .collect();
+        for i in 0..docs.len() {
+            for j in (i + 1)..docs.len() {
+                if docs[i] == docs[j] {
                     self.ctx.record_error(ValidationError::ArrayNotUnique {
                         path: self.ctx.path(),
                         node_id: array_node_id,
@@ -140,8 +138,7 @@ impl<'a, 'doc, 's> ArrayValidator<'a, 'doc, 's> {
         for &item_id in items {
             // Fork state for trial validation
             let forked_state = self.ctx.fork_state();
-            let trial_ctx =
-                ValidationContext::with_state(self.ctx.document, self.ctx.schema, forked_state);
+            let trial_ctx = self.ctx.fork_with_state(forked_state);
 
             let item_parse_ctx = trial_ctx.parse_context(item_id);
             let child_validator = SchemaValidator {
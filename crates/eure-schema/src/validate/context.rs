@@ -16,13 +16,14 @@ use eure_document::value::ObjectKey;
 use crate::{SchemaDocument, SchemaNodeContent, SchemaNodeId};
 
 use super::error::{ValidationError, ValidationWarning};
+use super::resolver::SchemaResolver;
 
 // =============================================================================
 // ValidationOutput (final result for public API)
 // =============================================================================
 
 /// Final validation output returned to callers.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct ValidationOutput {
     /// No type errors (holes are allowed)
     pub is_valid: bool,
@@ -54,6 +55,8 @@ pub struct ValidationState {
     /// Temporary storage for variant errors during union validation.
     /// Each entry is (variant_name, variant_schema_id, errors_from_that_variant).
     pub(crate) variant_errors: Vec<(String, SchemaNodeId, Vec<ValidationError>)>,
+    /// Number of nodes dispatched through `SchemaValidator`.
+    pub(crate) visited_nodes: usize,
 }
 
 impl Default for ValidationState {
@@ -64,6 +67,7 @@ impl Default for ValidationState {
             errors: Vec::new(),
             warnings: Vec::new(),
             variant_errors: Vec::new(),
+            visited_nodes: 0,
         }
     }
 }
@@ -142,6 +146,7 @@ impl ValidationState {
             errors: Vec::new(),
             warnings: Vec::new(),
             variant_errors: Vec::new(), // Don't inherit variant errors
+            visited_nodes: 0,
         }
     }
 
@@ -150,6 +155,7 @@ impl ValidationState {
         self.has_holes |= other.has_holes;
         self.errors.extend(other.errors);
         self.warnings.extend(other.warnings);
+        self.visited_nodes += other.visited_nodes;
     }
 
     /// Consume and produce final output.
@@ -200,6 +206,8 @@ pub struct ValidationContext<'a> {
     pub schema: &'a SchemaDocument,
     /// Reference to the document being validated
     pub document: &'a EureDocument,
+    /// Resolver for cross-schema references (`$types.namespace.type-name`)
+    pub resolver: Option<&'a dyn SchemaResolver>,
     /// Mutable state (errors, warnings, path, holes)
     pub state: RefCell<ValidationState>,
 }
@@ -210,10 +218,17 @@ impl<'a> ValidationContext<'a> {
         Self {
             schema,
             document,
+            resolver: None,
             state: RefCell::new(ValidationState::new()),
         }
     }
 
+    /// Resolve cross-schema references with `resolver`.
+    pub fn with_resolver(mut self, resolver: &'a dyn SchemaResolver) -> Self {
+        self.resolver = Some(resolver);
+        self
+    }
+
     /// Create a context with existing state (for fork/merge).
     pub fn with_state(
         document: &'a EureDocument,
@@ -223,6 +238,18 @@ impl<'a> ValidationContext<'a> {
         Self {
             schema,
             document,
+            resolver: None,
+            state: RefCell::new(state),
+        }
+    }
+
+    /// Create a context sharing this context's schema, document, and resolver
+    /// with the given state (for trial validation).
+    pub fn fork_with_state(&self, state: ValidationState) -> Self {
+        Self {
+            schema: self.schema,
+            document: self.document,
+            resolver: self.resolver,
             state: RefCell::new(state),
         }
     }
@@ -308,7 +335,7 @@ impl<'a> ValidationContext<'a> {
                     if type_ref.namespace.is_some() {
                         return content; // Cross-schema refs not resolved
                     }
-                    if let Some(&resolved_id) = self.schema.types.get(&type_ref.name) {
+                    if let Some(resolved_id) = self.schema.resolve_reference(current_id) {
                         current_id = resolved_id;
                     } else {
                         return content; // Unresolved reference
@@ -10,7 +10,9 @@ use eure_document::path::EurePath;
 use eure_document::value::ObjectKey;
 use thiserror::Error;
 
-use crate::SchemaNodeId;
+use crate::{SchemaNodeId, TextFormat};
+
+use super::resolver::SchemaResolveError;
 
 // =============================================================================
 // ValidatorError (fail-fast internal errors)
@@ -180,6 +182,14 @@ pub enum ValidationError {
         schema_node_id: SchemaNodeId,
     },
 
+    #[error("String is not a valid {format} at path {path}")]
+    FormatMismatch {
+        format: TextFormat,
+        path: EurePath,
+        node_id: NodeId,
+        schema_node_id: SchemaNodeId,
+    },
+
     #[error("Array length {length} is out of bounds at path {path}")]
     ArrayLengthOutOfBounds {
         length: usize,
@@ -316,6 +326,18 @@ pub enum ValidationError {
         schema_node_id: SchemaNodeId,
     },
 
+    #[error("{}", format_unresolvable_reference(namespace, name, path, cause.as_ref()))]
+    UnresolvableReference {
+        namespace: String,
+        name: String,
+        path: EurePath,
+        node_id: NodeId,
+        schema_node_id: SchemaNodeId,
+        /// Why the namespace could not be loaded, or `None` if no resolver was
+        /// given or the loaded schema does not define the type.
+        cause: Option<SchemaResolveError>,
+    },
+
     #[error(
         "Invalid flatten target: expected Record, Union, or Map, got {actual_kind} at path {path}"
     )]
@@ -373,6 +395,20 @@ fn format_missing_required_fields(fields: &[String], path: &EurePath) -> String
 }
 
 /// Format a ParseError into a user-friendly validation error message.
+fn format_unresolvable_reference(
+    namespace: &str,
+    name: &str,
+    path: &EurePath,
+    cause: Option<&SchemaResolveError>,
+) -> String {
+    match cause {
+        Some(cause) => {
+            format!("Unresolvable type reference '{namespace}.{name}' at path {path}: {cause}")
+        }
+        None => format!("Unresolvable type reference '{namespace}.{name}' at path {path}"),
+    }
+}
+
 fn format_parse_error(path: &EurePath, error: &eure_document::parse::ParseError) -> String {
     use eure_document::parse::ParseErrorKind;
     match &error.kind {
@@ -468,6 +504,11 @@ impl ValidationError {
                 schema_node_id,
                 ..
             }
+            | Self::FormatMismatch {
+                node_id,
+                schema_node_id,
+                ..
+            }
             | Self::ArrayLengthOutOfBounds {
                 node_id,
                 schema_node_id,
@@ -543,6 +584,11 @@ impl ValidationError {
                 schema_node_id,
                 ..
             }
+            | Self::UnresolvableReference {
+                node_id,
+                schema_node_id,
+                ..
+            }
             | Self::InvalidFlattenTarget {
                 node_id,
                 schema_node_id,
@@ -566,6 +612,40 @@ impl ValidationError {
         }
     }
 
+    /// Get the path of the value this error concerns, relative to the
+    /// validated node.
+    pub fn path(&self) -> &EurePath {
+        match self {
+            Self::TypeMismatch { path, .. }
+            | Self::MissingRequiredField { path, .. }
+            | Self::UnknownField { path, .. }
+            | Self::OutOfRange { path, .. }
+            | Self::StringLengthOutOfBounds { path, .. }
+            | Self::PatternMismatch { path, .. }
+            | Self::FormatMismatch { path, .. }
+            | Self::ArrayLengthOutOfBounds { path, .. }
+            | Self::MapSizeOutOfBounds { path, .. }
+            | Self::TupleLengthMismatch { path, .. }
+            | Self::ArrayNotUnique { path, .. }
+            | Self::ArrayMissingContains { path, .. }
+            | Self::NoVariantMatched { path, .. }
+            | Self::AmbiguousUnion { path, .. }
+            | Self::InvalidVariantTag { path, .. }
+            | Self::ConflictingVariantTags { path, .. }
+            | Self::RequiresExplicitVariant { path, .. }
+            | Self::LiteralMismatch { path, .. }
+            | Self::LanguageMismatch { path, .. }
+            | Self::InvalidKeyType { path, .. }
+            | Self::NotMultipleOf { path, .. }
+            | Self::UndefinedTypeReference { path, .. }
+            | Self::UnresolvableReference { path, .. }
+            | Self::InvalidFlattenTarget { path, .. }
+            | Self::FlattenMapKeyMismatch { path, .. }
+            | Self::MissingRequiredExtension { path, .. }
+            | Self::ParseError { path, .. } => path,
+        }
+    }
+
     /// Find the deepest value-focused error in a chain of NoVariantMatched errors.
     ///
     /// For nested unions, this walks the best_match chain to find the actual error
@@ -590,6 +670,7 @@ impl ValidationError {
                     | Self::OutOfRange { .. }
                     | Self::NotMultipleOf { .. }
                     | Self::PatternMismatch { .. }
+                    | Self::FormatMismatch { .. }
                     | Self::StringLengthOutOfBounds { .. }
                     | Self::InvalidKeyType { .. }
                     | Self::UnknownField { .. } => best.error.deepest_error(),
@@ -613,6 +694,7 @@ impl ValidationError {
             | Self::OutOfRange { path, .. }
             | Self::StringLengthOutOfBounds { path, .. }
             | Self::PatternMismatch { path, .. }
+            | Self::FormatMismatch { path, .. }
             | Self::ArrayLengthOutOfBounds { path, .. }
             | Self::MapSizeOutOfBounds { path, .. }
             | Self::TupleLengthMismatch { path, .. }
@@ -628,6 +710,7 @@ impl ValidationError {
             | Self::InvalidKeyType { path, .. }
             | Self::NotMultipleOf { path, .. }
             | Self::UndefinedTypeReference { path, .. }
+            | Self::UnresolvableReference { path, .. }
             | Self::InvalidFlattenTarget { path, .. }
             | Self::FlattenMapKeyMismatch { path, .. }
             | Self::MissingRequiredExtension { path, .. }
@@ -656,6 +739,7 @@ impl ValidationError {
             Self::OutOfRange { .. } => 30,
             Self::StringLengthOutOfBounds { .. } => 30,
             Self::PatternMismatch { .. } => 30,
+            Self::FormatMismatch { .. } => 30,
             Self::FlattenMapKeyMismatch { .. } => 30, // Similar to PatternMismatch
             Self::ArrayLengthOutOfBounds { .. } => 30,
             Self::MapSizeOutOfBounds { .. } => 30,
@@ -667,10 +751,47 @@ impl ValidationError {
             Self::AmbiguousUnion { .. } => 0, // Not a mismatch
             Self::ConflictingVariantTags { .. } => 0, // Configuration error
             Self::UndefinedTypeReference { .. } => 0, // Configuration error
+            Self::UnresolvableReference { .. } => 0, // Configuration error
             Self::InvalidFlattenTarget { .. } => 0, // Schema construction error
             Self::RequiresExplicitVariant { .. } => 0, // Configuration error
         }
     }
+
+    /// Stable error code for this kind of error.
+    ///
+    /// Validation error codes live in the `E0xxx` range. [`ValidationError::ParseError`]
+    /// reports the code of the underlying parse error.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::TypeMismatch { .. } => "E0001",
+            Self::MissingRequiredField { .. } => "E0002",
+            Self::UnknownField { .. } => "E0003",
+            Self::OutOfRange { .. } => "E0004",
+            Self::StringLengthOutOfBounds { .. } => "E0005",
+            Self::PatternMismatch { .. } => "E0006",
+            Self::ArrayLengthOutOfBounds { .. } => "E0007",
+            Self::MapSizeOutOfBounds { .. } => "E0008",
+            Self::TupleLengthMismatch { .. } => "E0009",
+            Self::ArrayNotUnique { .. } => "E0010",
+            Self::ArrayMissingContains { .. } => "E0011",
+            Self::NoVariantMatched { .. } => "E0012",
+            Self::AmbiguousUnion { .. } => "E0013",
+            Self::InvalidVariantTag { .. } => "E0014",
+            Self::ConflictingVariantTags { .. } => "E0015",
+            Self::RequiresExplicitVariant { .. } => "E0016",
+            Self::LiteralMismatch { .. } => "E0017",
+            Self::LanguageMismatch { .. } => "E0018",
+            Self::InvalidKeyType { .. } => "E0019",
+            Self::NotMultipleOf { .. } => "E0020",
+            Self::UndefinedTypeReference { .. } => "E0021",
+            Self::UnresolvableReference { .. } => "E0022",
+            Self::InvalidFlattenTarget { .. } => "E0023",
+            Self::FlattenMapKeyMismatch { .. } => "E0024",
+            Self::MissingRequiredExtension { .. } => "E0025",
+            Self::FormatMismatch { .. } => "E0026",
+            Self::ParseError { error, .. } => error.kind.code(),
+        }
+    }
 }
 
 // =============================================================================
@@ -683,7 +804,14 @@ pub enum ValidationWarning {
     /// Unknown extension on a node
     UnknownExtension { name: String, path: EurePath },
     /// Deprecated field usage
-    DeprecatedField { field: String, path: EurePath },
+    DeprecatedField {
+        field: String,
+        path: EurePath,
+        /// The record node containing the field.
+        node_id: NodeId,
+        /// The schema node of the field.
+        schema_node_id: SchemaNodeId,
+    },
 }
 
 // =============================================================================
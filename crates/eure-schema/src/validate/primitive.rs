@@ -9,7 +9,7 @@ use eure_document::parse::{DocumentParser, ParseContext};
 use eure_document::value::PrimitiveValue;
 use num_bigint::BigInt;
 
-use crate::{Bound, FloatSchema, IntegerSchema, SchemaNodeId, TextSchema};
+use crate::{Bound, FloatSchema, IntegerSchema, SchemaNodeId, TextFormat, TextSchema};
 
 use super::context::ValidationContext;
 use super::error::{ValidationError, ValidatorError};
@@ -192,10 +192,150 @@ impl<'a, 'doc, 's> DocumentParser<'doc> for TextValidator<'a, 'doc, 's> {
             });
         }
 
+        // Validate format
+        if let Some(format) = self.schema.format
+            && !is_valid_format(format, text.as_str())
+        {
+            self.ctx.record_error(ValidationError::FormatMismatch {
+                format,
+                path: self.ctx.path(),
+                node_id,
+                schema_node_id: self.schema_node_id,
+            });
+        }
+
         Ok(())
     }
 }
 
+// =============================================================================
+// Text formats
+// =============================================================================
+
+fn is_valid_format(format: TextFormat, value: &str) -> bool {
+    match format {
+        TextFormat::Email => is_valid_email(value),
+        TextFormat::Uri => is_valid_uri(value),
+        TextFormat::Uuid => is_valid_uuid(value),
+        TextFormat::DateTime => is_valid_date_time(value),
+        TextFormat::Ipv4 => value.parse::<std::net::Ipv4Addr>().is_ok(),
+        TextFormat::Ipv6 => value.parse::<std::net::Ipv6Addr>().is_ok(),
+    }
+}
+
+/// `local@domain`, where the domain is a dot-separated list of hostname labels.
+fn is_valid_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !local.chars().any(|c| c.is_whitespace() || c.is_control())
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
+
+/// `scheme:rest`, per RFC 3986 scheme syntax, without whitespace.
+fn is_valid_uri(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once(':') else {
+        return false;
+    };
+    let mut scheme_chars = scheme.chars();
+    scheme_chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && scheme_chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        && !rest.is_empty()
+        && !rest.chars().any(|c| c.is_whitespace() || c.is_control())
+}
+
+/// Hyphenated UUID: `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`.
+fn is_valid_uuid(value: &str) -> bool {
+    value.len() == 36
+        && value.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+/// RFC 3339 `date-time`: `YYYY-MM-DDTHH:MM:SS[.frac](Z|+HH:MM|-HH:MM)`.
+fn is_valid_date_time(value: &str) -> bool {
+    fn number(s: &str, range: std::ops::RangeInclusive<u32>) -> Option<u32> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        s.parse().ok().filter(|n| range.contains(n))
+    }
+
+    let Some((date, time)) = value.split_once(['T', 't']) else {
+        return false;
+    };
+
+    // Date
+    let mut parts = date.split('-');
+    let (Some(year), Some(month), Some(day), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+    let (Some(year), Some(month)) = (
+        (year.len() == 4).then(|| number(year, 0..=9999)).flatten(),
+        (month.len() == 2).then(|| number(month, 1..=12)).flatten(),
+    ) else {
+        return false;
+    };
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    if day.len() != 2 || number(day, 1..=days_in_month).is_none() {
+        return false;
+    }
+
+    // Offset
+    let (time, offset) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        (time, None)
+    } else if let Some(sign) = time.rfind(['+', '-']) {
+        (&time[..sign], Some(&time[sign + 1..]))
+    } else {
+        return false;
+    };
+    if let Some(offset) = offset {
+        let Some((hour, minute)) = offset.split_once(':') else {
+            return false;
+        };
+        if hour.len() != 2
+            || minute.len() != 2
+            || number(hour, 0..=23).is_none()
+            || number(minute, 0..=59).is_none()
+        {
+            return false;
+        }
+    }
+
+    // Time
+    let (time, fraction) = match time.split_once('.') {
+        Some((time, fraction)) => (time, Some(fraction)),
+        None => (time, None),
+    };
+    if fraction.is_some_and(|f| f.is_empty() || !f.bytes().all(|b| b.is_ascii_digit())) {
+        return false;
+    }
+    let mut parts = time.split(':');
+    let (Some(hour), Some(minute), Some(second), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+    [(hour, 23), (minute, 59), (second, 60)]
+        .into_iter()
+        .all(|(part, max)| part.len() == 2 && number(part, 0..=max).is_some())
+}
+
 fn primitive_type_name(p: &PrimitiveValue) -> String {
     match p {
         PrimitiveValue::Null => "null".to_string(),
@@ -425,8 +565,9 @@ impl<'a, 'doc> DocumentParser<'doc> for NullValidator<'a, 'doc> {
 
 /// Validates literal values (exact match).
 ///
-/// Note: When validating inside a union, accessed extensions (like `$variant`)
-/// are excluded from comparison because they were consumed during parsing/validation.
+/// Values are compared with [`EureDocument::data_eq`]: maps, arrays and tuples
+/// must match the literal entry for entry, while extensions on either side
+/// (like a `$variant` consumed by a union) are ignored.
 pub struct LiteralValidator<'a, 'doc, 's> {
     pub ctx: &'a ValidationContext<'doc>,
     pub expected: &'s EureDocument,
@@ -439,10 +580,9 @@ impl<'a, 'doc, 's> DocumentParser<'doc> for LiteralValidator<'a, 'doc, 's> {
 
     fn parse(&mut self, parse_ctx: &ParseContext<'doc>) -> Result<(), ValidatorError> {
         let node_id = parse_ctx.node_id();
-        // Get subtree excluding accessed extensions (like $variant consumed by union)
         let actual = parse_ctx.node_subtree_to_document_excluding_accessed();
 
-        if actual != *self.expected {
+        if !actual.data_eq(self.expected) {
             self.ctx.record_error(ValidationError::LiteralMismatch {
                 expected: format!("{:?}", self.expected),
                 actual: format!("{:?}", actual),
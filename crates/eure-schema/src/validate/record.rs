@@ -85,6 +85,8 @@ impl<'a, 'doc, 's> DocumentParser<'doc> for RecordValidator<'a, 'doc, 's> {
                     self.ctx.record_warning(ValidationWarning::DeprecatedField {
                         field: field_name.to_string(),
                         path: self.ctx.path(),
+                        node_id,
+                        schema_node_id: field_schema.schema,
                     });
                 }
 
@@ -217,9 +219,9 @@ impl<'a, 'doc, 's> RecordValidator<'a, 'doc, 's> {
         let node = self.ctx.schema.node(schema_id);
         match &node.content {
             SchemaNodeContent::Map(_) => true,
-            SchemaNodeContent::Reference(type_ref) => {
+            SchemaNodeContent::Reference(_) => {
                 // Resolve the reference and recurse
-                if let Some(resolved_id) = self.ctx.schema.get_type(&type_ref.name) {
+                if let Some(resolved_id) = self.ctx.schema.resolve_reference(schema_id) {
                     self.flatten_target_is_map(resolved_id)
                 } else {
                     false
@@ -254,7 +256,7 @@ impl<'a, 'doc, 's> RecordValidator<'a, 'doc, 's> {
             ),
             SchemaNodeContent::Reference(type_ref) => {
                 // Resolve the reference and recurse
-                if let Some(resolved_id) = self.ctx.schema.get_type(&type_ref.name) {
+                if let Some(resolved_id) = self.ctx.schema.resolve_reference(flatten_schema_id) {
                     self.validate_flatten_target(flatten_ctx, resolved_id, parent_node_id)
                 } else {
                     // Record error for undefined type reference
@@ -384,7 +386,7 @@ impl<'a, 'doc, 's> RecordValidator<'a, 'doc, 's> {
         union_owned_fields: &HashSet<String>,
     ) -> Result<FlattenVariantTrial, ValidatorError> {
         let forked = self.ctx.fork_state();
-        let trial = ValidationContext::with_state(self.ctx.document, self.ctx.schema, forked);
+        let trial = self.ctx.fork_with_state(forked);
         let _ = flatten_ctx.parse_with(SchemaValidator {
             ctx: &trial,
             schema_node_id,
@@ -462,8 +464,8 @@ impl<'a, 'doc, 's> RecordValidator<'a, 'doc, 's> {
                     self.collect_owned_field_names(variant_schema_id, field_names, visited);
                 }
             }
-            SchemaNodeContent::Reference(type_ref) => {
-                if let Some(resolved_id) = self.ctx.schema.get_type(&type_ref.name) {
+            SchemaNodeContent::Reference(_) => {
+                if let Some(resolved_id) = self.ctx.schema.resolve_reference(schema_id) {
                     self.collect_owned_field_names(resolved_id, field_names, visited);
                 }
             }
@@ -2,6 +2,8 @@
 //!
 //! Resolves type references and delegates to SchemaValidator.
 
+use std::cell::RefCell;
+
 use eure_document::parse::{DocumentParser, ParseContext};
 
 use crate::{SchemaNodeId, TypeReference};
@@ -16,8 +18,9 @@ use super::error::{ValidationError, ValidatorError};
 
 /// Validates values by resolving type references.
 ///
-/// Looks up the referenced type in schema.types and delegates
-/// validation to SchemaValidator with the resolved schema.
+/// Resolves the referenced type via `SchemaDocument::resolve_reference` and delegates
+/// validation to SchemaValidator with the resolved schema. Cross-schema
+/// references are looked up in the schema loaded by the context's resolver.
 pub struct ReferenceValidator<'a, 'doc, 's> {
     pub ctx: &'a ValidationContext<'doc>,
     pub type_ref: &'s TypeReference,
@@ -31,20 +34,12 @@ impl<'a, 'doc, 's> DocumentParser<'doc> for ReferenceValidator<'a, 'doc, 's> {
     fn parse(&mut self, parse_ctx: &ParseContext<'doc>) -> Result<(), ValidatorError> {
         let node_id = parse_ctx.node_id();
 
-        // Cross-schema references not supported
         if let Some(namespace) = &self.type_ref.namespace {
-            self.ctx
-                .record_error(ValidationError::UndefinedTypeReference {
-                    name: format!("{}.{}", namespace, self.type_ref.name),
-                    path: self.ctx.path(),
-                    node_id,
-                    schema_node_id: self.schema_node_id,
-                });
-            return Ok(());
+            return self.parse_external(parse_ctx, namespace);
         }
 
-        // Look up the type in schema.types
-        if let Some(&resolved_id) = self.ctx.schema.types.get(&self.type_ref.name) {
+        // Follow the interned reference target
+        if let Some(resolved_id) = self.ctx.schema.resolve_reference(self.schema_node_id) {
             // Delegate to SchemaValidator with resolved type
             let child_validator = SchemaValidator {
                 ctx: self.ctx,
@@ -63,3 +58,50 @@ impl<'a, 'doc, 's> DocumentParser<'doc> for ReferenceValidator<'a, 'doc, 's> {
         }
     }
 }
+
+impl<'a, 'doc, 's> ReferenceValidator<'a, 'doc, 's> {
+    /// Validate against a type defined in the schema behind `namespace`.
+    fn parse_external(
+        &self,
+        parse_ctx: &ParseContext<'doc>,
+        namespace: &str,
+    ) -> Result<(), ValidatorError> {
+        let unresolvable = |cause| {
+            self.ctx
+                .record_error(ValidationError::UnresolvableReference {
+                    namespace: namespace.to_string(),
+                    name: self.type_ref.name.to_string(),
+                    path: self.ctx.path(),
+                    node_id: parse_ctx.node_id(),
+                    schema_node_id: self.schema_node_id,
+                    cause,
+                });
+            Ok(())
+        };
+        let Some(resolver) = self.ctx.resolver else {
+            return unresolvable(None);
+        };
+        let schema = match resolver.resolve(namespace) {
+            Ok(schema) => schema,
+            Err(error) => return unresolvable(Some(error)),
+        };
+        let Some(&resolved_id) = schema.types.get(&self.type_ref.name) else {
+            return unresolvable(None);
+        };
+
+        // Validate inside the external schema, sharing path and accumulated state.
+        let external = ValidationContext {
+            schema: &schema,
+            document: self.ctx.document,
+            resolver: self.ctx.resolver,
+            state: RefCell::new(self.ctx.fork_state()),
+        };
+        let mut child_validator = SchemaValidator {
+            ctx: &external,
+            schema_node_id: resolved_id,
+        };
+        let result = child_validator.parse(parse_ctx);
+        self.ctx.merge_state(external.state.into_inner());
+        result
+    }
+}
@@ -0,0 +1,92 @@
+//! Cross-schema reference resolution
+//!
+//! A type reference with a namespace (`$types.namespace.type-name`) points into
+//! another schema document. `SchemaResolver` loads that document on demand so
+//! validation can continue inside it.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use eure_parol::EureParseError;
+use thiserror::Error;
+
+use crate::SchemaDocument;
+use crate::convert::ConversionError;
+
+/// Error returned when a schema namespace cannot be loaded.
+#[derive(Debug, Clone, Error, PartialEq)]
+pub enum SchemaResolveError {
+    #[error("unknown schema namespace '{namespace}'")]
+    UnknownNamespace { namespace: String },
+
+    #[error("cannot read schema '{}' of namespace '{namespace}': {kind}", path.display())]
+    Read {
+        namespace: String,
+        path: PathBuf,
+        kind: std::io::ErrorKind,
+    },
+
+    #[error("invalid schema URL '{location}' for namespace '{namespace}'")]
+    InvalidUrl { namespace: String, location: String },
+
+    #[error(
+        "remote host not allowed for namespace '{namespace}': {host}; add it to security.allowed-hosts in Eure.eure"
+    )]
+    HostNotAllowed { namespace: String, host: String },
+
+    #[error("cannot fetch schema '{url}' of namespace '{namespace}': {message}")]
+    Fetch {
+        namespace: String,
+        url: String,
+        message: String,
+    },
+
+    #[error("schema of namespace '{namespace}' has a syntax error: {error}")]
+    Syntax {
+        namespace: String,
+        error: EureParseError,
+    },
+
+    #[error("schema of namespace '{namespace}' is not a valid document: {message}")]
+    InvalidDocument { namespace: String, message: String },
+
+    #[error("schema of namespace '{namespace}' is not a valid schema: {error}")]
+    Conversion {
+        namespace: String,
+        error: ConversionError,
+    },
+}
+
+impl SchemaResolveError {
+    /// The namespace that failed to load.
+    pub fn namespace(&self) -> &str {
+        match self {
+            Self::UnknownNamespace { namespace }
+            | Self::Read { namespace, .. }
+            | Self::InvalidUrl { namespace, .. }
+            | Self::HostNotAllowed { namespace, .. }
+            | Self::Fetch { namespace, .. }
+            | Self::Syntax { namespace, .. }
+            | Self::InvalidDocument { namespace, .. }
+            | Self::Conversion { namespace, .. } => namespace,
+        }
+    }
+}
+
+/// Loads the schema document behind a cross-schema reference namespace.
+pub trait SchemaResolver {
+    /// Resolve `namespace` to its schema document.
+    fn resolve(&self, namespace: &str) -> Result<Arc<SchemaDocument>, SchemaResolveError>;
+}
+
+/// In-memory resolver keyed by namespace.
+impl SchemaResolver for HashMap<String, Arc<SchemaDocument>> {
+    fn resolve(&self, namespace: &str) -> Result<Arc<SchemaDocument>, SchemaResolveError> {
+        self.get(namespace)
+            .cloned()
+            .ok_or_else(|| SchemaResolveError::UnknownNamespace {
+                namespace: namespace.to_string(),
+            })
+    }
+}
@@ -79,7 +79,7 @@ impl<'a> TraceResolver<'a> {
                             },
                         ),
                     );
-                } else if let Some(target_id) = self.schema.get_type(&type_ref.name) {
+                } else if let Some(target_id) = self.schema.resolve_reference(schema_id) {
                     let next_trace = trace.with_hop(schema_path(self.schema_node_paths, target_id));
                     self.resolve_node(node_id, target_id, next_trace, forced_variant);
                 } else {
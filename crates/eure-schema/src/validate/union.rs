@@ -3,7 +3,7 @@
 //! Validates union values using parse_union() API pattern.
 
 use eure_document::parse::union::has_explicit_variant_tag;
-use eure_document::parse::{DocumentParser, ParseContext};
+use eure_document::parse::{DocumentParser, ParseContext, ParseErrorKind};
 
 use crate::{SchemaNodeId, UnionSchema};
 
@@ -122,7 +122,18 @@ impl<'a, 'doc, 's> DocumentParser<'doc> for UnionValidator<'a, 'doc, 's> {
                 }
 
                 // Convert ValidatorError to ValidationError with schema context
-                if let Some(parse_error) = e.as_parse_error() {
+                if let Some(parse_error) = e.as_parse_error()
+                    && let ParseErrorKind::AmbiguousUnion(variants) = &parse_error.kind
+                {
+                    // Several unambiguous variants matched and no short-circuit
+                    // variant took precedence
+                    self.ctx.record_error(ValidationError::AmbiguousUnion {
+                        path: self.ctx.path(),
+                        variants: variants.clone(),
+                        node_id: parse_ctx.node_id(),
+                        schema_node_id: self.schema_node_id,
+                    });
+                } else if let Some(parse_error) = e.as_parse_error() {
                     // Wrap ParseError with schema context
                     self.ctx.record_error(ValidationError::ParseError {
                         path: self.ctx.path(),
@@ -168,7 +179,7 @@ fn validate_variant<'doc>(
 ) -> Result<(), ValidatorError> {
     // Fork state for trial validation
     let forked_state = ctx.fork_state();
-    let trial_ctx = ValidationContext::with_state(ctx.document, ctx.schema, forked_state);
+    let trial_ctx = ctx.fork_with_state(forked_state);
 
     let child_validator = SchemaValidator {
         ctx: &trial_ctx,
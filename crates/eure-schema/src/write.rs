@@ -2,6 +2,7 @@
 
 use crate::identifiers::{CONTENT, EXT_TYPE, OPTIONAL, TAG, VARIANT, VARIANT_REPR};
 use crate::interop::VariantRepr;
+use crate::range::render_range;
 use crate::{
     ArraySchema, BindingStyle, Bound, CodegenDefaults, Description, ExtTypeSchema, FieldCodegen,
     FloatPrecision, FloatSchema, IntegerSchema, MapSchema, RecordCodegen, RecordFieldSchema,
@@ -843,26 +844,7 @@ fn format_bound_range<T>(
     if matches!(min, Bound::Unbounded) && matches!(max, Bound::Unbounded) {
         return None;
     }
-
-    let left = match min {
-        Bound::Inclusive(_) => '[',
-        Bound::Exclusive(_) | Bound::Unbounded => '(',
-    };
-    let right = match max {
-        Bound::Inclusive(_) => ']',
-        Bound::Exclusive(_) | Bound::Unbounded => ')',
-    };
-
-    let min_str = match min {
-        Bound::Unbounded => String::new(),
-        Bound::Inclusive(v) | Bound::Exclusive(v) => format_value(v),
-    };
-    let max_str = match max {
-        Bound::Unbounded => String::new(),
-        Bound::Inclusive(v) | Bound::Exclusive(v) => format_value(v),
-    };
-
-    Some(format!("{left}{min_str}, {max_str}{right}"))
+    Some(render_range(min, max, format_value))
 }
 
 fn format_bigint(value: &BigInt) -> String {
@@ -1070,6 +1052,7 @@ mod tests {
             min_length: None,
             max_length: None,
             pattern: None,
+            format: None,
             unknown_fields: Default::default(),
         }));
 
@@ -1089,6 +1072,7 @@ mod tests {
                 min_length: Some(1),
                 max_length: None,
                 pattern: None,
+                format: None,
                 unknown_fields: Default::default(),
             }));
         let constrained_doc = schema_to_document(&schema_constrained).expect("schema emit");
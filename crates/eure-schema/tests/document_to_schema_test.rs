@@ -2251,6 +2251,22 @@ fn test_error_invalid_float_range_format() {
     );
 }
 
+#[test]
+fn test_error_invalid_text_pattern() {
+    let doc = eure!({
+        field {
+            %variant = @code("text"),
+            pattern = "[a-z",
+        },
+    });
+    let result = document_to_schema(&doc);
+
+    assert!(matches!(
+        result.unwrap_err(),
+        ConversionError::InvalidPattern { pattern, .. } if pattern == "[a-z"
+    ));
+}
+
 #[test]
 fn test_error_invalid_type_path() {
     let doc = eure!({
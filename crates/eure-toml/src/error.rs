@@ -16,4 +16,33 @@ pub enum TomlToEureError {
     /// Value decode error.
     #[error("Decode error: {message}")]
     DecodeError { message: String },
+
+    /// A key or table is defined again, or extended after being bound to a
+    /// value that cannot hold keys. Such input is not valid TOML.
+    ///
+    /// `key` is the dotted path of the redefined binding.
+    #[error("Invalid TOML: '{key}' is defined more than once")]
+    Redefinition { key: String },
+
+    /// A TOML value or construct that has no Eure counterpart here.
+    ///
+    /// `key` is the dotted path of the offending binding.
+    #[error("Unsupported TOML {kind} at '{key}'")]
+    UnsupportedValue { key: String, kind: &'static str },
+
+    /// I/O error while reading TOML input or writing Eure output.
+    #[error("I/O error: {message}")]
+    Io {
+        kind: std::io::ErrorKind,
+        message: String,
+    },
+}
+
+impl From<std::io::Error> for TomlToEureError {
+    fn from(error: std::io::Error) -> Self {
+        TomlToEureError::Io {
+            kind: error.kind(),
+            message: error.to_string(),
+        }
+    }
 }
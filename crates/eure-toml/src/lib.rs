@@ -24,8 +24,9 @@ pub use error::TomlToEureError;
 pub use query::{TomlToEureDocument, TomlToEureSource};
 
 use eure_document::document::constructor::{DocumentConstructor, Scope};
+use eure_document::document::node::NodeValue;
 use eure_document::identifier::Identifier;
-use eure_document::path::{ArrayIndexKind, PathSegment};
+use eure_document::path::{ArrayIndexKind, EurePath, PathSegment};
 use eure_document::source::{
     ArrayElementSource, BindSource, BindingSource, Comment, EureSource, SectionBody,
     SourceDocument, SourceKey, SourcePathSegment, Trivia,
@@ -34,6 +35,7 @@ use eure_document::text::{Language, Text};
 use eure_document::value::ObjectKey;
 use eure_document::value::PrimitiveValue;
 use num_bigint::BigInt;
+use std::io::{Read, Write};
 use toml_parser::decoder::Encoding;
 use toml_parser::decoder::ScalarKind;
 use toml_parser::parser::EventReceiver;
@@ -60,6 +62,22 @@ pub fn to_source_document(toml_str: &str) -> Result<SourceDocument, TomlToEureEr
     converter.finish()
 }
 
+/// Convert TOML read from `reader` and write formatted Eure source to `writer`.
+///
+/// This is a convenience for file-to-file conversion that avoids assembling
+/// intermediate strings on the caller side.
+pub fn convert_reader<R: Read, W: Write>(
+    mut reader: R,
+    mut writer: W,
+) -> Result<(), TomlToEureError> {
+    let mut toml_str = String::new();
+    reader.read_to_string(&mut toml_str)?;
+    let source_doc = to_source_document(&toml_str)?;
+    writer.write_all(format_source_document(&source_doc).as_bytes())?;
+    writer.flush()?;
+    Ok(())
+}
+
 /// Error collector for toml_parser
 struct ErrorCollector {
     errors: Vec<TomlToEureError>,
@@ -160,6 +178,9 @@ struct TomlParserConverter<'a> {
 
     /// Array nodes that should be formatted multi-line
     multiline_arrays: std::collections::HashSet<eure_document::document::NodeId>,
+
+    /// Conversion errors, reported by `finish`
+    errors: Vec<TomlToEureError>,
 }
 
 impl<'a> TomlParserConverter<'a> {
@@ -176,21 +197,44 @@ impl<'a> TomlParserConverter<'a> {
             pending_trivia: Vec::new(),
             saw_newline: false,
             multiline_arrays: std::collections::HashSet::new(),
+            errors: Vec::new(),
         }
     }
 
     fn finish(mut self) -> Result<SourceDocument, TomlToEureError> {
+        if let Some(err) = self.errors.first() {
+            return Err(err.clone());
+        }
         // Close any remaining sections
         self.close_current_section();
-        // Any remaining pending trivia becomes trailing trivia of the root source
+        // Any remaining pending trivia becomes trailing trivia of the root source,
+        // or its leading trivia if the file holds nothing but comments
         if !self.pending_trivia.is_empty() {
-            self.sources[0].trailing_trivia = std::mem::take(&mut self.pending_trivia);
+            let trivia = std::mem::take(&mut self.pending_trivia);
+            if self.root_is_empty() {
+                self.sources[0].leading_trivia.extend(trivia);
+            } else {
+                self.sources[0].trailing_trivia = trivia;
+            }
         }
         let mut source_doc = SourceDocument::new(self.constructor.finish(), self.sources);
         source_doc.multiline_arrays = self.multiline_arrays;
         Ok(source_doc)
     }
 
+    /// Whether no value, binding, or section has been added to the root source yet.
+    fn root_is_empty(&self) -> bool {
+        let root = &self.sources[0];
+        root.value.is_none() && root.bindings.is_empty() && root.sections.is_empty()
+    }
+
+    /// Whether the parser is still before the first item of the file.
+    fn at_document_start(&self) -> bool {
+        matches!(self.context_stack.as_slice(), [ValueContext::Root])
+            && self.current_keys.is_empty()
+            && self.root_is_empty()
+    }
+
     fn current_context(&self) -> &ValueContext {
         self.context_stack.last().unwrap()
     }
@@ -285,6 +329,66 @@ impl<'a> TomlParserConverter<'a> {
         }
     }
 
+    /// Navigate through `current_keys`, recording an error if a key cannot
+    /// hold further keys
+    fn navigate_current_keys(&mut self) -> bool {
+        let segments: Vec<_> = self
+            .current_keys
+            .iter()
+            .map(|(key, _)| self.parse_key(key).1)
+            .collect();
+        for segment in segments {
+            if self.constructor.navigate(segment.clone()).is_err() {
+                self.navigation_failed(segment);
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Dotted path of the binding currently being converted
+    fn key_path(&self) -> String {
+        let mut path = self.constructor.current_path().to_vec();
+        path.extend(
+            self.current_keys
+                .iter()
+                .map(|(key, _)| self.parse_key(key).1),
+        );
+        EurePath::from_iter(path).to_string()
+    }
+
+    /// Record that navigating to `segment` failed, because the value already
+    /// there cannot hold keys
+    fn navigation_failed(&mut self, segment: PathSegment) {
+        let mut path = self.constructor.current_path().to_vec();
+        let into_array = matches!(self.constructor.current_node().content, NodeValue::Array(_));
+        path.push(segment);
+        let key = EurePath::from_iter(path).to_string();
+        self.errors.push(if into_array {
+            TomlToEureError::UnsupportedValue {
+                key,
+                kind: "table inside an array",
+            }
+        } else {
+            TomlToEureError::Redefinition { key }
+        });
+    }
+
+    /// Record that the value at the current position is defined again
+    fn redefined_here(&mut self) {
+        self.errors.push(TomlToEureError::Redefinition {
+            key: EurePath::from_iter(self.constructor.current_path().iter().cloned()).to_string(),
+        });
+    }
+
+    /// Record a value that cannot be converted at the current key path
+    fn unsupported(&mut self, kind: &'static str) {
+        self.errors.push(TomlToEureError::UnsupportedValue {
+            key: self.key_path(),
+            kind,
+        });
+    }
+
     /// Create a SourcePathSegment from a SourceKey
     fn source_path_segment(&self, key: SourceKey) -> SourcePathSegment {
         SourcePathSegment { key, array: None }
@@ -300,13 +404,6 @@ impl<'a> TomlParserConverter<'a> {
         }
     }
 
-    /// Navigate to the key path and bind a value
-    fn bind_value(&mut self, value: PrimitiveValue) {
-        self.constructor
-            .bind_primitive(value)
-            .expect("binding should succeed");
-    }
-
     /// Add a binding to the current context
     fn add_binding(&mut self, path: Vec<SourcePathSegment>, node: eure_document::document::NodeId) {
         // Don't consume trivia when in inline contexts (it should go to the outer binding)
@@ -384,7 +481,7 @@ impl<'a> TomlParserConverter<'a> {
         kind: ScalarKind,
         value: &str,
         encoding: Option<Encoding>,
-    ) -> PrimitiveValue {
+    ) -> Result<PrimitiveValue, &'static str> {
         match kind {
             ScalarKind::String => {
                 // Check if this is a multi-line string (TOML """ or ''')
@@ -403,33 +500,22 @@ impl<'a> TomlParserConverter<'a> {
                         content.push('\n');
                     }
 
-                    // Find the minimum block level needed
-                    let syntax_hint = if content.contains("``````") {
-                        // Content has 6 backticks, can't safely delimit
-                        // Use Block6 and hope for the best
-                        SyntaxHint::Block6
-                    } else if content.contains("`````") {
-                        SyntaxHint::Block6
-                    } else if content.contains("````") {
-                        SyntaxHint::Block5
-                    } else if content.contains("```") {
-                        SyntaxHint::Block4
-                    } else {
-                        SyntaxHint::Block3
+                    let mut text = Text::new(content, Language::Implicit);
+                    let Some(fence_len) = text.fence_len() else {
+                        // No code block can hold 6 backticks in a row; keep the
+                        // string as escaped plaintext instead
+                        return Ok(PrimitiveValue::Text(Text::plaintext(value.to_string())));
                     };
-
-                    PrimitiveValue::Text(Text {
-                        content,
-                        language: Language::Implicit,
-                        syntax_hint: Some(syntax_hint),
-                    })
+                    text.syntax_hint = Some(SyntaxHint::block_with_fence_len(fence_len));
+                    debug_assert!(text.validate_consistency().is_ok());
+                    Ok(PrimitiveValue::Text(text))
                 } else {
                     // Use plaintext for single-line strings
                     let text = Text::plaintext(value.to_string());
-                    PrimitiveValue::Text(text)
+                    Ok(PrimitiveValue::Text(text))
                 }
             }
-            ScalarKind::Boolean(b) => PrimitiveValue::Bool(b),
+            ScalarKind::Boolean(b) => Ok(PrimitiveValue::Bool(b)),
             ScalarKind::Integer(_radix) => {
                 // Parse the integer, handling underscores
                 let clean: String = value.chars().filter(|c| *c != '_').collect();
@@ -443,29 +529,27 @@ impl<'a> TomlParserConverter<'a> {
                     clean.parse::<i64>()
                 };
                 match parsed {
-                    Ok(n) => PrimitiveValue::Integer(BigInt::from(n)),
-                    Err(_) => {
-                        // i64 overflow: try parsing as BigInt for very large numbers
-                        let n = clean.parse::<BigInt>().unwrap_or_else(|e| {
-                            panic!("TOML parser validated integer '{clean}' failed to parse: {e}")
-                        });
-                        PrimitiveValue::Integer(n)
-                    }
+                    Ok(n) => Ok(PrimitiveValue::Integer(BigInt::from(n))),
+                    // i64 overflow: try parsing as BigInt for very large numbers
+                    Err(_) => clean
+                        .parse::<BigInt>()
+                        .map(PrimitiveValue::Integer)
+                        .map_err(|_| "integer"),
                 }
             }
             ScalarKind::Float => {
                 let clean: String = value.chars().filter(|c| *c != '_').collect();
                 if clean == "inf" || clean == "+inf" {
-                    PrimitiveValue::F64(f64::INFINITY)
+                    Ok(PrimitiveValue::F64(f64::INFINITY))
                 } else if clean == "-inf" {
-                    PrimitiveValue::F64(f64::NEG_INFINITY)
+                    Ok(PrimitiveValue::F64(f64::NEG_INFINITY))
                 } else if clean == "nan" || clean == "+nan" || clean == "-nan" {
-                    PrimitiveValue::F64(f64::NAN)
+                    Ok(PrimitiveValue::F64(f64::NAN))
                 } else {
-                    let f = clean.parse::<f64>().unwrap_or_else(|e| {
-                        panic!("TOML parser validated float '{clean}' failed to parse: {e}")
-                    });
-                    PrimitiveValue::F64(f)
+                    clean
+                        .parse::<f64>()
+                        .map(PrimitiveValue::F64)
+                        .map_err(|_| "float")
                 }
             }
             ScalarKind::DateTime => {
@@ -480,7 +564,10 @@ impl<'a> TomlParserConverter<'a> {
                     // Date only
                     "date"
                 };
-                PrimitiveValue::Text(Text::new(value.to_string(), Language::Other(lang.into())))
+                Ok(PrimitiveValue::Text(Text::new(
+                    value.to_string(),
+                    Language::Other(lang.into()),
+                )))
             }
         }
     }
@@ -519,22 +606,31 @@ impl<'a> EventReceiver for TomlParserConverter<'a> {
         let scope = self.constructor.begin_scope();
 
         // Navigate for each segment
+        let mut navigated = true;
         for seg in &path {
             let path_seg = match &seg.key {
                 SourceKey::Ident(id) => PathSegment::Ident(id.clone()),
                 SourceKey::String(s, _) => PathSegment::Value(ObjectKey::String(s.clone())),
                 _ => continue,
             };
-            self.constructor
-                .navigate(path_seg)
-                .expect("navigation should succeed");
+            if self.constructor.navigate(path_seg.clone()).is_err() {
+                self.navigation_failed(path_seg);
+                navigated = false;
+                break;
+            }
         }
 
         // Ensure it's a map
-        if self.constructor.current_node().content.is_hole() {
-            self.constructor
-                .bind_empty_map()
-                .expect("binding should succeed");
+        if navigated {
+            match self.constructor.current_node().content {
+                NodeValue::Hole(_) => {
+                    if self.constructor.bind_empty_map().is_err() {
+                        self.redefined_here();
+                    }
+                }
+                NodeValue::Map(_) => {}
+                _ => self.redefined_here(),
+            }
         }
 
         self.context_stack.push(ValueContext::StdTable {
@@ -580,30 +676,50 @@ impl<'a> EventReceiver for TomlParserConverter<'a> {
         // Navigate to this path in the document
         let scope = self.constructor.begin_scope();
 
+        let mut navigated = true;
         for (i, (key, _)) in keys.iter().enumerate() {
             let (_, path_seg) = self.parse_key(key);
-            self.constructor
-                .navigate(path_seg)
-                .expect("navigation should succeed");
+            if self.constructor.navigate(path_seg.clone()).is_err() {
+                self.navigation_failed(path_seg);
+                navigated = false;
+                break;
+            }
 
             if i == keys.len() - 1 {
                 // Last key - ensure it's an array and push new element
-                if self.constructor.current_node().content.is_hole() {
-                    self.constructor
-                        .bind_empty_array()
-                        .expect("binding should succeed");
+                match self.constructor.current_node().content {
+                    NodeValue::Hole(_) => {
+                        if self.constructor.bind_empty_array().is_err() {
+                            self.redefined_here();
+                            navigated = false;
+                            break;
+                        }
+                    }
+                    NodeValue::Array(_) => {}
+                    _ => {
+                        self.redefined_here();
+                        navigated = false;
+                        break;
+                    }
                 }
-                self.constructor
+                if self
+                    .constructor
                     .navigate(PathSegment::ArrayIndex(ArrayIndexKind::Push))
-                    .expect("array navigation should succeed");
+                    .is_err()
+                {
+                    self.redefined_here();
+                    navigated = false;
+                    break;
+                }
             }
         }
 
         // Ensure current position is a map
-        if self.constructor.current_node().content.is_hole() {
-            self.constructor
-                .bind_empty_map()
-                .expect("binding should succeed");
+        if navigated
+            && self.constructor.current_node().content.is_hole()
+            && self.constructor.bind_empty_map().is_err()
+        {
+            self.redefined_here();
         }
 
         self.context_stack.push(ValueContext::ArrayTable {
@@ -630,12 +746,7 @@ impl<'a> EventReceiver for TomlParserConverter<'a> {
             .collect();
 
         // Navigate to the key path first
-        for (key, _) in &self.current_keys {
-            let (_, path_seg) = self.parse_key(key);
-            self.constructor
-                .navigate(path_seg)
-                .expect("navigation should succeed");
-        }
+        let mut navigated = self.navigate_current_keys();
 
         // Check if we're in an array context (values don't have keys)
         if let Some(ValueContext::Array {
@@ -645,9 +756,19 @@ impl<'a> EventReceiver for TomlParserConverter<'a> {
             ..
         }) = self.context_stack.last_mut()
         {
-            self.constructor
-                .navigate(PathSegment::ArrayIndex(ArrayIndexKind::Push))
-                .expect("array navigation should succeed");
+            if navigated
+                && self
+                    .constructor
+                    .navigate(PathSegment::ArrayIndex(ArrayIndexKind::Push))
+                    .is_err()
+            {
+                navigated = false;
+                // `self.redefined_here()` would borrow the context stack again
+                let key = EurePath::from_iter(self.constructor.current_path().iter().cloned());
+                self.errors.push(TomlToEureError::Redefinition {
+                    key: key.to_string(),
+                });
+            }
 
             // Capture pending trivia for this element
             let trivia = std::mem::take(element_pending_trivia);
@@ -663,9 +784,9 @@ impl<'a> EventReceiver for TomlParserConverter<'a> {
             self.saw_newline = false;
         }
 
-        self.constructor
-            .bind_empty_map()
-            .expect("binding should succeed");
+        if navigated && self.constructor.bind_empty_map().is_err() {
+            self.redefined_here();
+        }
         self.context_stack.push(ValueContext::InlineTable {
             scope,
             binding_path,
@@ -704,12 +825,7 @@ impl<'a> EventReceiver for TomlParserConverter<'a> {
             .collect();
 
         // Navigate to the key path first
-        for (key, _) in &self.current_keys {
-            let (_, path_seg) = self.parse_key(key);
-            self.constructor
-                .navigate(path_seg)
-                .expect("navigation should succeed");
-        }
+        let mut navigated = self.navigate_current_keys();
 
         // Check if we're in an array context (nested arrays)
         // Handle pending trivia for this element from parent array
@@ -720,9 +836,19 @@ impl<'a> EventReceiver for TomlParserConverter<'a> {
             ..
         }) = self.context_stack.last_mut()
         {
-            self.constructor
-                .navigate(PathSegment::ArrayIndex(ArrayIndexKind::Push))
-                .expect("array navigation should succeed");
+            if navigated
+                && self
+                    .constructor
+                    .navigate(PathSegment::ArrayIndex(ArrayIndexKind::Push))
+                    .is_err()
+            {
+                navigated = false;
+                // `self.redefined_here()` would borrow the context stack again
+                let key = EurePath::from_iter(self.constructor.current_path().iter().cloned());
+                self.errors.push(TomlToEureError::Redefinition {
+                    key: key.to_string(),
+                });
+            }
             let trivia = std::mem::take(element_pending_trivia);
             let idx = *element_index;
             *element_index += 1;
@@ -736,9 +862,9 @@ impl<'a> EventReceiver for TomlParserConverter<'a> {
             self.saw_newline = false;
         }
 
-        self.constructor
-            .bind_empty_array()
-            .expect("binding should succeed");
+        if navigated && self.constructor.bind_empty_array().is_err() {
+            self.redefined_here();
+        }
         self.context_stack.push(ValueContext::Array {
             scope,
             element_index: 0,
@@ -807,7 +933,14 @@ impl<'a> EventReceiver for TomlParserConverter<'a> {
 
     fn scalar(&mut self, span: Span, kind: Option<Encoding>, _error: &mut dyn ErrorSink) {
         let (scalar_kind, value) = self.decode_scalar(span, kind);
-        let primitive = self.scalar_to_primitive(scalar_kind, &value, kind);
+        let primitive = match self.scalar_to_primitive(scalar_kind, &value, kind) {
+            Ok(primitive) => primitive,
+            Err(kind) => {
+                self.unsupported(kind);
+                self.current_keys.clear();
+                return;
+            }
+        };
 
         // Build path from current_keys
         let path: Vec<SourcePathSegment> = self
@@ -821,11 +954,10 @@ impl<'a> EventReceiver for TomlParserConverter<'a> {
 
         // Navigate to the path
         let scope = self.constructor.begin_scope();
-        for (key, _) in &self.current_keys {
-            let (_, path_seg) = self.parse_key(key);
-            self.constructor
-                .navigate(path_seg)
-                .expect("navigation should succeed");
+        if !self.navigate_current_keys() {
+            self.constructor.end_scope(scope).expect("scope mismatch");
+            self.current_keys.clear();
+            return;
         }
 
         // Check if we're in an array context
@@ -836,10 +968,20 @@ impl<'a> EventReceiver for TomlParserConverter<'a> {
             ..
         }) = self.context_stack.last_mut()
         {
-            // Navigate to array index
-            self.constructor
+            // Navigate to array index, failing if the array could not be bound
+            if self
+                .constructor
                 .navigate(PathSegment::ArrayIndex(ArrayIndexKind::Push))
-                .expect("array navigation should succeed");
+                .is_err()
+            {
+                let key = EurePath::from_iter(self.constructor.current_path().iter().cloned());
+                self.errors.push(TomlToEureError::Redefinition {
+                    key: key.to_string(),
+                });
+                self.constructor.end_scope(scope).expect("scope mismatch");
+                self.current_keys.clear();
+                return;
+            }
 
             // Capture pending trivia for this element
             let trivia = std::mem::take(element_pending_trivia);
@@ -865,7 +1007,12 @@ impl<'a> EventReceiver for TomlParserConverter<'a> {
         }
 
         let node_id = self.constructor.current_node_id();
-        self.bind_value(primitive);
+        if self.constructor.bind_primitive(primitive).is_err() {
+            self.redefined_here();
+            self.constructor.end_scope(scope).expect("scope mismatch");
+            self.current_keys.clear();
+            return;
+        }
         self.constructor.end_scope(scope).expect("scope mismatch");
 
         // Only add binding if we have a path (not in array context without keys)
@@ -952,6 +1099,17 @@ impl<'a> EventReceiver for TomlParserConverter<'a> {
                 element_pending_trivia.push(trivia);
             } else {
                 self.pending_trivia.push(trivia);
+                // A comment block separated from the first item by a blank line
+                // is a file header (e.g. a license), not a comment on that item
+                if self.at_document_start()
+                    && self
+                        .pending_trivia
+                        .iter()
+                        .any(|trivia| matches!(trivia, Trivia::Comment(_)))
+                {
+                    let header = std::mem::take(&mut self.pending_trivia);
+                    self.sources[0].leading_trivia.extend(header);
+                }
             }
         }
 
@@ -1017,6 +1175,137 @@ name = "second"
         assert_eq!(source.root_source().sections.len(), 2);
     }
 
+    #[test]
+    fn test_array_of_tables_leading_comment() {
+        let toml = r#"[[items]]
+name = "first"
+
+# The second item
+[[items]]
+name = "second"
+"#;
+        let source = to_source_document(toml).expect("conversion should succeed");
+        let sections = &source.root_source().sections;
+        assert_eq!(sections.len(), 2);
+        assert!(sections[0].trivia_before.is_empty());
+        assert_eq!(
+            sections[1].trivia_before,
+            vec![
+                Trivia::BlankLine,
+                Trivia::Comment(Comment::Line("The second item".to_string())),
+            ]
+        );
+
+        let formatted = format_source_document(&source);
+        let comment = formatted
+            .find("// The second item")
+            .expect("comment should be preserved");
+        assert!(formatted[..comment].contains("\"first\""));
+        assert!(formatted[comment..].contains("\"second\""));
+    }
+
+    #[test]
+    fn test_multi_line_comment_block_above_key() {
+        let toml = r#"# first line
+# second line
+#   third line
+key = 1
+"#;
+        let source = to_source_document(toml).expect("conversion should succeed");
+        let bindings = &source.root_source().bindings;
+        assert_eq!(bindings.len(), 1);
+        assert_eq!(
+            bindings[0].trivia_before,
+            vec![
+                Trivia::Comment(Comment::Line("first line".to_string())),
+                Trivia::Comment(Comment::Line("second line".to_string())),
+                Trivia::Comment(Comment::Line("third line".to_string())),
+            ]
+        );
+
+        let formatted = format_source_document(&source);
+        assert!(
+            formatted.contains("// first line\n// second line\n// third line\nkey = 1"),
+            "{formatted}"
+        );
+    }
+
+    #[test]
+    fn test_comment_only_file_keeps_comments() {
+        let toml = "# nothing here yet\n# see docs\n";
+        let source = to_source_document(toml).expect("conversion should succeed");
+        let root = source.root_source();
+        assert!(root.bindings.is_empty());
+        assert!(root.trailing_trivia.is_empty());
+        assert_eq!(
+            root.leading_trivia,
+            vec![
+                Trivia::Comment(Comment::Line("nothing here yet".to_string())),
+                Trivia::Comment(Comment::Line("see docs".to_string())),
+            ]
+        );
+
+        let formatted = format_source_document(&source);
+        assert_eq!(formatted, "// nothing here yet\n// see docs\n");
+    }
+
+    #[test]
+    fn test_license_header_before_first_key() {
+        let toml = r#"# SPDX-License-Identifier: MIT
+# Copyright (c) Example
+
+name = "app"
+"#;
+        let source = to_source_document(toml).expect("conversion should succeed");
+        let root = source.root_source();
+        assert_eq!(
+            root.leading_trivia,
+            vec![
+                Trivia::Comment(Comment::Line("SPDX-License-Identifier: MIT".to_string())),
+                Trivia::Comment(Comment::Line("Copyright (c) Example".to_string())),
+                Trivia::BlankLine,
+            ]
+        );
+        assert_eq!(root.bindings.len(), 1);
+        assert!(root.bindings[0].trivia_before.is_empty());
+
+        let formatted = format_source_document(&source);
+        assert!(
+            formatted.starts_with(
+                "// SPDX-License-Identifier: MIT\n// Copyright (c) Example\n\nname = \"app\""
+            ),
+            "{formatted}"
+        );
+    }
+
+    #[test]
+    fn test_section_key_order_matches_source() {
+        let toml = r#"[server]
+zeta = 1
+alpha = 2
+mid = 3
+"#;
+        let source = to_source_document(toml).expect("conversion should succeed");
+        let section = &source.root_source().sections[0];
+        let SectionBody::Items { bindings, .. } = &section.body else {
+            panic!("expected items body");
+        };
+        let keys: Vec<_> = bindings
+            .iter()
+            .map(|binding| match &binding.path[0].key {
+                SourceKey::Ident(id) => id.to_string(),
+                other => panic!("unexpected key: {other:?}"),
+            })
+            .collect();
+        assert_eq!(keys, ["zeta", "alpha", "mid"]);
+
+        let formatted = format_source_document(&source);
+        let zeta = formatted.find("zeta").unwrap();
+        let alpha = formatted.find("alpha").unwrap();
+        let mid = formatted.find("mid").unwrap();
+        assert!(zeta < alpha && alpha < mid, "unexpected order: {formatted}");
+    }
+
     #[test]
     fn test_interleaved_sections() {
         // With toml_parser, we should preserve the source order!
@@ -1074,4 +1363,173 @@ description = "Second example"
             formatted
         );
     }
+
+    #[test]
+    fn test_multiline_string_with_triple_backticks() {
+        let toml = "readme = \"\"\"\n```sh\ncargo build\n```\n\"\"\"\n";
+        let formatted = format_source_document(&to_source_document(toml).unwrap());
+        assert!(
+            formatted.contains("````\n```sh\ncargo build\n```\n````"),
+            "Expected a 4-backtick fence in output: {}",
+            formatted
+        );
+    }
+
+    #[test]
+    fn test_mixed_scalar_array() {
+        use eure_document::eure;
+
+        let toml = r#"values = [1, "x", true, 1.5, 1979-05-27]"#;
+        let source = to_source_document(toml).expect("conversion should succeed");
+        assert_eq!(
+            *source.document(),
+            eure!({ values = [1, "x", true, 1.5, @code("date", "1979-05-27")] })
+        );
+    }
+
+    #[test]
+    fn test_multiline_string_with_six_backticks() {
+        use eure_document::eure;
+
+        let toml = "readme = \"\"\"\n``````\n\"\"\"\n";
+        let source = to_source_document(toml).unwrap();
+        assert_eq!(*source.document(), eure!({ readme = "``````\n" }));
+        let formatted = format_source_document(&source);
+        assert_eq!(formatted, "readme = \"``````\\n\"\n");
+    }
+
+    #[test]
+    fn test_convert_reader() {
+        let toml = r#"[server]
+host = "localhost"
+port = 8080
+"#;
+        let mut output = Vec::new();
+        convert_reader(std::io::Cursor::new(toml), &mut output).expect("conversion should succeed");
+
+        let expected = format_source_document(&to_source_document(toml).unwrap());
+        assert_eq!(String::from_utf8(output).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_convert_reader_invalid_utf8() {
+        let mut output = Vec::new();
+        let result = convert_reader(std::io::Cursor::new(vec![0xff, 0xfe]), &mut output);
+        assert!(matches!(
+            result,
+            Err(TomlToEureError::Io {
+                kind: std::io::ErrorKind::InvalidData,
+                ..
+            })
+        ));
+    }
+
+    fn redefinition(key: &str) -> TomlToEureError {
+        TomlToEureError::Redefinition {
+            key: key.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_redefined_key_is_rejected() {
+        let toml = "[server]\nport = 80\nport = 8080\n";
+        assert_eq!(
+            to_source_document(toml).unwrap_err(),
+            redefinition("server.port")
+        );
+    }
+
+    #[test]
+    fn test_dotted_key_into_scalar_is_rejected() {
+        let toml = "a = 1\na.b = 2\n";
+        assert_eq!(to_source_document(toml).unwrap_err(), redefinition("a.b"));
+    }
+
+    #[test]
+    fn test_table_over_scalar_is_rejected() {
+        assert_eq!(
+            to_source_document("a = 1\n[a]\nb = 2\n").unwrap_err(),
+            redefinition("a")
+        );
+        assert_eq!(
+            to_source_document("[a]\nb = 1\n[a.b]\n").unwrap_err(),
+            redefinition("a.b")
+        );
+        assert_eq!(
+            to_source_document("[[a]]\n[a]\n").unwrap_err(),
+            redefinition("a")
+        );
+    }
+
+    #[test]
+    fn test_array_table_over_non_array_is_rejected() {
+        assert_eq!(
+            to_source_document("a = 1\n[[a]]\n").unwrap_err(),
+            redefinition("a")
+        );
+        assert_eq!(
+            to_source_document("[a]\n[[a]]\n").unwrap_err(),
+            redefinition("a")
+        );
+    }
+
+    #[test]
+    fn test_inline_value_over_scalar_is_rejected() {
+        assert_eq!(
+            to_source_document("a = 1\na = {b = 1}\n").unwrap_err(),
+            redefinition("a")
+        );
+        assert_eq!(
+            to_source_document("a = 1\na = [1]\n").unwrap_err(),
+            redefinition("a")
+        );
+    }
+
+    #[test]
+    fn test_table_inside_array_of_tables_is_unsupported() {
+        assert_eq!(
+            to_source_document("[[a]]\n[a.b]\nc = 1\n").unwrap_err(),
+            TomlToEureError::UnsupportedValue {
+                key: "a.b".to_string(),
+                kind: "table inside an array",
+            }
+        );
+    }
+
+    /// Convert `toml` to Eure source, check that the source parses back to the
+    /// converted document, and return the source.
+    fn round_trip(toml: &str) -> String {
+        let source = to_source_document(toml).expect("conversion should succeed");
+        let formatted = format_source_document(&source);
+        let reparsed = eure::document::parse_to_document(&formatted, "round-trip.eure")
+            .unwrap_or_else(|e| panic!("formatted output should parse: {e:?}\n{formatted}"));
+        assert_eq!(&reparsed, source.document(), "{formatted}");
+        formatted
+    }
+
+    #[test]
+    fn test_round_trip_inline_table_and_dotted_key_stay_distinct() {
+        let inline = round_trip("a = { b = 1 }\n");
+        let dotted = round_trip("a.b = 1\n");
+        assert_eq!(inline.trim(), "a = { b => 1 }");
+        assert_eq!(dotted.trim(), "a.b = 1");
+    }
+
+    #[test]
+    fn test_round_trip_inline_table_and_dotted_key_in_section() {
+        let formatted = round_trip(
+            r#"[server]
+tls = { cert = "a.pem", key = "a.key" }
+limits.connections = 100
+"#,
+        );
+        assert!(
+            formatted.contains(r#"tls = { cert => "a.pem", key => "a.key" }"#),
+            "{formatted}"
+        );
+        assert!(
+            formatted.contains("limits.connections = 100"),
+            "{formatted}"
+        );
+    }
 }
@@ -1,9 +1,14 @@
+mod compact;
 mod span;
 
 use ahash::HashMap;
-use std::{collections::BTreeMap, convert::Infallible};
+use std::{
+    collections::{BTreeMap, HashSet},
+    convert::Infallible,
+};
 use thiserror::Error;
 
+pub use compact::CompactCst;
 pub use span::*;
 
 use crate::{
@@ -120,6 +125,8 @@ pub struct ConcreteSyntaxTree<T, Nt> {
     children: HashMap<CstNodeId, Vec<CstNodeId>>,
     parent: HashMap<CstNodeId, CstNodeId>,
     dynamic_tokens: BTreeMap<DynamicTokenId, String>,
+    /// Reverse index of `dynamic_tokens` so identical contents share one id.
+    dynamic_token_ids: HashMap<String, DynamicTokenId>,
     next_dynamic_token_id: u32,
     root: CstNodeId,
 }
@@ -138,6 +145,7 @@ where
             children: HashMap::default(),
             parent: HashMap::default(),
             dynamic_tokens: BTreeMap::new(),
+            dynamic_token_ids: HashMap::default(),
             next_dynamic_token_id: 0,
             root,
         }
@@ -256,9 +264,85 @@ where
         }
     }
 
+    /// Check that the `children` and `parent` maps are consistent with each other.
+    ///
+    /// Verifies that every referenced id exists, every child points back to the
+    /// parent listing it (and vice versa), the root has no parent, and no node is
+    /// its own ancestor. Nodes are checked in id order, so the reported node is
+    /// deterministic.
+    pub fn validate(&self) -> Result<(), TreeInvariantError> {
+        let exists = |node: CstNodeId| {
+            if node.0 < self.nodes.len() {
+                Ok(())
+            } else {
+                Err(TreeInvariantError::NodeIdNotFound { node })
+            }
+        };
+
+        exists(self.root)?;
+        if let Some(&parent) = self.parent.get(&self.root) {
+            return Err(TreeInvariantError::RootHasParent {
+                root: self.root,
+                parent,
+            });
+        }
+
+        let mut parents: Vec<_> = self.children.keys().copied().collect();
+        parents.sort();
+        for parent in parents {
+            exists(parent)?;
+            for &child in &self.children[&parent] {
+                exists(child)?;
+                let actual = self.parent.get(&child).copied();
+                if actual != Some(parent) {
+                    return Err(TreeInvariantError::ParentMismatch {
+                        node: child,
+                        parent,
+                        actual,
+                    });
+                }
+            }
+        }
+
+        let mut nodes: Vec<_> = self.parent.keys().copied().collect();
+        nodes.sort();
+        for &node in &nodes {
+            let parent = self.parent[&node];
+            exists(node)?;
+            exists(parent)?;
+            if !self
+                .children
+                .get(&parent)
+                .is_some_and(|children| children.contains(&node))
+            {
+                return Err(TreeInvariantError::MissingChild { node, parent });
+            }
+        }
+
+        for &node in &nodes {
+            let mut visited = HashSet::new();
+            let mut current = node;
+            while let Some(&parent) = self.parent.get(&current) {
+                if parent == node || !visited.insert(parent) {
+                    return Err(TreeInvariantError::Cycle { node });
+                }
+                current = parent;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Intern a dynamic token, returning the existing id if the same content
+    /// was inserted before.
     pub fn insert_dynamic_terminal(&mut self, data: impl Into<String>) -> DynamicTokenId {
+        let data = data.into();
+        if let Some(&id) = self.dynamic_token_ids.get(&data) {
+            return id;
+        }
         let id = DynamicTokenId(self.next_dynamic_token_id);
-        self.dynamic_tokens.insert(id, data.into());
+        self.dynamic_token_ids.insert(data.clone(), id);
+        self.dynamic_tokens.insert(id, data);
         self.next_dynamic_token_id += 1;
         id
     }
@@ -286,6 +370,53 @@ where
         // Remove children mapping (but don't delete child nodes recursively)
         self.children.remove(&id);
     }
+
+    /// Iterate `root` and all nodes reachable from it in pre-order (parent
+    /// before children, children in source order).
+    pub fn pre_order(&self, root: CstNodeId) -> impl Iterator<Item = CstNodeId> + '_ {
+        let mut stack = vec![root];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(self.children(node).rev());
+            Some(node)
+        })
+    }
+
+    /// Reconstruct the source text by concatenating every terminal reachable
+    /// from the root, in order.
+    ///
+    /// Input terminals are read from `input` and dynamic terminals from the
+    /// tree. Whitespace and comments are terminals too, so a tree parsed from
+    /// `input` and left unmodified reproduces it byte-for-byte.
+    pub fn to_source(&self, input: &str) -> String {
+        let mut source = String::with_capacity(input.len());
+        for node in self.pre_order(self.root()) {
+            if let Some(CstNodeData::Terminal { data, .. }) = self.node_data(node)
+                && let Some(text) = self.get_str(data, input)
+            {
+                source.push_str(text);
+            }
+        }
+        source
+    }
+}
+
+impl<T, Nt> ConcreteSyntaxTree<T, Nt>
+where
+    T: Copy + PartialEq,
+    Nt: Copy + PartialEq,
+{
+    /// Collect the descendants of `root` (excluding `root` itself) whose kind
+    /// is `kind`, in pre-order.
+    pub fn descendants_of_kind(&self, root: CstNodeId, kind: NodeKind<T, Nt>) -> Vec<CstNodeId> {
+        self.pre_order(root)
+            .skip(1)
+            .filter(|&node| {
+                self.node_data(node)
+                    .is_some_and(|data| data.node_kind() == kind)
+            })
+            .collect()
+    }
 }
 
 impl TerminalKind {
@@ -334,150 +465,211 @@ impl ConcreteSyntaxTree<TerminalKind, NonTerminalKind> {
         facade: &F,
         parent: CstNodeId,
         nodes: [NodeKind<TerminalKind, NonTerminalKind>; N],
-        mut visitor: impl FnMut(
-            [CstNodeId; N],
-            &'v mut V,
-        ) -> Result<(O, &'v mut V), CstConstructError<E>>,
+        visitor: impl FnMut([CstNodeId; N], &'v mut V) -> Result<(O, &'v mut V), CstConstructError<E>>,
         visit_ignored: &'v mut V,
     ) -> Result<O, CstConstructError<E>> {
-        let children = self.children(parent).collect::<Vec<_>>();
-        let mut children = children.into_iter();
-        let mut result = Vec::with_capacity(N);
-        let mut ignored = Vec::with_capacity(N);
-        'outer: for expected_kind in nodes {
-            'inner: for (idx, child) in children.by_ref().enumerate() {
-                let child_data = self
-                    .node_data(child)
-                    .ok_or(ViewConstructionError::NodeIdNotFound { node: child })?;
-                match child_data {
-                    CstNodeData::Terminal { kind, data } => {
-                        if NodeKind::Terminal(kind) == expected_kind {
-                            result.push(child);
-                            continue 'outer;
-                        } else if kind.is_builtin_whitespace() || kind.is_builtin_new_line() {
-                            if kind.auto_ws_is_off(idx) {
-                                return Err(ViewConstructionError::UnexpectedNode {
-                                    node: child,
-                                    data: child_data,
-                                    expected_kind,
-                                });
-                            }
-                            ignored.push((child, kind, data));
-                            continue 'inner;
-                        } else if kind.is_builtin_line_comment() || kind.is_builtin_block_comment()
-                        {
-                            ignored.push((child, kind, data));
-                            continue 'inner;
-                        } else {
-                            return Err(ViewConstructionError::UnexpectedNode {
-                                node: child,
-                                data: child_data,
-                                expected_kind,
-                            });
-                        }
-                    }
-                    CstNodeData::NonTerminal { kind, .. } => {
-                        if NodeKind::NonTerminal(kind) == expected_kind {
-                            result.push(child);
-                            continue 'outer;
-                        } else {
-                            return Err(ViewConstructionError::UnexpectedNode {
-                                node: child,
-                                data: child_data,
-                                expected_kind,
-                            });
-                        }
-                    }
-                }
+        collect_nodes_of(self, facade, parent, nodes, visitor, visit_ignored)
+    }
+
+    /// Replace the text of the subtree rooted at `node` with `text`.
+    ///
+    /// A terminal node keeps its kind and now refers to a dynamic token. A
+    /// non-terminal node keeps its kind, every descendant is detached, and a
+    /// single new dynamic terminal holding `text` becomes its only child. The
+    /// new terminal takes the kind of the first non-trivia terminal of the old
+    /// subtree, or [TerminalKind::Text] if there is none. Trivia inside the
+    /// subtree is dropped with the rest of it.
+    pub fn replace_subtree_with_text(&mut self, node: CstNodeId, text: &str) -> DynamicTokenId {
+        let token = self.insert_dynamic_terminal(text);
+        match self.node_data(node) {
+            Some(CstNodeData::Terminal { kind, .. }) => {
+                self.update_node(
+                    node,
+                    CstNodeData::new_terminal(kind, TerminalData::Dynamic(token)),
+                );
             }
-            return Err(ViewConstructionError::UnexpectedEndOfChildren { parent });
-        }
-        for (child, kind, data) in ignored {
-            match kind {
-                TerminalKind::Whitespace => visit_ignored.visit_builtin_whitespace_terminal(
-                    Whitespace(child),
-                    data,
-                    facade,
-                )?,
-                TerminalKind::NewLine => {
-                    visit_ignored.visit_builtin_new_line_terminal(NewLine(child), data, facade)?
+            Some(CstNodeData::NonTerminal { kind, .. }) => {
+                let descendants: Vec<CstNodeId> = self.pre_order(node).skip(1).collect();
+                let terminal_kind = descendants
+                    .iter()
+                    .find_map(|&id| match self.node_data(id) {
+                        Some(CstNodeData::Terminal { kind, .. }) if !kind.is_builtin_terminal() => {
+                            Some(kind)
+                        }
+                        _ => None,
+                    })
+                    .unwrap_or(TerminalKind::Text);
+                for id in descendants {
+                    self.children.remove(&id);
+                    self.parent.remove(&id);
                 }
-                TerminalKind::LineComment => visit_ignored.visit_builtin_line_comment_terminal(
-                    LineComment(child),
-                    data,
-                    facade,
-                )?,
-                TerminalKind::BlockComment => visit_ignored.visit_builtin_block_comment_terminal(
-                    BlockComment(child),
-                    data,
-                    facade,
-                )?,
-                _ => unreachable!(),
+                self.children.remove(&node);
+                self.add_node_with_parent(
+                    CstNodeData::new_terminal(terminal_kind, TerminalData::Dynamic(token)),
+                    node,
+                );
+                self.update_node(
+                    node,
+                    CstNodeData::new_non_terminal(kind, NonTerminalData::Dynamic),
+                );
             }
+            None => {}
         }
-        let (result, visit_ignored) = visitor(
-            result
-                .try_into()
-                .expect("Result should have the same length as nodes"),
-            visit_ignored,
-        )?;
-        for child in children.by_ref() {
-            let child_data = self
+        token
+    }
+
+    pub fn root_handle(&self) -> RootHandle {
+        RootHandle(self.root())
+    }
+
+    pub fn visit_from_root<V: CstVisitor<Self>>(&self, visitor: &mut V) -> Result<(), V::Error> {
+        visitor.visit_root_handle(self.root_handle(), self)
+    }
+}
+
+/// Match the children of `parent` in `tree` against `nodes`, skipping and
+/// visiting builtin trivia. Shared by every [CstFacade] implementation.
+fn collect_nodes_of<
+    'v,
+    const N: usize,
+    V: BuiltinTerminalVisitor<E, F>,
+    O,
+    E,
+    C: CstFacade,
+    F: CstFacade,
+>(
+    tree: &C,
+    facade: &F,
+    parent: CstNodeId,
+    nodes: [NodeKind<TerminalKind, NonTerminalKind>; N],
+    mut visitor: impl FnMut([CstNodeId; N], &'v mut V) -> Result<(O, &'v mut V), CstConstructError<E>>,
+    visit_ignored: &'v mut V,
+) -> Result<O, CstConstructError<E>> {
+    let children = tree.children(parent).collect::<Vec<_>>();
+    let mut children = children.into_iter();
+    let mut result = Vec::with_capacity(N);
+    let mut ignored = Vec::with_capacity(N);
+    'outer: for expected_kind in nodes {
+        'inner: for (idx, child) in children.by_ref().enumerate() {
+            let child_data = tree
                 .node_data(child)
                 .ok_or(ViewConstructionError::NodeIdNotFound { node: child })?;
             match child_data {
                 CstNodeData::Terminal { kind, data } => {
-                    if kind.is_builtin_terminal() {
-                        match kind {
-                            TerminalKind::Whitespace => visit_ignored
-                                .visit_builtin_whitespace_terminal(
-                                    Whitespace(child),
-                                    data,
-                                    facade,
-                                )?,
-                            TerminalKind::NewLine => visit_ignored
-                                .visit_builtin_new_line_terminal(NewLine(child), data, facade)?,
-                            TerminalKind::LineComment => visit_ignored
-                                .visit_builtin_line_comment_terminal(
-                                    LineComment(child),
-                                    data,
-                                    facade,
-                                )?,
-                            TerminalKind::BlockComment => visit_ignored
-                                .visit_builtin_block_comment_terminal(
-                                    BlockComment(child),
-                                    data,
-                                    facade,
-                                )?,
-                            _ => unreachable!(),
+                    if NodeKind::Terminal(kind) == expected_kind {
+                        result.push(child);
+                        continue 'outer;
+                    } else if kind.is_builtin_whitespace() || kind.is_builtin_new_line() {
+                        if kind.auto_ws_is_off(idx) {
+                            return Err(ViewConstructionError::UnexpectedNode {
+                                node: child,
+                                data: child_data,
+                                expected_kind,
+                            });
                         }
+                        ignored.push((child, kind, data));
+                        continue 'inner;
+                    } else if kind.is_builtin_line_comment() || kind.is_builtin_block_comment() {
+                        ignored.push((child, kind, data));
+                        continue 'inner;
                     } else {
                         return Err(ViewConstructionError::UnexpectedNode {
                             node: child,
                             data: child_data,
-                            expected_kind: NodeKind::Terminal(kind),
+                            expected_kind,
                         });
                     }
                 }
                 CstNodeData::NonTerminal { kind, .. } => {
+                    if NodeKind::NonTerminal(kind) == expected_kind {
+                        result.push(child);
+                        continue 'outer;
+                    } else {
+                        return Err(ViewConstructionError::UnexpectedNode {
+                            node: child,
+                            data: child_data,
+                            expected_kind,
+                        });
+                    }
+                }
+            }
+        }
+        return Err(ViewConstructionError::UnexpectedEndOfChildren { parent });
+    }
+    for (child, kind, data) in ignored {
+        match kind {
+            TerminalKind::Whitespace => {
+                visit_ignored.visit_builtin_whitespace_terminal(Whitespace(child), data, facade)?
+            }
+            TerminalKind::NewLine => {
+                visit_ignored.visit_builtin_new_line_terminal(NewLine(child), data, facade)?
+            }
+            TerminalKind::LineComment => visit_ignored.visit_builtin_line_comment_terminal(
+                LineComment(child),
+                data,
+                facade,
+            )?,
+            TerminalKind::BlockComment => visit_ignored.visit_builtin_block_comment_terminal(
+                BlockComment(child),
+                data,
+                facade,
+            )?,
+            _ => unreachable!(),
+        }
+    }
+    let (result, visit_ignored) = visitor(
+        result
+            .try_into()
+            .expect("Result should have the same length as nodes"),
+        visit_ignored,
+    )?;
+    for child in children.by_ref() {
+        let child_data = tree
+            .node_data(child)
+            .ok_or(ViewConstructionError::NodeIdNotFound { node: child })?;
+        match child_data {
+            CstNodeData::Terminal { kind, data } => {
+                if kind.is_builtin_terminal() {
+                    match kind {
+                        TerminalKind::Whitespace => visit_ignored
+                            .visit_builtin_whitespace_terminal(Whitespace(child), data, facade)?,
+                        TerminalKind::NewLine => visit_ignored.visit_builtin_new_line_terminal(
+                            NewLine(child),
+                            data,
+                            facade,
+                        )?,
+                        TerminalKind::LineComment => visit_ignored
+                            .visit_builtin_line_comment_terminal(
+                                LineComment(child),
+                                data,
+                                facade,
+                            )?,
+                        TerminalKind::BlockComment => visit_ignored
+                            .visit_builtin_block_comment_terminal(
+                                BlockComment(child),
+                                data,
+                                facade,
+                            )?,
+                        _ => unreachable!(),
+                    }
+                } else {
                     return Err(ViewConstructionError::UnexpectedNode {
                         node: child,
                         data: child_data,
-                        expected_kind: NodeKind::NonTerminal(kind),
+                        expected_kind: NodeKind::Terminal(kind),
                     });
                 }
             }
+            CstNodeData::NonTerminal { kind, .. } => {
+                return Err(ViewConstructionError::UnexpectedNode {
+                    node: child,
+                    data: child_data,
+                    expected_kind: NodeKind::NonTerminal(kind),
+                });
+            }
         }
-        Ok(result)
-    }
-
-    pub fn root_handle(&self) -> RootHandle {
-        RootHandle(self.root())
-    }
-
-    pub fn visit_from_root<V: CstVisitor<Self>>(&self, visitor: &mut V) -> Result<(), V::Error> {
-        visitor.visit_root_handle(self.root_handle(), self)
     }
+    Ok(result)
 }
 
 pub trait CstFacade: Sized {
@@ -519,6 +711,16 @@ pub trait CstFacade: Sized {
 
     fn root_handle(&self) -> RootHandle;
 
+    /// Returns the ancestors of a node, from its parent up to and including the root.
+    fn ancestors(&self, node: CstNodeId) -> impl Iterator<Item = CstNodeId> {
+        std::iter::successors(self.parent(node), move |&ancestor| self.parent(ancestor))
+    }
+
+    /// Returns the node itself followed by its [ancestors](CstFacade::ancestors).
+    fn ancestors_inclusive(&self, node: CstNodeId) -> impl Iterator<Item = CstNodeId> {
+        std::iter::successors(Some(node), move |&ancestor| self.parent(ancestor))
+    }
+
     /// Returns the string representation of a terminal. Returns None if the terminal is a dynamic token and not found.
     fn get_terminal_str<'a: 'c, 'b: 'c, 'c, T: TerminalHandle>(
         &'a self,
@@ -698,6 +900,49 @@ impl CstFacade for ConcreteSyntaxTree<TerminalKind, NonTerminalKind> {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+/// Structural inconsistency reported by [ConcreteSyntaxTree::validate].
+pub enum TreeInvariantError {
+    /// A node ID referenced by the tree does not exist
+    #[error("Node ID not found in the tree: {node}")]
+    NodeIdNotFound {
+        /// The index of the node.
+        node: CstNodeId,
+    },
+    /// The root node has a parent
+    #[error("Root node {root} has parent {parent}")]
+    RootHasParent {
+        /// The index of the root node.
+        root: CstNodeId,
+        /// The index of the parent.
+        parent: CstNodeId,
+    },
+    /// A node is listed as a child but its parent entry points elsewhere
+    #[error("Node {node} is a child of {parent} but its parent entry is {actual:?}")]
+    ParentMismatch {
+        /// The index of the node.
+        node: CstNodeId,
+        /// The index of the node listing it as a child.
+        parent: CstNodeId,
+        /// The parent recorded for the node, if any.
+        actual: Option<CstNodeId>,
+    },
+    /// A node has a parent entry but is not in that parent's children
+    #[error("Node {node} has parent {parent} but is not among its children")]
+    MissingChild {
+        /// The index of the node.
+        node: CstNodeId,
+        /// The index of the recorded parent.
+        parent: CstNodeId,
+    },
+    /// A node is its own ancestor
+    #[error("Node {node} is its own ancestor")]
+    Cycle {
+        /// The index of the node.
+        node: CstNodeId,
+    },
+}
+
 #[derive(Debug, Clone, Error)]
 /// Error that occurs when constructing a view from a [NonTerminalHandle].
 pub enum ViewConstructionError<T, Nt, E = Infallible> {
@@ -931,3 +1176,220 @@ pub trait RecursiveView<F: CstFacade> {
         visit_ignored: &mut impl BuiltinTerminalVisitor<E, F>,
     ) -> Result<Vec<Self::Item>, CstConstructError<E>>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_tree() -> ConcreteSyntaxTree<TerminalKind, NonTerminalKind> {
+        ConcreteSyntaxTree::new(CstNodeData::new_non_terminal(
+            NonTerminalKind::Root,
+            NonTerminalData::Dynamic,
+        ))
+    }
+
+    #[test]
+    fn test_insert_dynamic_terminal_interns_identical_content() {
+        let mut tree = create_test_tree();
+
+        let first = tree.insert_dynamic_terminal(",");
+        let second = tree.insert_dynamic_terminal(",");
+
+        assert_eq!(first, second);
+        assert_eq!(tree.dynamic_tokens.len(), 1);
+        assert_eq!(tree.dynamic_token(first), Some(","));
+    }
+
+    #[test]
+    fn test_insert_dynamic_terminal_distinct_content() {
+        let mut tree = create_test_tree();
+
+        let comma = tree.insert_dynamic_terminal(",");
+        let newline = tree.insert_dynamic_terminal("\n");
+
+        assert_ne!(comma, newline);
+        assert_eq!(tree.dynamic_token(comma), Some(","));
+        assert_eq!(tree.dynamic_token(newline), Some("\n"));
+    }
+
+    #[test]
+    fn test_validate_well_formed_tree() {
+        let mut tree = create_test_tree();
+        let root = tree.root();
+        let binding = tree.add_node_with_parent(
+            CstNodeData::new_non_terminal(NonTerminalKind::Binding, NonTerminalData::Dynamic),
+            root,
+        );
+        let ident = tree.add_node_with_parent(
+            CstNodeData::new_terminal(TerminalKind::Ident, TerminalData::Input(InputSpan::EMPTY)),
+            binding,
+        );
+        assert_eq!(tree.validate(), Ok(()));
+
+        tree.change_parent(ident, root);
+        assert_eq!(tree.validate(), Ok(()));
+
+        tree.remove_node(binding);
+        assert_eq!(tree.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_corrupted_tree() {
+        let mut tree = create_test_tree();
+        let root = tree.root();
+        let binding = tree.add_node_with_parent(
+            CstNodeData::new_non_terminal(NonTerminalKind::Binding, NonTerminalData::Dynamic),
+            root,
+        );
+        let ident = tree.add_node_with_parent(
+            CstNodeData::new_terminal(TerminalKind::Ident, TerminalData::Input(InputSpan::EMPTY)),
+            binding,
+        );
+
+        // Child listed under root while its parent entry still points to binding
+        tree.children.entry(root).or_default().push(ident);
+        assert_eq!(
+            tree.validate(),
+            Err(TreeInvariantError::ParentMismatch {
+                node: ident,
+                parent: root,
+                actual: Some(binding),
+            })
+        );
+        tree.children.get_mut(&root).unwrap().pop();
+
+        // Parent entry without matching children entry
+        tree.children.remove(&binding);
+        assert_eq!(
+            tree.validate(),
+            Err(TreeInvariantError::MissingChild {
+                node: ident,
+                parent: binding,
+            })
+        );
+        tree.children.insert(binding, vec![ident]);
+
+        // Cycle: root -> binding -> ident -> binding
+        tree.add_child(ident, binding);
+        tree.children.get_mut(&root).unwrap().clear();
+        assert_eq!(
+            tree.validate(),
+            Err(TreeInvariantError::Cycle { node: binding })
+        );
+    }
+
+    #[test]
+    fn test_validate_root_with_parent_and_missing_node() {
+        let mut tree = create_test_tree();
+        let root = tree.root();
+        let binding = tree.add_node_with_parent(
+            CstNodeData::new_non_terminal(NonTerminalKind::Binding, NonTerminalData::Dynamic),
+            root,
+        );
+
+        tree.add_child(binding, root);
+        assert_eq!(
+            tree.validate(),
+            Err(TreeInvariantError::RootHasParent {
+                root,
+                parent: binding,
+            })
+        );
+
+        let mut tree = create_test_tree();
+        tree.add_child(tree.root(), CstNodeId(42));
+        assert_eq!(
+            tree.validate(),
+            Err(TreeInvariantError::NodeIdNotFound {
+                node: CstNodeId(42)
+            })
+        );
+    }
+
+    #[test]
+    fn test_pre_order_visits_parents_before_children() {
+        let mut tree = create_test_tree();
+        let root = tree.root();
+        let binding = tree.add_node_with_parent(
+            CstNodeData::new_non_terminal(NonTerminalKind::Binding, NonTerminalData::Dynamic),
+            root,
+        );
+        let ident = tree.add_node_with_parent(
+            CstNodeData::new_terminal(TerminalKind::Ident, TerminalData::Input(InputSpan::EMPTY)),
+            binding,
+        );
+        let ws = tree.add_node_with_parent(
+            CstNodeData::new_terminal(
+                TerminalKind::Whitespace,
+                TerminalData::Input(InputSpan::EMPTY),
+            ),
+            root,
+        );
+
+        assert_eq!(
+            tree.pre_order(root).collect::<Vec<_>>(),
+            vec![root, binding, ident, ws]
+        );
+    }
+
+    #[test]
+    fn test_ancestors_of_deep_node() {
+        let mut tree = create_test_tree();
+        let root = tree.root();
+        let mut node = root;
+        for _ in 0..4 {
+            node = tree.add_node_with_parent(
+                CstNodeData::new_non_terminal(NonTerminalKind::Binding, NonTerminalData::Dynamic),
+                node,
+            );
+        }
+        let leaf = tree.add_node_with_parent(
+            CstNodeData::new_terminal(TerminalKind::Ident, TerminalData::Input(InputSpan::EMPTY)),
+            node,
+        );
+
+        let ancestors: Vec<_> = tree.ancestors(leaf).collect();
+        assert_eq!(ancestors.len(), 5);
+        assert_eq!(ancestors.first(), Some(&node));
+        assert_eq!(ancestors.last(), Some(&root));
+
+        let inclusive: Vec<_> = tree.ancestors_inclusive(leaf).collect();
+        assert_eq!(inclusive[0], leaf);
+        assert_eq!(inclusive[1..], ancestors[..]);
+
+        assert_eq!(tree.ancestors(root).count(), 0);
+    }
+
+    #[test]
+    fn test_descendants_of_kind() {
+        let mut tree = create_test_tree();
+        let root = tree.root();
+        let terminal =
+            |kind| CstNodeData::new_terminal(kind, TerminalData::Input(InputSpan::EMPTY));
+
+        let mut idents = Vec::new();
+        for _ in 0..2 {
+            let binding = tree.add_node_with_parent(
+                CstNodeData::new_non_terminal(NonTerminalKind::Binding, NonTerminalData::Dynamic),
+                root,
+            );
+            idents.push(tree.add_node_with_parent(terminal(TerminalKind::Ident), binding));
+            tree.add_node_with_parent(terminal(TerminalKind::Whitespace), binding);
+        }
+        idents.push(tree.add_node_with_parent(terminal(TerminalKind::Ident), root));
+
+        assert_eq!(
+            tree.descendants_of_kind(root, NodeKind::Terminal(TerminalKind::Ident)),
+            idents
+        );
+        assert_eq!(
+            tree.descendants_of_kind(root, NodeKind::NonTerminal(NonTerminalKind::Binding))
+                .len(),
+            2
+        );
+        assert!(
+            tree.descendants_of_kind(root, NodeKind::NonTerminal(NonTerminalKind::Root))
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    CstConstructError,
+    node_kind::{NodeKind, NonTerminalKind, TerminalKind},
+    nodes::RootHandle,
+    visitor::BuiltinTerminalVisitor,
+};
+
+use super::{
+    ConcreteSyntaxTree, CstFacade, CstNodeData, CstNodeId, DynamicTokenId, NonTerminalData,
+    TerminalData, ViewConstructionError, collect_nodes_of,
+};
+
+/// Marks a node without a parent in [CompactCst::parents].
+const NO_PARENT: u32 = u32::MAX;
+
+/// A read-only concrete syntax tree with contiguous child storage.
+///
+/// [ConcreteSyntaxTree] keeps children and parents in hash maps so the tree
+/// can be edited cheaply. Once a tree is only going to be read, those maps
+/// can be flattened: all child lists are stored back to back in one vector,
+/// and each node records the `(start, len)` range of its own children.
+/// Created by [ConcreteSyntaxTree::into_compact].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactCst {
+    nodes: Vec<CstNodeData<TerminalKind, NonTerminalKind>>,
+    /// `(start, len)` into `children` for each node, indexed by node id.
+    child_ranges: Vec<(u32, u32)>,
+    children: Vec<CstNodeId>,
+    /// Parent of each node, indexed by node id, or [NO_PARENT].
+    parents: Vec<u32>,
+    dynamic_tokens: BTreeMap<DynamicTokenId, String>,
+    root: CstNodeId,
+}
+
+impl ConcreteSyntaxTree<TerminalKind, NonTerminalKind> {
+    /// Convert into a [CompactCst] for read-only use.
+    ///
+    /// Every node keeps its id, so handles and spans taken from this tree stay
+    /// valid on the compacted one.
+    pub fn into_compact(self) -> CompactCst {
+        let to_u32 = |n: usize| u32::try_from(n).expect("CST too large to compact");
+        let mut child_ranges = Vec::with_capacity(self.nodes.len());
+        let mut children = Vec::with_capacity(self.parent.len());
+        let mut parents = vec![NO_PARENT; self.nodes.len()];
+        for id in 0..self.nodes.len() {
+            let start = to_u32(children.len());
+            if let Some(node_children) = self.children.get(&CstNodeId(id)) {
+                children.extend_from_slice(node_children);
+            }
+            child_ranges.push((start, to_u32(children.len()) - start));
+        }
+        for (child, parent) in self.parent {
+            if let Some(slot) = parents.get_mut(child.0) {
+                *slot = to_u32(parent.0);
+            }
+        }
+        CompactCst {
+            nodes: self.nodes,
+            child_ranges,
+            children,
+            parents,
+            dynamic_tokens: self.dynamic_tokens,
+            root: self.root,
+        }
+    }
+}
+
+impl CompactCst {
+    pub fn root(&self) -> CstNodeId {
+        self.root
+    }
+
+    /// The number of nodes in the tree, including unreachable ones.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    fn child_slice(&self, node: CstNodeId) -> &[CstNodeId] {
+        match self.child_ranges.get(node.0) {
+            Some(&(start, len)) => &self.children[start as usize..(start + len) as usize],
+            None => &[],
+        }
+    }
+}
+
+impl CstFacade for CompactCst {
+    fn get_str<'a: 'c, 'b: 'c, 'c>(
+        &'a self,
+        terminal: TerminalData,
+        input: &'b str,
+    ) -> Option<&'c str> {
+        match terminal {
+            TerminalData::Input(span) => Some(span.as_str(input)),
+            TerminalData::Dynamic(id) => self.dynamic_token(id),
+        }
+    }
+
+    fn node_data(&self, node: CstNodeId) -> Option<CstNodeData<TerminalKind, NonTerminalKind>> {
+        self.nodes.get(node.0).copied()
+    }
+
+    fn has_no_children(&self, node: CstNodeId) -> bool {
+        self.child_slice(node).is_empty()
+    }
+
+    fn children(&self, node: CstNodeId) -> impl DoubleEndedIterator<Item = CstNodeId> {
+        self.child_slice(node).iter().copied()
+    }
+
+    fn get_terminal(
+        &self,
+        node: CstNodeId,
+        kind: TerminalKind,
+    ) -> Result<TerminalData, CstConstructError> {
+        let node_data = self
+            .node_data(node)
+            .ok_or(ViewConstructionError::NodeIdNotFound { node })?;
+        let (_, data) = node_data.expected_terminal_or_error(node, kind)?;
+        Ok(data)
+    }
+
+    fn get_non_terminal(
+        &self,
+        node: CstNodeId,
+        kind: NonTerminalKind,
+    ) -> Result<NonTerminalData, CstConstructError> {
+        let node_data = self
+            .node_data(node)
+            .ok_or(ViewConstructionError::NodeIdNotFound { node })?;
+        let (_, data) = node_data.expected_non_terminal_or_error(node, kind)?;
+        Ok(data)
+    }
+
+    fn collect_nodes<'v, const N: usize, V: BuiltinTerminalVisitor<E, Self>, O, E>(
+        &self,
+        parent: CstNodeId,
+        nodes: [NodeKind<TerminalKind, NonTerminalKind>; N],
+        visitor: impl FnMut([CstNodeId; N], &'v mut V) -> Result<(O, &'v mut V), CstConstructError<E>>,
+        visit_ignored: &'v mut V,
+    ) -> Result<O, CstConstructError<E>> {
+        collect_nodes_of(self, self, parent, nodes, visitor, visit_ignored)
+    }
+
+    fn dynamic_token(&self, id: DynamicTokenId) -> Option<&str> {
+        self.dynamic_tokens.get(&id).map(|s| s.as_str())
+    }
+
+    fn parent(&self, node: CstNodeId) -> Option<CstNodeId> {
+        match self.parents.get(node.0) {
+            Some(&parent) if parent != NO_PARENT => Some(CstNodeId(parent as usize)),
+            _ => None,
+        }
+    }
+
+    fn root_handle(&self) -> RootHandle {
+        RootHandle(self.root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tree::InputSpan;
+
+    #[test]
+    fn test_compact_answers_like_original() {
+        let mut tree = ConcreteSyntaxTree::new(CstNodeData::new_non_terminal(
+            NonTerminalKind::Root,
+            NonTerminalData::Dynamic,
+        ));
+        let root = tree.root();
+        let binding = tree.add_node_with_parent(
+            CstNodeData::new_non_terminal(NonTerminalKind::Binding, NonTerminalData::Dynamic),
+            root,
+        );
+        let ident = tree.add_node_with_parent(
+            CstNodeData::new_terminal(TerminalKind::Ident, TerminalData::Input(InputSpan::EMPTY)),
+            binding,
+        );
+        let token = tree.insert_dynamic_terminal(" ");
+        tree.add_node_with_parent(
+            CstNodeData::new_terminal(TerminalKind::Whitespace, TerminalData::Dynamic(token)),
+            binding,
+        );
+        let detached = tree.add_node_with_parent(
+            CstNodeData::new_non_terminal(NonTerminalKind::Binding, NonTerminalData::Dynamic),
+            root,
+        );
+        tree.remove_node(detached);
+        tree.change_parent(ident, root);
+
+        let compact = tree.clone().into_compact();
+        assert_eq!(compact.len(), 5);
+        assert_eq!(CstFacade::root_handle(&compact), tree.root_handle());
+        for id in (0..compact.len() + 1).map(CstNodeId) {
+            assert_eq!(
+                CstFacade::children(&compact, id).collect::<Vec<_>>(),
+                tree.children(id).collect::<Vec<_>>(),
+                "children of {id}"
+            );
+            assert_eq!(
+                CstFacade::parent(&compact, id),
+                tree.parent(id),
+                "parent of {id}"
+            );
+            assert_eq!(
+                CstFacade::has_no_children(&compact, id),
+                tree.has_no_children(id)
+            );
+            assert_eq!(CstFacade::node_data(&compact, id), tree.node_data(id));
+        }
+        assert_eq!(CstFacade::dynamic_token(&compact, token), Some(" "));
+    }
+}
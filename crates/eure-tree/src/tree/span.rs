@@ -3,6 +3,8 @@
 pub struct LineNumbers<'a> {
     phantom: std::marker::PhantomData<&'a str>,
     indexes: Vec<u32>,
+    /// Byte offset of the start of each line.
+    line_starts: Vec<u32>,
 }
 
 /// Information about a character position in text
@@ -19,17 +21,34 @@ pub struct CharInfo {
 impl LineNumbers<'_> {
     pub fn new(input: &str) -> Self {
         let mut indexes = vec![];
-        for (i, c) in input.chars().enumerate() {
+        let mut line_starts = vec![0];
+        for (i, (byte, c)) in input.char_indices().enumerate() {
             if c == '\n' {
                 indexes.push(i as u32);
+                line_starts.push(byte as u32 + 1);
             }
         }
         Self {
             phantom: std::marker::PhantomData,
             indexes,
+            line_starts,
         }
     }
 
+    /// The 0-indexed line and byte column of the byte `offset`.
+    ///
+    /// Lines end at `\n`, so the `\r` of a `\r\n` ending is the last column
+    /// of its line.
+    pub fn line_col(&self, offset: u32) -> (u32, u32) {
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        (line as u32, offset - self.line_starts[line])
+    }
+
+    /// The byte offset where the 0-indexed `line` starts, if the input has it.
+    pub fn line_start(&self, line: u32) -> Option<u32> {
+        self.line_starts.get(line as usize).copied()
+    }
+
     /// Get detailed character position information for a given character index
     pub fn get_char_info(&self, index: u32) -> CharInfo {
         // Find the number of newline characters that come before this index
@@ -90,6 +109,14 @@ impl InputSpan {
     pub fn as_str<'a>(&self, input: &'a str) -> &'a str {
         &input[self.start as usize..self.end as usize]
     }
+
+    /// The 0-indexed `(line, column)` of the start and end of this span.
+    pub fn line_col_range(&self, line_numbers: &LineNumbers) -> ((u32, u32), (u32, u32)) {
+        (
+            line_numbers.line_col(self.start),
+            line_numbers.line_col(self.end),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -794,4 +821,27 @@ mod tests {
             }
         ); // 's' of 'second'
     }
+
+    #[test]
+    fn test_line_col_multi_line() {
+        let input = "a = 1\r\nbc = 2\nd = 3";
+        let line_numbers = LineNumbers::new(input);
+
+        assert_eq!(line_numbers.line_col(0), (0, 0));
+        assert_eq!(line_numbers.line_col(4), (0, 4));
+        // `\r` and `\n` of the CRLF end line 0
+        assert_eq!(line_numbers.line_col(5), (0, 5));
+        assert_eq!(line_numbers.line_col(6), (0, 6));
+        // First byte after the CRLF
+        assert_eq!(line_numbers.line_col(7), (1, 0));
+        assert_eq!(line_numbers.line_col(12), (1, 5));
+        assert_eq!(line_numbers.line_col(14), (2, 0));
+        assert_eq!(line_numbers.line_col(input.len() as u32), (2, 5));
+
+        let start = input.find("bc").unwrap() as u32;
+        let span = InputSpan::new(start, input.find("d =").unwrap() as u32 + 1);
+        assert_eq!(span.line_col_range(&line_numbers), ((1, 0), (2, 1)));
+        assert_eq!(line_numbers.line_start(1), Some(7));
+        assert_eq!(line_numbers.line_start(3), None);
+    }
 }
@@ -0,0 +1,300 @@
+//! Project-wide `eure check` as a library function.
+//!
+//! [`run`] validates every target of an `Eure.eure` config and summarizes the
+//! result in a [`CheckOutcome`], whose [`exit_code`](CheckOutcome::exit_code)
+//! gives the deterministic status CI relies on.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use eure_env::EureConfig;
+use query_flow::{DurabilityLevel, Query, QueryError, QueryResultExt as _, QueryRuntime};
+use thiserror::Error;
+
+use crate::query::{
+    CacheOptions, ConfigValidationWarning, Glob, GlobResult, TextFile, TextFileContent,
+    ValidateTargets, ValidateTargetsResult, WithErrorReports, build_runtime, fetch_url,
+    fetch_url_cached, validate_config,
+};
+use crate::report::{ErrorReport, ErrorReports, Severity};
+
+/// A problem that prevented checking, as opposed to a validation error.
+#[derive(Debug, Error)]
+pub enum CheckError {
+    #[error(transparent)]
+    Config(#[from] ConfigValidationWarning),
+    #[error("{0}")]
+    Query(QueryError),
+}
+
+/// Summary of a check run.
+#[derive(Debug, Default)]
+pub struct CheckOutcome {
+    /// Number of reports with [`Severity::Error`].
+    pub errors: usize,
+    /// Number of reports with [`Severity::Warning`].
+    pub warnings: usize,
+    /// All reports, in target name order.
+    pub reports: Vec<ErrorReport>,
+    /// Problems that prevented checking. When non-empty, no files were validated.
+    pub failures: Vec<CheckError>,
+    /// Per-target results, in target name order. Empty when checking failed
+    /// before any file was validated.
+    pub targets: Arc<ValidateTargetsResult>,
+}
+
+impl CheckOutcome {
+    /// Process exit code: 0 when clean, 1 on validation errors, 2 when the
+    /// check could not run.
+    pub fn exit_code(&self) -> i32 {
+        if !self.failures.is_empty() {
+            2
+        } else if self.errors > 0 {
+            1
+        } else {
+            0
+        }
+    }
+
+    fn add_reports<'a>(&mut self, reports: impl IntoIterator<Item = &'a ErrorReport>) {
+        for report in reports {
+            match report.severity {
+                Severity::Error => self.errors += 1,
+                Severity::Warning => self.warnings += 1,
+                _ => {}
+            }
+            self.reports.push(report.clone());
+        }
+    }
+}
+
+/// Validate every target in `config` against its schema.
+///
+/// `root` is the directory containing the config file. Target schemas are
+/// checked up front with [`validate_config`]; any problem there is reported as
+/// a failure instead of validating files.
+pub fn run(config: &EureConfig, root: &Path) -> CheckOutcome {
+    run_on(&build_runtime(), config, root, None)
+}
+
+/// [`run`] on a caller-owned `runtime`.
+///
+/// The runtime keeps the loaded files, so the caller can format the reports
+/// afterwards. Remote files are fetched through the HTTPS cache when
+/// `cache_opts` is given.
+pub fn run_on(
+    runtime: &QueryRuntime,
+    config: &EureConfig,
+    root: &Path,
+    cache_opts: Option<&CacheOptions>,
+) -> CheckOutcome {
+    let failures: Vec<CheckError> = validate_config(config, root)
+        .into_iter()
+        .map(CheckError::from)
+        .collect();
+    if !failures.is_empty() {
+        return CheckOutcome {
+            failures,
+            ..CheckOutcome::default()
+        };
+    }
+
+    let mut targets: Vec<_> = config
+        .targets
+        .iter()
+        .map(|(name, target)| (name.clone(), target.clone()))
+        .collect();
+    targets.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let query = WithErrorReports::new(ValidateTargets::new(Arc::new(targets), root.to_path_buf()));
+    let result =
+        match run_with_file_loading(runtime, query, cache_opts).downcast_err::<ErrorReports>() {
+            Ok(Ok(result)) => result,
+            Ok(Err(reports)) => {
+                let mut outcome = CheckOutcome::default();
+                outcome.add_reports(reports.get().iter());
+                return outcome;
+            }
+            Err(e) => {
+                return CheckOutcome {
+                    failures: vec![CheckError::Query(e)],
+                    ..CheckOutcome::default()
+                };
+            }
+        };
+
+    let mut outcome = CheckOutcome::default();
+    for (_, target_result) in result.iter() {
+        for (_, reports) in target_result.iter() {
+            outcome.add_reports(reports.iter());
+        }
+    }
+    outcome.targets = result;
+    outcome
+}
+
+/// Run `query`, loading files and expanding globs from disk whenever it suspends.
+///
+/// Remote files are fetched through the HTTPS cache when `cache_opts` is given.
+/// Files that cannot be loaded and globs that cannot be expanded are resolved as
+/// asset errors, so the query reports them instead of seeing missing input.
+pub fn run_with_file_loading<Q>(
+    runtime: &QueryRuntime,
+    query: Q,
+    cache_opts: Option<&CacheOptions>,
+) -> Result<Arc<Q::Output>, QueryError>
+where
+    Q: Query + Clone,
+{
+    loop {
+        match runtime.query(query.clone()) {
+            Err(QueryError::Suspend { .. }) => {}
+            result => return result,
+        }
+        for pending in runtime.pending_assets() {
+            if let Some(file) = pending.key::<TextFile>() {
+                let content = match file {
+                    TextFile::Local(path) => std::fs::read_to_string(path.as_path())
+                        .map(TextFileContent)
+                        .map_err(|e| anyhow!("{}: {e}", path.display())),
+                    TextFile::Remote(url) => match cache_opts {
+                        Some(opts) => fetch_url_cached(url, opts),
+                        None => fetch_url(url),
+                    }
+                    .map_err(|e| anyhow!("Failed to fetch {url}: {e}")),
+                };
+                match content {
+                    Ok(content) => {
+                        runtime.resolve_asset(file.clone(), content, DurabilityLevel::Static)
+                    }
+                    Err(e) => runtime.resolve_asset_error::<TextFile>(
+                        file.clone(),
+                        e,
+                        DurabilityLevel::Static,
+                    ),
+                }
+            } else if let Some(glob_key) = pending.key::<Glob>() {
+                match expand_glob(&glob_key.full_pattern()) {
+                    Ok(files) => runtime.resolve_asset(
+                        glob_key.clone(),
+                        GlobResult(files),
+                        DurabilityLevel::Static,
+                    ),
+                    Err(e) => runtime.resolve_asset_error::<Glob>(
+                        glob_key.clone(),
+                        e,
+                        DurabilityLevel::Static,
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// Expand `pattern` on the filesystem, failing on an invalid pattern or an
+/// unreadable path.
+fn expand_glob(pattern: &Path) -> anyhow::Result<Vec<TextFile>> {
+    let paths = glob::glob(&pattern.to_string_lossy())
+        .map_err(|e| anyhow!("Invalid glob pattern {}: {e}", pattern.display()))?;
+    paths
+        .map(|path| path.map(TextFile::from_path).map_err(|e| anyhow!("{e}")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eure_env::{ConfigWarning, Target};
+
+    fn project(files: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for (path, content) in files {
+            std::fs::write(dir.path().join(path), content).unwrap();
+        }
+        dir
+    }
+
+    fn config(schema: &str) -> EureConfig {
+        let mut config = EureConfig::default();
+        config.targets.insert(
+            "servers".to_string(),
+            Target {
+                globs: vec!["*.server.eure".to_string()],
+                schema: Some(schema.to_string()),
+            },
+        );
+        config
+    }
+
+    #[test]
+    fn test_clean_run() {
+        let dir = project(&[
+            ("server.schema.eure", "port = `integer`\n"),
+            ("a.server.eure", "port = 8080\n"),
+        ]);
+        let outcome = run(&config("server.schema.eure"), dir.path());
+        assert_eq!(outcome.errors, 0, "reports: {:?}", outcome.reports);
+        assert!(outcome.failures.is_empty());
+        let targets: Vec<_> = outcome
+            .targets
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect();
+        assert_eq!(targets, ["servers"]);
+        assert_eq!(outcome.exit_code(), 0);
+    }
+
+    #[test]
+    fn test_run_with_validation_errors() {
+        let dir = project(&[
+            ("server.schema.eure", "port = `integer`\n"),
+            ("a.server.eure", "port = 8080\n"),
+            ("b.server.eure", "port = \"http\"\n"),
+        ]);
+        let outcome = run(&config("server.schema.eure"), dir.path());
+        assert!(outcome.errors > 0);
+        assert_eq!(outcome.reports.len(), outcome.errors + outcome.warnings);
+        assert_eq!(outcome.exit_code(), 1);
+    }
+
+    #[test]
+    fn test_run_resolves_cross_schema_references() {
+        let dir = project(&[
+            ("network.schema.eure", "$types.port = `integer`\n"),
+            ("server.schema.eure", "port = `$types.network.port`\n"),
+            ("a.server.eure", "port = 8080\n"),
+        ]);
+        let outcome = run(&config("server.schema.eure"), dir.path());
+        assert_eq!(outcome.errors, 0, "reports: {:?}", outcome.reports);
+
+        std::fs::write(dir.path().join("b.server.eure"), "port = \"http\"\n").unwrap();
+        let outcome = run(&config("server.schema.eure"), dir.path());
+        assert_eq!(outcome.errors, 1, "reports: {:?}", outcome.reports);
+        assert!(outcome.reports[0].title.contains("integer"));
+    }
+
+    #[test]
+    fn test_run_with_missing_schema() {
+        let dir = project(&[("a.server.eure", "port = 8080\n")]);
+        let outcome = run(&config("server.schema.eure"), dir.path());
+        assert!(matches!(
+            outcome.failures.as_slice(),
+            [CheckError::Config(ConfigValidationWarning::Config(
+                ConfigWarning::MissingSchema { target, .. }
+            ))] if target == "servers"
+        ));
+        assert!(outcome.reports.is_empty());
+        assert_eq!(outcome.exit_code(), 2);
+    }
+
+    #[test]
+    fn test_expand_glob() {
+        let dir = project(&[("a.server.eure", "port = 8080\n")]);
+        assert_eq!(
+            expand_glob(&dir.path().join("*.server.eure")).unwrap(),
+            vec![TextFile::from_path(dir.path().join("a.server.eure"))]
+        );
+        assert!(expand_glob(&dir.path().join("[.server.eure")).is_err());
+    }
+}
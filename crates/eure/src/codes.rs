@@ -0,0 +1,576 @@
+//! Stable error codes and their long-form explanations.
+//!
+//! Validation errors ([`ValidationError::code`]) use the `E0xxx` range, parse
+//! errors ([`ParseErrorKind::code`]) use the `E1xxx` range and schema lints
+//! ([`Lint::code`]) use the `E2xxx` range.
+//!
+//! [`ValidationError::code`]: eure_schema::validate::ValidationError::code
+//! [`ParseErrorKind::code`]: eure_document::parse::ParseErrorKind::code
+//! [`Lint::code`]: eure_schema::lint::Lint::code
+
+/// Every known error code paired with its explanation.
+pub const CODES: &[(&str, &str)] = &[
+    // Validation errors
+    (
+        "E0001",
+        "Type mismatch.\n\nThe value has a different type than the schema expects, e.g. a text \
+         value where an integer is required. Change the value or adjust the schema type.",
+    ),
+    (
+        "E0002",
+        "Missing required field.\n\nA record is missing one or more fields the schema declares \
+         as required. Add the field, or mark it `$optional = true` in the schema.",
+    ),
+    (
+        "E0003",
+        "Unknown field.\n\nA record contains a field the schema does not declare and unknown \
+         fields are denied. Check the field name for typos or declare it in the schema.",
+    ),
+    (
+        "E0004",
+        "Value out of range.\n\nA number lies outside the `range` constraint of its schema. \
+         Use a value within the allowed bounds.",
+    ),
+    (
+        "E0005",
+        "String length out of bounds.\n\nA text value is shorter than `min-length` or longer \
+         than `max-length`.",
+    ),
+    (
+        "E0006",
+        "Pattern mismatch.\n\nA text value does not match the regular expression given by the \
+         schema's `pattern` constraint.",
+    ),
+    (
+        "E0007",
+        "Array length out of bounds.\n\nAn array has fewer items than `min-length` or more \
+         than `max-length`.",
+    ),
+    (
+        "E0008",
+        "Map size out of bounds.\n\nA map has fewer entries than `min-size` or more than \
+         `max-size`.",
+    ),
+    (
+        "E0009",
+        "Tuple length mismatch.\n\nA tuple has a different number of elements than the schema \
+         declares.",
+    ),
+    (
+        "E0010",
+        "Array elements not unique.\n\nThe schema requires `unique` array elements but the \
+         array contains duplicates.",
+    ),
+    (
+        "E0011",
+        "Array missing required element.\n\nThe schema's `contains` constraint requires an \
+         element that the array does not include.",
+    ),
+    (
+        "E0012",
+        "No union variant matched.\n\nThe value did not match any variant of an untagged \
+         union. The report shows the closest variant and why it failed; add `$variant` to \
+         select a variant explicitly.",
+    ),
+    (
+        "E0013",
+        "Ambiguous union.\n\nThe value matches more than one union variant and none has \
+         priority. Add `$variant` to disambiguate, or give the union a `priority` order.",
+    ),
+    (
+        "E0014",
+        "Invalid variant tag.\n\nThe `$variant` tag names a variant the union does not \
+         define.",
+    ),
+    (
+        "E0015",
+        "Conflicting variant tags.\n\nThe `$variant` extension and the union's representation \
+         select different variants.",
+    ),
+    (
+        "E0016",
+        "Explicit variant required.\n\nThis variant can only be selected with an explicit \
+         `$variant` tag.",
+    ),
+    (
+        "E0017",
+        "Literal mismatch.\n\nThe schema requires an exact literal value and the document \
+         contains a different one.",
+    ),
+    (
+        "E0018",
+        "Language mismatch.\n\nA code value is tagged with a different language than the \
+         schema expects.",
+    ),
+    (
+        "E0019",
+        "Invalid key type.\n\nA map or record key has a type the schema does not accept.",
+    ),
+    (
+        "E0020",
+        "Not a multiple.\n\nAn integer is not a multiple of the schema's `multiple-of` \
+         constraint.",
+    ),
+    (
+        "E0021",
+        "Undefined type reference.\n\nThe schema references a type that is not defined in \
+         `$types`.",
+    ),
+    (
+        "E0022",
+        "Unresolvable type reference.\n\nThe schema references a type in another namespace \
+         that could not be resolved.",
+    ),
+    (
+        "E0023",
+        "Invalid flatten target.\n\nOnly records, unions, and maps can be flattened into a \
+         record.",
+    ),
+    (
+        "E0024",
+        "Flatten map key mismatch.\n\nA key collected by a flattened map does not match the \
+         map's key schema.",
+    ),
+    (
+        "E0025",
+        "Missing required extension.\n\nA node is missing an extension the schema declares as \
+         required.",
+    ),
+    (
+        "E0026",
+        "Format mismatch.\n\nA text value is not a valid instance of the schema's `format`, \
+         e.g. an `email` field holding a string without an `@`.",
+    ),
+    // Parse errors
+    (
+        "E1001",
+        "Unexpected hole.\n\nA value is still an uninitialized hole (`!`). Fill it in before \
+         using the document.",
+    ),
+    (
+        "E1002",
+        "Type mismatch.\n\nThe value has a different type than the target type expects.",
+    ),
+    (
+        "E1003",
+        "Missing field.\n\nA required field of the target type is not present.",
+    ),
+    (
+        "E1004",
+        "Missing extension.\n\nA required extension of the target type is not present.",
+    ),
+    (
+        "E1005",
+        "Unknown variant.\n\nThe variant name does not exist in the target enum.",
+    ),
+    (
+        "E1006",
+        "Value out of range.\n\nA number does not fit in the target numeric type.",
+    ),
+    (
+        "E1007",
+        "Invalid value.\n\nThe value does not have the expected format, e.g. an invalid URL or \
+         UUID.",
+    ),
+    (
+        "E1008",
+        "Invalid regex.\n\nThe value could not be compiled as a regular expression.",
+    ),
+    (
+        "E1009",
+        "Invalid identifier.\n\nIdentifiers must start with a letter or underscore and contain \
+         only letters, digits, `_`, and `-`.",
+    ),
+    (
+        "E1010",
+        "Unexpected tuple length.\n\nA tuple has a different number of elements than the \
+         target type.",
+    ),
+    (
+        "E1011",
+        "Unknown field.\n\nA record contains a field the target type does not declare.",
+    ),
+    (
+        "E1012",
+        "Unknown extension.\n\nA node carries an extension the target type does not accept.",
+    ),
+    ("E1013", "Invalid key type.\n\nRecord keys must be strings."),
+    (
+        "E1014",
+        "No matching variant.\n\nThe value did not match any variant of the target union.",
+    ),
+    (
+        "E1015",
+        "Conflicting variant tags.\n\nThe `$variant` extension and the union's representation \
+         select different variants.",
+    ),
+    (
+        "E1016",
+        "Ambiguous union.\n\nThe value matches more than one variant of the target union.",
+    ),
+    (
+        "E1017",
+        "Literal mismatch.\n\nThe target type requires an exact literal value.",
+    ),
+    (
+        "E1018",
+        "Unexpected variant path.\n\nA `$variant` path was given for a type that is not a \
+         union.",
+    ),
+    (
+        "E1019",
+        "Invalid variant type.\n\nThe `$variant` extension must be a string.",
+    ),
+    (
+        "E1020",
+        "Invalid variant path.\n\nThe `$variant` extension is not a valid dotted path of \
+         identifiers.",
+    ),
+    (
+        "E1021",
+        "Record in extension scope.\n\nA type parsed with `#[eure(flatten_ext)]` reads record \
+         fields; use `#[eure(flatten)]` instead.",
+    ),
+    (
+        "E1022",
+        "Unexpected array length.\n\nAn array has a different number of elements than the \
+         target fixed-size array.",
+    ),
+    (
+        "E1023",
+        "Not a primitive.\n\nThe target type expects a primitive value but found an array, \
+         tuple, or map.",
+    ),
+    (
+        "E1024",
+        "Invalid value.\n\nA text value could not be parsed as the target type, e.g. \
+         `999.0.0.1` for an IP address.",
+    ),
+    // Schema lints
+    (
+        "E2001",
+        "Deprecated field.\n\nThe document sets a field whose schema is marked \
+         `$deprecated`. The document is still valid; migrate to the replacement named in the \
+         field's description.",
+    ),
+];
+
+/// Look up the long-form explanation for an error code such as `"E0001"`.
+pub fn explain(code: &str) -> Option<&'static str> {
+    CODES
+        .iter()
+        .find(|(known, _)| *known == code)
+        .map(|(_, explanation)| *explanation)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use eure_document::document::NodeId;
+    use eure_document::identifier::Identifier;
+    use eure_document::parse::{ParseError, ParseErrorKind, VariantPath};
+    use eure_document::path::EurePath;
+    use eure_document::value::{ObjectKey, ValueKind};
+    use eure_schema::lint::Lint;
+    use eure_schema::validate::ValidationError;
+    use eure_schema::{SchemaKind, SchemaNodeId, TextFormat};
+
+    use super::*;
+
+    fn parse_error_kinds() -> Vec<ParseErrorKind> {
+        vec![
+            ParseErrorKind::UnexpectedHole,
+            ParseErrorKind::TypeMismatch {
+                expected: ValueKind::Text,
+                actual: ValueKind::Integer,
+            },
+            ParseErrorKind::MissingField("a".into()),
+            ParseErrorKind::MissingExtension("a".into()),
+            ParseErrorKind::UnknownVariant("a".into()),
+            ParseErrorKind::OutOfRange("a".into()),
+            ParseErrorKind::InvalidPattern {
+                kind: "url".into(),
+                reason: "a".into(),
+            },
+            ParseErrorKind::InvalidRegex {
+                pattern: "(".into(),
+                error: regex::Regex::new(&String::from("(")).unwrap_err(),
+            },
+            ParseErrorKind::InvalidIdentifier("".parse::<Identifier>().unwrap_err()),
+            ParseErrorKind::UnexpectedTupleLength {
+                expected: 1,
+                actual: 2,
+            },
+            ParseErrorKind::UnknownField("a".into()),
+            ParseErrorKind::UnknownExtension(Identifier::new_unchecked("a")),
+            ParseErrorKind::InvalidKeyType(ObjectKey::Number(1.into())),
+            ParseErrorKind::NoMatchingVariant {
+                variant: None,
+                best_match: None,
+            },
+            ParseErrorKind::ConflictingVariantTags {
+                explicit: "a".into(),
+                repr: "b".into(),
+            },
+            ParseErrorKind::AmbiguousUnion(vec![]),
+            ParseErrorKind::LiteralMismatch {
+                expected: "a".into(),
+                actual: "b".into(),
+            },
+            ParseErrorKind::UnexpectedVariantPath(VariantPath::parse("a").unwrap()),
+            ParseErrorKind::InvalidVariantType(ValueKind::Integer),
+            ParseErrorKind::InvalidVariantPath("a..b".into()),
+            ParseErrorKind::RecordInExtensionScope,
+            ParseErrorKind::UnexpectedArrayLength {
+                expected: 1,
+                actual: 2,
+            },
+            ParseErrorKind::NotPrimitive {
+                actual: ValueKind::Map,
+            },
+            ParseErrorKind::InvalidValue {
+                expected: "IP address".into(),
+                got: "a".into(),
+            },
+        ]
+    }
+
+    fn lints() -> Vec<Lint> {
+        vec![Lint::Deprecated {
+            field: "a".into(),
+            path: EurePath::root(),
+            node_id: NodeId(0),
+            message: None,
+        }]
+    }
+
+    fn validation_errors() -> Vec<ValidationError> {
+        let path = EurePath::root;
+        let node_id = NodeId(0);
+        let schema_node_id = SchemaNodeId(0);
+        vec![
+            ValidationError::TypeMismatch {
+                expected: "a".into(),
+                actual: "b".into(),
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::MissingRequiredField {
+                fields: vec!["a".into()],
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::UnknownField {
+                field: "a".into(),
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::OutOfRange {
+                value: "1".into(),
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::StringLengthOutOfBounds {
+                length: 1,
+                min: None,
+                max: None,
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::PatternMismatch {
+                pattern: "a".into(),
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::ArrayLengthOutOfBounds {
+                length: 1,
+                min: None,
+                max: None,
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::MapSizeOutOfBounds {
+                size: 1,
+                min: None,
+                max: None,
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::TupleLengthMismatch {
+                expected: 1,
+                actual: 2,
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::ArrayNotUnique {
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::ArrayMissingContains {
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::NoVariantMatched {
+                path: path(),
+                best_match: None,
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::AmbiguousUnion {
+                path: path(),
+                variants: vec![],
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::InvalidVariantTag {
+                tag: "a".into(),
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::ConflictingVariantTags {
+                explicit: "a".into(),
+                repr: "b".into(),
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::RequiresExplicitVariant {
+                variant: "a".into(),
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::LiteralMismatch {
+                expected: "a".into(),
+                actual: "b".into(),
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::LanguageMismatch {
+                expected: "a".into(),
+                actual: "b".into(),
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::InvalidKeyType {
+                key: ObjectKey::Number(1.into()),
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::NotMultipleOf {
+                divisor: "2".into(),
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::UndefinedTypeReference {
+                name: "a".into(),
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::UnresolvableReference {
+                namespace: "a".into(),
+                name: "b".into(),
+                path: path(),
+                node_id,
+                schema_node_id,
+                cause: None,
+            },
+            ValidationError::InvalidFlattenTarget {
+                actual_kind: SchemaKind::Text,
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::FlattenMapKeyMismatch {
+                key: "a".into(),
+                pattern: None,
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::MissingRequiredExtension {
+                extension: "a".into(),
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+            ValidationError::FormatMismatch {
+                format: TextFormat::Email,
+                path: path(),
+                node_id,
+                schema_node_id,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_codes_are_distinct_and_explained() {
+        let codes: Vec<&str> = validation_errors()
+            .iter()
+            .map(ValidationError::code)
+            .chain(parse_error_kinds().iter().map(ParseErrorKind::code))
+            .chain(lints().iter().map(Lint::code))
+            .collect();
+        let unique: HashSet<&str> = codes.iter().copied().collect();
+        assert_eq!(unique.len(), codes.len(), "duplicate codes in {codes:?}");
+        assert_eq!(codes.len(), CODES.len());
+        for code in codes {
+            assert!(explain(code).is_some(), "{code} has no explanation");
+        }
+    }
+
+    #[test]
+    fn test_codes_are_stable() {
+        let type_mismatch = ValidationError::TypeMismatch {
+            expected: "a".into(),
+            actual: "b".into(),
+            path: EurePath::root(),
+            node_id: NodeId(0),
+            schema_node_id: SchemaNodeId(0),
+        };
+        assert_eq!(type_mismatch.code(), "E0001");
+        assert_eq!(ParseErrorKind::MissingField("a".into()).code(), "E1003");
+        assert!(
+            explain("E0002")
+                .unwrap()
+                .starts_with("Missing required field.")
+        );
+        assert_eq!(lints()[0].code(), crate::report::DEPRECATED_CODE);
+        assert_eq!(explain("E9999"), None);
+    }
+
+    #[test]
+    fn test_wrapped_errors_report_inner_code() {
+        let nested = ParseErrorKind::UnexpectedHole.at("a.b");
+        assert_eq!(nested.code(), "E1001");
+
+        let error = ValidationError::ParseError {
+            path: EurePath::root(),
+            node_id: NodeId(0),
+            schema_node_id: SchemaNodeId(0),
+            error: ParseError {
+                node_id: NodeId(0),
+                kind: nested,
+            },
+        };
+        assert_eq!(error.code(), "E1001");
+    }
+}
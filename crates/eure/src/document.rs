@@ -24,7 +24,7 @@ use eure_tree::tree::InputSpan;
 use std::collections::HashMap;
 use thiserror::Error;
 
-use eure_document::path::PathSegment;
+use eure_document::path::{EurePath, PathSegment};
 use eure_document::source::{BindSource, Comment, SectionBody, SourceDocument, SourceId, Trivia};
 use eure_document::value::ObjectKey;
 
@@ -51,6 +51,9 @@ pub struct OriginMap {
     /// CST-based key span storage: (CstNodeId, ObjectKey) -> InputSpan.
     /// Used for error reporting when we only have the CST node ID, not the document NodeId.
     pub key_span_by_cst: HashMap<(CstNodeId, ObjectKey), InputSpan>,
+    /// `Keys` CST node -> the node its key path navigates to.
+    /// Used to resolve the document path of a binding or section header.
+    pub target: HashMap<CstNodeId, NodeId>,
 }
 
 impl OriginMap {
@@ -71,6 +74,11 @@ impl OriginMap {
         self.value.insert(node_id, cst_node_id);
     }
 
+    /// Record the node a `Keys` CST node navigates to.
+    pub fn record_target(&mut self, keys_cst_node_id: CstNodeId, node_id: NodeId) {
+        self.target.insert(keys_cst_node_id, node_id);
+    }
+
     /// Record a map key origin.
     pub fn record_key(&mut self, map_node_id: NodeId, key: ObjectKey, cst_node_id: CstNodeId) {
         self.key.insert((map_node_id, key), cst_node_id);
@@ -127,6 +135,76 @@ impl OriginMap {
     }
 }
 
+/// Lookup from CST nodes to the document paths they define.
+///
+/// Built once per document so that repeated [`PathIndex::path_of`] calls
+/// (e.g. one per key when computing highlights) only walk CST ancestors.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PathIndex {
+    owners: HashMap<CstNodeId, EurePath>,
+}
+
+impl PathIndex {
+    /// Index every definition, value and `Keys` target origin of `doc`.
+    ///
+    /// When one CST node is the origin of several document nodes (e.g. a
+    /// binding and its value), the deepest path wins. A `Keys` node always
+    /// resolves to the node it navigates to.
+    pub fn new(doc: &EureDocument, origins: &OriginMap) -> Self {
+        let paths: HashMap<NodeId, EurePath> = doc.paths().collect();
+        let mut owners: HashMap<CstNodeId, EurePath> = HashMap::new();
+        for (node_id, &cst_node_id) in origins.definition.iter().chain(&origins.value) {
+            let Some(path) = paths.get(node_id) else {
+                continue;
+            };
+            owners
+                .entry(cst_node_id)
+                .and_modify(|owner| {
+                    if path.0.len() > owner.0.len() {
+                        *owner = path.clone();
+                    }
+                })
+                .or_insert_with(|| path.clone());
+        }
+        for (&keys_cst_node_id, node_id) in &origins.target {
+            if let Some(path) = paths.get(node_id) {
+                owners.insert(keys_cst_node_id, path.clone());
+            }
+        }
+        Self { owners }
+    }
+
+    /// Interpret `cst` and index the result.
+    ///
+    /// If document construction fails, the document built up to the failing
+    /// node is indexed instead, so paths still resolve for everything before
+    /// the error.
+    pub fn from_cst(input: &str, cst: &Cst) -> Self {
+        let mut visitor = CstInterpreter::new(input);
+        // The error itself is reported by the document queries; only the
+        // partially built document matters here.
+        let _ = visitor.visit_root_handle(cst.root_handle(), cst);
+        let (doc, origins) = visitor.into_document_and_origin_map();
+        Self::new(&doc, &origins)
+    }
+
+    /// Get the document path of the innermost key or value enclosing `node`,
+    /// e.g. `server.port` for the `8080` terminal in `@ server { port = 8080 }`.
+    ///
+    /// Returns `None` if `node` lies outside every key and value (e.g. in a
+    /// comment at the top level) or after the point where construction failed.
+    pub fn path_of(&self, cst: &Cst, node: CstNodeId) -> Option<&EurePath> {
+        let mut current = Some(node);
+        while let Some(cst_node_id) = current {
+            if let Some(path) = self.owners.get(&cst_node_id) {
+                return Some(path);
+            }
+            current = cst.parent(cst_node_id);
+        }
+        None
+    }
+}
+
 #[derive(Debug, Error, PartialEq, Eq, Clone)]
 pub enum InlineCodeError {
     #[error("Does not match InlineCode1 pattern")]
@@ -160,6 +238,15 @@ pub enum DocumentConstructionError {
         /// The document NodeId where the error occurred (parent node for key errors)
         parent_node_id: Option<NodeId>,
     },
+    /// A binding targets a node that an earlier binding already assigned.
+    #[error("Duplicate key: `{}` is already assigned", .error.path)]
+    DuplicateBinding {
+        error: InsertError,
+        /// The rejected binding.
+        node_id: CstNodeId,
+        /// The binding (or key) that first assigned the value.
+        first_node_id: CstNodeId,
+    },
     #[error("Dynamic token not found: {0:?}")]
     DynamicTokenNotFound(DynamicTokenId),
     #[error("Failed to parse big integer: {0}")]
@@ -209,6 +296,7 @@ impl DocumentConstructionError {
                 node_id.and_then(|id| cst.span(id))
             }
             DocumentConstructionError::DocumentInsert { node_id, .. } => cst.span(*node_id),
+            DocumentConstructionError::DuplicateBinding { node_id, .. } => cst.span(*node_id),
             DocumentConstructionError::InvalidInlineCode { node_id, .. } => cst.span(*node_id),
             DocumentConstructionError::InvalidCodeBlock { node_id, .. } => cst.span(*node_id),
             DocumentConstructionError::InvalidStringKey { node_id, .. } => cst.span(*node_id),
@@ -450,6 +538,7 @@ pub fn cst_to_document_and_origin_map(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use eure_document::identifier::Identifier;
     use eure_document::plan::LayoutPlan;
     use eure_document::value::ObjectKey;
     use eure_fmt::format_source_document;
@@ -478,6 +567,56 @@ mod tests {
         None
     }
 
+    #[test]
+    fn test_path_of_value_in_section_block() {
+        let input = "@ server {\n  port = 8080\n}\n";
+        let cst = eure_parol::parse(input, "<input>").unwrap();
+        let integer = find_node_by_kind(&cst, cst.root(), NonTerminalKind::Integer)
+            .expect("Should find Integer");
+        let terminal = cst
+            .children(integer)
+            .next()
+            .expect("Integer has a terminal");
+
+        let path = PathIndex::from_cst(input, &cst)
+            .path_of(&cst, terminal)
+            .cloned()
+            .expect("terminal should have a path");
+        assert_eq!(path.to_string(), "server.port");
+    }
+
+    #[test]
+    fn test_path_of_array_element() {
+        let input = "items[] = 1\nitems[] = 2\n";
+        let cst = eure_parol::parse(input, "<input>").unwrap();
+        let second = cst
+            .descendants_of_kind(cst.root(), NodeKind::NonTerminal(NonTerminalKind::Integer))
+            .into_iter()
+            .nth(1)
+            .expect("Should find second Integer");
+
+        let path = PathIndex::from_cst(input, &cst)
+            .path_of(&cst, second)
+            .cloned()
+            .expect("integer should have a path");
+        assert_eq!(path.to_string(), "items[1]");
+    }
+
+    #[test]
+    fn test_path_of_before_construction_error() {
+        let input = "a = 1\na = 2\n";
+        let cst = eure_parol::parse(input, "<input>").unwrap();
+        assert!(cst_to_document_and_origin_map(input, &cst).is_err());
+
+        let first = find_node_by_kind(&cst, cst.root(), NonTerminalKind::Integer)
+            .expect("Should find Integer");
+        let path = PathIndex::from_cst(input, &cst)
+            .path_of(&cst, first)
+            .cloned()
+            .expect("integer before the error should have a path");
+        assert_eq!(path.to_string(), "a");
+    }
+
     /// This test demonstrates that non-terminal spans include leading/trailing whitespace,
     /// and that get_shrunk_span correctly excludes them.
     #[test]
@@ -696,6 +835,33 @@ mod tests {
         assert_eq!(reparsed, doc);
     }
 
+    #[test]
+    fn test_extension_key_formats_with_dollar() {
+        // `eure!` spells extensions `%ext` because `$` is reserved in macros;
+        // source text always uses `$ext`.
+        let doc = eure_document::eure!({
+            server.%note = "primary"
+            server.port = 8080
+        });
+        let source = LayoutPlan::auto(doc.clone()).expect("layout plan").emit();
+        let formatted = format_source_document(&source);
+
+        assert!(formatted.contains("$note"), "formatted: {formatted}");
+        assert!(!formatted.contains('%'), "formatted: {formatted}");
+
+        let reparsed = parse_document(&formatted);
+        let server = reparsed
+            .parse_context(reparsed.get_root_id())
+            .parse_record()
+            .unwrap()
+            .field("server")
+            .unwrap()
+            .node_id();
+        let note: Identifier = "note".parse().unwrap();
+        assert!(reparsed.node(server).get_extension(&note).is_some());
+        assert_eq!(reparsed, doc);
+    }
+
     #[test]
     fn test_parse_to_source_document_preserves_leading_comment() {
         let input = "// hello\nvalue = 1\n";
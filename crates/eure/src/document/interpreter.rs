@@ -1,6 +1,8 @@
 use eure_document::value::Tuple;
 use eure_document::{
-    document::{EureDocument, source_constructor::SourceConstructor},
+    document::{
+        EureDocument, InsertError, InsertErrorKind, NodeId, source_constructor::SourceConstructor,
+    },
     identifier::Identifier,
     path::{ArrayIndexKind, PathSegment},
     source::Comment,
@@ -342,6 +344,39 @@ impl<'a> CstInterpreter<'a> {
         self.origins.record_value(node_id, cst_node_id);
     }
 
+    /// Build the error for a binding whose target is not a hole.
+    ///
+    /// When the target already holds a value from an earlier binding, the
+    /// error also points at that binding so both locations can be reported.
+    fn binding_target_error(
+        &self,
+        error: InsertError,
+        target: NodeId,
+        cst_node_id: CstNodeId,
+    ) -> DocumentConstructionError {
+        let first = self
+            .origins
+            .value
+            .get(&target)
+            .or_else(|| self.origins.definition.get(&target));
+        match first {
+            Some(&first_node_id)
+                if matches!(error.kind, InsertErrorKind::BindingTargetHasValue) =>
+            {
+                DocumentConstructionError::DuplicateBinding {
+                    error,
+                    node_id: cst_node_id,
+                    first_node_id,
+                }
+            }
+            _ => DocumentConstructionError::DocumentInsert {
+                error,
+                node_id: cst_node_id,
+                parent_node_id: None,
+            },
+        }
+    }
+
     /// Record a map key origin for precise error spans.
     fn record_key_origin(
         &mut self,
@@ -591,13 +626,10 @@ impl<F: CstFacade> CstVisitor<F> for CstInterpreter<'_> {
 
                     // Validate binding target is a Hole
                     let node_id = self.document.current_node_id();
-                    self.document.require_hole().map_err(|e| {
-                        DocumentConstructionError::DocumentInsert {
-                            error: e,
-                            node_id: handle.node_id(),
-                            parent_node_id: None,
-                        }
-                    })?;
+                    self.origins.record_target(item.keys.node_id(), node_id);
+                    self.document
+                        .require_hole()
+                        .map_err(|e| self.binding_target_error(e, node_id, handle.node_id()))?;
 
                     // Record value span for this object entry
                     self.record_value(node_id, item.keys.node_id());
@@ -1029,13 +1061,10 @@ impl<F: CstFacade> CstVisitor<F> for CstInterpreter<'_> {
 
         // Validate binding target is a Hole
         let node_id = self.document.current_node_id();
+        self.origins.record_target(view.keys.node_id(), node_id);
         self.document
             .require_hole()
-            .map_err(|e| DocumentConstructionError::DocumentInsert {
-                error: e,
-                node_id: handle.node_id(),
-                parent_node_id: None,
-            })?;
+            .map_err(|e| self.binding_target_error(e, node_id, handle.node_id()))?;
 
         self.record_value(node_id, handle.node_id());
 
@@ -1107,13 +1136,10 @@ impl<F: CstFacade> CstVisitor<F> for CstInterpreter<'_> {
 
         // Validate section target is unbound
         let node_id = self.document.current_node_id();
+        self.origins.record_target(keys.node_id(), node_id);
         self.document
             .require_hole()
-            .map_err(|e| DocumentConstructionError::DocumentInsert {
-                error: e,
-                node_id: handle.node_id(),
-                parent_node_id: None,
-            })?;
+            .map_err(|e| self.binding_target_error(e, node_id, handle.node_id()))?;
 
         self.record_value(node_id, handle.node_id());
 
@@ -17,7 +17,7 @@ use eure_document::source::{
     BindSource, BindingSource, Comment, EureSource, SectionBody, SourceDocument, SourceKey,
     SourcePath, StringStyle, Trivia,
 };
-use eure_document::text::{Text, TextParseError};
+use eure_document::text::{Text, TextParseError, escape};
 use eure_document::value::{ObjectKey, Tuple};
 use eure_fmt::format_source_document;
 
@@ -262,7 +262,7 @@ impl Display for EditPath {
                 EditKey::Ident(ident) => write!(f, "{ident}")?,
                 EditKey::Extension(ident) => write!(f, "${ident}")?,
                 EditKey::String(value, style) => match style {
-                    StringStyle::Quoted => write!(f, "\"{}\"", escape_quoted(value))?,
+                    StringStyle::Quoted => write!(f, "\"{}\"", escape(value))?,
                     StringStyle::Literal => write!(f, "'{value}'")?,
                     StringStyle::DelimitedLitStr(level) => {
                         let open = "<".repeat(*level as usize);
@@ -289,20 +289,6 @@ impl Display for EditPath {
     }
 }
 
-fn escape_quoted(input: &str) -> String {
-    input
-        .chars()
-        .flat_map(|ch| match ch {
-            '\\' => ['\\', '\\'].into_iter().collect::<Vec<_>>(),
-            '"' => ['\\', '"'].into_iter().collect::<Vec<_>>(),
-            '\n' => ['\\', 'n'].into_iter().collect::<Vec<_>>(),
-            '\r' => ['\\', 'r'].into_iter().collect::<Vec<_>>(),
-            '\t' => ['\\', 't'].into_iter().collect::<Vec<_>>(),
-            other => [other].into_iter().collect::<Vec<_>>(),
-        })
-        .collect()
-}
-
 #[derive(Debug, Clone)]
 pub enum EditValue {
     Document(EureDocument),
@@ -0,0 +1,124 @@
+//! Opt-in environment variable interpolation for parsed documents.
+//!
+//! Parsing never expands variables. Call [`interpolate`] on the resulting
+//! document to replace `${VAR}` and `${VAR:-default}` in text values.
+
+use thiserror::Error;
+
+use crate::document::{EureDocument, NodeId, NodeValue};
+use crate::value::PrimitiveValue;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum InterpolateError {
+    #[error("undefined variable `{name}`")]
+    UndefinedVariable { name: String, node_id: NodeId },
+    #[error("unterminated `${{` in text")]
+    Unterminated { node_id: NodeId },
+}
+
+/// Expand `${VAR}` occurrences in every text value of `doc`.
+///
+/// Each variable is resolved with `lookup`; `${VAR:-default}` falls back to
+/// `default` when the lookup returns `None`. Substituted text is not expanded
+/// again. To read the process environment, pass
+/// `&|name| std::env::var(name).ok()`.
+pub fn interpolate(
+    doc: &mut EureDocument,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<(), InterpolateError> {
+    for index in 0..doc.node_count() {
+        let node_id = NodeId(index);
+        let NodeValue::Primitive(PrimitiveValue::Text(text)) = &mut doc.node_mut(node_id).content
+        else {
+            continue;
+        };
+        if !text.content.contains("${") {
+            continue;
+        }
+        text.content = expand(&text.content, node_id, lookup)?;
+    }
+    Ok(())
+}
+
+fn expand(
+    input: &str,
+    node_id: NodeId,
+    lookup: &dyn Fn(&str) -> Option<String>,
+) -> Result<String, InterpolateError> {
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find("${") {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after
+            .find('}')
+            .ok_or(InterpolateError::Unterminated { node_id })?;
+        let (name, default) = match after[..end].split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (&after[..end], None),
+        };
+        match lookup(name) {
+            Some(value) => output.push_str(&value),
+            None => match default {
+                Some(default) => output.push_str(default),
+                None => {
+                    return Err(InterpolateError::UndefinedVariable {
+                        name: name.to_string(),
+                        node_id,
+                    });
+                }
+            },
+        }
+        rest = &after[end + 1..];
+    }
+    output.push_str(rest);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eure;
+
+    fn lookup(name: &str) -> Option<String> {
+        (name == "API_TOKEN").then(|| "secret".to_string())
+    }
+
+    #[test]
+    fn test_defined_variable() {
+        let mut doc = eure!({ token = "Bearer ${API_TOKEN}" });
+        interpolate(&mut doc, &lookup).unwrap();
+        assert_eq!(doc, eure!({ token = "Bearer secret" }));
+    }
+
+    #[test]
+    fn test_default_fallback() {
+        let mut doc = eure!({
+            token = "${API_TOKEN:-none}",
+            region = "${REGION:-us-east-1}",
+        });
+        interpolate(&mut doc, &lookup).unwrap();
+        assert_eq!(
+            doc,
+            eure!({
+                token = "secret",
+                region = "us-east-1",
+            })
+        );
+    }
+
+    #[test]
+    fn test_undefined_variable() {
+        let mut doc = eure!({ region = "${REGION}" });
+        let region = doc
+            .paths()
+            .find_map(|(id, path)| (path.to_string() == "region").then_some(id));
+        assert_eq!(
+            interpolate(&mut doc, &lookup),
+            Err(InterpolateError::UndefinedVariable {
+                name: "REGION".to_string(),
+                node_id: region.unwrap(),
+            })
+        );
+    }
+}
@@ -1,7 +1,13 @@
+#[cfg(feature = "native")]
+pub mod check;
+pub mod codes;
 pub mod document;
 pub mod edit;
+pub mod env;
 pub mod query;
 pub mod report;
+#[cfg(feature = "native")]
+pub mod schema_resolver;
 pub mod tree;
 pub mod value;
 
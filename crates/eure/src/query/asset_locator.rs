@@ -13,7 +13,7 @@ use super::assets::{TextFile, TextFileContent, WorkspaceId};
 use super::error::EureQueryError;
 
 /// The default allowed host (always trusted).
-const DEFAULT_ALLOWED_HOST: &str = "eure.dev";
+pub(crate) const DEFAULT_ALLOWED_HOST: &str = "eure.dev";
 
 /// Asset locator that validates URL hosts before allowing fetches.
 ///
@@ -82,7 +82,7 @@ fn validate_url_host(db: &impl Db, url: &Url) -> Result<(), QueryError> {
 /// Supports:
 /// - Exact match: "example.com" matches "example.com"
 /// - Wildcard subdomain: "*.example.com" matches "sub.example.com" and "example.com"
-fn host_matches(host: &str, pattern: &str) -> bool {
+pub(crate) fn host_matches(host: &str, pattern: &str) -> bool {
     if let Some(suffix) = pattern.strip_prefix("*.") {
         // Wildcard pattern: *.example.com matches sub.example.com and example.com
         host == suffix || host.ends_with(&format!(".{}", suffix))
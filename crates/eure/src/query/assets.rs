@@ -9,6 +9,7 @@
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use eure_document::document::schema_ref::is_remote_location;
 use query_flow::asset_key;
 use url::Url;
 
@@ -36,7 +37,7 @@ impl TextFile {
 
     /// Parse a string as either a URL (if starts with https://) or a local path.
     pub fn parse(s: &str) -> Result<Self, EureQueryError> {
-        if s.starts_with("https://") {
+        if is_remote_location(s) {
             Url::parse(s)
                 .map(Self::from_url)
                 .map_err(|e| EureQueryError::InvalidUrl {
@@ -53,7 +54,7 @@ impl TextFile {
     /// - If `target` starts with "https://", returns a `TextFile::Remote`
     /// - Otherwise, joins `target` with `base_dir` and returns a `TextFile::Local`
     pub fn resolve(target: &str, base_dir: &Path) -> Result<Self, EureQueryError> {
-        if target.starts_with("https://") {
+        if is_remote_location(target) {
             Self::parse(target)
         } else {
             Ok(Self::from_path(base_dir.join(target)))
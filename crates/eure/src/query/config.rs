@@ -3,14 +3,23 @@
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use eure_env::EureConfig;
+use eure_env::{ConfigWarning, EureConfig};
 use query_flow::{Db, QueryError, query};
 
-use crate::document::cst_to_document_and_origin_map;
+use crate::document::path::EurePath;
+use crate::document::schema_ref::is_remote_location;
+use crate::document::{
+    DocumentConstructionError, EureDocument, OriginMap, cst_to_document_and_origin_map,
+    map_key_segment,
+};
+use crate::report::{ErrorReport, ErrorReports, Origin};
+use crate::tree::{Cst, InputSpan};
+use crate::value::ObjectKey;
 
 use super::assets::{TextFile, WorkspaceId};
 use super::error::FileError;
-use super::parse::ParseDocument;
+use super::parse::{ParseCst, ParseDocument};
+use super::report::WithErrorReports;
 
 /// Resolved configuration with its directory.
 #[derive(Clone, PartialEq)]
@@ -85,6 +94,113 @@ pub fn workspace_config(
     })
 }
 
+/// Whether `file` is the config file of a registered workspace.
+pub(crate) fn is_workspace_config(db: &impl Db, file: &TextFile) -> Result<bool, QueryError> {
+    let Some(path) = file.as_local_path() else {
+        return Ok(false);
+    };
+    for workspace_id in db.list_asset_keys::<WorkspaceId>() {
+        if db.asset(workspace_id)?.config_path == path {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Query the problems of a config file as error reports.
+///
+/// Reports the errors of [`ParseConfig`] and, once the config parses, a
+/// warning for each glob that does not compile and each local target schema
+/// file that does not exist. Warnings point at the offending `globs` or
+/// `schema` value.
+#[query(debug = "{Self}({config_file})")]
+pub fn get_config_error_reports(
+    db: &impl Db,
+    config_file: TextFile,
+) -> Result<ErrorReports, QueryError> {
+    let config = match db.query(WithErrorReports::new(ParseConfig::new(config_file.clone()))) {
+        Ok(config) => config,
+        Err(QueryError::UserError(e)) => {
+            return match e.downcast_ref::<ErrorReports>() {
+                Some(reports) => Ok(reports.clone()),
+                None => Err(QueryError::UserError(e)),
+            };
+        }
+        Err(e) => return Err(e),
+    };
+    let parsed = db.query(ParseDocument::new(config_file.clone()))?;
+    let cst = db.query(ParseCst::new(config_file.clone()))?;
+    let origin = |target: &str, field: &str| {
+        target_field_span(&parsed.doc, &parsed.origins, &cst.cst, target, field)
+            .map(|span| Origin::new(config_file.clone(), span))
+            .unwrap_or_else(|| Origin::file_start(config_file.clone()))
+    };
+
+    let mut reports = ErrorReports::new();
+    for warning in config.glob_warnings() {
+        reports.push(ErrorReport::warning(
+            warning.to_string(),
+            origin(warning.target(), "globs"),
+        ));
+    }
+
+    let config_dir = config_file
+        .as_local_path()
+        .and_then(Path::parent)
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    let mut names: Vec<&String> = config.targets.keys().collect();
+    names.sort();
+    for name in names {
+        let Some(schema) = &config.targets[name].schema else {
+            continue;
+        };
+        if is_remote_location(schema) {
+            continue;
+        }
+        let path = config_dir.join(schema);
+        match db.asset(TextFile::from_path(path.clone())) {
+            Ok(_) => {}
+            Err(QueryError::UserError(_)) => {
+                let warning = ConfigWarning::MissingSchema {
+                    target: name.clone(),
+                    path,
+                };
+                reports.push(ErrorReport::warning(
+                    warning.to_string(),
+                    origin(name, "schema"),
+                ));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(reports)
+}
+
+/// Span of `targets.<target>.<field>` in a config document, falling back to
+/// the target's key when the field is absent.
+fn target_field_span(
+    doc: &EureDocument,
+    origins: &OriginMap,
+    cst: &Cst,
+    target: &str,
+    field: &str,
+) -> Option<InputSpan> {
+    let node_at = |path: &EurePath| {
+        doc.paths()
+            .find_map(|(node_id, node_path)| (node_path == *path).then_some(node_id))
+    };
+    let target_key = ObjectKey::String(target.to_string());
+    let targets_path = EurePath(vec![map_key_segment(&"targets".into())]);
+    let mut field_path = targets_path.clone();
+    field_path.0.push(map_key_segment(&target_key));
+    field_path.0.push(map_key_segment(&field.into()));
+
+    node_at(&field_path)
+        .and_then(|field_id| origins.get_value_span(field_id, cst))
+        .or_else(|| origins.get_key_span(node_at(&targets_path)?, &target_key, cst))
+}
+
 // ============================================================================
 // Synchronous API for CLI usage
 // ============================================================================
@@ -129,3 +245,127 @@ pub fn load_config(path: &Path) -> Result<EureConfig, LoadConfigError> {
     doc.parse::<EureConfig>(root_id)
         .map_err(|e| LoadConfigError::Config(e.to_string()))
 }
+
+/// A problem found by [`validate_config`].
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ConfigValidationWarning {
+    #[error(transparent)]
+    Config(#[from] ConfigWarning),
+
+    #[error("target `{target}`: schema file `{}` is not valid: {error}", .path.display())]
+    SchemaDocument {
+        target: String,
+        path: PathBuf,
+        error: DocumentConstructionError,
+    },
+
+    #[error("target `{target}`: schema file `{}` is not a valid schema: {error}", .path.display())]
+    SchemaConversion {
+        target: String,
+        path: PathBuf,
+        error: eure_schema::convert::ConversionError,
+    },
+}
+
+impl ConfigValidationWarning {
+    /// Name of the target this warning belongs to.
+    pub fn target(&self) -> &str {
+        match self {
+            ConfigValidationWarning::Config(warning) => warning.target(),
+            ConfigValidationWarning::SchemaDocument { target, .. }
+            | ConfigValidationWarning::SchemaConversion { target, .. } => target,
+        }
+    }
+}
+
+/// Validate a loaded config against the filesystem.
+///
+/// Extends [`EureConfig::validate`], which only checks schema syntax, by also
+/// converting each local target schema to a `SchemaDocument`.
+#[cfg(feature = "native")]
+pub fn validate_config(config: &EureConfig, config_dir: &Path) -> Vec<ConfigValidationWarning> {
+    let config_warnings = config.validate(config_dir);
+    let reported: Vec<&Path> = config_warnings
+        .iter()
+        .filter_map(|w| match w {
+            ConfigWarning::MissingSchema { path, .. }
+            | ConfigWarning::UnreadableSchema { path, .. }
+            | ConfigWarning::InvalidSchema { path, .. } => Some(path.as_path()),
+            ConfigWarning::InvalidGlob { .. } => None,
+        })
+        .collect();
+
+    let mut warnings = Vec::new();
+    for (name, target) in &config.targets {
+        let Some(schema) = &target.schema else {
+            continue;
+        };
+        if is_remote_location(schema) {
+            continue;
+        }
+        let path = config_dir.join(schema);
+        if reported.contains(&path.as_path()) {
+            continue;
+        }
+        if let Some(warning) = convert_target_schema(name, path) {
+            warnings.push(warning);
+        }
+    }
+    warnings.extend(
+        config_warnings
+            .into_iter()
+            .map(ConfigValidationWarning::from),
+    );
+
+    warnings.sort_by(|a, b| a.target().cmp(b.target()));
+    warnings
+}
+
+/// Convert the schema of target `name` at `path`, which [`EureConfig::validate`]
+/// has already read and parsed without problems.
+#[cfg(feature = "native")]
+fn convert_target_schema(name: &str, path: PathBuf) -> Option<ConfigValidationWarning> {
+    let source = match std::fs::read_to_string(&path) {
+        Ok(source) => source,
+        Err(e) => {
+            return Some(
+                ConfigWarning::UnreadableSchema {
+                    target: name.to_string(),
+                    path,
+                    kind: e.kind(),
+                }
+                .into(),
+            );
+        }
+    };
+    let cst = match eure_parol::parse(&source, &path) {
+        Ok(cst) => cst,
+        Err(error) => {
+            return Some(
+                ConfigWarning::InvalidSchema {
+                    target: name.to_string(),
+                    path,
+                    error,
+                }
+                .into(),
+            );
+        }
+    };
+    let doc = match crate::document::cst_to_document(&source, &cst) {
+        Ok(doc) => doc,
+        Err(error) => {
+            return Some(ConfigValidationWarning::SchemaDocument {
+                target: name.to_string(),
+                path,
+                error,
+            });
+        }
+    };
+    eure_schema::convert::document_to_schema(&doc)
+        .err()
+        .map(|error| ConfigValidationWarning::SchemaConversion {
+            target: name.to_string(),
+            path,
+            error,
+        })
+}
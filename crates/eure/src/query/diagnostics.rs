@@ -4,21 +4,28 @@
 //! - `get_file_diagnostics`: All diagnostics for a file (thin wrapper around GetFileErrorReports)
 //! - `collect_diagnostic_targets`: All files needing diagnostics
 //! - `collect_schema_files`: Local schema files referenced by open documents
+//! - `collect_schema_dependents`: Documents validated against a given schema file
 //! - `get_all_diagnostics`: All diagnostics for all targets (CLI use)
+//!
+//! It also defines `JsonDiagnostic`, the machine-readable form used by
+//! `eure check --format json`.
 
 use indexmap::{IndexMap, IndexSet};
+use serde::Serialize;
 use std::sync::Arc;
 
 use query_flow::{Db, QueryError, QueryResultExt, query};
 
-use crate::report::{ErrorReport, Severity};
+use crate::report::{DEPRECATED_CODE, ErrorReport, Severity};
 
-use super::assets::{OpenDocuments, OpenDocumentsList, TextFile};
+use super::assets::{Glob, OpenDocuments, OpenDocumentsList, TextFile, WorkspaceId};
+use super::config::WorkspaceConfig;
 use super::report::GetFileErrorReports;
 use super::schema::ResolveSchema;
 
 /// Severity level for diagnostics.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum DiagnosticSeverity {
     Error,
     Warning,
@@ -26,6 +33,13 @@ pub enum DiagnosticSeverity {
     Hint,
 }
 
+/// Extra classification of a diagnostic, for editors that render it specially.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticTag {
+    /// The diagnostic points at a use of something deprecated.
+    Deprecated,
+}
+
 /// A diagnostic message with source location.
 #[derive(Debug, Clone, PartialEq)]
 pub struct DiagnosticMessage {
@@ -39,6 +53,101 @@ pub struct DiagnosticMessage {
     pub message: String,
     /// Severity of the diagnostic.
     pub severity: DiagnosticSeverity,
+    /// Optional error code (e.g., "schema:type-mismatch").
+    pub code: Option<String>,
+    /// Tags derived from the error code.
+    pub tags: Vec<DiagnosticTag>,
+}
+
+impl DiagnosticMessage {
+    /// Convert to a [`JsonDiagnostic`], resolving byte offsets against `source`.
+    pub fn to_json_diagnostic(&self, source: &str) -> JsonDiagnostic {
+        JsonDiagnostic {
+            file: self.file.to_string(),
+            range: DiagnosticRange {
+                start: DiagnosticPosition::from_offset(source, self.start),
+                end: DiagnosticPosition::from_offset(source, self.end),
+            },
+            severity: self.severity,
+            code: self.code.clone(),
+            message: self.message.clone(),
+        }
+    }
+}
+
+impl From<&ErrorReport> for DiagnosticMessage {
+    fn from(report: &ErrorReport) -> Self {
+        DiagnosticMessage {
+            file: report.primary_origin.file.clone(),
+            start: report.primary_origin.span.start as usize,
+            end: report.primary_origin.span.end as usize,
+            message: report.title.to_string(),
+            severity: match report.severity {
+                Severity::Error => DiagnosticSeverity::Error,
+                Severity::Warning => DiagnosticSeverity::Warning,
+                Severity::Note => DiagnosticSeverity::Info,
+                Severity::Hint => DiagnosticSeverity::Hint,
+            },
+            code: report.code.as_ref().map(|code| code.to_string()),
+            tags: match report.code.as_deref() {
+                Some(DEPRECATED_CODE) => vec![DiagnosticTag::Deprecated],
+                _ => vec![],
+            },
+        }
+    }
+}
+
+// =============================================================================
+// Serializable Diagnostics
+// =============================================================================
+
+/// A serializable diagnostic for CI tooling.
+///
+/// Positions are 1-based lines and columns, with columns counted in Unicode
+/// scalar values, which is what most annotation formats expect.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct JsonDiagnostic {
+    /// Display path or URL of the file.
+    pub file: String,
+    /// Source range of the diagnostic.
+    pub range: DiagnosticRange,
+    /// Severity of the diagnostic.
+    pub severity: DiagnosticSeverity,
+    /// Optional error code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    /// The diagnostic message.
+    pub message: String,
+}
+
+/// A start/end pair of [`DiagnosticPosition`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct DiagnosticRange {
+    pub start: DiagnosticPosition,
+    pub end: DiagnosticPosition,
+}
+
+/// A 1-based line and column in a source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct DiagnosticPosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl DiagnosticPosition {
+    /// Resolve a byte offset in `source`, clamping it to the source length.
+    pub fn from_offset(source: &str, offset: usize) -> Self {
+        let mut offset = offset.min(source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        DiagnosticPosition {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
 }
 
 // =============================================================================
@@ -54,14 +163,15 @@ pub fn get_file_diagnostics(
     file: TextFile,
 ) -> Result<Vec<DiagnosticMessage>, QueryError> {
     let reports = db.query(GetFileErrorReports::new(file))?;
-    Ok(reports.iter().map(error_report_to_diagnostic).collect())
+    Ok(reports.iter().map(DiagnosticMessage::from).collect())
 }
 
 // =============================================================================
 // Collection Queries
 // =============================================================================
 
-/// Collect all diagnostic targets: open documents + referenced schema files.
+/// Collect all diagnostic targets: open documents, referenced schema files and
+/// workspace config files.
 ///
 /// This is the primary query for determining which files need diagnostics.
 /// LSP and CLI should poll this to discover all relevant files.
@@ -77,7 +187,18 @@ pub fn collect_diagnostic_targets(db: &impl Db) -> Result<IndexSet<TextFile>, Qu
     let schema_files = db.query(CollectSchemaFiles::new())?;
     targets.extend(schema_files.iter().cloned());
 
-    // TODO: Files from workspace config
+    // 3. Workspace config files that exist
+    for workspace_id in db.list_asset_keys::<WorkspaceId>() {
+        let workspace = db.asset(workspace_id)?;
+        let config_file = TextFile::from_path(workspace.config_path.clone());
+        match db.asset(config_file.clone()) {
+            Ok(_) => {
+                targets.insert(config_file);
+            }
+            Err(QueryError::UserError(_)) => {}
+            Err(e) => return Err(e),
+        }
+    }
 
     Ok(targets)
 }
@@ -128,6 +249,65 @@ pub fn collect_schema_files(db: &impl Db) -> Result<IndexSet<TextFile>, QueryErr
     Ok(schemas)
 }
 
+/// Collect the documents that are validated against `schema_file`.
+///
+/// Dependents are the open documents whose resolved schema is `schema_file`,
+/// and every file matched by the globs of a workspace config target whose
+/// `schema` is `schema_file`. Matched files need not be open, so this is how
+/// on-disk documents are found when their schema changes.
+#[query(debug = "{Self}({schema_file})")]
+pub fn collect_schema_dependents(
+    db: &impl Db,
+    schema_file: TextFile,
+) -> Result<IndexSet<TextFile>, QueryError> {
+    let mut dependents = IndexSet::new();
+
+    let open_docs: Arc<OpenDocumentsList> = db.asset(OpenDocuments)?;
+    for file in open_docs.0.iter().filter(|file| **file != schema_file) {
+        let resolved = match db.query(ResolveSchema::new(file.clone())) {
+            Ok(r) => r,
+            Err(QueryError::UserError(_)) => continue,
+            Err(e) => return Err(e),
+        };
+        if resolved
+            .as_ref()
+            .as_ref()
+            .is_some_and(|resolved| resolved.file == schema_file)
+        {
+            dependents.insert(file.clone());
+        }
+    }
+
+    for workspace_id in db.list_asset_keys::<WorkspaceId>() {
+        let resolved = match db.query(WorkspaceConfig::new(workspace_id)) {
+            Ok(resolved) => resolved,
+            // Missing or broken config: no targets to follow
+            Err(QueryError::UserError(_)) => continue,
+            Err(e) => return Err(e),
+        };
+        let globs = resolved
+            .config
+            .targets
+            .values()
+            .filter(|target| {
+                target.schema.as_ref().is_some_and(|schema| {
+                    TextFile::resolve(schema, &resolved.config_dir)
+                        .is_ok_and(|file| file == schema_file)
+                })
+            })
+            .flat_map(|target| target.globs.iter())
+            .map(|pattern| db.asset(Glob::new(resolved.config_dir.clone(), pattern.clone())))
+            // Register all Glob as pending assets before suspending
+            .collect::<Vec<_>>();
+        for matched in globs {
+            dependents.extend(matched?.0.iter().cloned());
+        }
+    }
+
+    dependents.shift_remove(&schema_file);
+    Ok(dependents)
+}
+
 // =============================================================================
 // Global Query (CLI use)
 // =============================================================================
@@ -151,26 +331,6 @@ pub fn get_all_diagnostics(
     Ok(result)
 }
 
-// =============================================================================
-// Helper Functions
-// =============================================================================
-
-/// Convert an error report to a diagnostic message.
-fn error_report_to_diagnostic(report: &ErrorReport) -> DiagnosticMessage {
-    DiagnosticMessage {
-        file: report.primary_origin.file.clone(),
-        start: report.primary_origin.span.start as usize,
-        end: report.primary_origin.span.end as usize,
-        message: report.title.to_string(),
-        severity: match report.severity {
-            Severity::Error => DiagnosticSeverity::Error,
-            Severity::Warning => DiagnosticSeverity::Warning,
-            Severity::Note => DiagnosticSeverity::Info,
-            Severity::Hint => DiagnosticSeverity::Hint,
-        },
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,4 +340,71 @@ mod tests {
         assert_eq!(DiagnosticSeverity::Error, DiagnosticSeverity::Error);
         assert_ne!(DiagnosticSeverity::Error, DiagnosticSeverity::Warning);
     }
+
+    #[test]
+    fn test_diagnostic_position_from_offset() {
+        let source = "a = 1\nあ = 2\n";
+        assert_eq!(
+            DiagnosticPosition::from_offset(source, 0),
+            DiagnosticPosition { line: 1, column: 1 }
+        );
+        // "あ" is three bytes but a single column
+        assert_eq!(
+            DiagnosticPosition::from_offset(source, 9),
+            DiagnosticPosition { line: 2, column: 2 }
+        );
+        assert_eq!(
+            DiagnosticPosition::from_offset(source, 100),
+            DiagnosticPosition { line: 3, column: 1 }
+        );
+    }
+
+    #[test]
+    fn test_format_error_reports_json() {
+        use crate::query::{TextFileContent, build_runtime};
+        use crate::report::{ErrorReport, ErrorReports, Origin, format_error_reports_json};
+        use eure_tree::tree::InputSpan;
+        use query_flow::DurabilityLevel;
+        use std::path::PathBuf;
+
+        let runtime = build_runtime();
+        let file = TextFile::from_path(PathBuf::from("test.eure"));
+        runtime.resolve_asset(
+            file.clone(),
+            TextFileContent("name = \"Alice\"\nage = \"ten\"\n".to_string()),
+            DurabilityLevel::Volatile,
+        );
+
+        let mut reports = ErrorReports::default();
+        reports.push(
+            ErrorReport::error(
+                "Type mismatch: expected integer, but got text",
+                Origin::new(file, InputSpan { start: 21, end: 26 }),
+            )
+            .with_code("schema:type-mismatch"),
+        );
+
+        let json = format_error_reports_json(&runtime, &reports).unwrap();
+        assert_eq!(
+            json,
+            r#"[
+  {
+    "file": "test.eure",
+    "range": {
+      "start": {
+        "line": 2,
+        "column": 7
+      },
+      "end": {
+        "line": 2,
+        "column": 12
+      }
+    },
+    "severity": "error",
+    "code": "schema:type-mismatch",
+    "message": "Type mismatch: expected integer, but got text"
+  }
+]"#
+        );
+    }
 }
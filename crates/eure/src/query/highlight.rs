@@ -0,0 +1,104 @@
+//! Key path highlighting for Eure documents.
+//!
+//! Finds every occurrence of the key path under a cursor, e.g. repeated
+//! `items[] = ...` array pushes or section headers that address the same path.
+
+use crate::document::PathIndex;
+use crate::document::path::{ArrayIndexKind, EurePath, PathSegment};
+use crate::tree::*;
+use query_flow::{Db, QueryError, query};
+
+use super::assets::TextFile;
+use super::parse::ParseCst;
+
+/// Find the spans of all `Keys` nodes that resolve to the same path as the
+/// `Keys` node at `offset`.
+///
+/// Paths are resolved through [`PathIndex`], so keys written differently
+/// (e.g. `a.b` and `b` inside `@ a`) still match. Array indices all compare
+/// equal, so pushes to the same array match.
+///
+/// Returns an empty vector if `offset` is not on a key.
+pub fn key_path_highlights(input: &str, cst: &Cst, offset: u32) -> Vec<InputSpan> {
+    let all_keys =
+        cst.descendants_of_kind(cst.root(), NodeKind::NonTerminal(NonTerminalKind::Keys));
+    let Some(target) = all_keys.iter().copied().find(|&keys| {
+        cst.span(keys)
+            .is_some_and(|span| span.start <= offset && offset <= span.end)
+    }) else {
+        return vec![];
+    };
+
+    let index = PathIndex::from_cst(input, cst);
+    let key_path = |keys| index.path_of(cst, keys).map(normalize_array_indices);
+    let Some(target_path) = key_path(target) else {
+        return vec![];
+    };
+    all_keys
+        .into_iter()
+        .filter(|&keys| key_path(keys).as_ref() == Some(&target_path))
+        .filter_map(|keys| cst.span(keys))
+        .collect()
+}
+
+/// Query to get key path highlights for the cursor at byte `offset`.
+///
+/// Uses tolerant parsing so highlights work while the document has errors.
+#[query(debug = "{Self}({file}, {offset})")]
+pub fn get_key_path_highlights(
+    db: &impl Db,
+    file: TextFile,
+    offset: u32,
+) -> Result<Vec<InputSpan>, QueryError> {
+    let parsed_cst = db.query(ParseCst::new(file.clone()))?;
+    let source = db.asset(file.clone())?;
+
+    Ok(key_path_highlights(source.get(), &parsed_cst.cst, offset))
+}
+
+/// Replace every array index with a push so that elements of one array compare equal.
+fn normalize_array_indices(path: &EurePath) -> EurePath {
+    EurePath::from_iter(path.0.iter().map(|segment| match segment {
+        PathSegment::ArrayIndex(_) => PathSegment::ArrayIndex(ArrayIndexKind::Push),
+        segment => segment.clone(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn highlights<'a>(input: &'a str, cursor: &str) -> Vec<&'a str> {
+        let cst = crate::parol::parse(input, "<input>").expect("Failed to parse");
+        let offset = input.find(cursor).expect("cursor not found") as u32;
+        key_path_highlights(input, &cst, offset)
+            .into_iter()
+            .map(|span| span.as_str(input))
+            .collect()
+    }
+
+    #[test]
+    fn test_array_pushes_to_same_key() {
+        let input = "items[] = 1\nother = 2\nitems[] = 3\n";
+        let spans = highlights(input, "items");
+        assert_eq!(spans, vec!["items[]", "items[]"]);
+    }
+
+    #[test]
+    fn test_section_prefix() {
+        let input = "@ servers[]\nport = 1\n@ clients[]\nport = 2\n@ servers[]\nport = 3\n";
+        let cst = crate::parol::parse(input, "<input>").expect("Failed to parse");
+        let offset = input.find("port").unwrap() as u32;
+        let spans = key_path_highlights(input, &cst, offset);
+        let starts: Vec<_> = spans.iter().map(|span| span.start as usize).collect();
+        let first = input.find("port").unwrap();
+        let third = input.rfind("port").unwrap();
+        assert_eq!(starts, vec![first, third]);
+    }
+
+    #[test]
+    fn test_not_on_key() {
+        let input = "a = 1\n";
+        assert!(highlights(input, "1").is_empty());
+    }
+}
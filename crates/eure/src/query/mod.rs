@@ -9,12 +9,17 @@ pub mod assets;
 pub mod config;
 pub mod diagnostics;
 pub mod error;
+pub mod highlight;
 #[cfg(feature = "http")]
 pub mod http;
 pub mod parse;
+pub mod quick_fix;
 pub mod report;
 pub mod schema;
+pub mod selection_range;
 pub mod semantic_token;
+pub mod symbol;
+pub mod type_occurrence;
 pub mod validation;
 
 pub use asset_locator::TextFileLocator;
@@ -42,27 +47,41 @@ pub fn build_runtime() -> QueryRuntime {
     runtime.register_asset_locator(TextFileLocator);
     runtime
 }
-pub use config::{LoadConfigError, ParseConfig, ResolveConfig, ResolvedConfig, load_config};
+#[cfg(feature = "native")]
+pub use config::validate_config;
+pub use config::{
+    ConfigValidationWarning, GetConfigErrorReports, LoadConfigError, ParseConfig, ResolveConfig,
+    ResolvedConfig, load_config,
+};
 pub use diagnostics::{
-    CollectDiagnosticTargets, CollectSchemaFiles, DiagnosticMessage, DiagnosticSeverity,
-    GetAllDiagnostics, GetFileDiagnostics,
+    CollectDiagnosticTargets, CollectSchemaDependents, CollectSchemaFiles, DiagnosticMessage,
+    DiagnosticPosition, DiagnosticRange, DiagnosticSeverity, DiagnosticTag, GetAllDiagnostics,
+    GetFileDiagnostics, JsonDiagnostic,
 };
+pub use highlight::{GetKeyPathHighlights, key_path_highlights};
 #[cfg(feature = "http")]
 pub use http::fetch_url;
 #[cfg(feature = "native")]
 pub use http::{CacheOptions, base_cache_dir, fetch_url_cached, https_cache_dir, parse_duration};
 pub use parse::{ParseCst, ParseDocument, ParseEure, ParsedCst, ParsedDocument, ValidCst};
+pub use quick_fix::{GetMissingFieldFixes, MissingFieldFix};
 pub use report::{GetFileErrorReports, WithErrorReports, WithFormattedError};
 #[cfg(feature = "http")]
 pub use reqwest;
 pub use schema::{
-    DocumentToSchemaQuery, GetSchemaExtension, GetSchemaExtensionDiagnostics,
-    GetValidationErrorsFormatted, GetValidationErrorsFormattedExplicit, ResolveSchema,
-    ValidateAgainstExplicitSchema, ValidateAgainstSchema, ValidatedSchema,
+    CheckSchemaSelf, DocumentToSchemaQuery, GetSchemaExtension, GetSchemaExtensionDiagnostics,
+    GetValidationErrorsFormatted, GetValidationErrorsFormattedExplicit, LintAgainstSchema,
+    ResolveSchema, ValidateAgainstExplicitSchema, ValidateAgainstSchema, ValidateWithSchema,
+    ValidatedSchema,
 };
+pub use selection_range::{GetSelectionSpans, selection_spans};
 pub use semantic_token::{
     GetSemanticTokens, SemanticToken, SemanticTokenModifier, SemanticTokenType, semantic_tokens,
 };
+pub use symbol::{GetNamedSymbols, NamedSymbol, NamedSymbolKind, named_symbols};
+pub use type_occurrence::{
+    GetTypeOccurrences, TypeOccurrence, TypeOccurrenceKind, type_occurrences,
+};
 pub use validation::{
     TargetValidationResult, TargetsValidationResult, ValidateDocument, ValidateTarget,
     ValidateTargetResult, ValidateTargets, ValidateTargetsResult,
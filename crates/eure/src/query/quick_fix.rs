@@ -0,0 +1,244 @@
+//! Quick fixes derived from schema validation errors.
+//!
+//! Currently offers one fix: inserting required record fields reported by
+//! `MissingRequiredField`, using the schema's default value when it renders
+//! on a single line and a hole (`!`) otherwise.
+
+use eure_document::document::EureDocument;
+use eure_document::identifier::Identifier;
+use eure_document::plan::LayoutPlan;
+use eure_fmt::format_source_document;
+use eure_schema::validate::ValidationError;
+use eure_schema::{SchemaDocument, SchemaNodeContent, SchemaNodeId};
+use eure_tree::tree::InputSpan;
+use query_flow::{Db, QueryError, query};
+
+use super::assets::TextFile;
+use super::parse::{ParseDocument, ValidCst};
+use super::schema::{DocumentToSchemaQuery, ResolveSchema, ValidateWithSchema};
+
+/// An edit inserting the required fields missing from a record.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissingFieldFix {
+    /// Span of the record the fields are missing from.
+    pub span: InputSpan,
+    /// Byte offset at which `text` is inserted.
+    pub insert_at: u32,
+    /// Names of the missing fields, in schema order.
+    pub fields: Vec<String>,
+    /// Source text to insert, one `field = value` binding per line.
+    pub text: String,
+}
+
+/// Query the fixes for all `MissingRequiredField` errors in a document.
+///
+/// Returns an empty vector if the document has no schema, or if the document
+/// or schema fails to parse (those errors are reported by diagnostics).
+#[query(debug = "{Self}({file})")]
+pub fn get_missing_field_fixes(
+    db: &impl Db,
+    file: TextFile,
+) -> Result<Vec<MissingFieldFix>, QueryError> {
+    let Some(resolved) = db.query(ResolveSchema::new(file.clone()))?.as_ref().clone() else {
+        return Ok(vec![]);
+    };
+    let schema_file = resolved.file;
+    let parsed = match db.query(ParseDocument::new(file.clone())) {
+        Ok(parsed) => parsed,
+        Err(QueryError::UserError(_)) => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    let schema = match db.query(DocumentToSchemaQuery::new(schema_file.clone())) {
+        Ok(schema) => schema,
+        Err(QueryError::UserError(_)) => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    let cst = db.query(ValidCst::new(file.clone()))?;
+    let source = db.asset(file.clone())?;
+
+    let result = db.query(ValidateWithSchema::new(file, schema_file))?;
+    let mut fixes = Vec::new();
+    for error in &result.errors {
+        if let ValidationError::MissingRequiredField {
+            fields,
+            node_id,
+            schema_node_id,
+            ..
+        } = error
+            && let Some(span) = parsed.origins.get_value_span(*node_id, &cst)
+        {
+            fixes.push(missing_field_fix(
+                source.get(),
+                span,
+                *node_id == parsed.doc.get_root_id(),
+                fields,
+                &schema.schema,
+                *schema_node_id,
+            ));
+        }
+    }
+    Ok(fixes)
+}
+
+fn missing_field_fix(
+    source: &str,
+    span: InputSpan,
+    is_root: bool,
+    fields: &[String],
+    schema: &SchemaDocument,
+    record: SchemaNodeId,
+) -> MissingFieldFix {
+    let record_text = span.as_str(source);
+    // Insert before the closing brace of an inline object (`{ a = 1 }`) or a
+    // block binding (`key { ... }`), and after the last binding of the root
+    // or a section otherwise.
+    let (insert_at, inline) = match record_text.strip_suffix('}') {
+        Some(body) if !is_root => (
+            span.start as usize + body.trim_end().len(),
+            record_text.starts_with('{'),
+        ),
+        _ => (span.start as usize + record_text.trim_end().len(), false),
+    };
+    // A binding on its own line takes the indentation of the line it follows,
+    // one level deeper when that line opens the block.
+    let line_start = source[..insert_at].rfind('\n').map_or(0, |i| i + 1);
+    let line = &source[line_start..insert_at];
+    let indent: String = line
+        .chars()
+        .take_while(|c| *c == ' ' || *c == '\t')
+        .collect();
+    let opens_block = !inline && line.ends_with('{');
+    let separator = if inline {
+        ", ".to_string()
+    } else if opens_block {
+        format!("\n{indent}  ")
+    } else {
+        format!("\n{indent}")
+    };
+
+    let mut text = String::new();
+    for field in fields {
+        let key = if Identifier::is_valid(field) {
+            field.clone()
+        } else {
+            format!("{field:?}")
+        };
+        let value = field_default(schema, record, field)
+            .and_then(render_default)
+            .unwrap_or_else(|| "!".to_string());
+        text.push_str(&format!("{separator}{key} = {value}"));
+    }
+    if opens_block {
+        text.push_str(&format!("\n{indent}"));
+    }
+
+    MissingFieldFix {
+        span,
+        insert_at: insert_at as u32,
+        fields: fields.to_vec(),
+        text,
+    }
+}
+
+fn field_default<'a>(
+    schema: &'a SchemaDocument,
+    record: SchemaNodeId,
+    field: &str,
+) -> Option<&'a EureDocument> {
+    let SchemaNodeContent::Record(record) = &schema.node(record).content else {
+        return None;
+    };
+    let field_schema = record.properties.get(field)?.schema;
+    schema.node(field_schema).metadata.default.as_ref()
+}
+
+/// Render a default value as the right-hand side of a binding, if it fits on
+/// one line.
+fn render_default(default: &EureDocument) -> Option<String> {
+    let source = LayoutPlan::auto(default.clone()).ok()?.emit();
+    let formatted = format_source_document(&source);
+    let value = formatted.trim_end().strip_prefix("= ")?;
+    (!value.contains('\n')).then(|| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query::{TextFileContent, build_runtime};
+    use query_flow::DurabilityLevel;
+    use std::path::PathBuf;
+
+    fn fixes(doc: &str, schema: &str) -> (String, Vec<MissingFieldFix>) {
+        let runtime = build_runtime();
+        let file = TextFile::from_path(PathBuf::from("test.eure"));
+        let source = format!("$schema = \"test.schema.eure\"\n{doc}");
+        runtime.resolve_asset(
+            file.clone(),
+            TextFileContent(source.clone()),
+            DurabilityLevel::Volatile,
+        );
+        runtime.resolve_asset(
+            TextFile::from_path(PathBuf::from("test.schema.eure")),
+            TextFileContent(schema.to_string()),
+            DurabilityLevel::Volatile,
+        );
+        let fixes = runtime.query(GetMissingFieldFixes::new(file)).unwrap();
+        (source, fixes.as_ref().clone())
+    }
+
+    fn apply(source: &str, fix: &MissingFieldFix) -> String {
+        let mut out = source.to_string();
+        out.insert_str(fix.insert_at as usize, &fix.text);
+        out
+    }
+
+    #[test]
+    fn test_missing_root_field_inserts_hole() {
+        let (source, fixes) = fixes("age = 1\n", "name = `text`\nage = `integer`\n");
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].fields, vec!["name".to_string()]);
+        assert_eq!(
+            apply(&source, &fixes[0]),
+            "$schema = \"test.schema.eure\"\nage = 1\nname = !\n"
+        );
+    }
+
+    #[test]
+    fn test_missing_field_in_block_inserts_before_brace() {
+        let (source, fixes) = fixes(
+            "user {\n  age = 1\n}\n",
+            "user {\n  name = `text`\n  age = `integer`\n}\n",
+        );
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(
+            apply(&source, &fixes[0]),
+            "$schema = \"test.schema.eure\"\nuser {\n  age = 1\n  name = !\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_missing_field_in_empty_nested_block_is_indented() {
+        let (source, fixes) = fixes(
+            "outer {\n  user {}\n}\n",
+            "outer {\n  user {\n    name = `text`\n  }\n}\n",
+        );
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(
+            apply(&source, &fixes[0]),
+            "$schema = \"test.schema.eure\"\nouter {\n  user {\n    name = !\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_missing_field_uses_schema_default() {
+        let (source, fixes) = fixes(
+            "age = 1\n",
+            "name = `text`\nname.$default = \"anonymous\"\nage = `integer`\n",
+        );
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(
+            apply(&source, &fixes[0]),
+            "$schema = \"test.schema.eure\"\nage = 1\nname = \"anonymous\"\n"
+        );
+    }
+}
@@ -6,11 +6,12 @@ use eure_schema::convert::ConversionError;
 use query_flow::{Cachable, Db, Query, QueryError, QueryResultExt as _, query};
 
 use super::assets::{OpenDocuments, OpenDocumentsList, TextFile};
+use super::config::{GetConfigErrorReports, is_workspace_config};
 use super::error::{EureQueryError, FileError};
 use super::parse::{ParseCst, ParseDocument};
 use super::schema::{
-    DocumentToSchemaQuery, GetSchemaExtension, GetSchemaExtensionDiagnostics, ResolveSchema,
-    ValidateAgainstSchema,
+    DocumentToSchemaQuery, GetSchemaExtension, GetSchemaExtensionDiagnostics, LintAgainstSchema,
+    ResolveSchema, ValidateAgainstSchema,
 };
 use crate::document::DocumentConstructionErrorWithOriginMap;
 use crate::report::{
@@ -114,6 +115,7 @@ where
 /// Includes:
 /// - Parse errors
 /// - Document construction errors
+/// - Config errors and warnings (if this file is a workspace config)
 /// - Schema extension errors
 /// - Validation errors (if this is a document with a schema)
 /// - Schema conversion errors (if this file is referenced as a schema)
@@ -137,14 +139,23 @@ pub fn get_file_error_reports(db: &impl Db, file: TextFile) -> Result<ErrorRepor
         return Ok(reports); // Stop here if doc construction failed
     }
 
-    // 3. Schema extension errors ($schema wrong type)
+    // 3. Config errors (only if this file is a workspace's config)
+    if is_workspace_config(db, &file)? {
+        reports.extend(
+            db.query(GetConfigErrorReports::new(file.clone()))?
+                .iter()
+                .cloned(),
+        );
+    }
+
+    // 4. Schema extension errors ($schema wrong type)
     reports.extend(
         db.query(GetSchemaExtensionDiagnostics::new(file.clone()))?
             .iter()
             .cloned(),
     );
 
-    // 4. Validation errors - check schema validity first, then validate
+    // 5. Validation errors - check schema validity first, then validate
     let resolved = match db.query(ResolveSchema::new(file.clone())) {
         Ok(r) => r,
         Err(QueryError::UserError(e)) => {
@@ -177,6 +188,8 @@ pub fn get_file_error_reports(db: &impl Db, file: TextFile) -> Result<ErrorRepor
                 // Schema is valid, run validation
                 let validation = db.query(ValidateAgainstSchema::new(file.clone()))?;
                 reports.extend(validation.iter().cloned());
+                let lints = db.query(LintAgainstSchema::new(file.clone()))?;
+                reports.extend(lints.iter().cloned());
             }
             Err(QueryError::UserError(e)) => {
                 // Schema failed - show appropriate error/warning
@@ -193,7 +206,7 @@ pub fn get_file_error_reports(db: &impl Db, file: TextFile) -> Result<ErrorRepor
         }
     }
 
-    // 5. Schema conversion errors (only if this file is a schema)
+    // 6. Schema conversion errors (only if this file is a schema)
     let schema_files = collect_schema_files(db)?;
     if schema_files.contains(&file)
         && let Err(e) = db.query(WithErrorReports::new(DocumentToSchemaQuery::new(
@@ -294,10 +307,51 @@ mod tests {
         // Should have exactly one report for the duplicate key
         assert_eq!(reports.len(), 1);
         assert_eq!(reports[0].primary_origin.file, file);
-        assert!(
-            reports[0]
-                .title
-                .contains("Binding target already has a value")
+        assert_eq!(
+            reports[0].title,
+            "Duplicate key: `name` is already assigned"
+        );
+    }
+
+    #[test]
+    fn test_file_error_reports_duplicate_key_points_at_both_bindings() {
+        use crate::report::{AnnotationKind, Element};
+
+        let runtime = build_runtime();
+        let file = TextFile::from_path(PathBuf::from("test.eure"));
+        let source = "name = \"Alice\"\nname = \"Bob\"";
+        runtime.resolve_asset(
+            file.clone(),
+            TextFileContent(source.to_string()),
+            DurabilityLevel::Volatile,
+        );
+
+        let reports = runtime
+            .query(GetFileErrorReports::new(file.clone()))
+            .unwrap();
+        assert_eq!(reports.len(), 1);
+
+        let second = reports[0].primary_origin.span;
+        assert_eq!(
+            &source[second.start as usize..second.end as usize],
+            "name = \"Bob\""
+        );
+
+        let first = reports[0]
+            .elements
+            .iter()
+            .find_map(|element| match element {
+                Element::Annotation {
+                    origin,
+                    kind: AnnotationKind::Secondary,
+                    label,
+                } if label == "first assigned here" => Some(origin.span),
+                _ => None,
+            })
+            .expect("secondary annotation for the first binding");
+        assert_eq!(
+            &source[first.start as usize..first.end as usize],
+            "name = \"Alice\""
         );
     }
 
@@ -328,4 +382,99 @@ mod tests {
         // Should have no reports
         assert_eq!(reports.len(), 0);
     }
+
+    #[test]
+    fn test_invalid_schema_pattern_points_at_pattern_string() {
+        let runtime = build_runtime();
+        let file = TextFile::from_path(PathBuf::from("test.schema.eure"));
+        let source = "name {\n  $variant: text\n  pattern = \"[a-z\"\n}\n";
+
+        runtime.resolve_asset(
+            file.clone(),
+            TextFileContent(source.to_string()),
+            DurabilityLevel::Volatile,
+        );
+
+        let Err(err) = runtime.query(WithErrorReports::new(DocumentToSchemaQuery::new(
+            file.clone(),
+        ))) else {
+            panic!("invalid regex should fail schema conversion");
+        };
+        let reports = err
+            .downcast_ref::<ErrorReports>()
+            .expect("conversion error should be reported");
+
+        assert_eq!(reports.len(), 1);
+        let span = reports[0].primary_origin.span;
+        let pattern_start = source.find("\"[a-z\"").unwrap();
+        assert_eq!(
+            &source[span.start as usize..span.end as usize],
+            &source[pattern_start..pattern_start + "\"[a-z\"".len()]
+        );
+    }
+
+    #[test]
+    fn test_validation_error_reports_carry_error_code() {
+        let runtime = build_runtime();
+        let schema = TextFile::from_path(PathBuf::from("test.schema.eure"));
+        let file = TextFile::from_path(PathBuf::from("test.eure"));
+        runtime.resolve_asset(
+            OpenDocuments,
+            OpenDocumentsList(vec![file.clone()]),
+            DurabilityLevel::Volatile,
+        );
+        runtime.resolve_asset(
+            schema,
+            TextFileContent("name = `text`\n".to_string()),
+            DurabilityLevel::Volatile,
+        );
+        runtime.resolve_asset(
+            file.clone(),
+            TextFileContent("$schema = \"test.schema.eure\"\nname = 1\n".to_string()),
+            DurabilityLevel::Volatile,
+        );
+
+        let reports = runtime.query(GetFileErrorReports::new(file)).unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].code.as_deref(), Some("E0001"));
+        assert!(crate::codes::explain("E0001").is_some());
+    }
+
+    #[test]
+    fn test_schema_file_reports_self_check_defects() {
+        let runtime = build_runtime();
+        let meta_schema = crate::query::schema::meta_schema_file();
+        let meta_source = std::fs::read_to_string(meta_schema.as_local_path().unwrap()).unwrap();
+        runtime.resolve_asset(
+            meta_schema,
+            TextFileContent(meta_source),
+            DurabilityLevel::Volatile,
+        );
+        let file = TextFile::from_path(PathBuf::from("test.schema.eure"));
+        runtime.resolve_asset(
+            OpenDocuments,
+            OpenDocumentsList(vec![file.clone()]),
+            DurabilityLevel::Volatile,
+        );
+        let source = "first = `text`\nfirst.$codegen.name = \"second\"\nsecond = `integer`\n";
+        runtime.resolve_asset(
+            file.clone(),
+            TextFileContent(source.to_string()),
+            DurabilityLevel::Volatile,
+        );
+
+        let reports = runtime
+            .query(GetFileErrorReports::new(file.clone()))
+            .unwrap();
+        assert_eq!(reports.len(), 1, "reports: {reports:?}");
+        assert_eq!(reports[0].severity, crate::report::Severity::Error);
+        assert!(
+            reports[0]
+                .title
+                .contains("renames fields `first` and `second` to the same name `second`"),
+            "title: {}",
+            reports[0].title
+        );
+        assert_eq!(reports[0].primary_origin.file, file);
+    }
 }
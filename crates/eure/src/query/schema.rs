@@ -3,19 +3,26 @@
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use eure_document::document::schema_ref::{SchemaReference, is_remote_location};
 use eure_document::value::ObjectKey;
 use eure_schema::SchemaDocument;
 use eure_schema::convert::{SchemaSourceMap, document_to_schema_with_layout};
+use eure_schema::lint::collect_lints;
 use eure_schema::type_path_trace::LayoutStrategies;
-use eure_schema::validate::{ValidationError, validate};
+#[cfg(feature = "native")]
+use eure_schema::validate::validate_with_resolver;
+use eure_schema::validate::{ValidationError, ValidationOutput, validate};
 use eure_tree::prelude::Cst;
 use eure_tree::tree::InputSpan;
 use query_flow::{Db, QueryError, query};
 
 use crate::document::OriginMap;
+#[cfg(feature = "native")]
+use crate::schema_resolver::FileSchemaResolver;
 
 use crate::report::{
-    ErrorReport, ErrorReports, Origin, format_error_reports, report_schema_validation_errors,
+    ErrorReport, ErrorReports, Origin, format_error_reports, report_schema_defects,
+    report_schema_lints, report_schema_validation_errors,
 };
 
 use super::assets::TextFile;
@@ -96,13 +103,9 @@ pub fn validate_against_schema(
         return Ok(ErrorReports::new());
     };
 
-    // Parse document
-    let doc_result = db.query(ParseDocument::new(doc_file.clone()))?;
-    let doc_parsed = doc_result.as_ref().clone();
-
     // Load and convert schema - handle errors appropriately
-    let schema_result = match db.query(DocumentToSchemaQuery::new(schema.file.clone())) {
-        Ok(result) => result,
+    match db.query(DocumentToSchemaQuery::new(schema.file.clone())) {
+        Ok(_) => {}
         Err(QueryError::UserError(e)) => {
             // Schema conversion errors are returned as ErrorReports with proper location
             if let Some(reports) = e.downcast_ref::<ErrorReports>() {
@@ -118,11 +121,96 @@ pub fn validate_against_schema(
             return Err(QueryError::UserError(e));
         }
         Err(other) => return Err(other),
+    }
+
+    let output = db.query(ValidateWithSchema::new(
+        doc_file.clone(),
+        schema.file.clone(),
+    ))?;
+
+    let mut reports =
+        report_schema_validation_errors(db, doc_file.clone(), schema.file.clone(), &output.errors)?;
+    // A document that passes the meta-schema is a schema itself, so check it
+    // for defects the meta-schema cannot express.
+    if reports.is_empty() && schema.file == meta_schema_file() {
+        match db.query(CheckSchemaSelf::new(doc_file)) {
+            Ok(defects) => reports.extend(defects.iter().cloned()),
+            // Conversion errors are reported where schema files are converted
+            Err(QueryError::UserError(_)) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(reports)
+}
+
+/// Check a schema file for internal inconsistencies.
+///
+/// Converts `file` to a schema and reports each defect found by
+/// [`SchemaDocument::validate_self`] at the schema node it concerns.
+#[query(debug = "{Self}({file})")]
+pub fn check_schema_self(db: &impl Db, file: TextFile) -> Result<ErrorReports, QueryError> {
+    let schema = db.query(DocumentToSchemaQuery::new(file.clone()))?;
+    let defects = schema.schema.validate_self();
+    report_schema_defects(db, file, &schema.source_map, &defects)
+}
+
+/// Lint a document against its resolved schema.
+///
+/// Returns warning reports, currently for uses of `$deprecated` fields.
+/// Returns empty reports if no schema is found.
+#[query(debug = "{Self}({doc_file})")]
+pub fn lint_against_schema(db: &impl Db, doc_file: TextFile) -> Result<ErrorReports, QueryError> {
+    let Some(schema) = db
+        .query(ResolveSchema::new(doc_file.clone()))?
+        .as_ref()
+        .clone()
+    else {
+        return Ok(ErrorReports::new());
     };
 
-    let result = validate(&doc_parsed.doc, &schema_result.schema);
+    let output = db.query(ValidateWithSchema::new(
+        doc_file.clone(),
+        schema.file.clone(),
+    ))?;
+    let schema_result = db.query(DocumentToSchemaQuery::new(schema.file))?;
+    let lints = collect_lints(&output.warnings, &schema_result.schema);
 
-    report_schema_validation_errors(db, doc_file, schema.file, &result.errors)
+    report_schema_lints(db, doc_file, &lints)
+}
+
+/// Validate a document against a schema file, keeping errors and warnings.
+///
+/// Validation errors and lints are both derived from this single pass.
+/// Cross-schema references (`$types.namespace.type-name`) of a local schema
+/// are resolved with a [`FileSchemaResolver`] rooted at the schema's directory,
+/// honoring the `security` section of the document's workspace config. Other
+/// schemas report them as unresolvable.
+#[query(debug = "{Self}({doc_file}, {schema_file})")]
+pub fn validate_with_schema(
+    db: &impl Db,
+    doc_file: TextFile,
+    schema_file: TextFile,
+) -> Result<ValidationOutput, QueryError> {
+    let doc_parsed = db.query(ParseDocument::new(doc_file.clone()))?;
+    let schema_result = db.query(DocumentToSchemaQuery::new(schema_file.clone()))?;
+
+    #[cfg(feature = "native")]
+    if let Some(base_dir) = schema_file.as_local_path().and_then(Path::parent) {
+        let security = db
+            .query(ResolveConfig::new(doc_file))?
+            .as_ref()
+            .as_ref()
+            .and_then(|resolved| resolved.config.security.clone())
+            .unwrap_or_default();
+        let resolver = FileSchemaResolver::new(base_dir).with_security(security);
+        return Ok(validate_with_resolver(
+            &doc_parsed.doc,
+            &schema_result.schema,
+            &resolver,
+        ));
+    }
+
+    Ok(validate(&doc_parsed.doc, &schema_result.schema))
 }
 
 /// Validate document against an explicitly provided schema file.
@@ -138,14 +226,12 @@ pub fn validate_against_explicit_schema(
     doc_file: TextFile,
     schema_file: TextFile,
 ) -> Result<ErrorReports, QueryError> {
-    let doc_result = db.query(ParseDocument::new(doc_file.clone()))?;
-    let doc_parsed = doc_result.as_ref().clone();
-
-    let schema_result = db.query(DocumentToSchemaQuery::new(schema_file.clone()))?;
-
-    let result = validate(&doc_parsed.doc, &schema_result.schema);
+    let output = db.query(ValidateWithSchema::new(
+        doc_file.clone(),
+        schema_file.clone(),
+    ))?;
 
-    report_schema_validation_errors(db, doc_file, schema_file, &result.errors)
+    report_schema_validation_errors(db, doc_file, schema_file, &output.errors)
 }
 
 /// Validate document against an explicitly provided schema and return formatted error strings.
@@ -215,31 +301,22 @@ pub fn get_schema_extension(
 ) -> Result<Option<ResolvedSchemaExtension>, QueryError> {
     let parsed = db.query(ParseDocument::new(file.clone()))?;
 
-    let root_id = parsed.doc.get_root_id();
-    let root_ctx = parsed.doc.parse_context(root_id);
-
-    // Check if $schema extension exists
-    let Some(schema_ctx) = root_ctx.ext_optional("schema") else {
+    // Invalid types are reported by get_schema_extension_diagnostics
+    let Some(reference) = SchemaReference::from_document(&parsed.doc) else {
         return Ok(None);
     };
 
-    // Try to get $schema extension as a string
-    let Ok(Some(schema_path)) = root_ctx.parse_ext_optional::<String>("schema") else {
-        return Ok(None); // Invalid type, diagnostics handled by get_schema_extension_diagnostics
-    };
-
     // Get the span for the $schema value
-    let node_id = schema_ctx.node_id();
     let cst = db.query(ParseCst::new(file.clone()))?;
     let span = parsed
         .origins
-        .get_value_span(node_id, &cst.cst)
+        .get_value_span(reference.node_id, &cst.cst)
         .unwrap_or(InputSpan::EMPTY);
 
     let origin = Origin::new(file, span);
 
     Ok(Some(ResolvedSchemaExtension {
-        path: schema_path,
+        path: reference.path,
         origin,
     }))
 }
@@ -311,7 +388,7 @@ pub fn resolve_schema(db: &impl Db, file: TextFile) -> Result<Option<ResolvedSch
             }));
         }
         // For remote files, only absolute URLs are supported
-        if ext.path.starts_with("https://") {
+        if is_remote_location(&ext.path) {
             return Ok(Some(ResolvedSchema {
                 file: TextFile::parse(&ext.path)?,
                 origin: Some(ext.origin.clone()),
@@ -345,7 +422,7 @@ pub fn resolve_schema(db: &impl Db, file: TextFile) -> Result<Option<ResolvedSch
 }
 
 /// Get the built-in meta-schema file.
-fn meta_schema_file() -> TextFile {
+pub(crate) fn meta_schema_file() -> TextFile {
     const LOCAL_META_SCHEMA: &str = concat!(
         env!("CARGO_MANIFEST_DIR"),
         "/../../assets/schemas/eure-schema.schema.eure"
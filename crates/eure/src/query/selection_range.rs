@@ -0,0 +1,79 @@
+//! Selection ranges for expand/shrink selection.
+//!
+//! The chain for a cursor is the span of every CST node on the path from the
+//! innermost node containing it up to the root, e.g. value → binding →
+//! section → document.
+
+use crate::tree::*;
+use query_flow::{Db, QueryError, query};
+
+use super::assets::TextFile;
+use super::parse::ParseCst;
+
+/// Spans of the CST nodes containing `offset`, innermost first.
+///
+/// Nodes whose span equals their child's (single-child wrappers) contribute
+/// one entry, so every span strictly contains the previous one. Returns an
+/// empty vector if the document has no node at `offset`.
+pub fn selection_spans(cst: &Cst, offset: u32) -> Vec<InputSpan> {
+    let mut chain: Vec<InputSpan> = Vec::new();
+    let mut current = Some(cst.root());
+    while let Some(node) = current {
+        if let Some(span) = cst.span(node)
+            && chain.last() != Some(&span)
+        {
+            chain.push(span);
+        }
+        current = cst.children(node).find(|&child| {
+            cst.span(child)
+                .is_some_and(|span| span.start <= offset && offset < span.end)
+        });
+    }
+    chain.reverse();
+    chain
+}
+
+/// Query to get the selection spans for the cursor at byte `offset`.
+///
+/// Uses tolerant parsing so selection works while the document has errors.
+#[query(debug = "{Self}({file}, {offset})")]
+pub fn get_selection_spans(
+    db: &impl Db,
+    file: TextFile,
+    offset: u32,
+) -> Result<Vec<InputSpan>, QueryError> {
+    let parsed_cst = db.query(ParseCst::new(file))?;
+    Ok(selection_spans(&parsed_cst.cst, offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_section_value() {
+        let input = "@ server\nhost = \"localhost\"\nport = 8080\n";
+        let cst = crate::parol::parse(input, "<input>").expect("Failed to parse");
+        let offset = input.find("8080").unwrap() as u32 + 1;
+        let spans = selection_spans(&cst, offset);
+
+        let texts: Vec<&str> = spans.iter().map(|span| span.as_str(input)).collect();
+        assert_eq!(texts[0], "8080");
+        assert!(
+            texts.iter().any(|text| text.trim_end() == "port = 8080"),
+            "no binding range in {texts:?}"
+        );
+        assert!(
+            texts
+                .iter()
+                .any(|text| text.starts_with("@ server") && text.contains("port = 8080")),
+            "no section range in {texts:?}"
+        );
+        assert_eq!(texts.last().unwrap().trim(), input.trim());
+
+        for pair in spans.windows(2) {
+            assert!(pair[1].start <= pair[0].start && pair[0].end <= pair[1].end);
+            assert_ne!(pair[0], pair[1]);
+        }
+    }
+}
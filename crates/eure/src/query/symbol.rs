@@ -0,0 +1,141 @@
+//! Named symbols defined by a document, for symbol search.
+//!
+//! A document defines two kinds of symbols: its top-level sections (root keys
+//! whose value is a map) and the named types in its `$types` extension.
+
+use eure_document::document::node::NodeValue;
+use eure_document::document::{EureDocument, NodeId};
+use eure_document::identifier::Identifier;
+use eure_document::value::ObjectKey;
+use eure_tree::tree::InputSpan;
+use query_flow::{Db, QueryError, query};
+
+use crate::document::OriginMap;
+use crate::tree::Cst;
+
+use super::assets::TextFile;
+use super::parse::{ParseDocument, ValidCst};
+
+const TYPES: Identifier = Identifier::new_unchecked("types");
+
+/// The kind of a [`NamedSymbol`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NamedSymbolKind {
+    /// A top-level key whose value is a map.
+    Section,
+    /// A type defined in `$types`.
+    Type,
+}
+
+/// A named definition in a document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedSymbol {
+    pub name: String,
+    pub kind: NamedSymbolKind,
+    /// Span of the symbol's key.
+    pub span: InputSpan,
+}
+
+/// Collect the named symbols of a document, sections first, in source order.
+pub fn named_symbols(doc: &EureDocument, origins: &OriginMap, cst: &Cst) -> Vec<NamedSymbol> {
+    let root_id = doc.get_root_id();
+    let mut symbols = Vec::new();
+
+    if let NodeValue::Map(map) = &doc.node(root_id).content {
+        for (key, &child) in map.iter() {
+            if matches!(doc.node(child).content, NodeValue::Map(_)) {
+                push_symbol(
+                    &mut symbols,
+                    origins,
+                    cst,
+                    root_id,
+                    key,
+                    child,
+                    NamedSymbolKind::Section,
+                );
+            }
+        }
+    }
+
+    if let Some(types_id) = doc.node(root_id).get_extension(&TYPES)
+        && let NodeValue::Map(types) = &doc.node(types_id).content
+    {
+        for (key, &child) in types.iter() {
+            push_symbol(
+                &mut symbols,
+                origins,
+                cst,
+                types_id,
+                key,
+                child,
+                NamedSymbolKind::Type,
+            );
+        }
+    }
+
+    symbols
+}
+
+fn push_symbol(
+    symbols: &mut Vec<NamedSymbol>,
+    origins: &OriginMap,
+    cst: &Cst,
+    map_id: NodeId,
+    key: &ObjectKey,
+    child: NodeId,
+    kind: NamedSymbolKind,
+) {
+    let Some(span) = origins
+        .get_key_span(map_id, key, cst)
+        .or_else(|| origins.get_definition_span(child, cst))
+    else {
+        return;
+    };
+    let name = match key {
+        ObjectKey::String(name) => name.clone(),
+        other => other.to_string(),
+    };
+    symbols.push(NamedSymbol { name, kind, span });
+}
+
+/// Query the named symbols of a file.
+///
+/// Returns an empty vector if the file fails to parse (those errors are
+/// reported by diagnostics).
+#[query(debug = "{Self}({file})")]
+pub fn get_named_symbols(db: &impl Db, file: TextFile) -> Result<Vec<NamedSymbol>, QueryError> {
+    let parsed = match db.query(ParseDocument::new(file.clone())) {
+        Ok(parsed) => parsed,
+        Err(QueryError::UserError(_)) => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    let cst = db.query(ValidCst::new(file))?;
+    Ok(named_symbols(&parsed.doc, &parsed.origins, &cst))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::cst_to_document_and_origin_map;
+
+    fn symbols(input: &str) -> Vec<(String, NamedSymbolKind, &str)> {
+        let cst = crate::parol::parse(input, "<input>").expect("Failed to parse");
+        let (doc, origins) = cst_to_document_and_origin_map(input, &cst).unwrap();
+        named_symbols(&doc, &origins, &cst)
+            .into_iter()
+            .map(|symbol| (symbol.name, symbol.kind, symbol.span.as_str(input)))
+            .collect()
+    }
+
+    #[test]
+    fn test_sections_and_types() {
+        let input = "name = \"x\"\n$types.user = `text`\n@ server\nport = 1\n";
+        assert_eq!(
+            symbols(input),
+            vec![
+                ("server".to_string(), NamedSymbolKind::Section, "server"),
+                ("user".to_string(), NamedSymbolKind::Type, "user"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! Occurrences of local schema type names, for rename.
+//!
+//! A type is defined as a key of the root `$types` extension and referenced by
+//! text values of the form `` `$types.name` ``. References into other schemas
+//! (`$types.namespace.name`) are not local occurrences.
+
+use eure_document::document::node::NodeValue;
+use eure_document::document::{EureDocument, NodeId};
+use eure_document::identifier::Identifier;
+use eure_document::value::{ObjectKey, PrimitiveValue};
+use eure_tree::tree::InputSpan;
+use query_flow::{Db, QueryError, query};
+
+use crate::document::OriginMap;
+use crate::tree::Cst;
+
+use super::assets::{TextFile, TextFileContent};
+use super::parse::{ParseDocument, ValidCst};
+
+const TYPES: Identifier = Identifier::new_unchecked("types");
+const REFERENCE_PREFIX: &str = "$types.";
+
+/// Whether a [`TypeOccurrence`] defines or references the type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TypeOccurrenceKind {
+    Definition,
+    Reference,
+}
+
+/// A local type name in a document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeOccurrence {
+    pub name: String,
+    pub kind: TypeOccurrenceKind,
+    /// Span of the name alone, without the `$types.` prefix or delimiters.
+    pub span: InputSpan,
+}
+
+/// Collect the local type definitions and references of a document.
+///
+/// Definitions come first, in source order, followed by references in node
+/// order.
+pub fn type_occurrences(
+    doc: &EureDocument,
+    origins: &OriginMap,
+    cst: &Cst,
+    input: &str,
+) -> Vec<TypeOccurrence> {
+    let root_id = doc.get_root_id();
+    let mut occurrences = Vec::new();
+
+    if let Some(types_id) = doc.node(root_id).get_extension(&TYPES)
+        && let NodeValue::Map(types) = &doc.node(types_id).content
+    {
+        for (key, _) in types.iter() {
+            if let ObjectKey::String(name) = key
+                && let Some(span) = origins.get_key_span(types_id, key, cst)
+            {
+                occurrences.push(TypeOccurrence {
+                    name: name.clone(),
+                    kind: TypeOccurrenceKind::Definition,
+                    span,
+                });
+            }
+        }
+    }
+
+    for index in 0..doc.node_count() {
+        let node_id = NodeId(index);
+        let NodeValue::Primitive(PrimitiveValue::Text(text)) = &doc.node(node_id).content else {
+            continue;
+        };
+        let Some(name) = text.content.strip_prefix(REFERENCE_PREFIX) else {
+            continue;
+        };
+        if name.parse::<Identifier>().is_err() {
+            continue;
+        }
+        let Some(value_span) = origins.get_value_span(node_id, cst) else {
+            continue;
+        };
+        // Locate the name inside the literal, which may be delimited by quotes
+        // or backticks.
+        let Some(offset) = value_span.as_str(input).find(&text.content) else {
+            continue;
+        };
+        let start = value_span.start + (offset + REFERENCE_PREFIX.len()) as u32;
+        occurrences.push(TypeOccurrence {
+            name: name.to_string(),
+            kind: TypeOccurrenceKind::Reference,
+            span: InputSpan::new(start, start + name.len() as u32),
+        });
+    }
+
+    occurrences
+}
+
+/// Query the local type occurrences of a file.
+///
+/// Returns an empty vector if the file fails to parse (those errors are
+/// reported by diagnostics).
+#[query(debug = "{Self}({file})")]
+pub fn get_type_occurrences(
+    db: &impl Db,
+    file: TextFile,
+) -> Result<Vec<TypeOccurrence>, QueryError> {
+    let parsed = match db.query(ParseDocument::new(file.clone())) {
+        Ok(parsed) => parsed,
+        Err(QueryError::UserError(_)) => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+    let cst = db.query(ValidCst::new(file.clone()))?;
+    let source: std::sync::Arc<TextFileContent> = db.asset(file)?;
+    Ok(type_occurrences(
+        &parsed.doc,
+        &parsed.origins,
+        &cst,
+        source.get(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::cst_to_document_and_origin_map;
+
+    fn occurrences(input: &str) -> Vec<(String, TypeOccurrenceKind, &str)> {
+        let cst = crate::parol::parse(input, "<input>").expect("Failed to parse");
+        let (doc, origins) = cst_to_document_and_origin_map(input, &cst).unwrap();
+        type_occurrences(&doc, &origins, &cst, input)
+            .into_iter()
+            .map(|occurrence| {
+                (
+                    occurrence.name,
+                    occurrence.kind,
+                    occurrence.span.as_str(input),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_definitions_and_references() {
+        let input = "$types.user = `text`\nowner = `$types.user`\nother = `$types.net.port`\n";
+        assert_eq!(
+            occurrences(input),
+            vec![
+                ("user".to_string(), TypeOccurrenceKind::Definition, "user"),
+                ("user".to_string(), TypeOccurrenceKind::Reference, "user"),
+            ]
+        );
+    }
+}
@@ -14,9 +14,11 @@ use eure_document::value::ObjectKey;
 use eure_parol::EureParseError;
 use eure_parol::error::{ParseErrorEntry, ParseErrorKind};
 use eure_schema::SchemaNodeId;
-use eure_schema::convert::ConversionError;
+use eure_schema::convert::{ConversionError, SchemaSourceMap};
+use eure_schema::lint::Lint;
+use eure_schema::self_check::SchemaDefect;
 use eure_schema::validate::ValidationError;
-use eure_tree::prelude::{Cst, CstNodeId};
+use eure_tree::prelude::{Cst, CstFacade, CstNode, CstNodeId, NonTerminalKind};
 use eure_tree::tree::InputSpan;
 use query_flow::{Db, QueryError};
 use thisisplural::Plural;
@@ -24,8 +26,8 @@ use thisisplural::Plural;
 use crate::document::{DocumentConstructionError, OriginMap};
 use crate::query::error::EureQueryError;
 use crate::query::{
-    DecorStyle, DecorStyleKey, DocumentToSchemaQuery, ParseCst, ParseDocument, TextFile,
-    TextFileContent, ValidCst,
+    DecorStyle, DecorStyleKey, DiagnosticMessage, DocumentToSchemaQuery, ParseCst, ParseDocument,
+    TextFile, TextFileContent, ValidCst,
 };
 
 // ============================================================================
@@ -440,7 +442,33 @@ pub fn report_document_error(
         .span_with_origin_map(cst, origins)
         .or_else(|| error.span(cst))
         .unwrap_or(InputSpan::EMPTY);
-    ErrorReport::error(error.to_string(), Origin::new(file, span))
+    let report = ErrorReport::error(error.to_string(), Origin::new(file.clone(), span));
+    if let DocumentConstructionError::DuplicateBinding { first_node_id, .. } = error
+        && let Some(first_span) = cst.span(enclosing_binding(cst, *first_node_id))
+    {
+        return report.with_annotation(
+            Origin::new(file, first_span),
+            AnnotationKind::Secondary,
+            "first assigned here",
+        );
+    }
+    report
+}
+
+/// The innermost `Binding` containing `node`, so a key or value is reported
+/// together with the rest of its binding. `node` itself if it is not in one.
+fn enclosing_binding(cst: &Cst, node: CstNodeId) -> CstNodeId {
+    cst.ancestors_inclusive(node)
+        .find(|&id| {
+            matches!(
+                cst.node_data(id),
+                Some(CstNode::NonTerminal {
+                    kind: NonTerminalKind::Binding,
+                    ..
+                })
+            )
+        })
+        .unwrap_or(node)
 }
 
 fn report_parse_entry(entry: &ParseErrorEntry, file: TextFile) -> ErrorReport {
@@ -480,6 +508,81 @@ fn report_parse_entry(entry: &ParseErrorEntry, file: TextFile) -> ErrorReport {
     report
 }
 
+/// Error code of reports for uses of deprecated fields ([`Lint::Deprecated`]).
+pub const DEPRECATED_CODE: &str = "E2001";
+
+/// Convert schema lints to warning ErrorReports.
+///
+/// Deprecated fields are reported at their key, falling back to the value of
+/// the record containing them.
+pub fn report_schema_lints(
+    db: &impl Db,
+    file: TextFile,
+    lints: &[Lint],
+) -> Result<ErrorReports, QueryError> {
+    let mut reports = ErrorReports::default();
+    if lints.is_empty() {
+        return Ok(reports);
+    }
+
+    let doc = db.query(ParseDocument::new(file.clone()))?;
+    let cst = db.query(ValidCst::new(file.clone()))?;
+
+    for lint in lints {
+        let node_id = lint.node_id();
+        let span = match lint {
+            Lint::Deprecated { field, .. } => doc
+                .origins
+                .get_key_span(node_id, &ObjectKey::String(field.clone()), &cst)
+                .or_else(|| doc.origins.get_value_span(node_id, &cst)),
+        };
+        let origin = Origin::with_hints(
+            file.clone(),
+            span.unwrap_or(InputSpan::EMPTY),
+            OriginHints::default().with_doc(node_id),
+        );
+        let origin = if span.is_none() {
+            origin.as_fallback()
+        } else {
+            origin
+        };
+        reports.push(ErrorReport::warning(lint.to_string(), origin).with_code(lint.code()));
+    }
+    Ok(reports)
+}
+
+/// Convert schema self-check defects to ErrorReports.
+///
+/// Each defect is reported at the definition of the schema node it concerns,
+/// falling back to the start of the file when `source_map` has no entry.
+pub fn report_schema_defects(
+    db: &impl Db,
+    file: TextFile,
+    source_map: &SchemaSourceMap,
+    defects: &[SchemaDefect],
+) -> Result<ErrorReports, QueryError> {
+    let mut reports = ErrorReports::default();
+    if defects.is_empty() {
+        return Ok(reports);
+    }
+
+    let doc = db.query(ParseDocument::new(file.clone()))?;
+    let cst = db.query(ValidCst::new(file.clone()))?;
+
+    for defect in defects {
+        let node_id = source_map.get(&defect.node_id()).copied();
+        let span = node_id.and_then(|node_id| doc.origins.get_value_span(node_id, &cst));
+        let origin = match (node_id, span) {
+            (Some(node_id), Some(span)) => {
+                Origin::with_hints(file.clone(), span, OriginHints::default().with_doc(node_id))
+            }
+            _ => Origin::file_start(file.clone()),
+        };
+        reports.push(ErrorReport::error(defect.to_string(), origin));
+    }
+    Ok(reports)
+}
+
 /// Convert schema validation errors to ErrorReports.
 ///
 /// For `NoVariantMatched` errors, this expands all errors from the best matching
@@ -766,7 +869,7 @@ fn report_validation_error(
         ),
         None => error.to_string(),
     };
-    let mut report = ErrorReport::error(message, doc_origin);
+    let mut report = ErrorReport::error(message, doc_origin).with_code(error.code());
 
     // Add schema location as secondary annotation
     if let Some(schema_span) = resolve_schema_span(db, schema_node_id, schema_file.clone(), &schema)
@@ -845,6 +948,16 @@ pub fn report_conversion_error(
                 OriginHints::default().with_doc(parse_error.node_id),
             );
 
+            ErrorReport::error(error.to_string(), origin).with_code(parse_error.kind.code())
+        }
+        ConversionError::InvalidPattern { node_id, .. } => {
+            // Point at the pattern string itself rather than the enclosing schema
+            let span = origins
+                .get_value_span(*node_id, cst)
+                .unwrap_or(InputSpan::EMPTY);
+
+            let origin = Origin::with_hints(file, span, OriginHints::default().with_doc(*node_id));
+
             ErrorReport::error(error.to_string(), origin)
         }
         _ => {
@@ -986,6 +1099,22 @@ pub fn format_error_reports(
     Ok(results.join("\n"))
 }
 
+/// Render multiple ErrorReports as a JSON array of [`JsonDiagnostic`]s.
+///
+/// Returns `Err` with suspension if any file content isn't loaded yet.
+pub fn format_error_reports_json(
+    db: &impl Db,
+    reports: &ErrorReports,
+) -> Result<String, QueryError> {
+    let mut diagnostics = Vec::new();
+    for report in reports.iter() {
+        let message = DiagnosticMessage::from(report);
+        let source: Arc<TextFileContent> = db.asset(message.file.clone())?;
+        diagnostics.push(message.to_json_diagnostic(source.get()));
+    }
+    Ok(serde_json::to_string_pretty(&diagnostics).expect("diagnostics are serializable"))
+}
+
 fn build_snippet_groups<'a>(ctx: &'a RenderContext, report: &'a ErrorReport) -> Vec<Group<'a>> {
     let mut groups = Vec::new();
 
@@ -1000,6 +1129,11 @@ fn build_snippet_groups<'a>(ctx: &'a RenderContext, report: &'a ErrorReport) ->
         Severity::Hint => Level::HELP,
     };
 
+    let mut title = level.primary_title(report.title.as_ref());
+    if let Some(code) = &report.code {
+        title = title.id(code.as_ref());
+    }
+
     if let Some(file_info) = primary_file {
         let span = &report.primary_origin.span;
 
@@ -1018,13 +1152,11 @@ fn build_snippet_groups<'a>(ctx: &'a RenderContext, report: &'a ErrorReport) ->
                     .label(report.title.as_ref()),
             );
 
-        let primary_group = level
-            .primary_title(report.title.as_ref())
-            .element(primary_snippet);
+        let primary_group = title.element(primary_snippet);
         groups.push(primary_group);
     } else {
         // No file info - create a minimal group with just the title
-        let primary_group = level.primary_title(report.title.as_ref()).element(
+        let primary_group = title.element(
             Snippet::source("")
                 .line_start(1)
                 .annotation(SnippetAnnotation::Primary.span(0..0)),
@@ -1217,7 +1349,9 @@ pub fn report_from_eure_parse_error(
         .get_value_span(error.node_id, cst)
         .unwrap_or(InputSpan::EMPTY);
     let origin = Origin::with_hints(file, span, OriginHints::default().with_doc(error.node_id));
-    ErrorReports::from(vec![ErrorReport::error(error.to_string(), origin)])
+    ErrorReports::from(vec![
+        ErrorReport::error(error.to_string(), origin).with_code(error.kind.code()),
+    ])
 }
 
 /// Convert a ConfigError to ErrorReports.
@@ -0,0 +1,216 @@
+//! Filesystem-backed resolution of cross-schema references.
+//!
+//! A reference like `` `$types.network.port` `` names the `network` namespace.
+//! [`FileSchemaResolver`] maps each namespace to a schema file, either an
+//! explicitly registered location or `<namespace>.schema.eure` next to the
+//! referencing schema. Remote locations are only fetched from hosts allowed by
+//! [`SecurityConfig`], with `eure.dev` always trusted.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use eure_env::SecurityConfig;
+use eure_schema::SchemaDocument;
+use eure_schema::convert::document_to_schema;
+use eure_schema::validate::{SchemaResolveError, SchemaResolver};
+use url::Url;
+
+use crate::document::cst_to_document;
+use crate::document::schema_ref::is_remote_location;
+use crate::query::asset_locator::{DEFAULT_ALLOWED_HOST, host_matches};
+use crate::query::http::fetch_url;
+
+/// Resolves schema namespaces to schema files on disk or allowed remote hosts.
+///
+/// Loaded schemas are cached, so each namespace is read at most once.
+#[derive(Debug)]
+pub struct FileSchemaResolver {
+    base_dir: PathBuf,
+    locations: HashMap<String, String>,
+    security: SecurityConfig,
+    cache: Mutex<HashMap<String, Arc<SchemaDocument>>>,
+}
+
+impl FileSchemaResolver {
+    /// Create a resolver looking up unregistered namespaces in `base_dir`.
+    pub fn new(base_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_dir: base_dir.into(),
+            locations: HashMap::new(),
+            security: SecurityConfig::default(),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Set the security policy applied to remote locations.
+    pub fn with_security(mut self, security: SecurityConfig) -> Self {
+        self.security = security;
+        self
+    }
+
+    /// Register the schema location of `namespace`.
+    ///
+    /// `location` is either an `https://` URL or a path relative to the base
+    /// directory.
+    pub fn with_namespace(
+        mut self,
+        namespace: impl Into<String>,
+        location: impl Into<String>,
+    ) -> Self {
+        self.locations.insert(namespace.into(), location.into());
+        self
+    }
+
+    fn load(&self, namespace: &str) -> Result<SchemaDocument, SchemaResolveError> {
+        let namespace_string = || namespace.to_string();
+
+        let (name, content) = match self.locations.get(namespace) {
+            Some(location) if is_remote_location(location) => {
+                let url = Url::parse(location).map_err(|_| SchemaResolveError::InvalidUrl {
+                    namespace: namespace_string(),
+                    location: location.clone(),
+                })?;
+                self.check_host(&url, namespace)?;
+                let content = fetch_url(&url).map_err(|e| SchemaResolveError::Fetch {
+                    namespace: namespace_string(),
+                    url: url.to_string(),
+                    message: e.to_string(),
+                })?;
+                (PathBuf::from(url.as_str()), content.0)
+            }
+            Some(location) => self.read_file(&self.base_dir.join(location), namespace)?,
+            None => self.read_file(
+                &self.base_dir.join(format!("{namespace}.schema.eure")),
+                namespace,
+            )?,
+        };
+
+        let cst =
+            eure_parol::parse(&content, &name).map_err(|error| SchemaResolveError::Syntax {
+                namespace: namespace_string(),
+                error,
+            })?;
+        let doc =
+            cst_to_document(&content, &cst).map_err(|e| SchemaResolveError::InvalidDocument {
+                namespace: namespace_string(),
+                message: e.to_string(),
+            })?;
+        let (schema, _) =
+            document_to_schema(&doc).map_err(|error| SchemaResolveError::Conversion {
+                namespace: namespace_string(),
+                error,
+            })?;
+        Ok(schema)
+    }
+
+    fn read_file(
+        &self,
+        path: &Path,
+        namespace: &str,
+    ) -> Result<(PathBuf, String), SchemaResolveError> {
+        let content = std::fs::read_to_string(path).map_err(|e| SchemaResolveError::Read {
+            namespace: namespace.to_string(),
+            path: path.to_path_buf(),
+            kind: e.kind(),
+        })?;
+        Ok((path.to_path_buf(), content))
+    }
+
+    fn check_host(&self, url: &Url, namespace: &str) -> Result<(), SchemaResolveError> {
+        let host = url.host_str().unwrap_or("");
+        if host == DEFAULT_ALLOWED_HOST
+            || self
+                .security
+                .allowed_hosts
+                .iter()
+                .any(|allowed| host_matches(host, allowed))
+        {
+            Ok(())
+        } else {
+            Err(SchemaResolveError::HostNotAllowed {
+                namespace: namespace.to_string(),
+                host: host.to_string(),
+            })
+        }
+    }
+}
+
+impl SchemaResolver for FileSchemaResolver {
+    fn resolve(&self, namespace: &str) -> Result<Arc<SchemaDocument>, SchemaResolveError> {
+        if let Some(schema) = self.cache.lock().unwrap().get(namespace) {
+            return Ok(schema.clone());
+        }
+        let schema = Arc::new(self.load(namespace)?);
+        self.cache
+            .lock()
+            .unwrap()
+            .insert(namespace.to_string(), schema.clone());
+        Ok(schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::parse_to_document;
+    use eure_schema::validate::{ValidationError, validate_with_resolver};
+
+    #[test]
+    fn test_resolve_namespace_from_sibling_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("network.schema.eure"),
+            "$types.port = `integer`\n",
+        )
+        .unwrap();
+
+        let schema_doc =
+            parse_to_document("port = `$types.network.port`\n", "server.schema.eure").unwrap();
+        let (schema, _) = document_to_schema(&schema_doc).unwrap();
+        let resolver = FileSchemaResolver::new(dir.path());
+
+        let valid = parse_to_document("port = 8080\n", "server.eure").unwrap();
+        let result = validate_with_resolver(&valid, &schema, &resolver);
+        assert!(result.is_valid, "errors: {:?}", result.errors);
+
+        let invalid = parse_to_document("port = \"http\"\n", "server.eure").unwrap();
+        let result = validate_with_resolver(&invalid, &schema, &resolver);
+        assert!(!result.is_valid);
+    }
+
+    #[test]
+    fn test_remote_namespace_requires_allowed_host() {
+        let resolver = FileSchemaResolver::new(".")
+            .with_namespace("remote", "https://schemas.example.com/remote.schema.eure");
+        assert_eq!(
+            resolver.resolve("remote"),
+            Err(SchemaResolveError::HostNotAllowed {
+                namespace: "remote".to_string(),
+                host: "schemas.example.com".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_missing_namespace_is_unresolvable() {
+        let schema_doc =
+            parse_to_document("port = `$types.missing.port`\n", "server.schema.eure").unwrap();
+        let (schema, _) = document_to_schema(&schema_doc).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let resolver = FileSchemaResolver::new(dir.path());
+
+        let doc = parse_to_document("port = 8080\n", "server.eure").unwrap();
+        let result = validate_with_resolver(&doc, &schema, &resolver);
+        assert!(result.errors.iter().any(|e| matches!(
+            e,
+            ValidationError::UnresolvableReference {
+                namespace,
+                cause: Some(SchemaResolveError::Read { path, kind, .. }),
+                ..
+            } if namespace == "missing"
+                && *path == dir.path().join("missing.schema.eure")
+                && *kind == std::io::ErrorKind::NotFound
+        )));
+    }
+}
@@ -78,3 +78,66 @@ pub fn get_error_position_from_error(
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use eure_tree::tree::CstNodeData;
+
+    #[test]
+    fn test_replace_subtree_with_text() {
+        let input = "a = 1\nb = 2\n";
+        let mut cst = crate::parol::parse(input, "<input>").expect("Failed to parse");
+        let integer =
+            cst.descendants_of_kind(cst.root(), NodeKind::NonTerminal(NonTerminalKind::Integer))[1];
+
+        assert!(cst.validate().is_ok());
+        // The space before `2` is trivia inside the Integer subtree, so it is
+        // replaced too.
+        let token = cst.replace_subtree_with_text(integer, " 42");
+        assert_eq!(cst.dynamic_token(token), Some(" 42"));
+        assert!(cst.validate().is_ok());
+        let children: Vec<_> = cst.children(integer).collect();
+        assert_eq!(children.len(), 1);
+        assert_eq!(
+            cst.node_data(children[0]),
+            Some(CstNodeData::new_terminal(
+                TerminalKind::Integer,
+                TerminalData::Dynamic(token)
+            ))
+        );
+
+        let mut output = String::new();
+        write_cst(input, &cst, &mut output).unwrap();
+        assert_eq!(output, "a = 1\nb = 42\n");
+        assert_eq!(cst.to_source(input), "a = 1\nb = 42\n");
+    }
+
+    #[test]
+    fn test_to_source_round_trips_input() {
+        let input = "// header\n\n@ server\nhost   = \"localhost\"  // trailing\nports = [80,\t443]\n\tenabled = true\n";
+        let cst = crate::parol::parse(input, "<input>").expect("Failed to parse");
+        assert_eq!(cst.to_source(input), input);
+    }
+
+    #[test]
+    fn test_compact_cst_visits_like_original() {
+        let input = "$schema = \"./app.schema.eure\"\n\n@ server\nhost = \"localhost\" // trailing\nports = [80, 443]\nlimits = { cpu => 2, memory => \"1Gi\" }\n\n@ users[]\nname = \"alice\"\nbio = ```markdown\n# Alice\n```\n";
+        let cst = crate::parol::parse(input, "<input>").expect("Failed to parse");
+        let compact = cst.clone().into_compact();
+
+        let mut written = String::new();
+        WriteVisitor::new(input, &mut written)
+            .visit_root_handle(compact.root_handle(), &compact)
+            .unwrap();
+        assert_eq!(written, input);
+
+        let mut expected = String::new();
+        inspect_cst(input, &cst, &mut expected).unwrap();
+        let mut inspected = String::new();
+        InspectVisitor::new(input, &mut inspected)
+            .visit_root_handle(compact.root_handle(), &compact)
+            .unwrap();
+        assert_eq!(inspected, expected);
+    }
+}
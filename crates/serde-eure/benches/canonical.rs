@@ -0,0 +1,84 @@
+//! Benchmarks comparing deserialization with and without the document IR.
+//!
+//! Both sides deserialize the same large canonical document:
+//! 1. IR: Input -> CST -> EureDocument -> Rust value
+//! 2. Fast path: Input -> CST -> Rust value
+//!
+//! Run with: cargo bench -p serde-eure
+
+use std::collections::BTreeMap;
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use serde::Deserialize;
+use serde_eure::Deserializer;
+
+// =============================================================================
+// Test Data
+// =============================================================================
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+struct Item {
+    id: u64,
+    name: String,
+    price: f64,
+    in_stock: bool,
+    tags: Vec<String>,
+    dimensions: (u32, u32, u32),
+}
+
+/// Canonical input with `count` top-level items
+fn canonical_input(count: usize) -> String {
+    let mut s = String::new();
+    for i in 0..count {
+        s.push_str(&format!(
+            "item_{i} = {{ id => {i}, name => \"Item {i}\", price => {price:?}, in_stock => {in_stock}, tags => [\"tag-a\", \"tag-b\", \"tag-c\"], dimensions => (10, 20, {i}) }}\n",
+            price = (i as f64) * 9.99,
+            in_stock = i % 2 == 0
+        ));
+    }
+    s
+}
+
+// =============================================================================
+// Pipelines
+// =============================================================================
+
+/// IR pipeline: always builds the EureDocument
+fn with_ir(text: &str) -> BTreeMap<String, Item> {
+    let deserializer = Deserializer::from_str(text).expect("document should parse");
+    BTreeMap::deserialize(&deserializer).expect("deserialization should succeed")
+}
+
+/// Fast pipeline: reads the canonical document straight from the CST
+fn without_ir(text: &str) -> BTreeMap<String, Item> {
+    let deserializer = Deserializer::from_str_canonical_fast(text).expect("document should parse");
+    assert!(deserializer.is_fast_path());
+    BTreeMap::deserialize(&deserializer).expect("deserialization should succeed")
+}
+
+// =============================================================================
+// Benchmarks
+// =============================================================================
+
+fn bench_deserialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deserialize_canonical");
+
+    for count in [100, 1000] {
+        let input = canonical_input(count);
+        group.bench_with_input(BenchmarkId::new("ir", count), input.as_str(), |b, input| {
+            b.iter(|| with_ir(black_box(input)))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("fast_path", count),
+            input.as_str(),
+            |b, input| b.iter(|| without_ir(black_box(input))),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_deserialize);
+criterion_main!(benches);
@@ -0,0 +1,395 @@
+//! The canonical profile: documents that deserialize straight from the CST.
+//!
+//! A canonical document maps one-to-one onto its data model, so reading it
+//! does not need the [`EureDocument`](eure::document::EureDocument) IR:
+//!
+//! - the root holds only `key = value` bindings, with no root value binding,
+//!   sections, block bindings or text bindings;
+//! - every key is a single identifier or a `"..."` string without escapes,
+//!   and keys are unique within each map;
+//! - every value is `null`, a boolean, an integer, a finite float, a single
+//!   `"..."` or `'...'` string, an array, a tuple, or an inline `{ k => v }`
+//!   object without a value binding.
+
+use std::collections::HashSet;
+
+use eure::document::text::unescape;
+use eure_tree::prelude::*;
+use eure_tree::tree::{RecursiveView, TerminalHandle};
+use num_bigint::BigInt;
+use serde::de::{self, IntoDeserializer, Visitor};
+
+use crate::error::DeError;
+use crate::source::{visit_external_enum, visit_integer, visit_map, visit_seq};
+
+/// Whether the document in `cst` is canonical.
+pub fn is_canonical(input: &str, cst: &Cst) -> bool {
+    check_root(input, cst).is_ok()
+}
+
+/// A reason the document is not canonical; the reason itself is not needed.
+struct NotCanonical;
+
+impl From<CstConstructError> for NotCanonical {
+    fn from(_: CstConstructError) -> Self {
+        NotCanonical
+    }
+}
+
+fn check_root(input: &str, cst: &Cst) -> Result<(), NotCanonical> {
+    let mut keys = HashSet::new();
+    for (keys_handle, value) in root_entries(cst)? {
+        if !keys.insert(simple_key(input, cst, keys_handle)?) {
+            return Err(NotCanonical);
+        }
+        check_value(input, cst, value)?;
+    }
+    Ok(())
+}
+
+fn check_value(input: &str, cst: &Cst, value: ValueHandle) -> Result<(), NotCanonical> {
+    match value.get_view(cst)? {
+        ValueView::Null(_) | ValueView::Boolean(_) => Ok(()),
+        ValueView::Number(number) => match number.get_view(cst)? {
+            NumberView::Integer(_) | NumberView::Float(_) => Ok(()),
+            NumberView::Inf(_) | NumberView::NaN(_) => Err(NotCanonical),
+        },
+        ValueView::Strings(strings) => single_string(cst, strings).map(drop),
+        ValueView::Array(array) => array_values(cst, array)?
+            .into_iter()
+            .try_for_each(|value| check_value(input, cst, value)),
+        ValueView::Tuple(tuple) => tuple_values(cst, tuple)?
+            .into_iter()
+            .try_for_each(|value| check_value(input, cst, value)),
+        ValueView::Object(object) => {
+            let mut keys = HashSet::new();
+            for (keys_handle, value) in object_entries(cst, object)? {
+                if !keys.insert(simple_key(input, cst, keys_handle)?) {
+                    return Err(NotCanonical);
+                }
+                check_value(input, cst, value)?;
+            }
+            Ok(())
+        }
+        ValueView::Hole(_) | ValueView::CodeBlock(_) | ValueView::InlineCode(_) => {
+            Err(NotCanonical)
+        }
+    }
+}
+
+/// The `key = value` bindings of the root.
+fn root_entries(cst: &Cst) -> Result<Vec<(KeysHandle, ValueHandle)>, NotCanonical> {
+    let eure = cst.root_handle().get_view(cst)?.eure.get_view(cst)?;
+    if eure.eure_opt.get_view(cst)?.is_some() || eure.eure_sections.get_view(cst)?.is_some() {
+        return Err(NotCanonical);
+    }
+    let Some(bindings) = eure.eure_bindings.get_view(cst)? else {
+        return Ok(vec![]);
+    };
+    bindings
+        .get_all(cst)?
+        .into_iter()
+        .map(|binding| {
+            let binding = binding.get_view(cst)?;
+            match binding.binding_rhs.get_view(cst)? {
+                BindingRhsView::ValueBinding(rhs) => Ok((binding.keys, rhs.get_view(cst)?.value)),
+                BindingRhsView::SectionBinding(_) | BindingRhsView::TextBinding(_) => {
+                    Err(NotCanonical)
+                }
+            }
+        })
+        .collect()
+}
+
+/// The `k => v` entries of an inline object.
+fn object_entries(
+    cst: &Cst,
+    object: ObjectHandle,
+) -> Result<Vec<(KeysHandle, ValueHandle)>, NotCanonical> {
+    let object = object.get_view(cst)?;
+    if object.object_opt.get_view(cst)?.is_some() {
+        return Err(NotCanonical);
+    }
+    let Some(list) = object.object_list.get_view(cst)? else {
+        return Ok(vec![]);
+    };
+    Ok(list
+        .get_all(cst)?
+        .into_iter()
+        .map(|item| (item.keys, item.value))
+        .collect())
+}
+
+fn array_values(cst: &Cst, array: ArrayHandle) -> Result<Vec<ValueHandle>, CstConstructError> {
+    let mut values = vec![];
+    let mut next = array.get_view(cst)?.array_opt.get_view(cst)?;
+    while let Some(elements) = next {
+        let elements = elements.get_view(cst)?;
+        values.push(elements.value);
+        next = match elements.array_elements_opt.get_view(cst)? {
+            Some(tail) => tail.get_view(cst)?.array_elements_tail_opt.get_view(cst)?,
+            None => None,
+        };
+    }
+    Ok(values)
+}
+
+fn tuple_values(cst: &Cst, tuple: TupleHandle) -> Result<Vec<ValueHandle>, CstConstructError> {
+    let mut values = vec![];
+    let mut next = tuple.get_view(cst)?.tuple_opt.get_view(cst)?;
+    while let Some(elements) = next {
+        let elements = elements.get_view(cst)?;
+        values.push(elements.value);
+        next = match elements.tuple_elements_opt.get_view(cst)? {
+            Some(tail) => tail.get_view(cst)?.tuple_elements_tail_opt.get_view(cst)?,
+            None => None,
+        };
+    }
+    Ok(values)
+}
+
+/// The text of a key that is a single identifier or a `"..."` string without escapes.
+fn simple_key<'a>(input: &'a str, cst: &'a Cst, keys: KeysHandle) -> Result<&'a str, NotCanonical> {
+    let keys = keys.get_view(cst)?;
+    if keys.keys_list.get_view(cst)?.is_some() {
+        return Err(NotCanonical);
+    }
+    let FirstKeyView::Key(key) = keys.first_key.get_view(cst)? else {
+        return Err(NotCanonical);
+    };
+    match key.get_view(cst)? {
+        KeyView::KeyIdent(ident) => match ident.get_view(cst)? {
+            KeyIdentView::Ident(h) => terminal_str(input, cst, h.get_view(cst)?.ident),
+            KeyIdentView::True(h) => terminal_str(input, cst, h.get_view(cst)?.r#true),
+            KeyIdentView::False(h) => terminal_str(input, cst, h.get_view(cst)?.r#false),
+            KeyIdentView::Null(h) => terminal_str(input, cst, h.get_view(cst)?.r#null),
+        },
+        KeyView::String(string) => {
+            let StringView::Str(h) = string.get_view(cst)? else {
+                return Err(NotCanonical);
+            };
+            let token = terminal_str(input, cst, h.get_view(cst)?.str)?;
+            let content = &token[1..token.len() - 1];
+            if content.contains('\\') {
+                return Err(NotCanonical);
+            }
+            Ok(content)
+        }
+        _ => Err(NotCanonical),
+    }
+}
+
+/// A string value without continuations.
+enum SingleString {
+    /// `"..."`.
+    Escaped(StrHandle),
+    /// `'...'`.
+    Literal(LitStrHandle),
+}
+
+fn single_string(cst: &Cst, strings: StringsHandle) -> Result<SingleString, NotCanonical> {
+    let strings = strings.get_view(cst)?;
+    if strings.strings_list.get_view(cst)?.is_some() {
+        return Err(NotCanonical);
+    }
+    match strings.string.get_view(cst)? {
+        StringView::Str(h) => Ok(SingleString::Escaped(h)),
+        StringView::LitStr(h) => Ok(SingleString::Literal(h)),
+        _ => Err(NotCanonical),
+    }
+}
+
+fn terminal_str<'a, T: TerminalHandle>(
+    input: &'a str,
+    cst: &'a Cst,
+    handle: T,
+) -> Result<&'a str, NotCanonical> {
+    cst.get_terminal_str(input, handle)?
+        .map_err(|_| NotCanonical)
+}
+
+/// Deserializes a canonical document from its CST.
+///
+/// Must only be used on documents accepted by [`is_canonical`].
+#[derive(Clone, Copy)]
+pub(crate) struct CstDeserializer<'a> {
+    input: &'a str,
+    cst: &'a Cst,
+    /// The value to read, or `None` for the root bindings.
+    value: Option<ValueHandle>,
+}
+
+impl<'a> CstDeserializer<'a> {
+    pub(crate) fn root(input: &'a str, cst: &'a Cst) -> Self {
+        Self {
+            input,
+            cst,
+            value: None,
+        }
+    }
+
+    fn child(self, value: ValueHandle) -> Self {
+        Self {
+            value: Some(value),
+            ..self
+        }
+    }
+
+    fn entries(
+        self,
+        entries: Vec<(KeysHandle, ValueHandle)>,
+    ) -> Result<Vec<(&'a str, Self)>, DeError> {
+        entries
+            .into_iter()
+            .map(|(keys, value)| {
+                let key =
+                    simple_key(self.input, self.cst, keys).map_err(|_| DeError::NotCanonical)?;
+                Ok((key, self.child(value)))
+            })
+            .collect()
+    }
+
+    fn string(self, strings: StringsHandle) -> Result<String, DeError> {
+        let string = single_string(self.cst, strings).map_err(|_| DeError::NotCanonical)?;
+        match string {
+            SingleString::Escaped(h) => {
+                let token = self.token(h.get_view(self.cst)?.str)?;
+                unescape(&token[1..token.len() - 1])
+                    .map_err(|error| DeError::Custom(error.to_string()))
+            }
+            SingleString::Literal(h) => {
+                let token = self.token(h.get_view(self.cst)?.lit_str)?;
+                Ok(token[1..token.len() - 1].to_string())
+            }
+        }
+    }
+
+    fn token<T: TerminalHandle>(self, handle: T) -> Result<&'a str, DeError> {
+        terminal_str(self.input, self.cst, handle).map_err(|_| DeError::NotCanonical)
+    }
+}
+
+impl<'de> de::Deserializer<'de> for CstDeserializer<'_> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        let cst = self.cst;
+        let Some(value) = self.value else {
+            let entries = root_entries(cst).map_err(|_| DeError::NotCanonical)?;
+            return visit_map(self.entries(entries)?.into_iter(), visitor);
+        };
+        match value.get_view(cst)? {
+            ValueView::Null(_) => visitor.visit_unit(),
+            ValueView::Boolean(boolean) => match boolean.get_view(cst)? {
+                BooleanView::True(_) => visitor.visit_bool(true),
+                BooleanView::False(_) => visitor.visit_bool(false),
+            },
+            ValueView::Number(number) => match number.get_view(cst)? {
+                NumberView::Integer(integer) => {
+                    let token = self.token(integer.get_view(cst)?.integer)?;
+                    let value: BigInt = token
+                        .replace('_', "")
+                        .parse()
+                        .map_err(|_| DeError::Custom(format!("invalid integer: {token}")))?;
+                    visit_integer(&value, visitor)
+                }
+                NumberView::Float(float) => {
+                    let token = self.token(float.get_view(cst)?.float)?;
+                    let invalid = || DeError::Custom(format!("invalid float: {token}"));
+                    if let Some(number) = token.strip_suffix("f32") {
+                        let value = number.replace('_', "").parse().map_err(|_| invalid())?;
+                        visitor.visit_f32(value)
+                    } else {
+                        let number = token.strip_suffix("f64").unwrap_or(token);
+                        let value = number.replace('_', "").parse().map_err(|_| invalid())?;
+                        visitor.visit_f64(value)
+                    }
+                }
+                NumberView::Inf(_) | NumberView::NaN(_) => Err(DeError::NotCanonical),
+            },
+            ValueView::Strings(strings) => visitor.visit_string(self.string(strings)?),
+            ValueView::Array(array) => visit_seq(
+                array_values(cst, array)?
+                    .into_iter()
+                    .map(|value| self.child(value)),
+                visitor,
+            ),
+            ValueView::Tuple(tuple) => visit_seq(
+                tuple_values(cst, tuple)?
+                    .into_iter()
+                    .map(|value| self.child(value)),
+                visitor,
+            ),
+            ValueView::Object(object) => {
+                let entries = object_entries(cst, object).map_err(|_| DeError::NotCanonical)?;
+                visit_map(self.entries(entries)?.into_iter(), visitor)
+            }
+            ValueView::Hole(_) | ValueView::CodeBlock(_) | ValueView::InlineCode(_) => {
+                Err(DeError::NotCanonical)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self
+            .value
+            .map(|value| value.get_view(self.cst))
+            .transpose()?
+        {
+            Some(ValueView::Null(_)) => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Variants are represented externally, as in the document IR path.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        let cst = self.cst;
+        let view = self.value.map(|value| value.get_view(cst)).transpose()?;
+        match view {
+            Some(ValueView::Strings(strings)) => {
+                visitor.visit_enum(self.string(strings)?.into_deserializer())
+            }
+            Some(ValueView::Object(object)) => {
+                let entries = object_entries(cst, object).map_err(|_| DeError::NotCanonical)?;
+                match <[_; 1]>::try_from(self.entries(entries)?) {
+                    Ok([(variant, content)]) => visit_external_enum(variant, content, visitor),
+                    Err(_) => Err(DeError::TypeMismatch {
+                        expected: "variant",
+                        actual: "map".to_string(),
+                    }),
+                }
+            }
+            _ => Err(DeError::TypeMismatch {
+                expected: "variant",
+                actual: "non-variant value".to_string(),
+            }),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, DeError> for CstDeserializer<'_> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
@@ -0,0 +1,696 @@
+use eure::document::EureDocument;
+use eure::document::constructor::{DocumentConstructor, Scope};
+use eure::document::node::NodeValue;
+use eure::document::path::{ArrayIndexKind, PathSegment};
+use eure::value::{ObjectKey, PrimitiveValue, Text, Tuple};
+use eure_schema::interop::VariantRepr;
+use num_bigint::BigInt;
+use serde::Serialize;
+use serde::ser::{
+    Impossible, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant,
+    SerializeTuple, SerializeTupleStruct, SerializeTupleVariant,
+};
+
+use crate::error::SerError;
+
+/// Options for [`to_document`].
+#[derive(Debug, Clone, Default)]
+pub struct ToDocumentConfig {
+    /// How enum variants are laid out in the document.
+    ///
+    /// `None` (the default) uses Eure's own union semantics: the variant
+    /// content is written in place and its name is recorded in a `$variant`
+    /// extension. `Some(repr)` writes the variant in the given wire shape
+    /// instead, as `eure-json` would.
+    pub variant_repr: Option<VariantRepr>,
+}
+
+/// Build an [`EureDocument`] directly from a `Serialize` value.
+///
+/// Integers of any width become `BigInt`s, sequences become arrays, tuples
+/// and tuple structs become tuples, and structs and maps become maps.
+/// Options are written as their content or `null`.
+pub fn to_document<T: Serialize + ?Sized>(
+    value: &T,
+    config: &ToDocumentConfig,
+) -> Result<EureDocument, SerError> {
+    let mut constructor = DocumentConstructor::new();
+    value.serialize(DocumentSerializer {
+        constructor: &mut constructor,
+        config,
+    })?;
+    Ok(constructor.finish())
+}
+
+struct DocumentSerializer<'a> {
+    constructor: &'a mut DocumentConstructor,
+    config: &'a ToDocumentConfig,
+}
+
+impl DocumentSerializer<'_> {
+    fn reborrow(&mut self) -> DocumentSerializer<'_> {
+        DocumentSerializer {
+            constructor: self.constructor,
+            config: self.config,
+        }
+    }
+
+    fn bind(&mut self, value: PrimitiveValue) -> Result<(), SerError> {
+        self.constructor.bind_primitive(value)?;
+        Ok(())
+    }
+
+    fn serialize_at<T: Serialize + ?Sized>(
+        &mut self,
+        segment: PathSegment,
+        value: &T,
+    ) -> Result<(), SerError> {
+        let scope = self.constructor.begin_scope();
+        self.constructor.navigate(segment)?;
+        value.serialize(self.reborrow())?;
+        self.constructor.end_scope(scope)?;
+        Ok(())
+    }
+
+    /// Record `variant` for a newtype, tuple or struct variant and move to the
+    /// node its content belongs in. The returned scope, if any, must be closed
+    /// once the content is written.
+    fn enter_variant(&mut self, variant: &'static str) -> Result<Option<Scope>, SerError> {
+        let config = self.config;
+        match &config.variant_repr {
+            None => {
+                self.constructor.set_variant(variant)?;
+                Ok(None)
+            }
+            Some(VariantRepr::External) => {
+                self.constructor.bind_empty_map()?;
+                let scope = self.constructor.begin_scope();
+                self.constructor.navigate(field_segment(variant))?;
+                Ok(Some(scope))
+            }
+            Some(VariantRepr::Adjacent { tag, content }) => {
+                self.constructor.bind_empty_map()?;
+                self.serialize_at(field_segment(tag), variant)?;
+                let scope = self.constructor.begin_scope();
+                self.constructor.navigate(field_segment(content))?;
+                Ok(Some(scope))
+            }
+            Some(VariantRepr::Internal { .. }) => Err(SerError::InternalTagNotMap(variant)),
+            Some(VariantRepr::Untagged) => Ok(None),
+        }
+    }
+
+    fn exit_variant(&mut self, scope: Option<Scope>) -> Result<(), SerError> {
+        if let Some(scope) = scope {
+            self.constructor.end_scope(scope)?;
+        }
+        Ok(())
+    }
+}
+
+fn field_segment(name: &str) -> PathSegment {
+    PathSegment::Value(ObjectKey::String(name.to_string()))
+}
+
+impl<'a> serde::Serializer for DocumentSerializer<'a> {
+    type Ok = ();
+    type Error = SerError;
+    type SerializeSeq = SeqSerializer<'a>;
+    type SerializeTuple = SeqSerializer<'a>;
+    type SerializeTupleStruct = SeqSerializer<'a>;
+    type SerializeTupleVariant = SeqSerializer<'a>;
+    type SerializeMap = MapSerializer<'a>;
+    type SerializeStruct = MapSerializer<'a>;
+    type SerializeStructVariant = MapSerializer<'a>;
+
+    fn serialize_bool(mut self, v: bool) -> Result<(), SerError> {
+        self.bind(PrimitiveValue::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), SerError> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), SerError> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), SerError> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i64(mut self, v: i64) -> Result<(), SerError> {
+        self.bind(PrimitiveValue::Integer(BigInt::from(v)))
+    }
+
+    fn serialize_i128(mut self, v: i128) -> Result<(), SerError> {
+        self.bind(PrimitiveValue::Integer(BigInt::from(v)))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), SerError> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), SerError> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), SerError> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u64(mut self, v: u64) -> Result<(), SerError> {
+        self.bind(PrimitiveValue::Integer(BigInt::from(v)))
+    }
+
+    fn serialize_u128(mut self, v: u128) -> Result<(), SerError> {
+        self.bind(PrimitiveValue::Integer(BigInt::from(v)))
+    }
+
+    fn serialize_f32(mut self, v: f32) -> Result<(), SerError> {
+        self.bind(PrimitiveValue::F32(v))
+    }
+
+    fn serialize_f64(mut self, v: f64) -> Result<(), SerError> {
+        self.bind(PrimitiveValue::F64(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), SerError> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(mut self, v: &str) -> Result<(), SerError> {
+        self.bind(PrimitiveValue::Text(Text::plaintext(v.to_string())))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), SerError> {
+        let mut seq = self.serialize_seq(Some(v.len()))?;
+        for byte in v {
+            SerializeSeq::serialize_element(&mut seq, byte)?;
+        }
+        SerializeSeq::end(seq)
+    }
+
+    fn serialize_none(self) -> Result<(), SerError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), SerError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(mut self) -> Result<(), SerError> {
+        self.bind(PrimitiveValue::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), SerError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        mut self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), SerError> {
+        let config = self.config;
+        match &config.variant_repr {
+            None => {
+                self.constructor.set_variant(variant)?;
+                self.bind(PrimitiveValue::Null)
+            }
+            Some(VariantRepr::External) => self.serialize_str(variant),
+            Some(VariantRepr::Internal { tag } | VariantRepr::Adjacent { tag, .. }) => {
+                self.constructor.bind_empty_map()?;
+                self.serialize_at(field_segment(tag), variant)
+            }
+            Some(VariantRepr::Untagged) => self.serialize_unit(),
+        }
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), SerError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        mut self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), SerError> {
+        let config = self.config;
+        if let Some(VariantRepr::Internal { tag }) = &config.variant_repr {
+            value.serialize(self.reborrow())?;
+            if !matches!(self.constructor.current_node().content, NodeValue::Map(_)) {
+                return Err(SerError::InternalTagNotMap(variant));
+            }
+            return self.serialize_at(field_segment(tag), variant);
+        }
+        let scope = self.enter_variant(variant)?;
+        value.serialize(self.reborrow())?;
+        self.exit_variant(scope)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<SeqSerializer<'a>, SerError> {
+        self.constructor.bind_empty_array()?;
+        Ok(SeqSerializer {
+            ser: self,
+            tuple_len: None,
+            variant_scope: None,
+        })
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<SeqSerializer<'a>, SerError> {
+        self.constructor.bind_empty_tuple()?;
+        Ok(SeqSerializer {
+            ser: self,
+            tuple_len: Some(0),
+            variant_scope: None,
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer<'a>, SerError> {
+        self.serialize_tuple(len)
+    }
+
+    fn serialize_tuple_variant(
+        mut self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SeqSerializer<'a>, SerError> {
+        let variant_scope = self.enter_variant(variant)?;
+        self.constructor.bind_empty_tuple()?;
+        Ok(SeqSerializer {
+            ser: self,
+            tuple_len: Some(0),
+            variant_scope,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapSerializer<'a>, SerError> {
+        self.constructor.bind_empty_map()?;
+        Ok(MapSerializer {
+            ser: self,
+            pending_key: None,
+            variant_scope: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<MapSerializer<'a>, SerError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        mut self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<MapSerializer<'a>, SerError> {
+        let config = self.config;
+        let variant_scope = if let Some(VariantRepr::Internal { tag }) = &config.variant_repr {
+            self.constructor.bind_empty_map()?;
+            self.serialize_at(field_segment(tag), variant)?;
+            None
+        } else {
+            let scope = self.enter_variant(variant)?;
+            self.constructor.bind_empty_map()?;
+            scope
+        };
+        Ok(MapSerializer {
+            ser: self,
+            pending_key: None,
+            variant_scope,
+        })
+    }
+}
+
+struct SeqSerializer<'a> {
+    ser: DocumentSerializer<'a>,
+    /// Number of elements written so far when building a tuple, `None` for
+    /// an array.
+    tuple_len: Option<usize>,
+    variant_scope: Option<Scope>,
+}
+
+impl SeqSerializer<'_> {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        let segment = match &mut self.tuple_len {
+            None => PathSegment::ArrayIndex(ArrayIndexKind::Push),
+            Some(len) => {
+                let index = u8::try_from(*len).map_err(|_| SerError::TupleTooLong)?;
+                *len += 1;
+                PathSegment::TupleIndex(index)
+            }
+        };
+        self.ser.serialize_at(segment, value)
+    }
+
+    fn finish(mut self) -> Result<(), SerError> {
+        let scope = self.variant_scope.take();
+        self.ser.exit_variant(scope)
+    }
+}
+
+impl SerializeSeq for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        self.finish()
+    }
+}
+
+impl SerializeTuple for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        self.finish()
+    }
+}
+
+impl SerializeTupleStruct for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        self.finish()
+    }
+}
+
+impl SerializeTupleVariant for SeqSerializer<'_> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        self.finish()
+    }
+}
+
+struct MapSerializer<'a> {
+    ser: DocumentSerializer<'a>,
+    pending_key: Option<ObjectKey>,
+    variant_scope: Option<Scope>,
+}
+
+impl MapSerializer<'_> {
+    fn finish(mut self) -> Result<(), SerError> {
+        let scope = self.variant_scope.take();
+        self.ser.exit_variant(scope)
+    }
+}
+
+impl SerializeMap for MapSerializer<'_> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), SerError> {
+        self.pending_key = Some(key.serialize(ObjectKeySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        let key = self.pending_key.take().ok_or(SerError::ValueBeforeKey)?;
+        self.ser.serialize_at(PathSegment::Value(key), value)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        self.finish()
+    }
+}
+
+impl SerializeStruct for MapSerializer<'_> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerError> {
+        self.ser.serialize_at(field_segment(key), value)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        self.finish()
+    }
+}
+
+impl SerializeStructVariant for MapSerializer<'_> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerError> {
+        self.ser.serialize_at(field_segment(key), value)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        self.finish()
+    }
+}
+
+/// Serializes map keys into [`ObjectKey`]s: strings, integers, unit variants
+/// (by name) and tuples of those.
+struct ObjectKeySerializer;
+
+impl ObjectKeySerializer {
+    fn unsupported(kind: &'static str) -> SerError {
+        SerError::UnsupportedKey(kind)
+    }
+}
+
+impl serde::Serializer for ObjectKeySerializer {
+    type Ok = ObjectKey;
+    type Error = SerError;
+    type SerializeSeq = Impossible<ObjectKey, SerError>;
+    type SerializeTuple = TupleKeySerializer;
+    type SerializeTupleStruct = TupleKeySerializer;
+    type SerializeTupleVariant = Impossible<ObjectKey, SerError>;
+    type SerializeMap = Impossible<ObjectKey, SerError>;
+    type SerializeStruct = Impossible<ObjectKey, SerError>;
+    type SerializeStructVariant = Impossible<ObjectKey, SerError>;
+
+    fn serialize_bool(self, _v: bool) -> Result<ObjectKey, SerError> {
+        Err(Self::unsupported("bool"))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<ObjectKey, SerError> {
+        Ok(ObjectKey::Number(BigInt::from(v)))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<ObjectKey, SerError> {
+        Ok(ObjectKey::Number(BigInt::from(v)))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<ObjectKey, SerError> {
+        Ok(ObjectKey::Number(BigInt::from(v)))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<ObjectKey, SerError> {
+        Ok(ObjectKey::Number(BigInt::from(v)))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<ObjectKey, SerError> {
+        Ok(ObjectKey::Number(BigInt::from(v)))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<ObjectKey, SerError> {
+        Ok(ObjectKey::Number(BigInt::from(v)))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<ObjectKey, SerError> {
+        Ok(ObjectKey::Number(BigInt::from(v)))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<ObjectKey, SerError> {
+        Ok(ObjectKey::Number(BigInt::from(v)))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<ObjectKey, SerError> {
+        Ok(ObjectKey::Number(BigInt::from(v)))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<ObjectKey, SerError> {
+        Ok(ObjectKey::Number(BigInt::from(v)))
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<ObjectKey, SerError> {
+        Err(Self::unsupported("float"))
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<ObjectKey, SerError> {
+        Err(Self::unsupported("float"))
+    }
+
+    fn serialize_char(self, v: char) -> Result<ObjectKey, SerError> {
+        Ok(ObjectKey::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<ObjectKey, SerError> {
+        Ok(ObjectKey::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<ObjectKey, SerError> {
+        Err(Self::unsupported("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<ObjectKey, SerError> {
+        Err(Self::unsupported("none"))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<ObjectKey, SerError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<ObjectKey, SerError> {
+        Err(Self::unsupported("unit"))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<ObjectKey, SerError> {
+        Err(Self::unsupported("unit struct"))
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<ObjectKey, SerError> {
+        Ok(ObjectKey::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<ObjectKey, SerError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<ObjectKey, SerError> {
+        Err(Self::unsupported("newtype variant"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, SerError> {
+        Err(Self::unsupported("sequence"))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<TupleKeySerializer, SerError> {
+        Ok(TupleKeySerializer {
+            items: Vec::with_capacity(len),
+        })
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<TupleKeySerializer, SerError> {
+        self.serialize_tuple(len)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, SerError> {
+        Err(Self::unsupported("tuple variant"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, SerError> {
+        Err(Self::unsupported("map"))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, SerError> {
+        Err(Self::unsupported("struct"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, SerError> {
+        Err(Self::unsupported("struct variant"))
+    }
+}
+
+struct TupleKeySerializer {
+    items: Vec<ObjectKey>,
+}
+
+impl SerializeTuple for TupleKeySerializer {
+    type Ok = ObjectKey;
+    type Error = SerError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        self.items.push(value.serialize(ObjectKeySerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<ObjectKey, SerError> {
+        Ok(ObjectKey::Tuple(Tuple(self.items)))
+    }
+}
+
+impl SerializeTupleStruct for TupleKeySerializer {
+    type Ok = ObjectKey;
+    type Error = SerError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        SerializeTuple::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<ObjectKey, SerError> {
+        SerializeTuple::end(self)
+    }
+}
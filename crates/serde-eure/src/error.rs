@@ -1,8 +1,10 @@
 use std::fmt;
 
-use eure::document::InsertError;
 use eure::document::constructor::ScopeError;
 use eure::document::write::WriteError;
+use eure::document::{DocumentConstructionError, InsertError};
+use eure_parol::EureParseError;
+use eure_tree::prelude::CstConstructError;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -26,12 +28,22 @@ pub enum DeError {
     InvalidVariantName(String),
     #[error("PartialMap unsupported in serde-eure v1")]
     PartialMapUnsupported,
+    #[error("hole node cannot be deserialized")]
+    UnexpectedHole,
+    #[error("document is not canonical")]
+    NotCanonical,
     #[error(transparent)]
     Write(#[from] WriteError),
     #[error(transparent)]
     Insert(#[from] InsertError),
     #[error(transparent)]
     Scope(#[from] ScopeError),
+    #[error(transparent)]
+    Parse(#[from] EureParseError),
+    #[error(transparent)]
+    Document(#[from] DocumentConstructionError),
+    #[error(transparent)]
+    Cst(#[from] CstConstructError),
 }
 
 impl serde::de::Error for DeError {
@@ -56,6 +68,20 @@ pub enum SerError {
     BigIntOutOfRange,
     #[error("non-finite float")]
     NonFiniteFloat,
+    #[error("unsupported map key: {0}")]
+    UnsupportedKey(&'static str),
+    #[error("internally tagged variant `{0}` must serialize as a map")]
+    InternalTagNotMap(&'static str),
+    #[error("tuple has more than 256 elements")]
+    TupleTooLong,
+    #[error("map value serialized before its key")]
+    ValueBeforeKey,
+    #[error(transparent)]
+    Write(#[from] WriteError),
+    #[error(transparent)]
+    Insert(#[from] InsertError),
+    #[error(transparent)]
+    Scope(#[from] ScopeError),
 }
 
 impl serde::ser::Error for SerError {
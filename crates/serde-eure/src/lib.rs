@@ -1,9 +1,15 @@
+mod canonical;
 mod de;
+mod document;
 mod error;
 mod ser;
+mod source;
 #[cfg(test)]
 mod tests;
 
+pub use canonical::is_canonical;
 pub use de::from_deserializer;
+pub use document::{ToDocumentConfig, to_document};
 pub use error::{DeError, SerError};
 pub use ser::{to_serializer, to_serializer_root};
+pub use source::{Deserializer, from_str};
@@ -1244,7 +1244,7 @@ fn resolve_schema_node_id(
     ))
 }
 
-fn node_value_type(value: &NodeValue) -> &'static str {
+pub(crate) fn node_value_type(value: &NodeValue) -> &'static str {
     match value {
         NodeValue::Hole(_) => "hole",
         NodeValue::Primitive(PrimitiveValue::Null) => "null",
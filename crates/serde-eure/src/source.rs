@@ -0,0 +1,304 @@
+//! Deserializing Rust values from Eure source text.
+//!
+//! [`Deserializer::from_str`] always builds the [`EureDocument`] IR and reads
+//! values from it. [`Deserializer::from_str_canonical_fast`] reads canonical
+//! documents (see [`is_canonical`](crate::is_canonical)) straight from the CST
+//! instead, and falls back to the IR for everything else.
+
+use eure::document::node::NodeValue;
+use eure::document::{EureDocument, NodeId, cst_to_document};
+use eure::value::{ObjectKey, PrimitiveValue};
+use eure_tree::prelude::Cst;
+use num_bigint::BigInt;
+use serde::de::value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer};
+use serde::de::{self, DeserializeOwned, IntoDeserializer, Visitor};
+
+use crate::canonical::{CstDeserializer, is_canonical};
+use crate::error::DeError;
+use crate::ser::node_value_type;
+
+/// A serde deserializer over a parsed Eure document.
+pub struct Deserializer<'de> {
+    input: &'de str,
+    cst: Cst,
+    /// The document IR, or `None` when the canonical fast path is used.
+    document: Option<EureDocument>,
+}
+
+impl<'de> Deserializer<'de> {
+    /// Parse `input` and build its [`EureDocument`].
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(input: &'de str) -> Result<Self, DeError> {
+        let cst = eure_parol::parse(input, "<input>")?;
+        let document = cst_to_document(input, &cst)?;
+        Ok(Self {
+            input,
+            cst,
+            document: Some(document),
+        })
+    }
+
+    /// Parse `input`, skipping [`EureDocument`] construction if the document is
+    /// canonical.
+    ///
+    /// Deserialized values are identical to those of [`Deserializer::from_str`].
+    pub fn from_str_canonical_fast(input: &'de str) -> Result<Self, DeError> {
+        let cst = eure_parol::parse(input, "<input>")?;
+        let document = if is_canonical(input, &cst) {
+            None
+        } else {
+            Some(cst_to_document(input, &cst)?)
+        };
+        Ok(Self {
+            input,
+            cst,
+            document,
+        })
+    }
+
+    /// Whether values are read straight from the CST.
+    pub fn is_fast_path(&self) -> bool {
+        self.document.is_none()
+    }
+}
+
+/// Deserialize a value from Eure source text, using the canonical fast path
+/// when possible.
+pub fn from_str<T: DeserializeOwned>(input: &str) -> Result<T, DeError> {
+    T::deserialize(&Deserializer::from_str_canonical_fast(input)?)
+}
+
+impl<'de> de::Deserializer<'de> for &Deserializer<'_> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match &self.document {
+            Some(doc) => NodeDeserializer::root(doc).deserialize_any(visitor),
+            None => CstDeserializer::root(self.input, &self.cst).deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match &self.document {
+            Some(doc) => NodeDeserializer::root(doc).deserialize_option(visitor),
+            None => CstDeserializer::root(self.input, &self.cst).deserialize_option(visitor),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        match &self.document {
+            Some(doc) => NodeDeserializer::root(doc).deserialize_enum(name, variants, visitor),
+            None => CstDeserializer::root(self.input, &self.cst)
+                .deserialize_enum(name, variants, visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+/// Deserializes a node of an [`EureDocument`].
+#[derive(Clone, Copy)]
+struct NodeDeserializer<'a> {
+    doc: &'a EureDocument,
+    node_id: NodeId,
+}
+
+impl<'a> NodeDeserializer<'a> {
+    fn root(doc: &'a EureDocument) -> Self {
+        Self {
+            doc,
+            node_id: doc.get_root_id(),
+        }
+    }
+
+    fn child(self, node_id: NodeId) -> Self {
+        Self { node_id, ..self }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for NodeDeserializer<'_> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match &self.doc.node(self.node_id).content {
+            NodeValue::Hole(_) => Err(DeError::UnexpectedHole),
+            NodeValue::Primitive(PrimitiveValue::Null) => visitor.visit_unit(),
+            NodeValue::Primitive(PrimitiveValue::Bool(value)) => visitor.visit_bool(*value),
+            NodeValue::Primitive(PrimitiveValue::Integer(value)) => visit_integer(value, visitor),
+            NodeValue::Primitive(PrimitiveValue::F32(value)) => visitor.visit_f32(*value),
+            NodeValue::Primitive(PrimitiveValue::F64(value)) => visitor.visit_f64(*value),
+            NodeValue::Primitive(PrimitiveValue::Text(text)) => visitor.visit_str(&text.content),
+            NodeValue::Array(array) => {
+                visit_seq(array.iter().map(|&child| self.child(child)), visitor)
+            }
+            NodeValue::Tuple(tuple) => {
+                visit_seq(tuple.iter().map(|&child| self.child(child)), visitor)
+            }
+            NodeValue::Map(map) => visit_map(
+                map.iter()
+                    .map(|(key, &child)| (KeyDeserializer(key), self.child(child))),
+                visitor,
+            ),
+            NodeValue::PartialMap(_) => Err(DeError::PartialMapUnsupported),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match &self.doc.node(self.node_id).content {
+            NodeValue::Primitive(PrimitiveValue::Null) => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Variants are represented externally: a text names a unit variant, and
+    /// a single-entry map holds the variant name and its content.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        match &self.doc.node(self.node_id).content {
+            NodeValue::Primitive(PrimitiveValue::Text(text)) => {
+                visitor.visit_enum(text.content.as_str().into_deserializer())
+            }
+            NodeValue::Map(map) if map.len() == 1 => {
+                let (key, &child) = map.iter().next().expect("map has one entry");
+                visit_external_enum(KeyDeserializer(key), self.child(child), visitor)
+            }
+            other => Err(DeError::TypeMismatch {
+                expected: "variant",
+                actual: node_value_type(other).to_string(),
+            }),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, DeError> for NodeDeserializer<'_> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+/// Deserializes a map key of an [`EureDocument`].
+#[derive(Clone, Copy)]
+struct KeyDeserializer<'a>(&'a ObjectKey);
+
+impl<'de> de::Deserializer<'de> for KeyDeserializer<'_> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.0 {
+            ObjectKey::String(key) => visitor.visit_str(key),
+            ObjectKey::Number(key) => visit_integer(key, visitor),
+            ObjectKey::Tuple(tuple) => visit_seq(tuple.0.iter().map(KeyDeserializer), visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, DeError> for KeyDeserializer<'_> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+/// Visit an integer with the narrowest of `i64`, `u64`, `i128` and `u128` that holds it.
+pub(crate) fn visit_integer<'de, V: Visitor<'de>>(
+    value: &BigInt,
+    visitor: V,
+) -> Result<V::Value, DeError> {
+    if let Ok(value) = i64::try_from(value) {
+        visitor.visit_i64(value)
+    } else if let Ok(value) = u64::try_from(value) {
+        visitor.visit_u64(value)
+    } else if let Ok(value) = i128::try_from(value) {
+        visitor.visit_i128(value)
+    } else if let Ok(value) = u128::try_from(value) {
+        visitor.visit_u128(value)
+    } else {
+        Err(DeError::IntOutOfRange)
+    }
+}
+
+pub(crate) fn visit_seq<'de, I, V>(items: I, visitor: V) -> Result<V::Value, DeError>
+where
+    I: Iterator,
+    I::Item: IntoDeserializer<'de, DeError>,
+    V: Visitor<'de>,
+{
+    let mut seq = SeqDeserializer::new(items);
+    let value = visitor.visit_seq(&mut seq)?;
+    seq.end()?;
+    Ok(value)
+}
+
+pub(crate) fn visit_map<'de, I, K, D, V>(entries: I, visitor: V) -> Result<V::Value, DeError>
+where
+    I: Iterator<Item = (K, D)>,
+    K: IntoDeserializer<'de, DeError>,
+    D: IntoDeserializer<'de, DeError>,
+    V: Visitor<'de>,
+{
+    let mut map = MapDeserializer::new(entries);
+    let value = visitor.visit_map(&mut map)?;
+    map.end()?;
+    Ok(value)
+}
+
+/// Visit the enum variant `variant` whose content is `content`.
+pub(crate) fn visit_external_enum<'de, K, D, V>(
+    variant: K,
+    content: D,
+    visitor: V,
+) -> Result<V::Value, DeError>
+where
+    K: IntoDeserializer<'de, DeError>,
+    D: IntoDeserializer<'de, DeError>,
+    V: Visitor<'de>,
+{
+    let mut map = MapDeserializer::new(std::iter::once((variant, content)));
+    let value = visitor.visit_enum(MapAccessDeserializer::new(&mut map))?;
+    map.end()?;
+    Ok(value)
+}
@@ -5,6 +5,7 @@
 //!   union (External / Internal / Adjacent / Untagged), Any/schema-free
 //! - Ser: all primitive types, array, tuple, map, record, union (all 4 modes)
 //! - Roundtrip: ser → JSON string → de → compare original document
+//! - to_document: structs, wide integers, enums under each variant representation
 
 use eure::document::EureDocument;
 use eure::document::constructor::DocumentConstructor;
@@ -1845,3 +1846,336 @@ mod de_fixes {
         assert_eq!(actual, eure!({ value = 7.0, %variant = "measure" }));
     }
 }
+
+// ============================================================================
+// to_document tests: Serialize value → EureDocument
+// ============================================================================
+
+mod to_document {
+    use std::collections::BTreeMap;
+
+    use serde::Serialize;
+
+    use super::*;
+    use crate::{SerError, ToDocumentConfig, to_document};
+
+    #[derive(Serialize)]
+    struct Server {
+        name: String,
+        port: u16,
+        tags: Vec<String>,
+        pair: (i32, bool),
+        limits: BTreeMap<String, i64>,
+        parent: Option<String>,
+    }
+
+    #[derive(Serialize)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Pair(i32, i32),
+        Rect { w: i32, h: i32 },
+    }
+
+    #[derive(Serialize)]
+    struct Scene {
+        point: Shape,
+        circle: Shape,
+        pair: Shape,
+        rect: Shape,
+    }
+
+    fn scene() -> Scene {
+        Scene {
+            point: Shape::Point,
+            circle: Shape::Circle(1.5),
+            pair: Shape::Pair(1, 2),
+            rect: Shape::Rect { w: 2, h: 3 },
+        }
+    }
+
+    fn config(variant_repr: VariantRepr) -> ToDocumentConfig {
+        ToDocumentConfig {
+            variant_repr: Some(variant_repr),
+        }
+    }
+
+    #[test]
+    fn to_document_struct() {
+        let server = Server {
+            name: "api".to_string(),
+            port: 8080,
+            tags: vec!["a".to_string(), "b".to_string()],
+            pair: (7, true),
+            limits: [("cpu".to_string(), 4)].into_iter().collect(),
+            parent: None,
+        };
+        let actual = to_document(&server, &ToDocumentConfig::default()).unwrap();
+        let expected = eure!({
+            name = "api",
+            port = 8080,
+            tags = ["a", "b"],
+            pair = (7, true),
+            limits.cpu = 4,
+            parent = null,
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn to_document_preserves_wide_integers() {
+        let actual = to_document(&u128::MAX, &ToDocumentConfig::default()).unwrap();
+        assert_eq!(actual, bigint_doc(BigInt::from(u128::MAX)));
+        let actual = to_document(&i128::MIN, &ToDocumentConfig::default()).unwrap();
+        assert_eq!(actual, bigint_doc(BigInt::from(i128::MIN)));
+    }
+
+    #[test]
+    fn to_document_enum_uses_variant_extension() {
+        let actual = to_document(&scene(), &ToDocumentConfig::default()).unwrap();
+        let expected = eure!({
+            point = null,
+            point.%variant = "Point",
+            circle = 1.5,
+            circle.%variant = "Circle",
+            pair = (1, 2),
+            pair.%variant = "Pair",
+            rect.w = 2,
+            rect.h = 3,
+            rect.%variant = "Rect",
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn to_document_enum_external_repr() {
+        let actual = to_document(&scene(), &config(VariantRepr::External)).unwrap();
+        let expected = eure!({
+            point = "Point",
+            circle.Circle = 1.5,
+            pair.Pair = (1, 2),
+            rect.Rect.w = 2,
+            rect.Rect.h = 3,
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn to_document_enum_adjacent_repr() {
+        let repr = VariantRepr::Adjacent {
+            tag: "kind".to_string(),
+            content: "content".to_string(),
+        };
+        let actual = to_document(&scene(), &config(repr)).unwrap();
+        let expected = eure!({
+            point.kind = "Point",
+            circle.kind = "Circle",
+            circle.content = 1.5,
+            pair.kind = "Pair",
+            pair.content = (1, 2),
+            rect.kind = "Rect",
+            rect.content.w = 2,
+            rect.content.h = 3,
+        });
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn to_document_enum_internal_repr() {
+        #[derive(Serialize)]
+        struct Tagged {
+            point: Shape,
+            rect: Shape,
+        }
+
+        let repr = VariantRepr::Internal {
+            tag: "kind".to_string(),
+        };
+        let tagged = Tagged {
+            point: Shape::Point,
+            rect: Shape::Rect { w: 2, h: 3 },
+        };
+        let actual = to_document(&tagged, &config(repr.clone())).unwrap();
+        let expected = eure!({
+            point.kind = "Point",
+            rect.kind = "Rect",
+            rect.w = 2,
+            rect.h = 3,
+        });
+        assert_eq!(actual, expected);
+
+        let err = to_document(&Shape::Circle(1.5), &config(repr.clone())).unwrap_err();
+        assert!(
+            matches!(err, SerError::InternalTagNotMap("Circle")),
+            "got {err:?}"
+        );
+
+        let err = to_document(&Shape::Pair(1, 2), &config(repr)).unwrap_err();
+        assert!(
+            matches!(err, SerError::InternalTagNotMap("Pair")),
+            "got {err:?}"
+        );
+    }
+
+    #[test]
+    fn to_document_tuple_too_long() {
+        use serde::ser::SerializeTuple;
+
+        struct Long;
+
+        impl Serialize for Long {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut tuple = serializer.serialize_tuple(257)?;
+                for i in 0..257 {
+                    tuple.serialize_element(&i)?;
+                }
+                tuple.end()
+            }
+        }
+
+        let err = to_document(&Long, &ToDocumentConfig::default()).unwrap_err();
+        assert!(matches!(err, SerError::TupleTooLong), "got {err:?}");
+    }
+
+    #[test]
+    fn to_document_map_value_before_key() {
+        use serde::ser::SerializeMap;
+
+        struct Keyless;
+
+        impl Serialize for Keyless {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut map = serializer.serialize_map(None)?;
+                map.serialize_value(&1)?;
+                map.end()
+            }
+        }
+
+        let err = to_document(&Keyless, &ToDocumentConfig::default()).unwrap_err();
+        assert!(matches!(err, SerError::ValueBeforeKey), "got {err:?}");
+    }
+}
+
+// ============================================================================
+// Canonical fast path: Eure source → Rust value without the document IR
+// ============================================================================
+
+mod canonical {
+    use std::collections::BTreeMap;
+
+    use serde::Deserialize;
+
+    use crate::{DeError, Deserializer, from_str, is_canonical};
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Shape {
+        Point,
+        Circle(f64),
+        Rect { w: i32, h: i32 },
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Server {
+        name: String,
+        port: u16,
+        ratio: f32,
+        tags: Vec<String>,
+        pair: (i64, bool),
+        limits: BTreeMap<String, u64>,
+        parent: Option<String>,
+        shapes: Vec<Shape>,
+        note: String,
+    }
+
+    const CANONICAL: &str = r#"name = "api \"v2\"\n"
+port = 8_080
+ratio = 0.5f32
+tags = ["a", 'raw\n']
+pair = (-1, true)
+limits = { cpu => 4, "memory" => 1_024, null => 0 }
+parent = null
+shapes = ["Point", { Circle => 1.5 }, { Rect => { w => 2, h => 3 } }]
+note = ''
+"#;
+
+    fn both_paths<T: serde::de::DeserializeOwned>(input: &str) -> (T, T) {
+        let ir = Deserializer::from_str(input).unwrap();
+        assert!(!ir.is_fast_path());
+        let fast = Deserializer::from_str_canonical_fast(input).unwrap();
+        (T::deserialize(&ir).unwrap(), T::deserialize(&fast).unwrap())
+    }
+
+    #[test]
+    fn canonical_paths_agree() {
+        assert!(
+            Deserializer::from_str_canonical_fast(CANONICAL)
+                .unwrap()
+                .is_fast_path()
+        );
+        let (ir, fast) = both_paths::<Server>(CANONICAL);
+        assert_eq!(ir, fast);
+        assert_eq!(fast.name, "api \"v2\"\n");
+        assert_eq!(fast.tags, vec!["a".to_string(), "raw\\n".to_string()]);
+        assert_eq!(fast.limits["null"], 0);
+        assert_eq!(fast.shapes[2], Shape::Rect { w: 2, h: 3 });
+    }
+
+    #[test]
+    fn canonical_paths_agree_on_untyped_values() {
+        let (ir, fast) = both_paths::<serde_json::Value>(CANONICAL);
+        assert_eq!(ir, fast);
+    }
+
+    #[test]
+    fn canonical_from_str_falls_back_to_ir() {
+        let input = "@ server\nname = \"api\"\nport = 80\n";
+        assert!(
+            !Deserializer::from_str_canonical_fast(input)
+                .unwrap()
+                .is_fast_path()
+        );
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Root {
+            server: BTreeMap<String, serde_json::Value>,
+        }
+        let root: Root = from_str(input).unwrap();
+        assert_eq!(root.server["port"], 80);
+    }
+
+    #[test]
+    fn canonical_rejects_non_canonical_documents() {
+        let check = |input: &str| {
+            let cst = eure_parol::parse(input, "<input>").unwrap();
+            is_canonical(input, &cst)
+        };
+        assert!(check(""));
+        assert!(check(CANONICAL));
+        for input in [
+            "a.b = 1\n",
+            "a[] = 1\n",
+            "@ a\nb = 1\n",
+            "a = 1\na = 2\n",
+            "a = { b => 1, b => 2 }\n",
+            "\"a\\tb\" = 1\n",
+            "$variant = \"a\"\n",
+            "a = !\n",
+            "a = inf\n",
+            "a = \"x\" \\ \"y\"\n",
+            "a = `code`\n",
+            "a: text\n",
+            "a { b = 1 }\n",
+        ] {
+            assert!(!check(input), "{input:?} is not canonical");
+        }
+    }
+
+    #[test]
+    fn canonical_invalid_escape_is_an_error_on_both_paths() {
+        let input = "a = \"\\q\"\n";
+        assert!(Deserializer::from_str(input).is_err());
+        let fast = Deserializer::from_str_canonical_fast(input).unwrap();
+        let err = BTreeMap::<String, String>::deserialize(&fast).unwrap_err();
+        assert!(matches!(err, DeError::Custom(_)), "got {err:?}");
+    }
+}
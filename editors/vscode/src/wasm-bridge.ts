@@ -66,6 +66,10 @@ export class WasmBridge {
     this.core!.resolve_glob(id, files);
   }
 
+  takeScheduledTick(): number | undefined {
+    return this.core!.take_scheduled_tick();
+  }
+
   tick(): void {
     this.core!.tick();
   }
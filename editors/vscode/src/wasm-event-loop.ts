@@ -20,6 +20,8 @@ export class WasmEventLoop {
   private messageQueue: unknown[] = [];
   private processing = false;
   private needsPump = false;
+  private tickDue = false;
+  private tickTimer: ReturnType<typeof setTimeout> | null = null;
   private disposed = false;
   private cacheDir: Uri | null = null;
 
@@ -54,6 +56,10 @@ export class WasmEventLoop {
   dispose(): void {
     this.disposed = true;
     this.messageCallback = null;
+    if (this.tickTimer !== null) {
+      clearTimeout(this.tickTimer);
+      this.tickTimer = null;
+    }
   }
 
   private async pump(): Promise<void> {
@@ -61,7 +67,7 @@ export class WasmEventLoop {
     this.processing = true;
 
     try {
-      while ((this.messageQueue.length > 0 || this.needsPump) && !this.disposed) {
+      while ((this.messageQueue.length > 0 || this.needsPump || this.tickDue) && !this.disposed) {
         this.needsPump = false;
 
         while (this.messageQueue.length > 0 && !this.disposed) {
@@ -76,17 +82,27 @@ export class WasmEventLoop {
           // Yield to prevent UI blocking
           await new Promise((r) => setTimeout(r, 0));
         }
+
+        // Run deferred diagnostics once the scheduled delay has passed
+        if (this.tickDue && !this.disposed) {
+          this.tickDue = false;
+          debugLog('[EventLoop] pump: ticking');
+          this.bridge.tick();
+          this.flushOutbox();
+          await this.resolvePendingAssets();
+        }
       }
     } finally {
       this.processing = false;
       // Reschedule if messages arrived during processing
-      if ((this.needsPump || this.messageQueue.length > 0) && !this.disposed) {
+      if ((this.needsPump || this.tickDue || this.messageQueue.length > 0) && !this.disposed) {
         queueMicrotask(() => this.pump());
       }
     }
   }
 
   private flushOutbox(): void {
+    this.scheduleTick();
     const messages = this.bridge.drainOutbox();
     debugLog('[EventLoop] flushOutbox: draining ' + messages.length + ' messages');
     for (const outMsg of messages) {
@@ -95,6 +111,26 @@ export class WasmEventLoop {
     }
   }
 
+  /**
+   * Restart the tick timer if the core asked for a tick since the last check.
+   * A burst of edits keeps pushing the timer back, so it ticks once.
+   */
+  private scheduleTick(): void {
+    const delayMs = this.bridge.takeScheduledTick();
+    if (delayMs === undefined || this.disposed) return;
+    if (this.tickTimer !== null) {
+      clearTimeout(this.tickTimer);
+    }
+    this.tickTimer = setTimeout(() => {
+      this.tickTimer = null;
+      this.tickDue = true;
+      // A running pump picks the tick up before it finishes
+      if (!this.processing) {
+        void this.pump();
+      }
+    }, delayMs);
+  }
+
   private async resolvePendingAssets(): Promise<void> {
     const resolvedTextFilesInThisPump = new Set<string>();
     const resolvedGlobsInThisPump = new Set<string>();
@@ -166,7 +202,6 @@ export class WasmEventLoop {
         })
       );
 
-      this.bridge.tick();
       this.flushOutbox();
     }
   }